@@ -35,6 +35,10 @@ fn main() {
             true => String::from("si"),
             false => String::from("no"),
         },
+        timeout: None,
+        show_remaining_time: false,
+        show_elapsed_time: false,
+        max_attempts: None,
         render_config: RenderConfig::default(),
     }
     .prompt()
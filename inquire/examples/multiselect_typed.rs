@@ -0,0 +1,47 @@
+use std::fmt::{Display, Formatter};
+
+use inquire::MultiSelect;
+
+fn main() {
+    let services = vec![
+        Service::new("api", 3),
+        Service::new("worker", 2),
+        Service::new("scheduler", 1),
+        Service::new("gateway", 4),
+    ];
+
+    let ans = MultiSelect::new("Services to restart:", services)
+        .with_option_key(&|service| service.name.to_string())
+        .with_default_keys(&["api"])
+        .prompt();
+
+    match ans {
+        Ok(services) => {
+            let replicas: u32 = services.iter().map(|service| service.replicas).sum();
+            println!(
+                "Restarting {} services, {} replicas in total",
+                services.len(),
+                replicas
+            );
+        }
+        Err(_) => println!("No services were restarted"),
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Service {
+    name: &'static str,
+    replicas: u32,
+}
+
+impl Service {
+    fn new(name: &'static str, replicas: u32) -> Self {
+        Self { name, replicas }
+    }
+}
+
+impl Display for Service {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({} replicas)", self.name, self.replicas)
+    }
+}
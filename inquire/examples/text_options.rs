@@ -14,12 +14,20 @@ fn main() {
         message: "How are you feeling?",
         initial_value: None,
         default: None,
+        current_value: None,
         placeholder: Some("Good"),
+        skip_option: None,
         help_message: None,
         formatter: Text::DEFAULT_FORMATTER,
+        answer_mapper: None,
+        input_transformer: None,
         validators: Vec::new(),
         page_size: Text::DEFAULT_PAGE_SIZE,
         autocompleter: None,
+        timeout: None,
+        show_remaining_time: false,
+        show_elapsed_time: false,
+        max_attempts: None,
         render_config: RenderConfig::default(),
     }
     .prompt()
@@ -0,0 +1,35 @@
+use inquire::{list_option::ListOption, validator::Validation, TransferList};
+
+fn main() {
+    let steps = vec![
+        "Checkout",
+        "Install dependencies",
+        "Lint",
+        "Build",
+        "Unit tests",
+        "Integration tests",
+        "Package",
+        "Deploy",
+    ];
+
+    let validator = |a: &[ListOption<&&str>]| {
+        if a.is_empty() {
+            return Ok(Validation::Invalid("Choose at least one step".into()));
+        }
+
+        Ok(Validation::Valid)
+    };
+
+    let ans = TransferList::new(
+        "Which steps should the pipeline run, and in which order?",
+        steps,
+    )
+    .with_default([0, 3])
+    .with_validator(validator)
+    .prompt();
+
+    match ans {
+        Ok(steps) => println!("Pipeline: {}", steps.join(" -> ")),
+        Err(_) => println!("The pipeline could not be processed"),
+    }
+}
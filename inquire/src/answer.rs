@@ -0,0 +1,278 @@
+//! Type-erased answers, used to store the answers of different prompts in
+//! the same collection.
+
+use std::fmt::{self, Display};
+
+use crate::{formatter::DEFAULT_BOOL_FORMATTER, list_option::ListOption};
+
+/// Answer to a prompt, whatever the type returned by the prompt.
+///
+/// Wizards asking a dynamic sequence of questions can use it to keep all
+/// answers in a single collection, e.g. a `HashMap<&str, Answer>`, instead
+/// of boxing them. Prompt results are converted through [`IntoAnswer`].
+///
+/// # Example
+///
+/// ```no_run
+/// use std::collections::HashMap;
+///
+/// use inquire::{answer::{Answer, IntoAnswer}, Confirm, CustomType, Select, Text};
+///
+/// let mut answers: HashMap<&str, Answer> = HashMap::new();
+///
+/// answers.insert("name", Text::new("Name:").prompt()?.into_answer());
+/// answers.insert("age", CustomType::<u8>::new("Age:").prompt()?.into_answer());
+/// answers.insert("newsletter", Confirm::new("Subscribe?").prompt()?.into_answer());
+/// answers.insert(
+///     "plan",
+///     Select::new("Plan:", vec!["Free", "Pro"]).raw_prompt()?.into_answer(),
+/// );
+///
+/// if let Some(Answer::Int(age)) = answers.get("age") {
+///     println!("Next year, you will be {}", age + 1);
+/// }
+/// # inquire::error::InquireResult::Ok(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Answer {
+    /// Text, e.g. returned by `Text`, `Password` and `Editor` prompts.
+    Text(String),
+
+    /// Boolean, e.g. returned by `Confirm` prompts.
+    Bool(bool),
+
+    /// Integer, e.g. returned by `CustomType<u32>` and `Rating` prompts.
+    Int(i128),
+
+    /// Floating-point number, e.g. returned by `CustomType<f64>` prompts.
+    Float(f64),
+
+    /// Date, returned by `DateSelect` prompts.
+    #[cfg(feature = "date")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "date")))]
+    Date(chrono::NaiveDate),
+
+    /// Option selected in a list, e.g. by a `Select` prompt, with its value
+    /// converted to the string displayed to the user.
+    Option(ListOption<String>),
+
+    /// Options selected in a list, e.g. by a `MultiSelect` prompt, with
+    /// their values converted to the strings displayed to the user.
+    Options(Vec<ListOption<String>>),
+}
+
+impl Answer {
+    /// Returns the text, if the answer is [`Answer::Text`].
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Returns the boolean, if the answer is [`Answer::Bool`].
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the integer, if the answer is [`Answer::Int`].
+    pub fn as_int(&self) -> Option<i128> {
+        match self {
+            Self::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the number, if the answer is [`Answer::Float`] or [`Answer::Int`].
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            Self::Float(value) => Some(*value),
+            Self::Int(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+
+    /// Returns the date, if the answer is [`Answer::Date`].
+    #[cfg(feature = "date")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "date")))]
+    pub fn as_date(&self) -> Option<chrono::NaiveDate> {
+        match self {
+            Self::Date(date) => Some(*date),
+            _ => None,
+        }
+    }
+
+    /// Returns the selected option, if the answer is [`Answer::Option`].
+    pub fn as_option(&self) -> Option<&ListOption<String>> {
+        match self {
+            Self::Option(option) => Some(option),
+            _ => None,
+        }
+    }
+
+    /// Returns the selected options, if the answer is [`Answer::Options`].
+    pub fn as_options(&self) -> Option<&[ListOption<String>]> {
+        match self {
+            Self::Options(options) => Some(options),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    /// Formats the answer as prompts display it once answered, e.g. lists
+    /// of options are joined by commas.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(text) => f.write_str(text),
+            Self::Bool(value) => f.write_str(&DEFAULT_BOOL_FORMATTER(*value)),
+            Self::Int(value) => write!(f, "{}", value),
+            Self::Float(value) => write!(f, "{}", value),
+            #[cfg(feature = "date")]
+            Self::Date(date) => f.write_str(&crate::formatter::DEFAULT_DATE_FORMATTER(*date)),
+            Self::Option(option) => f.write_str(&option.value),
+            Self::Options(options) => {
+                for (i, option) in options.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    f.write_str(&option.value)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Conversion of prompt results into an [`Answer`].
+///
+/// Implemented for the types returned by the general-purpose prompts:
+/// strings, booleans, numbers, dates and the [`ListOption`]s returned by
+/// the `raw_prompt` methods of list prompts.
+pub trait IntoAnswer {
+    /// Converts the prompt result into an [`Answer`].
+    fn into_answer(self) -> Answer;
+}
+
+impl IntoAnswer for Answer {
+    fn into_answer(self) -> Answer {
+        self
+    }
+}
+
+impl IntoAnswer for String {
+    fn into_answer(self) -> Answer {
+        Answer::Text(self)
+    }
+}
+
+impl IntoAnswer for &str {
+    fn into_answer(self) -> Answer {
+        Answer::Text(self.to_owned())
+    }
+}
+
+impl IntoAnswer for bool {
+    fn into_answer(self) -> Answer {
+        Answer::Bool(self)
+    }
+}
+
+macro_rules! int_into_answer {
+    ($($t:ty),*) => {
+        $(
+            impl IntoAnswer for $t {
+                fn into_answer(self) -> Answer {
+                    Answer::Int(i128::from(self))
+                }
+            }
+        )*
+    };
+}
+
+int_into_answer!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl IntoAnswer for isize {
+    fn into_answer(self) -> Answer {
+        Answer::Int(self as i128)
+    }
+}
+
+impl IntoAnswer for usize {
+    fn into_answer(self) -> Answer {
+        Answer::Int(self as i128)
+    }
+}
+
+impl IntoAnswer for f32 {
+    fn into_answer(self) -> Answer {
+        Answer::Float(f64::from(self))
+    }
+}
+
+impl IntoAnswer for f64 {
+    fn into_answer(self) -> Answer {
+        Answer::Float(self)
+    }
+}
+
+#[cfg(feature = "date")]
+impl IntoAnswer for chrono::NaiveDate {
+    fn into_answer(self) -> Answer {
+        Answer::Date(self)
+    }
+}
+
+impl<T> IntoAnswer for ListOption<T>
+where
+    T: Display,
+{
+    fn into_answer(self) -> Answer {
+        Answer::Option(ListOption::new(self.index, self.value.to_string()))
+    }
+}
+
+impl<T> IntoAnswer for Vec<ListOption<T>>
+where
+    T: Display,
+{
+    fn into_answer(self) -> Answer {
+        Answer::Options(
+            self.into_iter()
+                .map(|option| ListOption::new(option.index, option.value.to_string()))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Answer, IntoAnswer};
+    use crate::list_option::ListOption;
+
+    #[test]
+    fn prompt_results_are_converted() {
+        assert_eq!(Answer::Text("Ferris".into()), "Ferris".into_answer());
+        assert_eq!(Answer::Bool(true), true.into_answer());
+        assert_eq!(Answer::Int(42), 42u8.into_answer());
+        assert_eq!(Answer::Int(-7), (-7i64).into_answer());
+        assert_eq!(Answer::Float(1.5), 1.5f32.into_answer());
+        assert_eq!(
+            Answer::Option(ListOption::new(1, "2".into())),
+            ListOption::new(1, 2).into_answer()
+        );
+    }
+
+    #[test]
+    fn answers_are_displayed_like_answered_prompts() {
+        let options = vec![ListOption::new(0, "Cheese"), ListOption::new(2, "Olives")];
+
+        assert_eq!("Cheese, Olives", options.into_answer().to_string());
+        assert_eq!("No", false.into_answer().to_string());
+        assert_eq!(Some(3.0), 3u32.into_answer().as_float());
+        assert_eq!(None, "3".into_answer().as_int());
+    }
+}
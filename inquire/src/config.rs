@@ -1,23 +1,222 @@
 //! Global config definitions.
 
-use std::sync::Mutex;
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
 use once_cell::sync::Lazy;
 
-use crate::ui::RenderConfig;
+use crate::ui::{KeyBindings, RenderConfig, Styled};
 
-static GLOBAL_RENDER_CONFIGURATION: Lazy<Mutex<RenderConfig<'static>>> =
-    Lazy::new(|| Mutex::new(RenderConfig::default()));
+/// Render config set with [`set_global_render_config`], if any.
+static GLOBAL_RENDER_CONFIGURATION: Mutex<Option<RenderConfig<'static>>> = Mutex::new(None);
 
+/// Returns the global render config, or the default one, built anew so that
+/// it follows the [detected background](crate::ui::TerminalBackground::detect).
 pub fn get_configuration() -> RenderConfig<'static> {
-    *GLOBAL_RENDER_CONFIGURATION.lock().unwrap()
+    GLOBAL_RENDER_CONFIGURATION
+        .lock()
+        .unwrap()
+        .unwrap_or_default()
 }
 
 /// Acquires a write lock to the global RenderConfig object
 /// and updates the inner value with the provided argument.
 pub fn set_global_render_config(config: RenderConfig<'static>) {
     let mut guard = GLOBAL_RENDER_CONFIGURATION.lock().unwrap();
-    *guard = config;
+    *guard = Some(config);
+}
+
+static GLOBAL_KEY_BINDINGS: Lazy<Mutex<KeyBindings<'static>>> =
+    Lazy::new(|| Mutex::new(KeyBindings::default()));
+
+pub fn get_key_bindings() -> KeyBindings<'static> {
+    *GLOBAL_KEY_BINDINGS.lock().unwrap()
+}
+
+/// Acquires a write lock to the global KeyBindings object
+/// and updates the inner value with the provided argument.
+pub fn set_global_key_bindings(bindings: KeyBindings<'static>) {
+    let mut guard = GLOBAL_KEY_BINDINGS.lock().unwrap();
+    *guard = bindings;
+}
+
+/// Behavior of a prompt started while another one, running on another
+/// thread, is using the terminal.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum BusyTerminalBehavior {
+    /// The prompt fails right away with
+    /// [`InquireError::TerminalBusy`](crate::InquireError::TerminalBusy).
+    #[default]
+    Fail,
+
+    /// The prompt waits for the other one to finish, prompts started on
+    /// different threads being answered one after the other.
+    Wait,
+}
+
+static GLOBAL_BUSY_TERMINAL_BEHAVIOR: Mutex<BusyTerminalBehavior> =
+    Mutex::new(BusyTerminalBehavior::Fail);
+
+pub fn get_busy_terminal_behavior() -> BusyTerminalBehavior {
+    *GLOBAL_BUSY_TERMINAL_BEHAVIOR.lock().unwrap()
+}
+
+/// Acquires a write lock to the global BusyTerminalBehavior object
+/// and updates the inner value with the provided argument.
+///
+/// A prompt started while another one is running on the same thread,
+/// e.g. from a callback, fails regardless of this setting.
+pub fn set_global_busy_terminal_behavior(behavior: BusyTerminalBehavior) {
+    let mut guard = GLOBAL_BUSY_TERMINAL_BEHAVIOR.lock().unwrap();
+    *guard = behavior;
+}
+
+/// Summary of a resolved prompt, passed to the
+/// [global telemetry hook](set_global_telemetry_hook).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PromptTelemetry {
+    /// Identifier of the prompt, which is its message.
+    pub prompt_id: String,
+
+    /// Time elapsed between the prompt being displayed and its resolution.
+    pub duration: Duration,
+
+    /// Number of times the user tried to submit an answer, including the
+    /// accepted one.
+    pub attempts: usize,
+
+    /// Whether the prompt resolved without an answer, e.g. because the user
+    /// canceled it or it timed out.
+    pub canceled: bool,
+
+    /// Whether the user deliberately skipped the prompt by choosing its skip
+    /// option, which is not counted as a cancellation.
+    pub skipped: bool,
+}
+
+type TelemetryHook = Arc<dyn Fn(&PromptTelemetry) + Send + Sync>;
+
+static GLOBAL_TELEMETRY_HOOK: Mutex<Option<TelemetryHook>> = Mutex::new(None);
+
+/// Sets the callback invoked after each prompt of the process resolves, be
+/// it answered or canceled, e.g. to aggregate UX metrics without wrapping
+/// every call site.
+///
+/// Prompts failing because of the terminal, e.g. with an IO error, are not
+/// reported.
+///
+/// # Example
+///
+/// ```
+/// use inquire::PromptTelemetry;
+///
+/// inquire::set_global_telemetry_hook(|telemetry: &PromptTelemetry| {
+///     eprintln!(
+///         "{} took {:?} ({} attempts, canceled: {})",
+///         telemetry.prompt_id, telemetry.duration, telemetry.attempts, telemetry.canceled
+///     );
+/// });
+/// ```
+pub fn set_global_telemetry_hook<F>(hook: F)
+where
+    F: Fn(&PromptTelemetry) + Send + Sync + 'static,
+{
+    *GLOBAL_TELEMETRY_HOOK.lock().unwrap() = Some(Arc::new(hook));
+}
+
+/// Removes the global telemetry hook.
+pub fn clear_global_telemetry_hook() {
+    *GLOBAL_TELEMETRY_HOOK.lock().unwrap() = None;
+}
+
+/// Reports a resolved prompt to the global telemetry hook, if any. The hook
+/// is called without holding the lock, so that it can run prompts itself.
+pub(crate) fn report_telemetry(telemetry: impl FnOnce() -> PromptTelemetry) {
+    let hook = GLOBAL_TELEMETRY_HOOK.lock().unwrap().clone();
+
+    if let Some(hook) = hook {
+        hook(&telemetry());
+    }
+}
+
+/// Frame rendered by a prompt, passed to the
+/// [global frame hook](set_global_frame_hook).
+#[derive(Clone, Debug)]
+pub struct RenderedFrame {
+    /// Time elapsed between the hook being set and the frame being rendered.
+    pub elapsed: Duration,
+
+    /// Styled content of each row of the frame.
+    pub rows: Vec<Vec<Styled<String>>>,
+}
+
+impl RenderedFrame {
+    /// Plain text of the frame, one line per row, without styles.
+    pub fn text(&self) -> String {
+        self.rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|styled| styled.content.as_str())
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Content of the frame with the ANSI escape codes of its styles, one line
+    /// per row, e.g. to be replayed as an asciinema event.
+    #[cfg(feature = "crossterm")]
+    pub fn to_ansi(&self) -> String {
+        crate::terminal::crossterm::CrosstermTerminal::styled_frame(&self.rows)
+    }
+}
+
+type FrameHook = Arc<dyn Fn(&RenderedFrame) + Send + Sync>;
+
+static GLOBAL_FRAME_HOOK: Mutex<Option<(Instant, FrameHook)>> = Mutex::new(None);
+
+/// Sets the callback invoked with every frame rendered by the prompts of the
+/// process, timestamped relatively to the moment the hook is set, e.g. to
+/// reconstruct a demo of the prompts of a CLI without recording the screen.
+///
+/// Frames are only rendered when their content changes, so the time between
+/// two frames includes the time the user spent looking at the first one.
+///
+/// # Example
+///
+/// ```
+/// use inquire::RenderedFrame;
+///
+/// inquire::set_global_frame_hook(|frame: &RenderedFrame| {
+///     eprintln!("[{:.3}] {}", frame.elapsed.as_secs_f64(), frame.text());
+/// });
+/// ```
+pub fn set_global_frame_hook<F>(hook: F)
+where
+    F: Fn(&RenderedFrame) + Send + Sync + 'static,
+{
+    *GLOBAL_FRAME_HOOK.lock().unwrap() = Some((Instant::now(), Arc::new(hook)));
+}
+
+/// Removes the global frame hook.
+pub fn clear_global_frame_hook() {
+    *GLOBAL_FRAME_HOOK.lock().unwrap() = None;
+}
+
+/// Reports a rendered frame to the global frame hook, if any. The hook is
+/// called without holding the lock, as with the telemetry hook.
+pub(crate) fn report_frame(rows: &[&[Styled<String>]]) {
+    let hook = GLOBAL_FRAME_HOOK.lock().unwrap().clone();
+
+    if let Some((started, hook)) = hook {
+        hook(&RenderedFrame {
+            elapsed: started.elapsed(),
+            rows: rows.iter().map(|row| row.to_vec()).collect(),
+        });
+    }
 }
 
 /// Default page size when displaying options to the user.
@@ -12,6 +12,11 @@ pub type CustomUserError = Box<dyn Error + Send + Sync + 'static>;
 pub enum InquireError {
     /// The input device is not a TTY, which means that enabling raw mode
     /// on the terminal in order to listen to input events is not possible.
+    ///
+    /// Also returned when the prompts would be rendered to something other
+    /// than a terminal, e.g. when stderr is redirected to a file. Check
+    /// [`is_interactive`](crate::is_interactive) beforehand to fall back
+    /// to non-interactive input, such as flags or environment variables.
     NotTTY,
 
     /// The given prompt configuration is not valid. A detailed error message
@@ -24,6 +29,13 @@ pub enum InquireError {
     /// The user canceled the operation by pressing ESC.
     OperationCanceled,
 
+    /// The user chose the skip option of the prompt, e.g. `(none)`, to
+    /// leave an optional question unanswered.
+    ///
+    /// Prompt methods returning an `Option`, such as `prompt_skippable`,
+    /// return `Ok(None)` instead.
+    OperationSkipped,
+
     /// The operation was interrupted by the user after they
     /// pressed Ctrl+C.
     ///
@@ -32,6 +44,20 @@ pub enum InquireError {
     /// pressing Ctrl+C will trigger SIGINT.
     OperationInterrupted,
 
+    /// The prompt was not answered before its configured timeout elapsed.
+    Timeout,
+
+    /// The answers given to the prompt were rejected more times than its
+    /// configured maximum number of attempts.
+    TooManyAttempts,
+
+    /// Another prompt, running on another thread or on the same one, was
+    /// using the terminal when the prompt started.
+    ///
+    /// See [`set_global_busy_terminal_behavior`](crate::set_global_busy_terminal_behavior)
+    /// to wait for the terminal to be free instead.
+    TerminalBusy,
+
     /// Error while executing IO operations.
     Custom(CustomUserError),
 }
@@ -75,15 +101,23 @@ impl From<io::Error> for InquireError {
 impl fmt::Display for InquireError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            InquireError::NotTTY => f.write_str("The input device is not a TTY"),
+            InquireError::NotTTY => f.write_str(
+                "The input device is not a TTY, prompts can only be answered from a terminal",
+            ),
             InquireError::InvalidConfiguration(s) => {
                 write!(f, "The prompt configuration is invalid: {}", s)
             }
             InquireError::IO(err) => write!(f, "IO error: {}", err),
             InquireError::OperationCanceled => f.write_str("Operation was canceled by the user"),
+            InquireError::OperationSkipped => f.write_str("Operation was skipped by the user"),
             InquireError::OperationInterrupted => {
                 f.write_str("Operation was interrupted by the user")
             }
+            InquireError::Timeout => f.write_str("The prompt timed out waiting for an answer"),
+            InquireError::TooManyAttempts => f.write_str("The answer was rejected too many times"),
+            InquireError::TerminalBusy => {
+                f.write_str("The terminal is being used by another prompt")
+            }
             InquireError::Custom(err) => write!(f, "User-provided error: {}", err),
         }
     }
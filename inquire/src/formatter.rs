@@ -46,7 +46,7 @@
 //! ? What's your name? My name is Mikael
 //! ```
 
-use crate::list_option::ListOption;
+use crate::{list_option::ListOption, ui::Styled, validator::MultiOptionContext};
 
 /// Type alias for formatters that receive a string slice as the input,
 /// required by [Text](crate::Text) and [Password](crate::Password) for example.
@@ -129,6 +129,118 @@ pub type OptionFormatter<'a, T> = &'a dyn Fn(ListOption<&T>) -> String;
 /// ```
 pub type MultiOptionFormatter<'a, T> = &'a dyn Fn(&[ListOption<&T>]) -> String;
 
+/// Type alias for formatters used in [Select](crate::Select) prompts that
+/// also receive the filter text typed by the user at the time of submission.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::list_option::ListOption;
+/// use inquire::formatter::FilteredOptionFormatter;
+///
+/// let formatter: FilteredOptionFormatter<str> = &|i, filter| match filter.is_empty() {
+///     true => i.value.to_string(),
+///     false => format!("{} (searched for '{}')", i.value, filter),
+/// };
+/// assert_eq!(String::from("a"), formatter(ListOption::new(0, "a"), ""));
+/// assert_eq!(String::from("b (searched for 'x')"), formatter(ListOption::new(1, "b"), "x"));
+/// ```
+pub type FilteredOptionFormatter<'a, T> = &'a dyn Fn(ListOption<&T>, &str) -> String;
+
+/// Type alias for formatters used in [`MultiSelect`](crate::MultiSelect) prompts
+/// that also receive the filter text typed by the user at the time of submission.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::list_option::ListOption;
+/// use inquire::formatter::FilteredMultiOptionFormatter;
+///
+/// let formatter: FilteredMultiOptionFormatter<str> =
+///     &|opts, filter| format!("{} options matching '{}'", opts.len(), filter);
+///
+/// let ans = vec![ListOption::new(0, "a"), ListOption::new(3, "d")];
+/// assert_eq!(String::from("2 options matching 'x'"), formatter(&ans, "x"));
+/// ```
+pub type FilteredMultiOptionFormatter<'a, T> = &'a dyn Fn(&[ListOption<&T>], &str) -> String;
+
+/// Type alias for formatters used in [`MultiSelect`](crate::MultiSelect) prompts
+/// that also receive the [`MultiOptionContext`] of the prompt, with every option
+/// and the filter text typed by the user at the time of submission.
+///
+/// The selected options keep their original indexes, which along with the
+/// total number of options allows summaries such as `3/10 modules selected`.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::list_option::ListOption;
+/// use inquire::formatter::MultiOptionContextFormatter;
+/// use inquire::validator::MultiOptionContext;
+///
+/// let formatter: MultiOptionContextFormatter<str> = &|opts, context| {
+///     let positions: Vec<String> = opts.iter().map(|o| (o.index + 1).to_string()).collect();
+///     format!(
+///         "{}/{} modules selected ({})",
+///         opts.len(),
+///         context.options.len(),
+///         positions.join(", ")
+///     )
+/// };
+///
+/// let options: Vec<ListOption<&str>> = ["a", "b", "c", "d", "e"]
+///     .iter()
+///     .enumerate()
+///     .map(|(i, o)| ListOption::new(i, *o))
+///     .collect();
+/// let context = MultiOptionContext::new("Modules:", &options);
+///
+/// let ans = vec![ListOption::new(0, "a"), ListOption::new(3, "d")];
+/// assert_eq!(String::from("2/5 modules selected (1, 4)"), formatter(&ans, &context));
+/// ```
+pub type MultiOptionContextFormatter<'a, T> =
+    &'a dyn Fn(&[ListOption<&T>], &MultiOptionContext<'_, T>) -> String;
+
+/// State of an option listed in [`Select`](crate::Select) and
+/// [`MultiSelect`](crate::MultiSelect) prompts while they are displayed.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct OptionState {
+    /// Whether the option is under the cursor.
+    pub highlighted: bool,
+
+    /// Whether the option is checked. Always `false` in `Select` prompts.
+    pub checked: bool,
+}
+
+/// Type alias for formatters controlling how each option is displayed in
+/// [`Select`](crate::Select) and [`MultiSelect`](crate::MultiSelect) prompts
+/// while they are displayed, independently of the final answer.
+///
+/// Formatters receive each listed option along with its [`OptionState`] and
+/// return the text to display. Its style is applied on top of the option style
+/// of the render config, e.g. to color options by status.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::{LiveOptionFormatter, OptionState};
+/// use inquire::ui::{Color, Styled};
+///
+/// let formatter: LiveOptionFormatter<str> = &|pod, state| match pod.ends_with("(failed)") {
+///     true => Styled::new(format!("{} [!]", pod)).with_fg(Color::LightRed),
+///     false if state.highlighted => Styled::new(format!("{} <", pod)),
+///     false => Styled::new(pod.to_string()),
+/// };
+///
+/// let state = OptionState::default();
+/// assert_eq!("api (failed) [!]", formatter("api (failed)", state).content);
+/// assert_eq!(Some(Color::LightRed), formatter("api (failed)", state).style.fg);
+///
+/// let state = OptionState { highlighted: true, checked: false };
+/// assert_eq!("worker <", formatter("worker", state).content);
+/// ```
+pub type LiveOptionFormatter<'a, T> = &'a dyn Fn(&T, OptionState) -> Styled<String>;
+
 /// Type alias for formatters used in [`CustomType`](crate::CustomType) prompts.
 ///
 /// Formatters receive the user input and return a [String] to be displayed
@@ -148,8 +260,84 @@ pub type MultiOptionFormatter<'a, T> = &'a dyn Fn(&[ListOption<&T>]) -> String;
 /// ```
 pub type CustomTypeFormatter<'a, T> = &'a dyn Fn(T) -> String;
 
-#[cfg(feature = "date")]
+/// Type alias for formatters used in [`CheckboxMatrix`](crate::CheckboxMatrix) prompts.
+///
+/// Formatters receive every row paired with the columns selected for it
+/// and return a [String] to be displayed to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::MatrixFormatter;
+///
+/// let formatter: MatrixFormatter<str, str> = &|rows| {
+///     let total: usize = rows.iter().map(|(_, cols)| cols.len()).sum();
+///     format!("{} permissions granted", total)
+/// };
+///
+/// let ans = vec![("alice", vec!["read", "write"]), ("bob", vec!["read"])];
+/// assert_eq!(String::from("3 permissions granted"), formatter(&ans));
+/// ```
+pub type MatrixFormatter<'a, R, C> = &'a dyn Fn(&[(&R, Vec<&C>)]) -> String;
+
+/// Type alias for formatters used in [`Likert`](crate::Likert) prompts.
+///
+/// Formatters receive every statement paired with the label of the point of
+/// the scale chosen for it, and return a [String] to be displayed to the user
+/// as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::LikertFormatter;
+///
+/// let formatter: LikertFormatter<str> = &|answers| {
+///     let agreed = answers.iter().filter(|(_, label)| label.ends_with("Agree")).count();
+///     format!("{} of {} statements agreed", agreed, answers.len())
+/// };
+///
+/// let ans = vec![("Fast", "Agree"), ("Cheap", "Neutral"), ("Safe", "Strongly Agree")];
+/// assert_eq!(String::from("2 of 3 statements agreed"), formatter(&ans));
+/// ```
+pub type LikertFormatter<'a, S> = &'a dyn Fn(&[(&S, &str)]) -> String;
+
+/// Type alias for formatters used in [`Rating`](crate::Rating) prompts.
+///
+/// Formatters receive the submitted value and the maximum value of the scale,
+/// and return a [String] to be displayed to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::RatingFormatter;
+///
+/// let formatter: RatingFormatter = &|value, max| "*".repeat(value as usize) + &"-".repeat((max - value) as usize);
+///
+/// assert_eq!(String::from("***--"), formatter(3, 5));
+/// assert_eq!(String::from("*"), formatter(1, 1));
+/// ```
+pub type RatingFormatter<'a> = &'a dyn Fn(u8, u8) -> String;
 
+/// Type alias for formatters used in [`KeyValue`](crate::KeyValue) prompts.
+///
+/// Formatters receive the entered key-value pairs and return a [String] to be
+/// displayed to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::KeyValueFormatter;
+///
+/// let formatter: KeyValueFormatter = &|entries| format!("{} headers", entries.len());
+///
+/// assert_eq!(
+///     String::from("1 headers"),
+///     formatter(&[("Accept".to_string(), "text/html".to_string())]),
+/// );
+/// ```
+pub type KeyValueFormatter<'a> = &'a dyn Fn(&[(String, String)]) -> String;
+
+#[cfg(feature = "date")]
 /// Type alias for formatters used in [`DateSelect`](crate::DateSelect) prompts.
 ///
 /// Formatters receive the user input and return a [String] to be displayed
@@ -170,6 +358,48 @@ pub type CustomTypeFormatter<'a, T> = &'a dyn Fn(T) -> String;
 /// ```
 pub type DateFormatter<'a> = &'a dyn Fn(chrono::NaiveDate) -> String;
 
+#[cfg(feature = "date")]
+/// Type alias for formatters used in [`MultiDateSelect`](crate::MultiDateSelect) prompts.
+///
+/// Formatters receive the checked dates, in chronological order, and return
+/// a [String] to be displayed to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use inquire::formatter::MultiDateFormatter;
+///
+/// let formatter: MultiDateFormatter = &|dates| format!("{} days", dates.len());
+///
+/// assert_eq!(
+///     String::from("2 days"),
+///     formatter(&[NaiveDate::from_ymd(2021, 7, 25), NaiveDate::from_ymd(2021, 7, 26)]),
+/// );
+/// ```
+pub type MultiDateFormatter<'a> = &'a dyn Fn(&[chrono::NaiveDate]) -> String;
+
+#[cfg(feature = "date")]
+/// Type alias for formatters used in [`TimeSelect`](crate::TimeSelect) prompts.
+///
+/// Formatters receive the user input and return a [String] to be displayed
+/// to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveTime;
+/// use inquire::formatter::TimeFormatter;
+///
+/// let formatter: TimeFormatter = &|val| val.format("%Hh%M").to_string();
+///
+/// assert_eq!(
+///     String::from("14h30"),
+///     formatter(NaiveTime::from_hms(14, 30, 0)),
+/// );
+/// ```
+pub type TimeFormatter<'a> = &'a dyn Fn(chrono::NaiveTime) -> String;
+
 /// String formatter used by default in inputs that return a `String` as input.
 /// Its behavior is to just echo the received input.
 ///
@@ -204,6 +434,32 @@ pub const DEFAULT_BOOL_FORMATTER: BoolFormatter<'_> = &|ans| {
     }
 };
 
+/// String formatter used by default in [`KeyValue`](crate::KeyValue) prompts.
+/// Prints the entries as `key=value` pairs separated by commas.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::DEFAULT_KEY_VALUE_FORMATTER;
+///
+/// let formatter = DEFAULT_KEY_VALUE_FORMATTER;
+///
+/// assert_eq!(
+///     String::from("env=prod, region=eu"),
+///     formatter(&[
+///         ("env".to_string(), "prod".to_string()),
+///         ("region".to_string(), "eu".to_string()),
+///     ]),
+/// );
+/// ```
+pub const DEFAULT_KEY_VALUE_FORMATTER: KeyValueFormatter<'_> = &|entries| {
+    entries
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<_>>()
+        .join(", ")
+};
+
 #[cfg(feature = "date")]
 /// String formatter used by default in [`DateSelect`](crate::DateSelect) prompts.
 /// Prints the selected date in the format: Month Day, Year.
@@ -226,3 +482,28 @@ pub const DEFAULT_BOOL_FORMATTER: BoolFormatter<'_> = &|ans| {
 /// );
 /// ```
 pub const DEFAULT_DATE_FORMATTER: DateFormatter<'_> = &|val| val.format("%B %-e, %Y").to_string();
+
+#[cfg(feature = "date")]
+/// String formatter used by default in [`MultiDateSelect`](crate::MultiDateSelect) prompts.
+/// Prints the checked dates in the format: Month Day, Year, separated by semicolons.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use inquire::formatter::DEFAULT_MULTI_DATE_FORMATTER;
+///
+/// let formatter = DEFAULT_MULTI_DATE_FORMATTER;
+///
+/// assert_eq!(
+///     String::from("July 25, 2021; August 1, 2021"),
+///     formatter(&[NaiveDate::from_ymd(2021, 7, 25), NaiveDate::from_ymd(2021, 8, 1)]),
+/// );
+/// ```
+pub const DEFAULT_MULTI_DATE_FORMATTER: MultiDateFormatter<'_> = &|dates| {
+    dates
+        .iter()
+        .map(|date| DEFAULT_DATE_FORMATTER(*date))
+        .collect::<Vec<_>>()
+        .join("; ")
+};
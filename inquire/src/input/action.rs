@@ -51,6 +51,13 @@ impl InnerAction for InputAction {
             }
             Key::Right(_) => Self::MoveCursor(Magnitude::Char, LineDirection::Right),
 
+            // Alt combinations are left to the application, but Ctrl+Alt is
+            // kept as it is how AltGr characters are reported on Windows.
+            Key::Char(_, m)
+                if m.contains(KeyModifiers::ALT) && !m.contains(KeyModifiers::CONTROL) =>
+            {
+                return None;
+            }
             Key::Char(c, _) => Self::Write(c),
             _ => return None,
         };
@@ -200,6 +207,22 @@ mod test {
         );
     }
 
+    #[test]
+    fn alt_chars_are_not_written() {
+        assert_eq!(
+            InputAction::from_key(Key::Char('x', KeyModifiers::ALT), &()),
+            None
+        );
+        // AltGr characters on Windows
+        assert_eq!(
+            InputAction::from_key(
+                Key::Char('@', KeyModifiers::CONTROL | KeyModifiers::ALT),
+                &()
+            ),
+            Some(InputAction::Write('@'))
+        );
+    }
+
     #[test]
     fn page_up_and_down_do_nothing() {
         assert_eq!(
@@ -96,6 +96,19 @@ impl Input {
         }
     }
 
+    /// Writes each character of the string at the current cursor position.
+    pub fn insert_str(&mut self, s: &str) -> InputActionResult {
+        if s.is_empty() {
+            return InputActionResult::Clean;
+        }
+
+        for c in s.chars() {
+            self.insert(c);
+        }
+
+        InputActionResult::ContentChanged
+    }
+
     pub fn clear(&mut self) {
         self.content.clear();
         self.cursor = 0;
@@ -66,6 +66,7 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![allow(clippy::bool_to_int_with_if)]
 mod ansi;
+pub mod answer;
 pub mod autocompletion;
 mod config;
 #[cfg(feature = "date")]
@@ -75,15 +76,32 @@ pub mod formatter;
 mod input;
 pub mod list_option;
 pub mod parser;
+pub mod persistence;
+pub mod prompt_thread;
 mod prompts;
+pub mod response;
+mod session;
 mod terminal;
+#[cfg(feature = "crossterm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
+pub mod testing;
 pub mod type_aliases;
 pub mod ui;
 mod utils;
 pub mod validator;
 
+#[cfg(feature = "callback")]
+#[cfg_attr(docsrs, doc(cfg(feature = "callback")))]
+pub use crate::terminal::callback;
+
 pub use crate::autocompletion::Autocomplete;
-pub use crate::config::set_global_render_config;
+pub use crate::config::{
+    clear_global_frame_hook, clear_global_telemetry_hook, set_global_busy_terminal_behavior,
+    set_global_frame_hook, set_global_key_bindings, set_global_render_config,
+    set_global_telemetry_hook, BusyTerminalBehavior, PromptTelemetry, RenderedFrame,
+};
 pub use crate::error::{CustomUserError, InquireError};
 pub use crate::input::action::*;
 pub use crate::prompts::*;
+pub use crate::session::{session, Session};
+pub use crate::terminal::is_interactive;
@@ -47,3 +47,118 @@ where
         self.value.fmt(f)
     }
 }
+
+/// Answer of a prompt with a filter input, paired with the filter text typed
+/// by the user at the time of submission.
+///
+/// Returned by [`Select::raw_prompt_with_filter`](crate::Select::raw_prompt_with_filter)
+/// and [`MultiSelect::raw_prompt_with_filter`](crate::MultiSelect::raw_prompt_with_filter),
+/// e.g. to log what the user searched for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FilteredAnswer<A> {
+    /// Answer submitted by the user.
+    pub answer: A,
+
+    /// Filter text at the time of submission, empty when the user did not
+    /// type any or the filter input is disabled.
+    pub filter: String,
+}
+
+impl<A> FilteredAnswer<A> {
+    /// Constructor for `FilteredAnswer`.
+    pub fn new(answer: A, filter: String) -> Self {
+        Self { answer, filter }
+    }
+}
+
+/// Answer of a quiz, paired with whether it matches the correct answer.
+///
+/// Returned by [`Select::quiz_prompt`](crate::Select::quiz_prompt) and
+/// [`MultiSelect::quiz_prompt`](crate::MultiSelect::quiz_prompt).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QuizAnswer<A> {
+    /// Answer submitted by the user.
+    pub answer: A,
+
+    /// Whether the answer is correct.
+    pub correct: bool,
+}
+
+impl<A> QuizAnswer<A> {
+    /// Constructor for `QuizAnswer`.
+    pub fn new(answer: A, correct: bool) -> Self {
+        Self { answer, correct }
+    }
+}
+
+/// Option wrapper that pairs the label displayed to the user with an arbitrary
+/// payload, which is returned alongside the answer.
+///
+/// Useful when the options presented in [Select](crate::Select) or
+/// [`MultiSelect`](crate::MultiSelect) prompts map to IDs, URLs or any other
+/// data that should not be rendered, removing the need to keep side tables
+/// keyed by the option index.
+///
+/// # Examples
+///
+/// ```no_run
+/// use inquire::{list_option::LabeledOption, Select};
+///
+/// let options = vec![
+///     LabeledOption::new("Production", "https://example.com"),
+///     LabeledOption::new("Staging", "https://staging.example.com"),
+/// ];
+///
+/// let url = Select::new("Environment:", options).prompt()?.data;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LabeledOption<L, D> {
+    /// Label displayed to the user.
+    pub label: L,
+
+    /// Payload associated with the option.
+    pub data: D,
+}
+
+impl<L, D> LabeledOption<L, D> {
+    /// Constructor for `LabeledOption`.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label displayed to the user.
+    /// * `data` - Payload returned when the option is selected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::LabeledOption;
+    ///
+    /// let option = LabeledOption::new("Alice", 42);
+    /// assert_eq!("Alice", option.to_string());
+    /// assert_eq!(42, option.into_data());
+    /// ```
+    pub fn new(label: L, data: D) -> Self {
+        Self { label, data }
+    }
+
+    /// Consumes the option, returning its payload.
+    pub fn into_data(self) -> D {
+        self.data
+    }
+}
+
+impl<L, D> From<(L, D)> for LabeledOption<L, D> {
+    fn from((label, data): (L, D)) -> Self {
+        Self::new(label, data)
+    }
+}
+
+impl<L, D> fmt::Display for LabeledOption<L, D>
+where
+    L: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.label.fmt(f)
+    }
+}
@@ -0,0 +1,411 @@
+//! Persistence of answers across invocations of your program.
+//!
+//! Prompts such as [`MultiSelect`](crate::MultiSelect) can remember the
+//! selections made by the user in a previous run and pre-check them in the
+//! next one, which is handy in iterative workflows. Answers are stored in a
+//! [`SelectionStore`] keyed by a prompt ID of your choice.
+//!
+//! Similarly, [`Select`](crate::Select) prompts can record the options chosen
+//! in a [`UsageStore`] and rank frequently and recently chosen options first.
+//!
+//! [`Password`](crate::Password) prompts can offer to reuse a secret saved in a [`SecretStore`], such as the keyring of
+//! the operating system, and to save the newly entered one.
+
+use std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::CustomUserError;
+
+/// Storage of previous selections of prompts, keyed by a prompt ID.
+///
+/// Selections are stored as the string representations of the selected
+/// options, or their keys when the prompt sets an
+/// [`OptionKey`](crate::type_aliases::OptionKey), so that they are still
+/// recognized when options are added, removed or reordered between runs.
+///
+/// # Example
+///
+/// ```
+/// use std::{cell::RefCell, collections::HashMap};
+/// use inquire::{persistence::SelectionStore, CustomUserError};
+///
+/// #[derive(Default)]
+/// struct MemoryStore(RefCell<HashMap<String, Vec<String>>>);
+///
+/// impl SelectionStore for MemoryStore {
+///     fn load(&self, prompt_id: &str) -> Result<Option<Vec<String>>, CustomUserError> {
+///         Ok(self.0.borrow().get(prompt_id).cloned())
+///     }
+///
+///     fn save(&self, prompt_id: &str, selections: &[String]) -> Result<(), CustomUserError> {
+///         self.0.borrow_mut().insert(prompt_id.to_string(), selections.to_vec());
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait SelectionStore {
+    /// Loads the selections previously saved for the given prompt ID, if any.
+    fn load(&self, prompt_id: &str) -> Result<Option<Vec<String>>, CustomUserError>;
+
+    /// Saves the selections made by the user for the given prompt ID,
+    /// replacing any previous ones.
+    fn save(&self, prompt_id: &str, selections: &[String]) -> Result<(), CustomUserError>;
+}
+
+/// [`SelectionStore`] backed by a plain text file, with one line per prompt ID.
+///
+/// The file and its parent directories are created on the first save. A
+/// missing file is treated as an empty store.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{persistence::FileSelectionStore, MultiSelect};
+///
+/// let store = FileSelectionStore::new(".my-tool/selections");
+///
+/// let crates = MultiSelect::new("Crates to publish:", vec!["core", "cli", "macros"])
+///     .with_selection_store("publish", &store)
+///     .prompt();
+/// ```
+#[derive(Clone, Debug)]
+pub struct FileSelectionStore {
+    path: PathBuf,
+}
+
+impl FileSelectionStore {
+    /// Creates a store backed by the file at the given path.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Path of the file backing the store.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl SelectionStore for FileSelectionStore {
+    fn load(&self, prompt_id: &str) -> Result<Option<Vec<String>>, CustomUserError> {
+        let entry = read_records(&self.path)?
+            .into_iter()
+            .find(|entry| entry.first().map(String::as_str) == Some(prompt_id))
+            .map(|entry| entry.into_iter().skip(1).collect());
+
+        Ok(entry)
+    }
+
+    fn save(&self, prompt_id: &str, selections: &[String]) -> Result<(), CustomUserError> {
+        let mut entries = read_records(&self.path)?;
+        entries.retain(|entry| entry.first().map(String::as_str) != Some(prompt_id));
+
+        let mut entry = vec![prompt_id.to_string()];
+        entry.extend(selections.iter().cloned());
+        entries.push(entry);
+
+        write_records(&self.path, &entries)
+    }
+}
+
+/// Usage statistics of an option, used to rank options by "frecency", a
+/// combination of how frequently and how recently they were chosen.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsageEntry {
+    /// String representation of the option, or its key when the prompt sets
+    /// an [`OptionKey`](crate::type_aliases::OptionKey).
+    pub option: String,
+
+    /// Number of times the option was chosen.
+    pub count: u32,
+
+    /// When the option was last chosen, in seconds since the Unix epoch.
+    pub last_used: u64,
+}
+
+impl UsageEntry {
+    /// Frecency score of the option at the given moment, in seconds since the
+    /// Unix epoch. Higher scores rank first.
+    ///
+    /// The usage count is weighted by how long ago the option was last chosen,
+    /// similarly to how shells rank their history.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::persistence::UsageEntry;
+    ///
+    /// let now = 1_700_000_000;
+    /// let recent = UsageEntry { option: "a".into(), count: 2, last_used: now - 60 };
+    /// let frequent = UsageEntry { option: "b".into(), count: 40, last_used: now - 30 * 86400 };
+    ///
+    /// assert!(frequent.frecency(now) > recent.frecency(now));
+    /// ```
+    pub fn frecency(&self, now: u64) -> u64 {
+        let age = now.saturating_sub(self.last_used);
+
+        let weight = match age {
+            a if a < 3600 => 16,
+            a if a < 86400 => 8,
+            a if a < 7 * 86400 => 2,
+            _ => 1,
+        };
+
+        u64::from(self.count) * weight
+    }
+}
+
+/// Storage of usage statistics of the options chosen in prompts, keyed by a
+/// prompt ID, used by [`Select`](crate::Select) prompts to boost frequently
+/// and recently chosen options to the top of the list.
+pub trait UsageStore {
+    /// Loads the usage statistics recorded for the given prompt ID.
+    fn load(&self, prompt_id: &str) -> Result<Vec<UsageEntry>, CustomUserError>;
+
+    /// Records that the given option was chosen in the prompt with the given ID.
+    fn record(&self, prompt_id: &str, option: &str) -> Result<(), CustomUserError>;
+}
+
+/// [`UsageStore`] backed by a plain text file, with one line per option of each prompt ID.
+///
+/// The file and its parent directories are created on the first record. A
+/// missing file is treated as an empty store.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{persistence::FileUsageStore, Select};
+///
+/// let store = FileUsageStore::new(".my-tool/usage");
+///
+/// let branch = Select::new("Branch:", vec!["main", "develop", "release"])
+///     .with_usage_store("checkout", &store)
+///     .prompt();
+/// ```
+#[derive(Clone, Debug)]
+pub struct FileUsageStore {
+    path: PathBuf,
+}
+
+impl FileUsageStore {
+    /// Creates a store backed by the file at the given path.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Path of the file backing the store.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl UsageStore for FileUsageStore {
+    fn load(&self, prompt_id: &str) -> Result<Vec<UsageEntry>, CustomUserError> {
+        let entries = read_records(&self.path)?
+            .into_iter()
+            .filter(|record| record.first().map(String::as_str) == Some(prompt_id))
+            .filter_map(|record| match record.as_slice() {
+                [_, option, count, last_used] => Some(UsageEntry {
+                    option: option.clone(),
+                    count: count.parse().ok()?,
+                    last_used: last_used.parse().ok()?,
+                }),
+                _ => None,
+            })
+            .collect();
+
+        Ok(entries)
+    }
+
+    fn record(&self, prompt_id: &str, option: &str) -> Result<(), CustomUserError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        let mut records = read_records(&self.path)?;
+
+        let existing = records
+            .iter_mut()
+            .find(|record| record.len() == 4 && record[0] == prompt_id && record[1] == option);
+
+        match existing {
+            Some(record) => {
+                let count = record[2].parse::<u32>().unwrap_or_default();
+                record[2] = count.saturating_add(1).to_string();
+                record[3] = now.to_string();
+            }
+            None => records.push(vec![
+                prompt_id.to_string(),
+                option.to_string(),
+                1.to_string(),
+                now.to_string(),
+            ]),
+        }
+
+        write_records(&self.path, &records)
+    }
+}
+
+/// Storage of secrets entered in [`Password`](crate::Password) prompts, keyed
+/// by the name of the service they belong to.
+///
+/// This crate does not talk to the keyring of the operating system itself:
+/// implement this trait on top of the keyring library of your choice, e.g.
+/// by reading and writing a `keyring::Entry` of the `keyring` crate.
+///
+/// # Example
+///
+/// ```
+/// use std::{cell::RefCell, collections::HashMap};
+/// use inquire::{persistence::SecretStore, CustomUserError};
+///
+/// #[derive(Default)]
+/// struct MemoryStore(RefCell<HashMap<String, String>>);
+///
+/// impl SecretStore for MemoryStore {
+///     fn load(&self, service: &str) -> Result<Option<String>, CustomUserError> {
+///         Ok(self.0.borrow().get(service).cloned())
+///     }
+///
+///     fn save(&self, service: &str, secret: &str) -> Result<(), CustomUserError> {
+///         self.0.borrow_mut().insert(service.to_string(), secret.to_string());
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait SecretStore {
+    /// Loads the secret saved for the given service, if any.
+    fn load(&self, service: &str) -> Result<Option<String>, CustomUserError>;
+
+    /// Saves the secret entered by the user for the given service, replacing
+    /// any previous one.
+    fn save(&self, service: &str, secret: &str) -> Result<(), CustomUserError>;
+}
+
+/// Reads the tab-separated records of a file, treating a missing file as empty.
+fn read_records(path: &Path) -> Result<Vec<Vec<String>>, CustomUserError> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err.into()),
+    };
+
+    Ok(content
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.split('\t').map(unescape).collect())
+        .collect())
+}
+
+/// Writes the records to a file as tab-separated lines, creating its parent directories.
+fn write_records(path: &Path, records: &[Vec<String>]) -> Result<(), CustomUserError> {
+    let content: String = records
+        .iter()
+        .map(|record| {
+            let fields: Vec<String> = record.iter().map(|f| escape(f)).collect();
+            format!("{}\n", fields.join("\t"))
+        })
+        .collect();
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content)?;
+
+    Ok(())
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some(c) => unescaped.push(c),
+            None => unescaped.push('\\'),
+        }
+    }
+
+    unescaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::{escape, unescape, FileSelectionStore, FileUsageStore, SelectionStore, UsageStore};
+
+    #[test]
+    fn escaping_roundtrips() {
+        for value in ["plain", "tab\there", "new\nline", "back\\slash\\t"] {
+            assert_eq!(value, unescape(&escape(value)));
+        }
+    }
+
+    #[test]
+    fn file_store_roundtrips_per_prompt_id() {
+        let path = std::env::temp_dir()
+            .join(format!("inquire-persistence-{}", std::process::id()))
+            .join("selections");
+        let store = FileSelectionStore::new(&path);
+
+        assert_eq!(None, store.load("a").unwrap());
+
+        store.save("a", &["x".into(), "y\tz".into()]).unwrap();
+        store.save("b", &[]).unwrap();
+        store.save("a", &["w".into()]).unwrap();
+
+        assert_eq!(Some(vec![String::from("w")]), store.load("a").unwrap());
+        assert_eq!(Some(vec![]), store.load("b").unwrap());
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn file_usage_store_counts_records() {
+        let path = std::env::temp_dir()
+            .join(format!("inquire-usage-{}", std::process::id()))
+            .join("usage");
+        let store = FileUsageStore::new(&path);
+
+        assert!(store.load("a").unwrap().is_empty());
+
+        store.record("a", "x").unwrap();
+        store.record("a", "y").unwrap();
+        store.record("a", "x").unwrap();
+        store.record("b", "x").unwrap();
+
+        let entries = store.load("a").unwrap();
+        assert_eq!(2, entries.len());
+        assert_eq!(("x", 2), (entries[0].option.as_str(), entries[0].count));
+        assert_eq!(("y", 1), (entries[1].option.as_str(), entries[1].count));
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,199 @@
+//! Dedicated thread running the prompts requested by the other threads of
+//! the application, one at a time.
+
+use std::{
+    io,
+    panic::{self, AssertUnwindSafe},
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+};
+
+use crate::error::{InquireError, InquireResult};
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Handle to a thread owning the terminal, on which prompts requested from
+/// any thread are run serially, their answers being sent back through
+/// channels.
+///
+/// Prompts borrow their callbacks, so they can not be sent to another
+/// thread themselves. Instead, each request is a closure building and
+/// running the prompt on the prompt thread, whose captured values must
+/// be owned.
+///
+/// Handles can be cloned and sent to other threads. The prompt thread
+/// finishes once all handles are dropped and the pending requests are
+/// answered.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::thread;
+///
+/// use inquire::{prompt_thread::PromptHandle, Confirm, Select};
+///
+/// let handle = PromptHandle::spawn()?;
+///
+/// let workers: Vec<_> = (0..3)
+///     .map(|worker| {
+///         let handle = handle.clone();
+///
+///         thread::spawn(move || {
+///             let retry = handle.ask(move || {
+///                 Confirm::new(&format!("Worker {} failed, retry?", worker)).prompt()
+///             });
+///
+///             matches!(retry, Ok(true))
+///         })
+///     })
+///     .collect();
+///
+/// let plan = handle.ask(|| Select::new("Plan:", vec!["Free", "Pro"]).prompt())?;
+///
+/// for worker in workers {
+///     worker.join().unwrap();
+/// }
+/// # inquire::error::InquireResult::Ok(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct PromptHandle {
+    jobs: Sender<Job>,
+}
+
+impl PromptHandle {
+    /// Spawns the prompt thread, returning a handle to request prompts from it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the operating system if the thread could not be
+    /// spawned.
+    pub fn spawn() -> io::Result<Self> {
+        let (jobs, queue) = mpsc::channel::<Job>();
+
+        thread::Builder::new()
+            .name("inquire-prompts".into())
+            .spawn(move || {
+                for job in queue {
+                    // a panicking request drops its answer channel, failing
+                    // the requester instead of the other requests
+                    let _unused = panic::catch_unwind(AssertUnwindSafe(job));
+                }
+            })?;
+
+        Ok(Self { jobs })
+    }
+
+    /// Runs the prompt built by the closure on the prompt thread, once the
+    /// previous requests are answered, and waits for its answer.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the prompt, or an [`InquireError::IO`] error
+    /// if the closure panicked.
+    pub fn ask<F, R>(&self, prompt: F) -> InquireResult<R>
+    where
+        F: FnOnce() -> InquireResult<R> + Send + 'static,
+        R: Send + 'static,
+    {
+        self.request(prompt)?
+            .recv()
+            .unwrap_or_else(|_| Err(stopped()))
+    }
+
+    /// Queues the prompt built by the closure on the prompt thread, returning
+    /// the channel its answer is sent through once the prompt is done.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InquireError::IO`] error if the prompt thread stopped.
+    pub fn request<F, R>(&self, prompt: F) -> InquireResult<Receiver<InquireResult<R>>>
+    where
+        F: FnOnce() -> InquireResult<R> + Send + 'static,
+        R: Send + 'static,
+    {
+        let (answer_sender, answer) = mpsc::channel();
+
+        let job: Job = Box::new(move || {
+            let _unused = answer_sender.send(prompt());
+        });
+        if self.jobs.send(job).is_err() {
+            return Err(stopped());
+        }
+
+        Ok(answer)
+    }
+}
+
+fn stopped() -> InquireError {
+    InquireError::IO(io::Error::new(
+        io::ErrorKind::BrokenPipe,
+        "the prompt thread stopped before answering",
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    use crate::error::InquireError;
+
+    use super::PromptHandle;
+
+    #[test]
+    fn requests_are_answered_serially_in_order() {
+        let handle = PromptHandle::spawn().unwrap();
+        let log = Arc::new(Mutex::new(vec![]));
+
+        let answers = (0..4)
+            .map(|i| {
+                let log = log.clone();
+                handle
+                    .request(move || {
+                        log.lock().unwrap().push(i);
+                        Ok(i * 10)
+                    })
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let answers = answers
+            .into_iter()
+            .map(|answer| answer.recv().unwrap().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![0, 10, 20, 30], answers);
+        assert_eq!(vec![0, 1, 2, 3], *log.lock().unwrap());
+    }
+
+    #[test]
+    fn requests_can_be_made_from_other_threads() {
+        let handle = PromptHandle::spawn().unwrap();
+
+        let workers = (0..3)
+            .map(|i| {
+                let handle = handle.clone();
+                thread::spawn(move || handle.ask(move || Ok(i + 1)).unwrap())
+            })
+            .collect::<Vec<_>>();
+
+        let total: i32 = workers.into_iter().map(|w| w.join().unwrap()).sum();
+        assert_eq!(6, total);
+    }
+
+    #[test]
+    fn panicking_request_does_not_stop_the_thread() {
+        let handle = PromptHandle::spawn().unwrap();
+
+        let failed = handle.ask::<_, ()>(|| panic!("broken prompt"));
+        assert!(matches!(failed, Err(InquireError::IO(_))));
+
+        assert_eq!("ok", handle.ask(|| Ok("ok")).unwrap());
+        assert!(matches!(
+            handle.ask::<_, ()>(|| Err(InquireError::OperationCanceled)),
+            Err(InquireError::OperationCanceled)
+        ));
+    }
+}
@@ -3,7 +3,7 @@
 
 use std::fmt::Debug;
 
-use crate::ui::{Key, KeyModifiers};
+use crate::ui::{Key, KeyBindings, KeyHelp};
 
 /// Top-level type to describe the directives a prompt
 /// receives.
@@ -22,6 +22,8 @@ where
     Cancel,
     /// Interrupts the prompt execution without a graceful shutdown.
     Interrupt,
+    /// Displays the key bindings of the prompt until the next key press.
+    Help,
     /// Specialized actions according to the prompt type.
     Inner(I),
 }
@@ -30,18 +32,34 @@ impl<I> Action<I>
 where
     I: Copy + Clone + PartialEq + Eq,
 {
-    /// Derives a prompt action from a Key event.
+    /// Derives a prompt action from a Key event, using the default key bindings.
     pub fn from_key<C>(key: Key, config: &C) -> Option<Action<I>>
     where
         I: InnerAction<Config = C>,
     {
-        match key {
-            Key::Enter
-            | Key::Char('\n', KeyModifiers::NONE)
-            | Key::Char('j', KeyModifiers::CONTROL) => Some(Action::Submit),
-            Key::Escape | Key::Char('g' | 'd', KeyModifiers::CONTROL) => Some(Action::Cancel),
-            Key::Char('c', KeyModifiers::CONTROL) => Some(Action::Interrupt),
-            key => I::from_key(key, config).map(Action::Inner),
+        Self::from_key_with_bindings(key, &KeyBindings::default(), config)
+    }
+
+    /// Derives a prompt action from a Key event, using the given key bindings
+    /// for the actions shared by all prompts.
+    pub fn from_key_with_bindings<C>(
+        key: Key,
+        bindings: &KeyBindings<'_>,
+        config: &C,
+    ) -> Option<Action<I>>
+    where
+        I: InnerAction<Config = C>,
+    {
+        if bindings.submit.contains(&key) {
+            Some(Action::Submit)
+        } else if bindings.cancel.contains(&key) {
+            Some(Action::Cancel)
+        } else if bindings.interrupt.contains(&key) {
+            Some(Action::Interrupt)
+        } else if bindings.help.contains(&key) {
+            Some(Action::Help)
+        } else {
+            I::from_key(key, config).map(Action::Inner)
         }
     }
 }
@@ -63,12 +81,18 @@ where
     fn from_key(key: Key, config: &Self::Config) -> Option<Self>
     where
         Self: Sized;
+
+    /// Lists the key bindings parsed by [`from_key`](InnerAction::from_key),
+    /// as displayed in the help overlay. Empty by default.
+    fn key_help(_config: &Self::Config) -> Vec<KeyHelp> {
+        vec![]
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::{
-        ui::{Key, KeyModifiers},
+        ui::{Key, KeyBindings, KeyModifiers},
         Action, InnerAction,
     };
 
@@ -148,4 +172,25 @@ mod test {
             Action::from_key(Key::Char('g', KeyModifiers::CONTROL), &())
         );
     }
+
+    #[test]
+    fn custom_submit_keys_replace_the_default_ones() {
+        let submit_keys = [Key::Char('s', KeyModifiers::CONTROL)];
+        let bindings = KeyBindings::default().with_submit_keys(&submit_keys);
+
+        assert_eq!(
+            Some(Action::<MockInnerAction>::Submit),
+            Action::from_key_with_bindings(Key::Char('s', KeyModifiers::CONTROL), &bindings, &())
+        );
+        assert_eq!(
+            Some(Action::<MockInnerAction>::Inner(MockInnerAction::Action(
+                Key::Enter
+            ))),
+            Action::from_key_with_bindings(Key::Enter, &bindings, &())
+        );
+        assert_eq!(
+            Some(Action::<MockInnerAction>::Cancel),
+            Action::from_key_with_bindings(Key::Escape, &bindings, &())
+        );
+    }
 }
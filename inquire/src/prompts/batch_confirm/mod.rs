@@ -0,0 +1,344 @@
+#[cfg(test)]
+mod test;
+
+use std::fmt::Display;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    parser::CustomTypeParser,
+    terminal::get_default_terminal,
+    ui::{Backend, CustomTypeBackend, RenderConfig},
+    CustomType,
+};
+
+/// Answer to a [`BatchConfirm`] prompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BatchAnswer {
+    /// Apply the operation to the current item.
+    Yes,
+    /// Skip the current item.
+    No,
+    /// Apply the operation to the current item and to all remaining ones, without asking again.
+    All,
+    /// Skip the current item and stop processing the remaining ones.
+    Quit,
+}
+
+impl BatchAnswer {
+    /// Returns whether the operation should be applied to the current item,
+    /// i.e. whether the answer is either [`Yes`](BatchAnswer::Yes) or [`All`](BatchAnswer::All).
+    pub fn applies(self) -> bool {
+        matches!(self, Self::Yes | Self::All)
+    }
+}
+
+impl Display for BatchAnswer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Self::Yes => "Yes",
+            Self::No => "No",
+            Self::All => "All",
+            Self::Quit => "Quit",
+        };
+
+        write!(f, "{}", text)
+    }
+}
+
+/// Confirm-like prompt offering the classic four-way answer set used in batch operations: `[y]es/[n]o/[a]ll/[q]uit`.
+///
+/// This prompt is a wrapper around the behavior of `CustomType` prompts, just like [`Confirm`](crate::Confirm). It returns a [`BatchAnswer`], so callers looping over several items can stop asking once the user answers [`All`](BatchAnswer::All) and stop processing once they answer [`Quit`](BatchAnswer::Quit).
+///
+/// The default parser accepts the following inputs (case-insensitive): `y`, `yes`, `n`, `no`, `a`, `all`, `q` and `quit`. If the user input does not match any of them, the following error message is displayed by default:
+/// - `# Invalid answer, try typing 'y' for yes, 'n' for no, 'a' for all or 'q' for quit`.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Default value**: Default value returned when the user submits an empty response.
+/// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Help message**: Message displayed at the line below the prompt.
+///   - Lists the available answers by default.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Formats the answer as "Yes", "No", "All" or "Quit" by default.
+/// - **Parser**: Custom parser for user inputs.
+/// - **Default value formatter**: Function that formats how the default value is displayed to the user.
+///   - By default, displays "y/n/a/q" with the default value capitalized, e.g. "y/N/a/q".
+/// - **Error message**: Error message to display when a value could not be parsed from the input.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{BatchAnswer, BatchConfirm};
+///
+/// let files = vec!["foo.txt", "bar.txt", "baz.txt"];
+/// let mut overwrite_all = false;
+///
+/// for file in files {
+///     if !overwrite_all {
+///         let message = format!("Overwrite {}?", file);
+///         match BatchConfirm::new(&message).prompt() {
+///             Ok(BatchAnswer::Yes) => {}
+///             Ok(BatchAnswer::All) => overwrite_all = true,
+///             Ok(BatchAnswer::No) => continue,
+///             Ok(BatchAnswer::Quit) | Err(_) => break,
+///         }
+///     }
+///
+///     println!("Overwriting {}", file);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct BatchConfirm<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Initial value of the prompt's text input.
+    ///
+    /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`default`].
+    ///
+    /// [`default`]: Self::default
+    pub starting_input: Option<&'a str>,
+
+    /// Default value, returned when the user input is empty.
+    pub default: Option<BatchAnswer>,
+
+    /// Short hint that describes the expected value of the input.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: CustomTypeFormatter<'a, BatchAnswer>,
+
+    /// Function that parses the user input and returns the result value.
+    pub parser: CustomTypeParser<'a, BatchAnswer>,
+
+    /// Function that formats the default value to be presented to the user
+    pub default_value_formatter: CustomTypeFormatter<'a, BatchAnswer>,
+
+    /// Error message displayed when a value could not be parsed from input.
+    pub error_message: String,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> BatchConfirm<'a> {
+    /// Default formatter, set to "Yes", "No", "All" or "Quit" depending on the answer.
+    pub const DEFAULT_FORMATTER: CustomTypeFormatter<'a, BatchAnswer> = &|ans| ans.to_string();
+
+    /// Default parser, accepting the full answers or their initials, case-insensitive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::{BatchAnswer, BatchConfirm};
+    ///
+    /// let parser = BatchConfirm::DEFAULT_PARSER;
+    /// assert_eq!(Ok(BatchAnswer::Yes), parser("y"));
+    /// assert_eq!(Ok(BatchAnswer::All), parser("ALL"));
+    /// assert_eq!(Ok(BatchAnswer::Quit), parser("q"));
+    /// assert_eq!(Err(()), parser("maybe"));
+    /// ```
+    pub const DEFAULT_PARSER: CustomTypeParser<'a, BatchAnswer> =
+        &|ans| match ans.to_lowercase().as_str() {
+            "y" | "yes" => Ok(BatchAnswer::Yes),
+            "n" | "no" => Ok(BatchAnswer::No),
+            "a" | "all" => Ok(BatchAnswer::All),
+            "q" | "quit" => Ok(BatchAnswer::Quit),
+            _ => Err(()),
+        };
+
+    /// Default formatter for default values, displaying the initials of all answers
+    /// with the default one capitalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::{BatchAnswer, BatchConfirm};
+    ///
+    /// let formatter = BatchConfirm::DEFAULT_DEFAULT_VALUE_FORMATTER;
+    /// assert_eq!(String::from("Y/n/a/q"), formatter(BatchAnswer::Yes));
+    /// assert_eq!(String::from("y/n/a/Q"), formatter(BatchAnswer::Quit));
+    /// ```
+    pub const DEFAULT_DEFAULT_VALUE_FORMATTER: CustomTypeFormatter<'a, BatchAnswer> = &|ans| {
+        [
+            (BatchAnswer::Yes, "y"),
+            (BatchAnswer::No, "n"),
+            (BatchAnswer::All, "a"),
+            (BatchAnswer::Quit, "q"),
+        ]
+        .iter()
+        .map(|(answer, initial)| match *answer == ans {
+            true => initial.to_uppercase(),
+            false => (*initial).to_string(),
+        })
+        .collect::<Vec<String>>()
+        .join("/")
+    };
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = Some("[y]es, [n]o, [a]ll, [q]uit");
+
+    /// Default error message displayed when parsing fails.
+    pub const DEFAULT_ERROR_MESSAGE: &'a str =
+        "Invalid answer, try typing 'y' for yes, 'n' for no, 'a' for all or 'q' for quit";
+
+    /// Creates a [BatchConfirm] with default properties
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_input: None,
+            default: None,
+            placeholder: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: Self::DEFAULT_FORMATTER,
+            parser: Self::DEFAULT_PARSER,
+            default_value_formatter: Self::DEFAULT_DEFAULT_VALUE_FORMATTER,
+            error_message: String::from(Self::DEFAULT_ERROR_MESSAGE),
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the starting input.
+    pub fn with_starting_input(mut self, message: &'a str) -> Self {
+        self.starting_input = Some(message);
+        self
+    }
+
+    /// Sets the default input.
+    pub fn with_default(mut self, default: BatchAnswer) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, BatchAnswer>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the parser.
+    pub fn with_parser(mut self, parser: CustomTypeParser<'a, BatchAnswer>) -> Self {
+        self.parser = parser;
+        self
+    }
+
+    /// Sets a custom error message displayed when a submission could not be parsed to a value.
+    pub fn with_error_message(mut self, error_message: &'a str) -> Self {
+        self.error_message = String::from(error_message);
+        self
+    }
+
+    /// Sets the default value formatter
+    pub fn with_default_value_formatter(
+        mut self,
+        formatter: CustomTypeFormatter<'a, BatchAnswer>,
+    ) -> Self {
+        self.default_value_formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<BatchAnswer>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<BatchAnswer> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<BatchAnswer> {
+        CustomType::from(self).prompt_with_backend(backend)
+    }
+}
+
+impl<'a> From<&'a str> for BatchConfirm<'a> {
+    fn from(val: &'a str) -> Self {
+        BatchConfirm::new(val)
+    }
+}
+
+impl<'a> From<BatchConfirm<'a>> for CustomType<'a, BatchAnswer> {
+    fn from(co: BatchConfirm<'a>) -> Self {
+        Self {
+            message: co.message,
+            starting_input: co.starting_input,
+            default: co.default,
+            current_value: None,
+            default_value_formatter: co.default_value_formatter,
+            placeholder: co.placeholder,
+            help_message: co.help_message,
+            formatter: co.formatter,
+            parser: co.parser,
+            validators: vec![],
+            error_message: co.error_message,
+            timeout: None,
+            show_remaining_time: false,
+            show_elapsed_time: false,
+            max_attempts: None,
+            render_config: co.render_config,
+        }
+    }
+}
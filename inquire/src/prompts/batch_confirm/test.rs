@@ -0,0 +1,60 @@
+use rstest::rstest;
+
+use crate::{
+    error::InquireResult,
+    ui::{test::FakeBackend, Key},
+    BatchAnswer, BatchConfirm, InquireError,
+};
+
+#[rstest]
+#[case("y", BatchAnswer::Yes)]
+#[case("YES", BatchAnswer::Yes)]
+#[case("n", BatchAnswer::No)]
+#[case("no", BatchAnswer::No)]
+#[case("a", BatchAnswer::All)]
+#[case("All", BatchAnswer::All)]
+#[case("q", BatchAnswer::Quit)]
+#[case("quit", BatchAnswer::Quit)]
+fn prompt_correctly_parses_input(
+    #[case] input: &str,
+    #[case] expected_result: BatchAnswer,
+) -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str(input);
+    keys.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(keys);
+
+    let result = BatchConfirm::from("Question").prompt_with_backend(&mut backend)?;
+    assert_eq!(expected_result, result, "Answer was not the expected one");
+
+    Ok(())
+}
+
+#[rstest]
+#[case("yeah")]
+#[case("al")]
+#[case("x")]
+fn invalid_inputs_are_properly_rejected(#[case] input: &str) {
+    let mut keys = Key::char_keys_from_str(input);
+    keys.push(Key::Enter);
+    keys.push(Key::Escape);
+
+    let mut backend = FakeBackend::new(keys);
+
+    let result = BatchConfirm::from("Question").prompt_with_backend(&mut backend);
+
+    assert!(matches!(result, Err(InquireError::OperationCanceled)));
+}
+
+#[test]
+fn prompt_with_default_can_be_readily_submitted() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let result = BatchConfirm::from("Question")
+        .with_default(BatchAnswer::All)
+        .prompt_with_backend(&mut backend)?;
+    assert_eq!(BatchAnswer::All, result);
+    assert!(result.applies());
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::CheckboxMatrixConfig;
+
+/// Set of actions for a CheckboxMatrixPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CheckboxMatrixPromptAction {
+    /// Moves the cursor to the row above.
+    MoveUp,
+    /// Moves the cursor to the row below.
+    MoveDown,
+    /// Moves the cursor to the column on the left.
+    MoveLeft,
+    /// Moves the cursor to the column on the right.
+    MoveRight,
+    /// Moves the cursor to the page above.
+    PageUp,
+    /// Moves the cursor to the page below.
+    PageDown,
+    /// Moves the cursor to the first row.
+    MoveToStart,
+    /// Moves the cursor to the last row.
+    MoveToEnd,
+    /// Toggles the cell under the cursor.
+    ToggleCurrentCell,
+    /// Selects every cell of the current row, or clears them if they were all selected.
+    ToggleCurrentRow,
+    /// Selects every cell of the current column, or clears them if they were all selected.
+    ToggleCurrentColumn,
+}
+
+impl InnerAction for CheckboxMatrixPromptAction {
+    type Config = CheckboxMatrixConfig;
+
+    fn from_key(key: Key, config: &CheckboxMatrixConfig) -> Option<Self> {
+        if config.vim_mode {
+            let action = match key {
+                Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
+                Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
+                Key::Char('h', KeyModifiers::NONE) => Some(Self::MoveLeft),
+                Key::Char('l', KeyModifiers::NONE) => Some(Self::MoveRight),
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::PageUp(_) => Self::PageUp,
+            Key::Home => Self::MoveToStart,
+
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::PageDown(_) => Self::PageDown,
+            Key::End => Self::MoveToEnd,
+
+            Key::Left(KeyModifiers::NONE) | Key::Char('b', KeyModifiers::CONTROL) => Self::MoveLeft,
+            Key::Right(KeyModifiers::NONE) | Key::Char('f', KeyModifiers::CONTROL) => {
+                Self::MoveRight
+            }
+
+            Key::Char(' ', KeyModifiers::NONE) => Self::ToggleCurrentCell,
+            Key::Char('r', KeyModifiers::NONE) => Self::ToggleCurrentRow,
+            Key::Char('c', KeyModifiers::NONE) => Self::ToggleCurrentColumn,
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
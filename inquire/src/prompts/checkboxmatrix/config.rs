@@ -0,0 +1,19 @@
+use crate::CheckboxMatrix;
+
+/// Configuration settings used in the execution of a CheckboxMatrixPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct CheckboxMatrixConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// Page size of the list of rows.
+    pub page_size: usize,
+}
+
+impl<R, C> From<&CheckboxMatrix<'_, R, C>> for CheckboxMatrixConfig {
+    fn from(value: &CheckboxMatrix<'_, R, C>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            page_size: value.page_size,
+        }
+    }
+}
@@ -0,0 +1,252 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use std::fmt::Display;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::MatrixFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    type_aliases::MatrixAnswer,
+    ui::{Backend, CheckboxMatrixBackend, RenderConfig},
+};
+
+use self::prompt::CheckboxMatrixPrompt;
+
+/// Prompt suitable for when you need the user to pick, for each item of a list, any number of choices of a shared set, such as granting read/write/admin permissions to each user.
+///
+/// Rows are rendered one per line and columns as checkboxes next to each row. The user moves the cursor with the arrow keys and toggles the highlighted cell by pressing space. Pressing `r` toggles the whole row and `c` toggles the whole column.
+///
+/// This prompt requires a prompt message and **non-empty** `Vec`s of rows and columns. Both can be of any type as long as they implement the `Display` trait, and columns must also be `Clone` since the same column may be selected for several rows.
+/// - If either list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+///
+/// The answer pairs every row, in the original order, with the columns selected for it, also in the original order. Rows without selections are paired with an empty `Vec`.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Rows and columns**: Items displayed to the user. Must be **non-empty**.
+/// - **Default selections**: `(row, column)` cells selected when the prompt is first rendered. If any of the cells is out-of-range, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - By default, prints each row with selections followed by its selected columns.
+/// - **Page size**: Number of rows displayed at once, 7 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::collections::HashMap;
+/// use inquire::CheckboxMatrix;
+///
+/// let users = vec!["alice", "bob", "carol"];
+/// let permissions = vec!["read", "write", "admin"];
+///
+/// let ans = CheckboxMatrix::new("Grant permissions:", users, permissions)
+///     .with_default(&[(0, 0), (0, 1)])
+///     .prompt();
+///
+/// match ans {
+///     Ok(grants) => {
+///         let grants: HashMap<_, _> = grants.into_iter().collect();
+///         println!("alice can {}", grants["alice"].join(", "));
+///     }
+///     Err(_) => println!("The permissions could not be processed"),
+/// }
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct CheckboxMatrix<'a, R, C> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Row items displayed to the user.
+    pub rows: Vec<R>,
+
+    /// Column choices available for each row.
+    pub columns: Vec<C>,
+
+    /// Default `(row, column)` cells to be selected from the start.
+    pub default: Option<Vec<(usize, usize)>>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the rows displayed to the user.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the cells using hjkl.
+    pub vim_mode: bool,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: MatrixFormatter<'a, R, C>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, R, C> CheckboxMatrix<'a, R, C>
+where
+    R: Display,
+    C: Display + Clone,
+{
+    /// String formatter used by default in [CheckboxMatrix](crate::CheckboxMatrix) prompts.
+    /// Prints each row that has selections followed by its selected columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::CheckboxMatrix;
+    ///
+    /// let formatter = CheckboxMatrix::<&str, &str>::DEFAULT_FORMATTER;
+    ///
+    /// let ans = vec![
+    ///     (&"alice", vec![&"read", &"write"]),
+    ///     (&"bob", vec![]),
+    ///     (&"carol", vec![&"admin"]),
+    /// ];
+    /// assert_eq!(String::from("alice: read, write; carol: admin"), formatter(&ans));
+    /// ```
+    pub const DEFAULT_FORMATTER: MatrixFormatter<'a, R, C> = &|ans| {
+        ans.iter()
+            .filter(|(_, columns)| !columns.is_empty())
+            .map(|(row, columns)| {
+                let columns = columns
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                format!("{}: {}", row, columns)
+            })
+            .collect::<Vec<String>>()
+            .join("; ")
+    };
+
+    /// Default page size, equal to the global default page size [config::DEFAULT_PAGE_SIZE]
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of vim mode, equal to the global default value [config::DEFAULT_VIM_MODE]
+    pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓←→ to move, space to toggle, r to toggle row, c to toggle column");
+
+    /// Creates a [CheckboxMatrix] with the provided message, rows and columns, along with default configuration values.
+    pub fn new(message: &'a str, rows: Vec<R>, columns: Vec<C>) -> Self {
+        Self {
+            message,
+            rows,
+            columns,
+            default: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: MatrixFormatter<'a, R, C>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the `(row, column)` cells to be selected by default.
+    ///
+    /// Duplicates are ignored.
+    pub fn with_default(mut self, default: &'a [(usize, usize)]) -> Self {
+        self.default = Some(default.to_vec());
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns every row paired with the columns selected for it.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<MatrixAnswer<R, C>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns every row paired with the columns selected for it.
+    pub fn prompt(self) -> InquireResult<MatrixAnswer<R, C>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: CheckboxMatrixBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<MatrixAnswer<R, C>> {
+        CheckboxMatrixPrompt::new(self)?.prompt(backend)
+    }
+}
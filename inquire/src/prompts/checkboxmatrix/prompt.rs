@@ -0,0 +1,257 @@
+use std::{collections::BTreeSet, fmt::Display};
+
+use crate::{
+    error::InquireResult,
+    formatter::MatrixFormatter,
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    type_aliases::MatrixAnswer,
+    ui::{width::str_width, CheckboxMatrixBackend},
+    utils::paginate,
+    CheckboxMatrix, InquireError,
+};
+
+use super::{action::CheckboxMatrixPromptAction, config::CheckboxMatrixConfig};
+
+pub struct CheckboxMatrixPrompt<'a, R, C> {
+    message: &'a str,
+    config: CheckboxMatrixConfig,
+    rows: Vec<R>,
+    columns: Vec<C>,
+    help_message: Option<&'a str>,
+    row_cursor: usize,
+    column_cursor: usize,
+    row_label_width: usize,
+    checked: BTreeSet<(usize, usize)>,
+    formatter: MatrixFormatter<'a, R, C>,
+}
+
+impl<'a, R, C> CheckboxMatrixPrompt<'a, R, C>
+where
+    R: Display,
+    C: Display + Clone,
+{
+    pub fn new(cm: CheckboxMatrix<'a, R, C>) -> InquireResult<Self> {
+        if cm.rows.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available rows can not be empty".into(),
+            ));
+        }
+        if cm.columns.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available columns can not be empty".into(),
+            ));
+        }
+        if let Some(default) = &cm.default {
+            for (row, column) in default {
+                if *row >= cm.rows.len() || *column >= cm.columns.len() {
+                    return Err(InquireError::InvalidConfiguration(format!(
+                        "Cell ({}, {}) is out-of-bounds for a matrix of {} rows and {} columns",
+                        row,
+                        column,
+                        cm.rows.len(),
+                        cm.columns.len()
+                    )));
+                }
+            }
+        }
+
+        let row_label_width = cm
+            .rows
+            .iter()
+            .map(|r| str_width(&r.to_string(), cm.render_config.ambiguous_width))
+            .max()
+            .unwrap_or_default();
+
+        let checked = cm
+            .default
+            .as_ref()
+            .map(|d| d.iter().cloned().collect())
+            .unwrap_or_default();
+
+        Ok(Self {
+            message: cm.message,
+            config: (&cm).into(),
+            rows: cm.rows,
+            columns: cm.columns,
+            help_message: cm.help_message,
+            row_cursor: 0,
+            column_cursor: 0,
+            row_label_width,
+            checked,
+            formatter: cm.formatter,
+        })
+    }
+
+    fn move_row_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let new_position = if wrap {
+            let after_wrap = qty.saturating_sub(self.row_cursor);
+            self.row_cursor
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.rows.len().saturating_sub(after_wrap))
+        } else {
+            self.row_cursor.saturating_sub(qty)
+        };
+
+        self.update_row_cursor(new_position)
+    }
+
+    fn move_row_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let mut new_position = self.row_cursor.saturating_add(qty);
+
+        if new_position >= self.rows.len() {
+            new_position = if wrap {
+                new_position % self.rows.len()
+            } else {
+                self.rows.len().saturating_sub(1)
+            }
+        }
+
+        self.update_row_cursor(new_position)
+    }
+
+    fn update_row_cursor(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.row_cursor {
+            self.row_cursor = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn move_column_cursor(&mut self, left: bool) -> ActionResult {
+        let len = self.columns.len();
+        let new_position = match left {
+            true => (self.column_cursor + len - 1) % len,
+            false => (self.column_cursor + 1) % len,
+        };
+
+        if new_position != self.column_cursor {
+            self.column_cursor = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn toggle_cells(&mut self, cells: Vec<(usize, usize)>) -> ActionResult {
+        let all_checked = cells.iter().all(|cell| self.checked.contains(cell));
+
+        for cell in cells {
+            match all_checked {
+                true => self.checked.remove(&cell),
+                false => self.checked.insert(cell),
+            };
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn get_final_answer(&mut self) -> MatrixAnswer<R, C> {
+        let columns = &self.columns;
+        let checked = &self.checked;
+
+        self.rows
+            .drain(..)
+            .enumerate()
+            .map(|(row, value)| {
+                let selected = checked
+                    .range((row, 0)..(row + 1, 0))
+                    .map(|(_, column)| columns[*column].clone())
+                    .collect();
+
+                (value, selected)
+            })
+            .collect()
+    }
+}
+
+impl<'a, Backend, R, C> Prompt<Backend> for CheckboxMatrixPrompt<'a, R, C>
+where
+    Backend: CheckboxMatrixBackend,
+    R: Display,
+    C: Display + Clone,
+{
+    type Config = CheckboxMatrixConfig;
+    type InnerAction = CheckboxMatrixPromptAction;
+    type Output = MatrixAnswer<R, C>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &CheckboxMatrixConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &MatrixAnswer<R, C>) -> String {
+        let refs: Vec<(&R, Vec<&C>)> = answer
+            .iter()
+            .map(|(row, columns)| (row, columns.iter().collect()))
+            .collect();
+        (self.formatter)(&refs)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<MatrixAnswer<R, C>>> {
+        Ok(Some(self.get_final_answer()))
+    }
+
+    fn handle(&mut self, action: CheckboxMatrixPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            CheckboxMatrixPromptAction::MoveUp => self.move_row_cursor_up(1, true),
+            CheckboxMatrixPromptAction::MoveDown => self.move_row_cursor_down(1, true),
+            CheckboxMatrixPromptAction::MoveLeft => self.move_column_cursor(true),
+            CheckboxMatrixPromptAction::MoveRight => self.move_column_cursor(false),
+            CheckboxMatrixPromptAction::PageUp => {
+                self.move_row_cursor_up(self.config.page_size, false)
+            }
+            CheckboxMatrixPromptAction::PageDown => {
+                self.move_row_cursor_down(self.config.page_size, false)
+            }
+            CheckboxMatrixPromptAction::MoveToStart => self.move_row_cursor_up(usize::MAX, false),
+            CheckboxMatrixPromptAction::MoveToEnd => self.move_row_cursor_down(usize::MAX, false),
+            CheckboxMatrixPromptAction::ToggleCurrentCell => {
+                self.toggle_cells(vec![(self.row_cursor, self.column_cursor)])
+            }
+            CheckboxMatrixPromptAction::ToggleCurrentRow => {
+                let row = self.row_cursor;
+                self.toggle_cells((0..self.columns.len()).map(|c| (row, c)).collect())
+            }
+            CheckboxMatrixPromptAction::ToggleCurrentColumn => {
+                let column = self.column_cursor;
+                self.toggle_cells((0..self.rows.len()).map(|r| (r, column)).collect())
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.render_checkbox_matrix_prompt(prompt)?;
+
+        let rows = self
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| ListOption::new(i, row))
+            .collect::<Vec<ListOption<&R>>>();
+
+        let page = paginate(self.config.page_size, &rows, Some(self.row_cursor));
+
+        backend.render_checkbox_matrix(
+            page,
+            &self.columns,
+            &self.checked,
+            self.column_cursor,
+            self.row_label_width,
+        )?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    CheckboxMatrix,
+};
+
+fn matrix() -> CheckboxMatrix<'static, &'static str, &'static str> {
+    CheckboxMatrix::new(
+        "Question",
+        vec!["alice", "bob", "carol"],
+        vec!["read", "write", "admin"],
+    )
+}
+
+#[test]
+fn arrows_and_space_toggle_cells() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE), // alice: read
+        Key::Down(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE), // bob: admin
+        Key::Left(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE), // bob: write
+        Key::Enter,
+    ]);
+
+    let ans = matrix().prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!(
+        vec![
+            ("alice", vec!["read"]),
+            ("bob", vec!["write", "admin"]),
+            ("carol", vec![]),
+        ],
+        ans
+    );
+}
+
+#[test]
+fn row_and_column_toggles() {
+    let mut backend = fake_backend(vec![
+        Key::Char('c', KeyModifiers::NONE), // everyone: read
+        Key::Up(KeyModifiers::NONE),        // wraps to carol
+        Key::Char('r', KeyModifiers::NONE), // carol already has read, so selects all
+        Key::Enter,
+    ]);
+
+    let ans = matrix().prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!(
+        vec![
+            ("alice", vec!["read"]),
+            ("bob", vec!["read"]),
+            ("carol", vec!["read", "write", "admin"]),
+        ],
+        ans
+    );
+}
+
+#[test]
+fn default_cells_can_be_cleared() {
+    let mut backend = fake_backend(vec![
+        Key::Char('r', KeyModifiers::NONE), // alice has everything, so clears the row
+        Key::Enter,
+    ]);
+
+    let ans = matrix()
+        .with_default(&[(0, 0), (0, 1), (0, 2), (2, 1)])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![("alice", vec![]), ("bob", vec![]), ("carol", vec!["write"])],
+        ans
+    );
+}
+
+#[test]
+fn out_of_bounds_default_is_rejected() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = matrix()
+        .with_default(&[(0, 3)])
+        .prompt_with_backend(&mut backend);
+
+    assert!(ans.is_err());
+}
@@ -4,6 +4,8 @@ mod test;
 
 pub use action::*;
 
+use std::time::Duration;
+
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
@@ -39,6 +41,9 @@ use crate::{
 ///   - By default, displays "y/n" with the default value capitalized, e.g. "y/N".
 /// - **Error message**: Error message to display when a value could not be parsed from the input.
 ///   - Set to "Invalid answer, try typing 'y' for yes or 'n' for no" by default.
+/// - **Timeout**: Maximum time to wait for an answer, after which the prompt fails with [`InquireError::Timeout`](crate::InquireError::Timeout). The time left can optionally be rendered below the prompt.
+/// - **Elapsed time**: Whether to render the time elapsed since the prompt was displayed below it, ticking every second, e.g. for time-boxed decisions.
+/// - **Max attempts**: Maximum number of rejected answers, after which the prompt returns the default value, if any, or fails with [`InquireError::TooManyAttempts`](crate::InquireError::TooManyAttempts).
 ///
 /// # Example
 ///
@@ -91,6 +96,21 @@ pub struct Confirm<'a> {
     /// Error message displayed when a value could not be parsed from input.
     pub error_message: String,
 
+    /// Maximum time to wait for an answer before failing with [`InquireError::Timeout`].
+    pub timeout: Option<Duration>,
+
+    /// Whether to render the time left until the timeout elapses.
+    pub show_remaining_time: bool,
+
+    /// Whether to render the time elapsed since the prompt was displayed, or the
+    /// time left when a timeout is set.
+    pub show_elapsed_time: bool,
+
+    /// Maximum number of answers that could not be parsed, after which the
+    /// prompt returns the default value, if any, or fails with
+    /// [`InquireError::TooManyAttempts`].
+    pub max_attempts: Option<usize>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -132,6 +152,10 @@ impl<'a> Confirm<'a> {
             parser: Self::DEFAULT_PARSER,
             default_value_formatter: Self::DEFAULT_DEFAULT_VALUE_FORMATTER,
             error_message: String::from(Self::DEFAULT_ERROR_MESSAGE),
+            timeout: None,
+            show_remaining_time: false,
+            show_elapsed_time: false,
+            max_attempts: None,
             render_config: get_configuration(),
         }
     }
@@ -188,6 +212,38 @@ impl<'a> Confirm<'a> {
         self
     }
 
+    /// Sets the maximum time to wait for an answer, after which the prompt
+    /// fails with [`InquireError::Timeout`].
+    ///
+    /// The deadline can only interrupt a pending key read when using the
+    /// `crossterm` backend. Other backends notice it on the next key press.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to render the time left until the timeout elapses.
+    pub fn with_show_remaining_time(mut self, show_remaining_time: bool) -> Self {
+        self.show_remaining_time = show_remaining_time;
+        self
+    }
+
+    /// Sets whether to render the time elapsed since the prompt was displayed,
+    /// ticking every second. When a timeout is set, the time left is rendered
+    /// instead.
+    pub fn with_show_elapsed_time(mut self, show_elapsed_time: bool) -> Self {
+        self.show_elapsed_time = show_elapsed_time;
+        self
+    }
+
+    /// Sets the maximum number of answers that could not be parsed, after
+    /// which the prompt returns the default value, if any, or fails with
+    /// [`InquireError::TooManyAttempts`].
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -246,6 +302,7 @@ impl<'a> From<Confirm<'a>> for CustomType<'a, bool> {
             message: co.message,
             starting_input: co.starting_input,
             default: co.default,
+            current_value: None,
             default_value_formatter: co.default_value_formatter,
             placeholder: co.placeholder,
             help_message: co.help_message,
@@ -253,6 +310,10 @@ impl<'a> From<Confirm<'a>> for CustomType<'a, bool> {
             parser: co.parser,
             validators: vec![],
             error_message: co.error_message,
+            timeout: co.timeout,
+            show_remaining_time: co.show_remaining_time,
+            show_elapsed_time: co.show_elapsed_time,
+            max_attempts: co.max_attempts,
             render_config: co.render_config,
         }
     }
@@ -535,15 +535,21 @@ fn ctrl_c_interrupts_prompt() -> InquireResult<()> {
     );
 
     assert_eq!(
-        1,
+        2,
         backend.frames.len(),
-        "Only an initial frame should have been rendered",
+        "Only an initial and a canceled frame should have been rendered",
+    );
+
+    let initial_frame = backend.frames().first().unwrap();
+    assert!(
+        initial_frame.has_token(&Token::Prompt("Question".into())),
+        "Initial frame did not contain the expected prompt token"
     );
 
     let final_frame = backend.frames().last().unwrap();
     assert!(
-        final_frame.has_token(&Token::Prompt("Question".into())),
-        "Final frame did not contain the expected prompt token"
+        final_frame.has_token(&Token::CanceledPrompt("Question".into())),
+        "Final frame did not contain the expected canceled prompt token"
     );
 
     Ok(())
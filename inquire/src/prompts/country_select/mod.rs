@@ -0,0 +1,292 @@
+mod data;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+use std::fmt;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    list_option::ListOption,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, SelectBackend},
+    Select,
+};
+
+use self::data::COUNTRIES;
+
+/// Country displayed as an option of the picker, along with the width its
+/// name is padded to, so that the codes are aligned in a column.
+#[derive(Clone)]
+struct CountryOption {
+    code: &'static str,
+    name: &'static str,
+    name_width: usize,
+    show_flag: bool,
+}
+
+impl fmt::Display for CountryOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.show_flag {
+            write!(f, "{} ", flag(self.code))?;
+        }
+
+        let padding = self.name_width.saturating_sub(self.name.width());
+        write!(f, "{}{} {}", self.name, " ".repeat(padding), self.code)
+    }
+}
+
+/// Flag emoji of a country, made of the regional indicator symbols of the
+/// letters of its code.
+fn flag(code: &str) -> String {
+    code.chars()
+        .filter_map(|c| char::from_u32(0x1F1E6 + (c as u32).checked_sub('A' as u32)?))
+        .collect()
+}
+
+/// Looks up the English short name of a country by its ISO 3166-1 alpha-2
+/// code, case-insensitively. Available via the `countries` feature.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::country_name;
+///
+/// assert_eq!(Some("Japan"), country_name("jp"));
+/// assert_eq!(None, country_name("XX"));
+/// ```
+pub fn country_name(code: &str) -> Option<&'static str> {
+    COUNTRIES
+        .iter()
+        .find(|(known, _)| known.eq_ignore_ascii_case(code))
+        .map(|(_, name)| *name)
+}
+
+/// Searchable picker of countries, returning the ISO 3166-1 alpha-2 code of the selected one, e.g. `"FR"`. Available via the `countries` feature, which embeds the list of countries.
+///
+/// Each country is displayed with its flag emoji, its English short name and its code, in aligned columns. Typing filters the countries by any of them, e.g. `france` or `fr`.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Countries**: Codes of the countries to choose from, in the given order. All countries by default, sorted by name.
+/// - **Default country**: Country under the cursor when the prompt is first rendered.
+/// - **Flags**: Whether to display the flag emojis, true by default. They are never displayed when the render config is [ASCII only](crate::ui::RenderConfig::ascii_only).
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Page size**: Number of countries displayed at once, 7 by default.
+///
+/// If the default country or any of the countries is not a known code, the prompt fails with an [`InquireError::InvalidConfiguration`] error.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::CountrySelect;
+///
+/// let country = CountrySelect::new("Where do you live?")
+///     .with_default("CA")
+///     .prompt();
+///
+/// match country {
+///     Ok(code) => println!("Shipping to {}", code),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct CountrySelect<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Codes of the countries to choose from. All countries when `None`.
+    pub countries: Option<Vec<&'a str>>,
+
+    /// Code of the country under the cursor when the prompt is first rendered.
+    pub default: Option<&'a str>,
+
+    /// Whether to display the flag emojis.
+    pub show_flags: bool,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the countries displayed to the user.
+    pub page_size: usize,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> CountrySelect<'a> {
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, enter to select, type a name or code to filter");
+
+    /// Creates a [CountrySelect] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            countries: None,
+            default: None,
+            show_flags: true,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the codes of the countries to choose from, in the given order.
+    pub fn with_countries(mut self, codes: &[&'a str]) -> Self {
+        self.countries = Some(codes.to_vec());
+        self
+    }
+
+    /// Sets the code of the country under the cursor when the prompt is first rendered.
+    pub fn with_default(mut self, code: &'a str) -> Self {
+        self.default = Some(code);
+        self
+    }
+
+    /// Sets whether to display the flag emojis.
+    pub fn with_show_flags(mut self, show_flags: bool) -> Self {
+        self.show_flags = show_flags;
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<&'static str>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<&'static str> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<&'static str> {
+        let countries = match &self.countries {
+            Some(codes) => codes
+                .iter()
+                .map(|code| find_country(code))
+                .collect::<InquireResult<Vec<_>>>()?,
+            None => COUNTRIES.to_vec(),
+        };
+
+        let starting_cursor = match self.default {
+            Some(default) => {
+                let (default, _) = find_country(default)?;
+                countries
+                    .iter()
+                    .position(|(code, _)| *code == default)
+                    .ok_or_else(|| {
+                        InquireError::InvalidConfiguration(format!(
+                            "Default country {} is not one of the countries to choose from",
+                            default
+                        ))
+                    })?
+            }
+            None => 0,
+        };
+
+        let name_width = countries
+            .iter()
+            .map(|(_, name)| name.width())
+            .max()
+            .unwrap_or(0);
+        let show_flag = self.show_flags && !self.render_config.ascii_only;
+
+        let options = countries
+            .into_iter()
+            .map(|(code, name)| CountryOption {
+                code,
+                name,
+                name_width,
+                show_flag,
+            })
+            .collect();
+
+        let formatter = &|option: ListOption<&CountryOption>| match option.value.show_flag {
+            true => format!("{} {}", flag(option.value.code), option.value.name),
+            false => String::from(option.value.name),
+        };
+
+        let mut select = Select::new(self.message, options)
+            .with_starting_cursor(starting_cursor)
+            .with_page_size(self.page_size)
+            .with_formatter(formatter)
+            .with_render_config(self.render_config);
+        select.help_message = self.help_message;
+
+        select
+            .prompt_with_backend(backend)
+            .map(|answer| answer.value.code)
+    }
+}
+
+fn find_country(code: &str) -> InquireResult<(&'static str, &'static str)> {
+    COUNTRIES
+        .iter()
+        .find(|(known, _)| known.eq_ignore_ascii_case(code))
+        .copied()
+        .ok_or_else(|| InquireError::InvalidConfiguration(format!("Unknown country code {}", code)))
+}
@@ -0,0 +1,101 @@
+use crate::{
+    error::InquireError,
+    terminal::crossterm::CrosstermTerminal,
+    test::fake_backend,
+    ui::{Backend, Key, KeyModifiers, RenderConfig},
+    CountrySelect,
+};
+
+fn text_keys(text: &str) -> Vec<Key> {
+    text.chars()
+        .map(|c| Key::Char(c, KeyModifiers::NONE))
+        .collect()
+}
+
+#[test]
+fn countries_are_filtered_by_name_and_the_code_is_returned() {
+    let mut keys = text_keys("new zealand");
+    keys.push(Key::Enter);
+    let mut backend = fake_backend(keys);
+
+    let ans = CountrySelect::new("Country")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("NZ", ans);
+}
+
+#[test]
+fn default_country_is_under_the_cursor() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = CountrySelect::new("Country")
+        .with_countries(&["fr", "de", "it"])
+        .with_default("DE")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("IT", ans);
+}
+
+#[test]
+fn unknown_codes_are_rejected() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = CountrySelect::new("Country")
+        .with_countries(&["FR", "XX"])
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = CountrySelect::new("Country")
+        .with_countries(&["FR", "DE"])
+        .with_default("IT")
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn flags_names_and_codes_are_aligned_in_columns() {
+    let mut backend = Backend::new(
+        vec![Key::Enter].into_iter(),
+        CrosstermTerminal::new_in_memory_output(),
+        RenderConfig::empty(),
+    )
+    .unwrap();
+
+    let ans = CountrySelect::new("Country:")
+        .with_countries(&["JP", "GB"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("JP", ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("\u{1F1EF}\u{1F1F5} Japan          JP"));
+    assert!(output.contains("\u{1F1EC}\u{1F1E7} United Kingdom GB"));
+    assert!(output.contains("? Country: \u{1F1EF}\u{1F1F5} Japan\x1b[K\r\n"));
+}
+
+#[test]
+fn flags_are_hidden_in_ascii_only_mode() {
+    let mut backend = Backend::new(
+        vec![Key::Enter].into_iter(),
+        CrosstermTerminal::new_in_memory_output(),
+        RenderConfig::empty().with_ascii_only(true),
+    )
+    .unwrap();
+
+    CountrySelect::new("Country:")
+        .with_countries(&["JP", "GB"])
+        .with_render_config(RenderConfig::empty().with_ascii_only(true))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("Japan          JP"));
+    assert!(!output.contains('\u{1F1EF}'));
+}
@@ -1,10 +1,10 @@
 mod action;
-mod config;
+pub(crate) mod config;
 mod prompt;
 
 pub use action::*;
 
-use std::str::FromStr;
+use std::{str::FromStr, time::Duration};
 
 use crate::{
     config::get_configuration,
@@ -46,6 +46,7 @@ use self::prompt::CustomTypePrompt;
 ///     formatter: &|i| format!("${:.2}", i),
 ///     default_value_formatter: &|i| format!("${:.2}", i),
 ///     default: None,
+///     current_value: None,
 ///     validators: vec![],
 ///     placeholder: Some("123.45"),
 ///     error_message: "Please type a valid number.".into(),
@@ -54,6 +55,10 @@ use self::prompt::CustomTypePrompt;
 ///         Ok(val) => Ok(val),
 ///         Err(_) => Err(()),
 ///     },
+///     timeout: None,
+///     show_remaining_time: false,
+///     show_elapsed_time: false,
+///     max_attempts: None,
 ///     render_config: RenderConfig::default(),
 /// };
 /// ```
@@ -91,6 +96,10 @@ pub struct CustomType<'a, T> {
     /// Default value, returned when the user input is empty.
     pub default: Option<T>,
 
+    /// Current value of the setting being edited, displayed next to the
+    /// prompt message, e.g. `Timeout [current: 30s]:`.
+    pub current_value: Option<T>,
+
     /// Short hint that describes the expected value of the input.
     pub placeholder: Option<&'a str>,
 
@@ -117,6 +126,22 @@ pub struct CustomType<'a, T> {
     /// Error message displayed when value could not be parsed from input.
     pub error_message: String,
 
+    /// Maximum time to wait for an answer before failing with [`InquireError::Timeout`].
+    pub timeout: Option<Duration>,
+
+    /// Whether to render the time left until the timeout elapses.
+    pub show_remaining_time: bool,
+
+    /// Whether to render the time elapsed since the prompt was displayed, or the
+    /// time left when a timeout is set.
+    pub show_elapsed_time: bool,
+
+    /// Maximum number of rejected answers, after which the prompt returns
+    /// the default value, if any, or fails with
+    /// [`InquireError::TooManyAttempts`]. An answer is rejected when it does
+    /// not pass the validators or can not be parsed.
+    pub max_attempts: Option<usize>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -144,6 +169,7 @@ where
             message,
             starting_input: None,
             default: None,
+            current_value: None,
             placeholder: None,
             help_message: None,
             formatter: &|val| val.to_string(),
@@ -151,6 +177,10 @@ where
             parser: &|a| a.parse::<T>().map_err(|_e| ()),
             validators: Self::DEFAULT_VALIDATORS,
             error_message: "Invalid input".into(),
+            timeout: None,
+            show_remaining_time: false,
+            show_elapsed_time: false,
+            max_attempts: None,
             render_config: get_configuration(),
         }
     }
@@ -171,6 +201,15 @@ where
         self
     }
 
+    /// Sets the current value of the setting being edited, displayed next to
+    /// the prompt message, e.g. `Timeout [current: 30s]:`. It is formatted
+    /// with the formatter of the prompt, and the final answer is displayed
+    /// as `30s → 45s` when it differs from the current value.
+    pub fn with_current_value(mut self, current_value: T) -> Self {
+        self.current_value = Some(current_value);
+        self
+    }
+
     /// Sets the placeholder.
     pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
         self.placeholder = Some(placeholder);
@@ -241,6 +280,41 @@ where
         self
     }
 
+    /// Sets the maximum time to wait for an answer, after which the prompt
+    /// fails with [`InquireError::Timeout`].
+    ///
+    /// The deadline can only interrupt a pending key read when using the
+    /// `crossterm` backend. Other backends notice it on the next key press.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to render the time left until the timeout elapses.
+    pub fn with_show_remaining_time(mut self, show_remaining_time: bool) -> Self {
+        self.show_remaining_time = show_remaining_time;
+        self
+    }
+
+    /// Sets whether to render the time elapsed since the prompt was displayed,
+    /// ticking every second. When a timeout is set, the time left is rendered
+    /// instead.
+    pub fn with_show_elapsed_time(mut self, show_elapsed_time: bool) -> Self {
+        self.show_elapsed_time = show_elapsed_time;
+        self
+    }
+
+    /// Sets the maximum number of rejected answers, after which the prompt
+    /// returns the default value, if any, or fails with
+    /// [`InquireError::TooManyAttempts`].
+    ///
+    /// Useful for scripts that should not ask again forever, e.g. when the
+    /// input is piped in.
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::{
     error::InquireResult,
     formatter::CustomTypeFormatter,
@@ -5,6 +7,7 @@ use crate::{
     parser::CustomTypeParser,
     prompts::prompt::{ActionResult, Prompt},
     ui::CustomTypeBackend,
+    utils::format_edited_answer,
     validator::{CustomTypeValidator, ErrorMessage, Validation},
     CustomType, InquireError,
 };
@@ -13,10 +16,15 @@ use super::{action::CustomTypePromptAction, config::CustomTypeConfig};
 
 pub struct CustomTypePrompt<'a, T> {
     message: &'a str,
+    timeout: Option<Duration>,
+    show_remaining_time: bool,
+    show_elapsed_time: bool,
+    max_attempts: Option<usize>,
     config: CustomTypeConfig,
     error: Option<ErrorMessage>,
     help_message: Option<&'a str>,
     default: Option<T>,
+    current_value: Option<T>,
     input: Input,
     formatter: CustomTypeFormatter<'a, T>,
     default_value_formatter: CustomTypeFormatter<'a, T>,
@@ -39,9 +47,14 @@ where
 
         Self {
             message: co.message,
+            timeout: co.timeout,
+            show_remaining_time: co.show_remaining_time,
+            show_elapsed_time: co.show_elapsed_time,
+            max_attempts: co.max_attempts,
             config: (&co).into(),
             error: None,
             default: co.default,
+            current_value: co.current_value,
             help_message: co.help_message,
             formatter: co.formatter,
             default_value_formatter: co.default_value_formatter,
@@ -100,7 +113,38 @@ where
     }
 
     fn format_answer(&self, answer: &T) -> String {
-        (self.formatter)((*answer).clone())
+        let current_value = self
+            .current_value
+            .clone()
+            .map(|current| (self.formatter)(current));
+        format_edited_answer(current_value, (self.formatter)((*answer).clone()))
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    fn show_remaining_time(&self) -> bool {
+        self.show_remaining_time
+    }
+
+    fn show_elapsed_time(&self) -> bool {
+        self.show_elapsed_time
+    }
+
+    fn max_attempts(&self) -> Option<usize> {
+        self.max_attempts
+    }
+
+    fn fallback_answer(&self) -> Option<T> {
+        self.default.clone()
+    }
+
+    fn can_submit(&self) -> InquireResult<bool> {
+        match self.get_final_answer() {
+            Ok(answer) => Ok(self.validate_current_answer(&answer)? == Validation::Valid),
+            Err(_) => Ok(false),
+        }
     }
 
     fn submit(&mut self) -> InquireResult<Option<T>> {
@@ -144,7 +188,17 @@ where
             .as_ref()
             .map(|val| default_value_formatter(val.clone()));
 
-        backend.render_prompt(prompt, default_message.as_deref(), &self.input)?;
+        let current_value = self
+            .current_value
+            .clone()
+            .map(|current| (self.formatter)(current));
+
+        backend.render_prompt(
+            prompt,
+            current_value.as_deref(),
+            default_message.as_deref(),
+            &self.input,
+        )?;
 
         if let Some(message) = self.help_message {
             backend.render_help_message(message)?;
@@ -1,3 +1,7 @@
+use std::cmp::Ordering;
+
+use chrono::{Duration, Months, NaiveDate};
+
 use crate::{
     ui::{Key, KeyModifiers},
     InnerAction,
@@ -27,6 +31,41 @@ pub enum DateSelectPromptAction {
     GoToNextYear,
 }
 
+impl DateSelectPromptAction {
+    /// Date the day cursor moves to from the given date, regardless of the
+    /// min and max dates.
+    pub(crate) fn move_from(self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Self::GoToPrevWeek => shift_days(date, -7),
+            Self::GoToNextWeek => shift_days(date, 7),
+            Self::GoToPrevDay => shift_days(date, -1),
+            Self::GoToNextDay => shift_days(date, 1),
+            Self::GoToPrevYear => shift_months(date, -12),
+            Self::GoToNextYear => shift_months(date, 12),
+            Self::GoToPrevMonth => shift_months(date, -1),
+            Self::GoToNextMonth => shift_months(date, 1),
+        }
+    }
+}
+
+fn shift_days(date: NaiveDate, qty: i64) -> NaiveDate {
+    let duration =
+        Duration::try_days(qty).expect("unexpected overflow when calculating duration of days");
+
+    date + duration
+}
+
+fn shift_months(date: NaiveDate, qty: i32) -> NaiveDate {
+    match qty.cmp(&0) {
+        Ordering::Greater | Ordering::Equal => date
+            .checked_add_months(Months::new(qty as u32))
+            .unwrap_or(NaiveDate::MAX),
+        Ordering::Less => date
+            .checked_sub_months(Months::new((-qty) as u32))
+            .unwrap_or(NaiveDate::MIN),
+    }
+}
+
 impl InnerAction for DateSelectPromptAction {
     type Config = DateSelectConfig;
 
@@ -1,3 +1,5 @@
+use std::cmp::{max, min};
+
 use chrono::NaiveDate;
 
 use crate::DateSelect;
@@ -15,6 +17,21 @@ pub struct DateSelectConfig {
     pub week_start: chrono::Weekday,
 }
 
+impl DateSelectConfig {
+    /// Brings the date back within the min and max dates.
+    pub(crate) fn clamp(&self, date: NaiveDate) -> NaiveDate {
+        let date = match self.min_date {
+            Some(min_date) => max(date, min_date),
+            None => date,
+        };
+
+        match self.max_date {
+            Some(max_date) => min(date, max_date),
+            None => date,
+        }
+    }
+}
+
 impl From<&DateSelect<'_>> for DateSelectConfig {
     fn from(value: &DateSelect<'_>) -> Self {
         Self {
@@ -1,5 +1,5 @@
 mod action;
-mod config;
+pub(crate) mod config;
 mod prompt;
 #[cfg(test)]
 #[cfg(feature = "crossterm")]
@@ -43,7 +43,7 @@ use self::prompt::DateSelectPrompt;
 ///   - Formats to "Month Day, Year" by default.
 /// - **Validators**: Custom validators to the user's selected date, displaying an error message if the date does not pass the requirements.
 /// - **Week start**: Which day of the week should be displayed in the first column of the calendar, Sunday by default.
-/// - **Min and max date**: Inclusive boundaries of allowed dates in the interactive calendar. If any boundary is set, the user will not be able to move past them, consequently not being able to select any dates out of the allowed range.
+/// - **Min and max date**: Inclusive boundaries of allowed dates in the interactive calendar. If any boundary is set, the user will not be able to move past them, consequently not being able to select any dates out of the allowed range. Days out of the range are rendered with the `calendar.unavailable_date` style of the render config, and moving by weeks, months or years stops at the boundary.
 ///
 /// # Example
 ///
@@ -167,13 +167,13 @@ impl<'a> DateSelect<'a> {
         self
     }
 
-    /// Sets the min date.
+    /// Sets the min date, inclusive. Earlier days are dimmed and can not be selected.
     pub fn with_min_date(mut self, min_date: NaiveDate) -> Self {
         self.min_date = Some(min_date);
         self
     }
 
-    /// Sets the max date.
+    /// Sets the max date, inclusive. Later days are dimmed and can not be selected.
     pub fn with_max_date(mut self, max_date: NaiveDate) -> Self {
         self.max_date = Some(max_date);
         self
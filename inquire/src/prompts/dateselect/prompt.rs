@@ -1,9 +1,6 @@
-use std::{
-    cmp::{max, min, Ordering},
-    ops::Add,
-};
+use std::collections::BTreeSet;
 
-use chrono::{Datelike, Duration, Months, NaiveDate};
+use chrono::{Datelike, NaiveDate};
 
 use crate::{
     date_utils::{get_current_date, get_month},
@@ -29,6 +26,13 @@ pub struct DateSelectPrompt<'a> {
 
 impl<'a> DateSelectPrompt<'a> {
     pub fn new(so: DateSelect<'a>) -> InquireResult<Self> {
+        if let (Some(min_date), Some(max_date)) = (so.min_date, so.max_date) {
+            if min_date > max_date {
+                return Err(InquireError::InvalidConfiguration(
+                    "Min date can not be greater than max date".into(),
+                ));
+            }
+        }
         if let Some(min_date) = so.min_date {
             if min_date > so.starting_date {
                 return Err(InquireError::InvalidConfiguration(
@@ -55,41 +59,12 @@ impl<'a> DateSelectPrompt<'a> {
         })
     }
 
-    fn shift_date(&mut self, duration: Duration) -> ActionResult {
-        self.update_date(self.current_date.add(duration))
-    }
-
-    fn shift_months(&mut self, qty: i32) -> ActionResult {
-        let new_date = match qty.cmp(&0) {
-            Ordering::Greater | Ordering::Equal => {
-                let qty_as_months = Months::new(qty as u32);
-                self.current_date
-                    .checked_add_months(qty_as_months)
-                    .unwrap_or(NaiveDate::MAX)
-            }
-            Ordering::Less => {
-                let qty_as_months = Months::new((-qty) as u32);
-                self.current_date
-                    .checked_sub_months(qty_as_months)
-                    .unwrap_or(NaiveDate::MIN)
-            }
-        };
-
-        self.update_date(new_date)
-    }
-
     fn update_date(&mut self, new_date: NaiveDate) -> ActionResult {
         if self.current_date == new_date {
             return ActionResult::Clean;
         }
 
-        self.current_date = new_date;
-        if let Some(min_date) = self.config.min_date {
-            self.current_date = max(self.current_date, min_date);
-        }
-        if let Some(max_date) = self.config.max_date {
-            self.current_date = min(self.current_date, max_date);
-        }
+        self.current_date = self.config.clamp(new_date);
 
         ActionResult::NeedsRedraw
     }
@@ -131,6 +106,10 @@ where
         &self.config
     }
 
+    fn can_submit(&self) -> InquireResult<bool> {
+        Ok(self.validate_current_answer()? == Validation::Valid)
+    }
+
     fn submit(&mut self) -> InquireResult<Option<NaiveDate>> {
         let answer = match self.validate_current_answer()? {
             Validation::Valid => Some(self.cur_answer()),
@@ -144,30 +123,7 @@ where
     }
 
     fn handle(&mut self, action: DateSelectPromptAction) -> InquireResult<ActionResult> {
-        let result = match action {
-            DateSelectPromptAction::GoToPrevWeek => self.shift_date(
-                Duration::try_weeks(-1)
-                    .expect("unexpected overflow when calculating duration of 1 week"),
-            ),
-            DateSelectPromptAction::GoToNextWeek => self.shift_date(
-                Duration::try_weeks(1)
-                    .expect("unexpected overflow when calculating duration of 1 week"),
-            ),
-            DateSelectPromptAction::GoToPrevDay => self.shift_date(
-                Duration::try_days(-1)
-                    .expect("unexpected overflow when calculating duration of 1 day"),
-            ),
-            DateSelectPromptAction::GoToNextDay => self.shift_date(
-                Duration::try_days(1)
-                    .expect("unexpected overflow when calculating duration of 1 day"),
-            ),
-            DateSelectPromptAction::GoToPrevYear => self.shift_months(-12),
-            DateSelectPromptAction::GoToNextYear => self.shift_months(12),
-            DateSelectPromptAction::GoToPrevMonth => self.shift_months(-1),
-            DateSelectPromptAction::GoToNextMonth => self.shift_months(1),
-        };
-
-        Ok(result)
+        Ok(self.update_date(action.move_from(self.current_date)))
     }
 
     fn render(&self, backend: &mut B) -> InquireResult<()> {
@@ -185,6 +141,7 @@ where
             self.config.week_start,
             get_current_date(),
             self.current_date,
+            &BTreeSet::new(),
             self.config.min_date,
             self.config.max_date,
         )?;
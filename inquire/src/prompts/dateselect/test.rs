@@ -810,15 +810,21 @@ fn ctrl_c_interrupts_prompt() -> InquireResult<()> {
     );
 
     assert_eq!(
-        1,
+        2,
         backend.frames.len(),
-        "Only an initial frame should have been rendered",
+        "Only an initial and a canceled frame should have been rendered",
+    );
+
+    let initial_frame = backend.frames().first().unwrap();
+    assert!(
+        initial_frame.has_token(&Token::Prompt("Question".into())),
+        "Initial frame did not contain the expected prompt token"
     );
 
     let final_frame = backend.frames().last().unwrap();
     assert!(
-        final_frame.has_token(&Token::Prompt("Question".into())),
-        "Final frame did not contain the expected prompt token"
+        final_frame.has_token(&Token::CanceledPrompt("Question".into())),
+        "Final frame did not contain the expected canceled prompt token"
     );
 
     Ok(())
@@ -1017,3 +1023,35 @@ fn multiple_validators_are_respected() -> InquireResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn min_date_greater_than_max_date_is_rejected() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let answer = DateSelect::new("Question")
+        .with_starting_date(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap())
+        .with_min_date(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap())
+        .with_max_date(NaiveDate::from_ymd_opt(2022, 12, 31).unwrap())
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(answer, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn month_navigation_stops_at_max_date() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char(']', KeyModifiers::NONE),
+        Key::Char(']', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let max_date = NaiveDate::from_ymd_opt(2023, 2, 10).unwrap();
+    let answer = DateSelect::new("Question")
+        .with_starting_date(NaiveDate::from_ymd_opt(2023, 1, 20).unwrap())
+        .with_max_date(max_date)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(max_date, answer);
+
+    Ok(())
+}
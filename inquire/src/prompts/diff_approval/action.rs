@@ -0,0 +1,77 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    DiffDecision, InnerAction,
+};
+
+use super::config::DiffApprovalConfig;
+
+/// Set of actions for a DiffApprovalPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DiffApprovalPromptAction {
+    /// Scrolls the diff one line up.
+    ScrollUp,
+    /// Scrolls the diff one line down.
+    ScrollDown,
+    /// Scrolls the diff one page up.
+    PageUp,
+    /// Scrolls the diff one page down.
+    PageDown,
+    /// Scrolls to the start of the diff.
+    ScrollToStart,
+    /// Scrolls to the end of the diff.
+    ScrollToEnd,
+    /// Highlights the previous decision.
+    PreviousDecision,
+    /// Highlights the next decision.
+    NextDecision,
+    /// Highlights the given decision.
+    HighlightDecision(DiffDecision),
+}
+
+impl InnerAction for DiffApprovalPromptAction {
+    type Config = DiffApprovalConfig;
+
+    fn from_key(key: Key, config: &DiffApprovalConfig) -> Option<Self> {
+        if config.vim_mode {
+            let action = match key {
+                Key::Char('k', KeyModifiers::NONE) => Some(Self::ScrollUp),
+                Key::Char('j', KeyModifiers::NONE) => Some(Self::ScrollDown),
+                Key::Char('h', KeyModifiers::NONE) => Some(Self::PreviousDecision),
+                Key::Char('l', KeyModifiers::NONE) => Some(Self::NextDecision),
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::ScrollUp,
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => {
+                Self::ScrollDown
+            }
+            Key::PageUp(_) => Self::PageUp,
+            Key::PageDown(_) | Key::Char(' ', KeyModifiers::NONE) => Self::PageDown,
+            Key::Home => Self::ScrollToStart,
+            Key::End => Self::ScrollToEnd,
+
+            Key::Left(KeyModifiers::NONE) => Self::PreviousDecision,
+            Key::Right(KeyModifiers::NONE) | Key::Tab => Self::NextDecision,
+
+            Key::Char('a' | 'y', KeyModifiers::NONE) => {
+                Self::HighlightDecision(DiffDecision::Approve)
+            }
+            Key::Char('r' | 'n', KeyModifiers::NONE) => {
+                Self::HighlightDecision(DiffDecision::Reject)
+            }
+            Key::Char('e', KeyModifiers::NONE) if config.allow_edit => {
+                Self::HighlightDecision(DiffDecision::Edit)
+            }
+
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,22 @@
+use crate::DiffApproval;
+
+/// Configuration settings used in the execution of a DiffApprovalPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct DiffApprovalConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// Number of diff lines displayed at once.
+    pub page_size: usize,
+    /// Whether the user can choose to edit the change.
+    pub allow_edit: bool,
+}
+
+impl From<&DiffApproval<'_>> for DiffApprovalConfig {
+    fn from(value: &DiffApproval<'_>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            page_size: value.page_size,
+            allow_edit: value.allow_edit,
+        }
+    }
+}
@@ -0,0 +1,261 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use std::fmt::Display;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, DiffBackend, RenderConfig},
+};
+
+use self::prompt::DiffApprovalPrompt;
+
+/// Decision taken by the user on a [DiffApproval] prompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DiffDecision {
+    /// The change was approved and should be applied.
+    Approve,
+
+    /// The change was rejected and should be discarded.
+    Reject,
+
+    /// The user wants to edit the change before deciding.
+    Edit,
+}
+
+impl Display for DiffDecision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Approve => write!(f, "approve"),
+            Self::Reject => write!(f, "reject"),
+            Self::Edit => write!(f, "edit"),
+        }
+    }
+}
+
+/// Prompt that displays a unified diff and asks the user to approve, reject or edit the change, the core interaction of release tools and migration runners.
+///
+/// The diff is provided by the caller and rendered with its file headers, hunk headers, added and removed lines styled according to the [`DiffRenderConfig`](crate::ui::DiffRenderConfig) of the render config. Long diffs are paginated, and the user scrolls through them with the up and down arrows, page up, page down and space.
+///
+/// The decisions are listed below the diff. The user highlights one with the left and right arrows, or by pressing `a` (approve), `r` (reject) or `e` (edit), and confirms it with enter.
+///
+/// Editing the change is up to the caller, for example by opening the file or the patch in an [Editor](crate::Editor) prompt when [`DiffDecision::Edit`] is returned, and possibly asking for approval again afterwards. The edit decision can be disabled.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Diff**: Unified diff displayed to the user, required when creating the prompt.
+/// - **Default decision**: Decision highlighted when the prompt is first rendered, reject by default.
+/// - **Allow edit**: Whether the edit decision is available, true by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - By default, prints `approved`, `rejected` or `edit requested`.
+/// - **Page size**: Number of diff lines displayed at once, 20 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{DiffApproval, DiffDecision};
+///
+/// let diff = "\
+/// --- a/Cargo.toml
+/// +++ b/Cargo.toml
+/// @@ -1,3 +1,3 @@
+///  [package]
+///  name = \"my-crate\"
+/// -version = \"0.1.0\"
+/// +version = \"0.2.0\"
+/// ";
+///
+/// match DiffApproval::new("Apply the version bump?", diff).prompt() {
+///     Ok(DiffDecision::Approve) => println!("Bumping version..."),
+///     Ok(DiffDecision::Edit) => println!("Opening editor..."),
+///     Ok(DiffDecision::Reject) | Err(_) => println!("Version left unchanged"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct DiffApproval<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Unified diff displayed to the user.
+    pub diff: &'a str,
+
+    /// Decision highlighted when the prompt is first rendered.
+    pub default: DiffDecision,
+
+    /// Whether the edit decision is available.
+    pub allow_edit: bool,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Number of diff lines displayed at once.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// scroll with j and k and highlight decisions with h and l.
+    pub vim_mode: bool,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: CustomTypeFormatter<'a, DiffDecision>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> DiffApproval<'a> {
+    /// Default formatter, prints `approved`, `rejected` or `edit requested`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::{DiffApproval, DiffDecision};
+    ///
+    /// let formatter = DiffApproval::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("approved"), formatter(DiffDecision::Approve));
+    /// assert_eq!(String::from("rejected"), formatter(DiffDecision::Reject));
+    /// assert_eq!(String::from("edit requested"), formatter(DiffDecision::Edit));
+    /// ```
+    pub const DEFAULT_FORMATTER: CustomTypeFormatter<'a, DiffDecision> = &|decision| match decision
+    {
+        DiffDecision::Approve => String::from("approved"),
+        DiffDecision::Reject => String::from("rejected"),
+        DiffDecision::Edit => String::from("edit requested"),
+    };
+
+    /// Default decision, reject, so that changes are never applied by accident.
+    pub const DEFAULT_DEFAULT: DiffDecision = DiffDecision::Reject;
+
+    /// Default value of the allow edit flag.
+    pub const DEFAULT_ALLOW_EDIT: bool = true;
+
+    /// Default number of diff lines displayed at once.
+    pub const DEFAULT_PAGE_SIZE: usize = 20;
+
+    /// Default value of vim mode, equal to the global default value [config::DEFAULT_VIM_MODE]
+    pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to scroll, ←→ to choose, enter to confirm");
+
+    /// Creates a [DiffApproval] with the provided message and diff, along with default configuration values.
+    pub fn new(message: &'a str, diff: &'a str) -> Self {
+        Self {
+            message,
+            diff,
+            default: Self::DEFAULT_DEFAULT,
+            allow_edit: Self::DEFAULT_ALLOW_EDIT,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the decision highlighted when the prompt is first rendered.
+    pub fn with_default(mut self, default: DiffDecision) -> Self {
+        self.default = default;
+        self
+    }
+
+    /// Enables or disables the edit decision.
+    pub fn with_allow_edit(mut self, allow_edit: bool) -> Self {
+        self.allow_edit = allow_edit;
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the number of diff lines displayed at once.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, DiffDecision>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<DiffDecision>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<DiffDecision> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: DiffBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<DiffDecision> {
+        DiffApprovalPrompt::from(self).prompt(backend)
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{
+    error::InquireResult,
+    formatter::CustomTypeFormatter,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::DiffBackend,
+    DiffApproval, DiffDecision,
+};
+
+use super::{action::DiffApprovalPromptAction, config::DiffApprovalConfig};
+
+pub struct DiffApprovalPrompt<'a> {
+    message: &'a str,
+    config: DiffApprovalConfig,
+    lines: Vec<&'a str>,
+    decisions: Vec<DiffDecision>,
+    help_message: Option<&'a str>,
+    scroll_offset: usize,
+    decision_index: usize,
+    formatter: CustomTypeFormatter<'a, DiffDecision>,
+}
+
+impl<'a> From<DiffApproval<'a>> for DiffApprovalPrompt<'a> {
+    fn from(da: DiffApproval<'a>) -> Self {
+        let mut decisions = vec![DiffDecision::Approve, DiffDecision::Reject];
+        if da.allow_edit {
+            decisions.push(DiffDecision::Edit);
+        }
+
+        let decision_index = decisions
+            .iter()
+            .position(|d| *d == da.default)
+            .unwrap_or_default();
+
+        Self {
+            message: da.message,
+            config: (&da).into(),
+            lines: da.diff.lines().collect(),
+            decisions,
+            help_message: da.help_message,
+            scroll_offset: 0,
+            decision_index,
+            formatter: da.formatter,
+        }
+    }
+}
+
+impl<'a> DiffApprovalPrompt<'a> {
+    fn max_scroll_offset(&self) -> usize {
+        self.lines.len().saturating_sub(self.config.page_size)
+    }
+
+    fn scroll_up(&mut self, qty: usize) -> ActionResult {
+        self.update_scroll_offset(self.scroll_offset.saturating_sub(qty))
+    }
+
+    fn scroll_down(&mut self, qty: usize) -> ActionResult {
+        let new_offset = self
+            .scroll_offset
+            .saturating_add(qty)
+            .min(self.max_scroll_offset());
+        self.update_scroll_offset(new_offset)
+    }
+
+    fn update_scroll_offset(&mut self, new_offset: usize) -> ActionResult {
+        if new_offset != self.scroll_offset {
+            self.scroll_offset = new_offset;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn update_decision_index(&mut self, new_index: usize) -> ActionResult {
+        if new_index != self.decision_index {
+            self.decision_index = new_index;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for DiffApprovalPrompt<'a>
+where
+    Backend: DiffBackend,
+{
+    type Config = DiffApprovalConfig;
+    type InnerAction = DiffApprovalPromptAction;
+    type Output = DiffDecision;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &DiffApprovalConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &DiffDecision) -> String {
+        (self.formatter)(*answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<DiffDecision>> {
+        Ok(self.decisions.get(self.decision_index).copied())
+    }
+
+    fn handle(&mut self, action: DiffApprovalPromptAction) -> InquireResult<ActionResult> {
+        let len = self.decisions.len();
+
+        let result = match action {
+            DiffApprovalPromptAction::ScrollUp => self.scroll_up(1),
+            DiffApprovalPromptAction::ScrollDown => self.scroll_down(1),
+            DiffApprovalPromptAction::PageUp => self.scroll_up(self.config.page_size),
+            DiffApprovalPromptAction::PageDown => self.scroll_down(self.config.page_size),
+            DiffApprovalPromptAction::ScrollToStart => self.scroll_up(usize::MAX),
+            DiffApprovalPromptAction::ScrollToEnd => self.scroll_down(usize::MAX),
+            DiffApprovalPromptAction::PreviousDecision => {
+                self.update_decision_index((self.decision_index + len - 1) % len)
+            }
+            DiffApprovalPromptAction::NextDecision => {
+                self.update_decision_index((self.decision_index + 1) % len)
+            }
+            DiffApprovalPromptAction::HighlightDecision(decision) => {
+                match self.decisions.iter().position(|d| *d == decision) {
+                    Some(index) => self.update_decision_index(index),
+                    None => ActionResult::Clean,
+                }
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        backend.render_diff_approval_prompt(self.message)?;
+
+        let last = self
+            .scroll_offset
+            .saturating_add(self.config.page_size)
+            .min(self.lines.len());
+
+        backend.render_diff(
+            &self.lines[self.scroll_offset..last],
+            self.scroll_offset,
+            self.lines.len(),
+        )?;
+
+        backend.render_diff_decisions(&self.decisions, self.decision_index)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
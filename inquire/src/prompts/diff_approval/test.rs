@@ -0,0 +1,69 @@
+use crate::{
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    DiffApproval, DiffDecision,
+};
+
+const DIFF: &str = "\
+--- a/file
++++ b/file
+@@ -1,2 +1,2 @@
+ context
+-old
++new
+";
+
+#[test]
+fn enter_submits_the_default_decision() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = DiffApproval::new("Question", DIFF)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(DiffDecision::Reject, ans);
+}
+
+#[test]
+fn shortcut_highlights_the_decision() {
+    let mut backend = fake_backend(vec![
+        Key::PageDown(KeyModifiers::NONE),
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = DiffApproval::new("Question", DIFF)
+        .with_page_size(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(DiffDecision::Approve, ans);
+}
+
+#[test]
+fn decisions_wrap_around() {
+    let mut backend = fake_backend(vec![Key::Left(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = DiffApproval::new("Question", DIFF)
+        .with_default(DiffDecision::Approve)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(DiffDecision::Edit, ans);
+}
+
+#[test]
+fn edit_is_skipped_when_disallowed() {
+    let mut backend = fake_backend(vec![
+        Key::Char('e', KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = DiffApproval::new("Question", DIFF)
+        .with_allow_edit(false)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(DiffDecision::Approve, ans);
+}
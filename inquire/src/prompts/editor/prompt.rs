@@ -116,6 +116,10 @@ where
         (self.formatter)(answer)
     }
 
+    fn can_submit(&self) -> InquireResult<bool> {
+        Ok(self.validate_current_answer()? == Validation::Valid)
+    }
+
     fn submit(&mut self) -> InquireResult<Option<String>> {
         let answer = match self.validate_current_answer()? {
             Validation::Valid => Some(self.cur_answer()?),
@@ -0,0 +1,25 @@
+use crate::{ui::Key, InnerAction, InputAction};
+
+use super::config::EmailConfig;
+
+/// Set of actions for an EmailPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EmailPromptAction {
+    /// Action on the value text input handler.
+    ValueInput(InputAction),
+    /// Replaces the domain of the input by the suggested one, if any.
+    AcceptSuggestion,
+}
+
+impl InnerAction for EmailPromptAction {
+    type Config = EmailConfig;
+
+    fn from_key(key: Key, _config: &EmailConfig) -> Option<Self> {
+        let action = match key {
+            Key::Tab => Self::AcceptSuggestion,
+            key => Self::ValueInput(InputAction::from_key(key, &())?),
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,234 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::{StringFormatter, DEFAULT_STRING_FORMATTER},
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, CustomTypeBackend, RenderConfig},
+    validator::StringValidator,
+};
+
+use self::prompt::EmailPrompt;
+
+/// Text prompt for email addresses, validated before being submitted.
+///
+/// While the user types, the domain of the address is compared to a list of common email domains. When it looks like a typo of one of them, e.g. `gmial.com`, a "did you mean gmail.com?" hint is displayed in place of the help message, and pressing tab replaces the domain by the suggested one. Addresses with an unknown domain can still be submitted as typed.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Initial value**: Initial value of the prompt's text input.
+/// - **Default value**: Default value returned when the user submits an empty response.
+/// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Help message**: Message displayed at the line below the prompt, when no domain is suggested.
+/// - **Suggested domains**: Domains compared to the typed one to detect typos, [`Email::DEFAULT_SUGGESTED_DOMAINS`] by default.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+/// - **Validators**: Custom validators to make sure a given submitted input pass the specified requirements, e.g. only allowing addresses of your company. They run after the address is checked to be well-formed.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Email;
+///
+/// let email = Email::new("What's your email?")
+///     .with_placeholder("ada@example.com")
+///     .prompt();
+///
+/// match email {
+///     Ok(email) => println!("We will send a confirmation to {}", email),
+///     Err(_) => println!("An error happened when asking for your email, try again later."),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Email<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Initial value of the prompt's text input.
+    pub initial_value: Option<&'a str>,
+
+    /// Default value, returned when the user input is empty.
+    pub default: Option<&'a str>,
+
+    /// Short hint that describes the expected value of the input.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user, when no domain is suggested.
+    pub help_message: Option<&'a str>,
+
+    /// Domains compared to the typed one to detect typos.
+    pub suggested_domains: Vec<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: StringFormatter<'a>,
+
+    /// Collection of validators to apply to the user input, once it is
+    /// checked to be a well-formed address.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn StringValidator>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> Email<'a> {
+    /// Default formatter, set to [DEFAULT_STRING_FORMATTER](crate::formatter::DEFAULT_STRING_FORMATTER)
+    pub const DEFAULT_FORMATTER: StringFormatter<'a> = DEFAULT_STRING_FORMATTER;
+
+    /// Default validators added to the [Email] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn StringValidator>> = vec![];
+
+    /// Default domains compared to the typed one to detect typos.
+    pub const DEFAULT_SUGGESTED_DOMAINS: &'static [&'static str] = &[
+        "gmail.com",
+        "googlemail.com",
+        "yahoo.com",
+        "hotmail.com",
+        "outlook.com",
+        "live.com",
+        "msn.com",
+        "icloud.com",
+        "me.com",
+        "aol.com",
+        "proton.me",
+        "protonmail.com",
+        "gmx.com",
+        "mail.com",
+        "yandex.com",
+        "zoho.com",
+        "yahoo.co.uk",
+        "hotmail.co.uk",
+    ];
+
+    /// Creates an [Email] with the provided message and default options.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            initial_value: None,
+            default: None,
+            placeholder: None,
+            help_message: None,
+            suggested_domains: Self::DEFAULT_SUGGESTED_DOMAINS.to_vec(),
+            formatter: Self::DEFAULT_FORMATTER,
+            validators: Self::DEFAULT_VALIDATORS,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the initial value of the prompt's text input.
+    pub fn with_initial_value(mut self, initial_value: &'a str) -> Self {
+        self.initial_value = Some(initial_value);
+        self
+    }
+
+    /// Sets the default input.
+    pub fn with_default(mut self, default: &'a str) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets the domains compared to the typed one to detect typos,
+    /// e.g. the domains of your company.
+    pub fn with_suggested_domains(mut self, suggested_domains: &[&'a str]) -> Self {
+        self.suggested_domains = suggested_domains.to_vec();
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: StringFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the user's answer.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: StringValidator + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<String>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<String> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<String> {
+        EmailPrompt::from(self).prompt(backend)
+    }
+}
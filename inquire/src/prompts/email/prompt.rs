@@ -0,0 +1,268 @@
+use crate::{
+    error::InquireResult,
+    formatter::StringFormatter,
+    input::{Input, InputActionResult},
+    prompts::prompt::{ActionResult, Prompt},
+    ui::CustomTypeBackend,
+    validator::{ErrorMessage, StringValidator, Validation},
+    Email, InquireError,
+};
+
+use super::{action::EmailPromptAction, config::EmailConfig};
+
+pub struct EmailPrompt<'a> {
+    message: &'a str,
+    config: EmailConfig,
+    default: Option<&'a str>,
+    help_message: Option<&'a str>,
+    input: Input,
+    suggested_domains: Vec<&'a str>,
+    suggestion: Option<&'a str>,
+    formatter: StringFormatter<'a>,
+    validators: Vec<Box<dyn StringValidator>>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a> From<Email<'a>> for EmailPrompt<'a> {
+    fn from(eo: Email<'a>) -> Self {
+        let input = Input::new_with(eo.initial_value.unwrap_or_default());
+        let input = if let Some(placeholder) = eo.placeholder {
+            input.with_placeholder(placeholder)
+        } else {
+            input
+        };
+
+        let mut prompt = Self {
+            message: eo.message,
+            config: (&eo).into(),
+            default: eo.default,
+            help_message: eo.help_message,
+            input,
+            suggested_domains: eo.suggested_domains,
+            suggestion: None,
+            formatter: eo.formatter,
+            validators: eo.validators,
+            error: None,
+        };
+        prompt.update_suggestion();
+
+        prompt
+    }
+}
+
+impl<'a> EmailPrompt<'a> {
+    /// Suggests the closest known domain when the typed one looks like a
+    /// typo of it, i.e. when they are at most one or two edits apart,
+    /// depending on the length of the typed domain.
+    fn update_suggestion(&mut self) {
+        self.suggestion = None;
+
+        let Some((_, domain)) = self.input.content().rsplit_once('@') else {
+            return;
+        };
+        let domain = domain.to_lowercase();
+
+        let max_distance = match domain.len() {
+            0..=3 => return,
+            4..=7 => 1,
+            _ => 2,
+        };
+
+        if self.suggested_domains.iter().any(|known| *known == domain) {
+            return;
+        }
+
+        self.suggestion = self
+            .suggested_domains
+            .iter()
+            .map(|known| (edit_distance(&domain, known), *known))
+            .filter(|(distance, _)| *distance <= max_distance)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, known)| known);
+    }
+
+    fn accept_suggestion(&mut self) -> ActionResult {
+        let Some(suggestion) = self.suggestion.take() else {
+            return ActionResult::Clean;
+        };
+
+        let Some((local, _)) = self.input.content().rsplit_once('@') else {
+            return ActionResult::Clean;
+        };
+
+        let input = Input::new_with(format!("{}@{}", local, suggestion));
+        self.input = match self.input.placeholder() {
+            Some(placeholder) => input.with_placeholder(placeholder),
+            None => input,
+        };
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn get_current_answer(&self) -> &str {
+        // Empty input with default values override any validators.
+        if self.input.content().is_empty() {
+            if let Some(val) = self.default {
+                return val;
+            }
+        }
+
+        self.input.content().trim()
+    }
+
+    fn validate_answer(&self, answer: &str) -> InquireResult<Validation> {
+        if !is_email_address(answer) {
+            return Ok(Validation::Invalid(
+                "Please type a valid email address".into(),
+            ));
+        }
+
+        for validator in &self.validators {
+            match validator.validate(answer) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for EmailPrompt<'a>
+where
+    Backend: CustomTypeBackend,
+{
+    type Config = EmailConfig;
+    type InnerAction = EmailPromptAction;
+    type Output = String;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &EmailConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &String) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn fallback_answer(&self) -> Option<String> {
+        self.default.map(String::from)
+    }
+
+    fn can_submit(&self) -> InquireResult<bool> {
+        Ok(self.validate_answer(self.get_current_answer())? == Validation::Valid)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<String>> {
+        let answer = self.get_current_answer();
+
+        let result = match self.validate_answer(answer)? {
+            Validation::Valid => Some(answer.to_owned()),
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                None
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn handle(&mut self, action: EmailPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            EmailPromptAction::ValueInput(input_action) => {
+                let result = self.input.handle(input_action);
+
+                if let InputActionResult::ContentChanged = result {
+                    self.update_suggestion();
+                }
+
+                result.into()
+            }
+            EmailPromptAction::AcceptSuggestion => self.accept_suggestion(),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        backend.render_prompt(self.message, None, self.default, &self.input)?;
+
+        if let Some(suggestion) = self.suggestion {
+            backend.render_help_message(&format!("did you mean {}? tab to accept", suggestion))?;
+        } else if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether the address is well-formed: a local part of the characters
+/// allowed unquoted, an `@` and a domain name with a top-level domain.
+fn is_email_address(address: &str) -> bool {
+    let Some((local, domain)) = address.rsplit_once('@') else {
+        return false;
+    };
+
+    let valid_local = !local.is_empty()
+        && local.len() <= 64
+        && !local.starts_with('.')
+        && !local.ends_with('.')
+        && !local.contains("..")
+        && local
+            .chars()
+            .all(|c| c.is_alphanumeric() || "!#$%&'*+-/=?^_`{|}~.".contains(c));
+
+    let labels: Vec<&str> = domain.split('.').collect();
+    let valid_domain = labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+        && labels[labels.len() - 1].chars().count() >= 2;
+
+    valid_local && valid_domain
+}
+
+/// Number of insertions, deletions, substitutions and transpositions of
+/// adjacent characters needed to turn one string into the other.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
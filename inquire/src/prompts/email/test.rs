@@ -0,0 +1,92 @@
+use crate::{
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::ErrorMessage,
+    Email,
+};
+
+fn keys(text: &str, then: &[Key]) -> Vec<Key> {
+    let mut keys: Vec<Key> = text
+        .chars()
+        .map(|c| Key::Char(c, KeyModifiers::NONE))
+        .collect();
+    keys.extend_from_slice(then);
+    keys
+}
+
+fn hint(domain: &str) -> Token {
+    Token::HelpMessage(format!("did you mean {}? tab to accept", domain))
+}
+
+#[test]
+fn typo_in_common_domain_is_suggested_and_accepted_with_tab() {
+    let mut backend = FakeBackend::new(keys("ada@gmial.com", &[Key::Tab, Key::Enter]));
+
+    let ans = Email::new("Email")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("ada@gmail.com", ans);
+
+    let frames = backend.frames();
+    assert!(frames[13].has_token(&hint("gmail.com")));
+    assert!(!frames[14].has_token(&hint("gmail.com")));
+}
+
+#[test]
+fn unknown_domains_are_submitted_as_typed() {
+    let mut backend = FakeBackend::new(keys("grace@navy.mil", &[Key::Tab, Key::Enter]));
+
+    let ans = Email::new("Email")
+        .with_help_message("work email")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("grace@navy.mil", ans);
+    let frames = backend.frames();
+    assert!(frames[..frames.len() - 1]
+        .iter()
+        .all(|frame| frame.has_token(&Token::HelpMessage("work email".into()))));
+}
+
+#[test]
+fn custom_domains_are_suggested() {
+    let mut backend = FakeBackend::new(keys("linus@exmaple.org", &[Key::Tab, Key::Enter]));
+
+    let ans = Email::new("Email")
+        .with_suggested_domains(&["example.org"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("linus@example.org", ans);
+}
+
+#[test]
+fn malformed_addresses_are_rejected() {
+    let mut backend = FakeBackend::new(keys(
+        "ada@localhost",
+        &[
+            Key::Enter,
+            Key::Char('.', KeyModifiers::NONE),
+            Key::Char('i', KeyModifiers::NONE),
+            Key::Char('o', KeyModifiers::NONE),
+            Key::Enter,
+        ],
+    ));
+
+    let ans = Email::new("Email")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("ada@localhost.io", ans);
+    assert!(backend
+        .frames()
+        .iter()
+        .any(
+            |frame| frame.has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+                "Please type a valid email address".into()
+            )))
+        ));
+}
@@ -0,0 +1,331 @@
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+use std::{collections::BTreeMap, fmt};
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    list_option::ListOption,
+    terminal::get_default_terminal,
+    ui::{
+        width::str_width, AmbiguousWidth, Backend, MultiSelectBackend, RenderConfig, SelectBackend,
+        TextBackend,
+    },
+    validator::Validation,
+    MultiSelect, Select, Text,
+};
+
+/// Row of the list of variables of an [EnvEditor] prompt.
+#[derive(Clone)]
+enum EnvRow {
+    Variable {
+        name: String,
+        value: String,
+        name_width: usize,
+    },
+    Add,
+    Remove,
+    Done,
+}
+
+impl fmt::Display for EnvRow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Variable {
+                name,
+                value,
+                name_width,
+            } => {
+                let padding = name_width.saturating_sub(str_width(name, AmbiguousWidth::Narrow));
+                write!(f, "{}{} = {}", name, " ".repeat(padding), value)
+            }
+            Self::Add => write!(f, "(add a variable)"),
+            Self::Remove => write!(f, "(remove variables)"),
+            Self::Done => write!(f, "(done)"),
+        }
+    }
+}
+
+/// Prompt to review and edit a set of environment-style variables, e.g. the configuration of a deployment, returning the final variables.
+///
+/// The variables are listed as `NAME = value` rows, followed by rows to add a variable, to remove variables and to finish. Selecting a variable opens a [Text](crate::Text) prompt to edit its value, where pressing ESC keeps the current value. Adding a variable asks for its name, which must be a valid environment variable name that is not set yet, and then for its value. Removing variables opens a [MultiSelect](crate::MultiSelect) prompt to check the ones to remove.
+///
+/// Pressing ESC in the list of variables cancels the whole prompt.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Variables**: Variables listed when the prompt is first rendered.
+/// - **Help message**: Message displayed at the line below the list of variables.
+/// - **Page size**: Number of rows displayed at once, 7 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::collections::BTreeMap;
+/// use inquire::EnvEditor;
+///
+/// let mut vars = BTreeMap::new();
+/// vars.insert("DATABASE_URL".to_string(), "postgres://localhost/app".to_string());
+/// vars.insert("LOG_LEVEL".to_string(), "info".to_string());
+///
+/// let vars = EnvEditor::new("Deployment environment:", vars).prompt();
+///
+/// match vars {
+///     Ok(vars) => println!("Deploying with {} variables", vars.len()),
+///     Err(_) => println!("Deployment aborted"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct EnvEditor<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Variables listed when the prompt is first rendered.
+    pub vars: BTreeMap<String, String>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the rows displayed to the user.
+    pub page_size: usize,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> EnvEditor<'a> {
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, enter to edit, type to filter, esc to cancel");
+
+    /// Help message of the prompt editing the value of a variable.
+    const EDIT_HELP_MESSAGE: &'static str = "enter to save, esc to keep the current value";
+
+    /// Creates an [EnvEditor] with the provided message and variables, along with default configuration values.
+    pub fn new(message: &'a str, vars: BTreeMap<String, String>) -> Self {
+        Self {
+            message,
+            vars,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<BTreeMap<String, String>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<BTreeMap<String, String>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<BTreeMap<String, String>>
+    where
+        B: SelectBackend + MultiSelectBackend + TextBackend,
+    {
+        let mut vars = self.vars;
+        let mut cursor = 0;
+
+        loop {
+            let name_width = vars
+                .keys()
+                .map(|name| str_width(name, AmbiguousWidth::Narrow))
+                .max()
+                .unwrap_or(0);
+
+            let mut rows: Vec<EnvRow> = vars
+                .iter()
+                .map(|(name, value)| EnvRow::Variable {
+                    name: name.clone(),
+                    value: value.clone(),
+                    name_width,
+                })
+                .collect();
+            rows.push(EnvRow::Add);
+            if !vars.is_empty() {
+                rows.push(EnvRow::Remove);
+            }
+            rows.push(EnvRow::Done);
+
+            let count = vars.len();
+            let format_row = |row: ListOption<&EnvRow>| match row.value {
+                EnvRow::Variable { name, .. } => format!("edit {}", name),
+                EnvRow::Add => String::from("add a variable"),
+                EnvRow::Remove => String::from("remove variables"),
+                EnvRow::Done => match count {
+                    1 => String::from("1 variable"),
+                    count => format!("{} variables", count),
+                },
+            };
+
+            let starting_cursor = cursor.min(rows.len() - 1);
+            let mut select = Select::new(self.message, rows)
+                .with_starting_cursor(starting_cursor)
+                .with_page_size(self.page_size)
+                .with_formatter(&format_row)
+                .with_render_config(self.render_config);
+            select.help_message = self.help_message;
+
+            let row = select.prompt_with_backend(backend)?;
+            cursor = row.index;
+
+            match row.value {
+                EnvRow::Variable { name, value, .. } => {
+                    let edited = Text::new(&format!("{}:", name))
+                        .with_initial_value(&value)
+                        .with_help_message(Self::EDIT_HELP_MESSAGE)
+                        .with_render_config(self.render_config)
+                        .prompt_with_backend(backend);
+
+                    if let Some(value) = skippable(edited)? {
+                        vars.insert(name, value);
+                    }
+                }
+                EnvRow::Add => {
+                    if let Some((name, value)) =
+                        prompt_new_variable(&vars, &self.render_config, backend)?
+                    {
+                        vars.insert(name.clone(), value);
+                        cursor = vars.keys().position(|key| *key == name).unwrap_or(0);
+                    }
+                }
+                EnvRow::Remove => {
+                    let names = vars.keys().cloned().collect();
+                    let removed = MultiSelect::new("Variables to remove:", names)
+                        .with_page_size(self.page_size)
+                        .with_render_config(self.render_config)
+                        .prompt_with_backend(backend);
+
+                    for name in skippable(removed)?.unwrap_or_default() {
+                        vars.remove(&name.value);
+                    }
+                }
+                EnvRow::Done => return Ok(vars),
+            }
+        }
+    }
+}
+
+/// Asks for the name and the value of a new variable, returning `None`
+/// when the user cancels either prompt.
+fn prompt_new_variable<B: TextBackend>(
+    vars: &BTreeMap<String, String>,
+    render_config: &RenderConfig<'_>,
+    backend: &mut B,
+) -> InquireResult<Option<(String, String)>> {
+    let existing: Vec<String> = vars.keys().cloned().collect();
+    let validator = move |input: &str| {
+        if !is_valid_name(input) {
+            Ok(Validation::Invalid(
+                "Names must start with a letter or an underscore, followed by letters, digits or underscores".into(),
+            ))
+        } else if existing.iter().any(|name| name == input) {
+            Ok(Validation::Invalid(
+                format!("{} is already set", input).into(),
+            ))
+        } else {
+            Ok(Validation::Valid)
+        }
+    };
+
+    let name = Text::new("Name:")
+        .with_validator(validator)
+        .with_render_config(*render_config)
+        .prompt_with_backend(backend);
+    let name = match skippable(name)? {
+        Some(name) => name,
+        None => return Ok(None),
+    };
+
+    let value = Text::new(&format!("{}:", name))
+        .with_render_config(*render_config)
+        .prompt_with_backend(backend);
+
+    Ok(skippable(value)?.map(|value| (name, value)))
+}
+
+/// Turns the cancellation of a sub-prompt into `None`, going back to the list of variables.
+fn skippable<T>(answer: InquireResult<T>) -> InquireResult<Option<T>> {
+    match answer {
+        Ok(answer) => Ok(Some(answer)),
+        Err(InquireError::OperationCanceled) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Whether the name is a portable environment variable name.
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    error::InquireError,
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    EnvEditor,
+};
+
+const DOWN: Key = Key::Down(KeyModifiers::NONE);
+
+fn text(text: &str) -> Vec<Key> {
+    text.chars()
+        .map(|c| Key::Char(c, KeyModifiers::NONE))
+        .collect()
+}
+
+fn vars(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+    pairs
+        .iter()
+        .map(|&(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn selected_variable_is_edited() {
+    let mut keys = vec![DOWN, Key::Enter, Key::Backspace];
+    keys.extend(text("3"));
+    keys.extend([Key::Enter, Key::End, Key::Enter]);
+    let mut backend = fake_backend(keys);
+
+    let ans = EnvEditor::new("Env", vars(&[("A", "1"), ("B", "2")]))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vars(&[("A", "1"), ("B", "3")]), ans);
+}
+
+#[test]
+fn canceled_edit_keeps_the_current_value() {
+    let mut keys = vec![Key::Enter, Key::Backspace, Key::Escape];
+    keys.extend([Key::End, Key::Enter]);
+    let mut backend = fake_backend(keys);
+
+    let ans = EnvEditor::new("Env", vars(&[("A", "1")]))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vars(&[("A", "1")]), ans);
+}
+
+#[test]
+fn variables_are_added_with_valid_and_unique_names() {
+    let mut keys = vec![DOWN, Key::Enter];
+    keys.extend(text("1X"));
+    keys.extend([Key::Enter, Key::Backspace, Key::Backspace]);
+    keys.extend(text("A"));
+    keys.push(Key::Enter);
+    keys.extend(text("_B"));
+    keys.push(Key::Enter);
+    keys.extend(text("two"));
+    keys.extend([Key::Enter, Key::End, Key::Enter]);
+    let mut backend = fake_backend(keys);
+
+    let ans = EnvEditor::new("Env", vars(&[("A", "1")]))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vars(&[("A", "1"), ("A_B", "two")]), ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("Names must start with a letter or an underscore"));
+    assert!(output.contains("A is already set"));
+}
+
+#[test]
+fn checked_variables_are_removed() {
+    let space = Key::Char(' ', KeyModifiers::NONE);
+    let keys = vec![
+        DOWN,
+        DOWN,
+        DOWN,
+        DOWN,
+        Key::Enter,
+        space,
+        DOWN,
+        DOWN,
+        space,
+        Key::Enter,
+        Key::End,
+        Key::Enter,
+    ];
+    let mut backend = fake_backend(keys);
+
+    let ans = EnvEditor::new("Env", vars(&[("A", "1"), ("B", "2"), ("C", "3")]))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vars(&[("B", "2")]), ans);
+}
+
+#[test]
+fn variables_are_listed_in_aligned_rows() {
+    let mut backend = fake_backend(vec![Key::End, Key::Enter]);
+
+    EnvEditor::new("Env", vars(&[("LOG_LEVEL", "info"), ("PORT", "8080")]))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("LOG_LEVEL = info"));
+    assert!(output.contains("PORT      = 8080"));
+    assert!(output.contains("2 variables"));
+}
+
+#[test]
+fn escape_in_the_list_cancels_the_prompt() {
+    let mut backend = fake_backend(vec![Key::Escape]);
+
+    let ans = EnvEditor::new("Env", vars(&[("A", "1")])).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+}
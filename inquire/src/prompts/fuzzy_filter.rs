@@ -0,0 +1,50 @@
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+use once_cell::sync::Lazy;
+
+use crate::utils::extended_filter_score;
+
+/// Fuzzy matcher shared by the list prompts, ranking options skim-style.
+pub static DEFAULT_MATCHER: Lazy<SkimMatcherV2> =
+    Lazy::new(|| SkimMatcherV2::default().ignore_case());
+
+/// Score of a value against a filter, requiring every term of the filter to
+/// fuzzy match, while terms prefixed by `!` exclude the values containing them.
+pub fn score(filter: &str, value: &str) -> Option<i64> {
+    extended_filter_score(filter, value, |term| {
+        DEFAULT_MATCHER.fuzzy_match(value, term)
+    })
+}
+
+/// Sorted indexes of the characters of a value matched by the required terms
+/// of a filter, to be highlighted when rendering the value.
+pub fn matched_indices(filter: &str, value: &str) -> Vec<usize> {
+    let mut indices = filter
+        .split_whitespace()
+        .filter(|term| term.len() == 1 || !term.starts_with('!'))
+        .filter_map(|term| DEFAULT_MATCHER.fuzzy_indices(value, term))
+        .flat_map(|(_, indices)| indices)
+        .collect::<Vec<usize>>();
+
+    indices.sort_unstable();
+    indices.dedup();
+    indices
+}
+
+#[cfg(test)]
+mod test {
+    use super::{matched_indices, score};
+
+    #[test]
+    fn every_term_must_match() {
+        assert!(score("prd east", "api-prod-us-east").is_some());
+        assert!(score("prd west", "api-prod-us-east").is_none());
+        assert!(score("prd !east", "api-prod-us-east").is_none());
+    }
+
+    #[test]
+    fn matched_indices_cover_the_required_terms_only() {
+        assert_eq!(vec![4, 5, 7], matched_indices("prd", "api-prod"));
+        assert_eq!(vec![0, 4, 5, 7], matched_indices("prd a !x", "api-prod"));
+        assert!(matched_indices("xyz", "api-prod").is_empty());
+    }
+}
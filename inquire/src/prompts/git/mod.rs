@@ -0,0 +1,370 @@
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+use std::{fmt, path::Path, process::Command};
+
+use crate::{
+    error::{InquireError, InquireResult},
+    list_option::ListOption,
+    ui::{
+        width::{str_width, truncate},
+        AmbiguousWidth, Truncation,
+    },
+    MultiSelect, Select,
+};
+
+/// Max width of the commit subjects column, longer subjects being truncated.
+const SUBJECT_MAX_WIDTH: usize = 50;
+
+/// Local branch of a git repository, as listed by [`GitBranch::select`].
+/// Available via the `git` feature.
+///
+/// Displayed as a line of `git branch -vv`: the current branch is marked
+/// with an asterisk, and branches with an upstream show how many commits
+/// they are ahead of and behind it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GitBranch {
+    /// Short name of the branch, e.g. `main`.
+    pub name: String,
+
+    /// Short name of the upstream branch, e.g. `origin/main`, if any.
+    pub upstream: Option<String>,
+
+    /// Number of commits of the branch that are not in its upstream.
+    pub ahead: usize,
+
+    /// Number of commits of the upstream that are not in the branch.
+    pub behind: usize,
+
+    /// Whether the branch is checked out.
+    pub is_head: bool,
+
+    name_width: usize,
+}
+
+impl GitBranch {
+    /// Lists the local branches of the repository, sorted by name.
+    pub fn list<P: AsRef<Path>>(repo: P) -> InquireResult<Vec<Self>> {
+        let output = git(
+            repo.as_ref(),
+            &[
+                "for-each-ref",
+                "--format=%(refname:short)%00%(upstream:short)%00%(upstream:track,nobracket)%00%(HEAD)",
+                "refs/heads",
+            ],
+        )?;
+
+        let mut branches: Vec<Self> = output
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split('\0');
+                let name = fields.next()?.to_string();
+                let upstream = Some(fields.next()?)
+                    .filter(|upstream| !upstream.is_empty())
+                    .map(String::from);
+                let (ahead, behind) = parse_track(fields.next()?);
+                let is_head = fields.next()? == "*";
+
+                Some(Self {
+                    name,
+                    upstream,
+                    ahead,
+                    behind,
+                    is_head,
+                    name_width: 0,
+                })
+            })
+            .collect();
+
+        let name_width = max_width(branches.iter().map(|branch| branch.name.as_str()));
+        for branch in &mut branches {
+            branch.name_width = name_width;
+        }
+
+        Ok(branches)
+    }
+
+    /// Builds a [`Select`] prompt listing the local branches of the repository,
+    /// with the cursor on the current branch. The answer is rendered as the
+    /// name of the chosen branch.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::GitBranch;
+    ///
+    /// let branch = GitBranch::select("Branch to rebase onto:", ".")?.prompt()?;
+    /// println!("Rebasing onto {}", branch.name);
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn select<'a, P: AsRef<Path>>(
+        message: &'a str,
+        repo: P,
+    ) -> InquireResult<Select<'a, Self>> {
+        let branches = Self::list(repo)?;
+        let head = branches
+            .iter()
+            .position(|branch| branch.is_head)
+            .unwrap_or(0);
+
+        Ok(Select::new(message, branches)
+            .with_starting_cursor(head)
+            .with_formatter(&|option| option.value.name.clone()))
+    }
+}
+
+impl fmt::Display for GitBranch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let marker = if self.is_head { '*' } else { ' ' };
+        write!(f, "{} {}", marker, self.name)?;
+
+        if let Some(upstream) = &self.upstream {
+            let padding = self
+                .name_width
+                .saturating_sub(str_width(&self.name, AmbiguousWidth::Narrow));
+            write!(f, "{} [{}", " ".repeat(padding), upstream)?;
+            match (self.ahead, self.behind) {
+                (0, 0) => {}
+                (ahead, 0) => write!(f, ": ahead {}", ahead)?,
+                (0, behind) => write!(f, ": behind {}", behind)?,
+                (ahead, behind) => write!(f, ": ahead {}, behind {}", ahead, behind)?,
+            }
+            write!(f, "]")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Tag of a git repository, as listed by [`GitTag::select`]. Available via
+/// the `git` feature.
+///
+/// Displayed with the subject of its message, or of the tagged commit for
+/// lightweight tags.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GitTag {
+    /// Name of the tag, e.g. `v1.2.0`.
+    pub name: String,
+
+    /// Subject of the message of the tag, or of the tagged commit.
+    pub subject: String,
+
+    name_width: usize,
+}
+
+impl GitTag {
+    /// Lists the tags of the repository, most recent first.
+    pub fn list<P: AsRef<Path>>(repo: P) -> InquireResult<Vec<Self>> {
+        let output = git(
+            repo.as_ref(),
+            &[
+                "for-each-ref",
+                "--sort=-creatordate",
+                "--format=%(refname:short)%00%(contents:subject)",
+                "refs/tags",
+            ],
+        )?;
+
+        let mut tags: Vec<Self> = output
+            .lines()
+            .filter_map(|line| {
+                let (name, subject) = line.split_once('\0')?;
+
+                Some(Self {
+                    name: name.to_string(),
+                    subject: subject.to_string(),
+                    name_width: 0,
+                })
+            })
+            .collect();
+
+        let name_width = max_width(tags.iter().map(|tag| tag.name.as_str()));
+        for tag in &mut tags {
+            tag.name_width = name_width;
+        }
+
+        Ok(tags)
+    }
+
+    /// Builds a [`Select`] prompt listing the tags of the repository, most
+    /// recent first. The answer is rendered as the name of the chosen tag.
+    pub fn select<'a, P: AsRef<Path>>(
+        message: &'a str,
+        repo: P,
+    ) -> InquireResult<Select<'a, Self>> {
+        let tags = Self::list(repo)?;
+
+        Ok(Select::new(message, tags).with_formatter(&|option| option.value.name.clone()))
+    }
+}
+
+impl fmt::Display for GitTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let padding = self
+            .name_width
+            .saturating_sub(str_width(&self.name, AmbiguousWidth::Narrow));
+        write!(f, "{}{} {}", self.name, " ".repeat(padding), self.subject)
+    }
+}
+
+/// Commit of a git repository, as listed by [`GitCommit::select`] and
+/// [`GitCommit::multi_select`]. Available via the `git` feature.
+///
+/// Displayed with its abbreviated hash, its subject and its author in
+/// aligned columns.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GitCommit {
+    /// Full hash of the commit.
+    pub hash: String,
+
+    /// Abbreviated hash of the commit.
+    pub short_hash: String,
+
+    /// Subject of the commit message, i.e. its first line.
+    pub subject: String,
+
+    /// Name of the author of the commit.
+    pub author: String,
+
+    subject_width: usize,
+}
+
+impl GitCommit {
+    /// Lists the most recent commits reachable from `HEAD`, at most `max_count`.
+    pub fn list<P: AsRef<Path>>(repo: P, max_count: usize) -> InquireResult<Vec<Self>> {
+        let output = git(
+            repo.as_ref(),
+            &[
+                "log",
+                &format!("--max-count={}", max_count),
+                "--format=%H%x00%h%x00%s%x00%an",
+            ],
+        )?;
+
+        let mut commits: Vec<Self> = output
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split('\0');
+
+                Some(Self {
+                    hash: fields.next()?.to_string(),
+                    short_hash: fields.next()?.to_string(),
+                    subject: fields.next()?.to_string(),
+                    author: fields.next()?.to_string(),
+                    subject_width: 0,
+                })
+            })
+            .collect();
+
+        let subject_width =
+            max_width(commits.iter().map(|commit| commit.subject.as_str())).min(SUBJECT_MAX_WIDTH);
+        for commit in &mut commits {
+            commit.subject_width = subject_width;
+        }
+
+        Ok(commits)
+    }
+
+    /// Builds a [`Select`] prompt listing the most recent commits reachable
+    /// from `HEAD`, at most `max_count`. The answer is rendered as the
+    /// abbreviated hash and subject of the chosen commit.
+    pub fn select<'a, P: AsRef<Path>>(
+        message: &'a str,
+        repo: P,
+        max_count: usize,
+    ) -> InquireResult<Select<'a, Self>> {
+        let commits = Self::list(repo, max_count)?;
+
+        Ok(Select::new(message, commits).with_formatter(&|option| {
+            format!("{} {}", option.value.short_hash, option.value.subject)
+        }))
+    }
+
+    /// Builds a [`MultiSelect`] prompt listing the most recent commits
+    /// reachable from `HEAD`, at most `max_count`, e.g. to pick commits to
+    /// cherry-pick. The answer is rendered as the abbreviated hashes of the
+    /// chosen commits.
+    pub fn multi_select<'a, P: AsRef<Path>>(
+        message: &'a str,
+        repo: P,
+        max_count: usize,
+    ) -> InquireResult<MultiSelect<'a, Self>> {
+        let commits = Self::list(repo, max_count)?;
+
+        Ok(
+            MultiSelect::new(message, commits).with_formatter(&|options: &[ListOption<
+                &Self,
+            >]| {
+                options
+                    .iter()
+                    .map(|option| option.value.short_hash.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }),
+        )
+    }
+}
+
+impl fmt::Display for GitCommit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let subject = truncate(
+            &self.subject,
+            self.subject_width,
+            Truncation::End,
+            AmbiguousWidth::Narrow,
+        );
+        let padding = self
+            .subject_width
+            .saturating_sub(str_width(&subject, AmbiguousWidth::Narrow));
+
+        write!(
+            f,
+            "{} {}{} {}",
+            self.short_hash,
+            subject,
+            " ".repeat(padding),
+            self.author
+        )
+    }
+}
+
+/// Runs a git command in the repository, returning its standard output.
+fn git(repo: &Path, args: &[&str]) -> InquireResult<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .output()?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(InquireError::Custom(stderr.trim().into()));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parses the ahead and behind counts of an upstream tracking status, e.g.
+/// `ahead 2, behind 1`. Missing counts are zero.
+fn parse_track(track: &str) -> (usize, usize) {
+    let mut ahead = 0;
+    let mut behind = 0;
+
+    for part in track.split(", ") {
+        match part.split_once(' ') {
+            Some(("ahead", count)) => ahead = count.parse().unwrap_or_default(),
+            Some(("behind", count)) => behind = count.parse().unwrap_or_default(),
+            _ => {}
+        }
+    }
+
+    (ahead, behind)
+}
+
+fn max_width<'a>(values: impl Iterator<Item = &'a str>) -> usize {
+    values
+        .map(|value| str_width(value, AmbiguousWidth::Narrow))
+        .max()
+        .unwrap_or(0)
+}
@@ -0,0 +1,203 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use crate::{
+    terminal::crossterm::CrosstermTerminal,
+    test::fake_backend,
+    ui::{Backend, Key, KeyModifiers, RenderConfig},
+    GitBranch, GitCommit, GitTag,
+};
+
+use super::parse_track;
+
+/// Temporary git repository, removed when dropped.
+struct Repo(PathBuf);
+
+impl Repo {
+    fn new(name: &str) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("inquire-git-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+
+        let repo = Self(path);
+        repo.git(&["init", "-q", "-b", "main"]);
+        repo
+    }
+
+    fn git(&self, args: &[&str]) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(&self.0)
+            .args([
+                "-c",
+                "user.email=test@example.com",
+                "-c",
+                "tag.gpgSign=false",
+            ])
+            .args(["-c", "commit.gpgSign=false", "-c", "user.name=Test"])
+            .args(args)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {:?}", args);
+    }
+
+    fn commit(&self, subject: &str, author: &str) {
+        self.git(&[
+            "commit",
+            "-q",
+            "--allow-empty",
+            "-m",
+            subject,
+            &format!("--author={} <{}@example.com>", author, author),
+        ]);
+    }
+
+    fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for Repo {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+#[test]
+fn tracking_status_is_parsed() {
+    assert_eq!((0, 0), parse_track(""));
+    assert_eq!((0, 0), parse_track("gone"));
+    assert_eq!((2, 0), parse_track("ahead 2"));
+    assert_eq!((0, 3), parse_track("behind 3"));
+    assert_eq!((2, 3), parse_track("ahead 2, behind 3"));
+}
+
+#[test]
+fn branches_are_listed_with_ahead_and_behind_counts() {
+    let repo = Repo::new("branches");
+    repo.commit("First", "ada");
+    repo.git(&["branch", "topic"]);
+    repo.commit("Second", "grace");
+    repo.git(&["checkout", "-q", "topic"]);
+    repo.git(&["branch", "-q", "--set-upstream-to=main"]);
+    repo.commit("Third", "ada");
+
+    let branches = GitBranch::list(repo.path()).unwrap();
+
+    assert_eq!(2, branches.len());
+    assert_eq!("main", branches[0].name);
+    assert!(!branches[0].is_head);
+    assert_eq!(None, branches[0].upstream);
+    assert_eq!("topic", branches[1].name);
+    assert!(branches[1].is_head);
+    assert_eq!(Some("main".into()), branches[1].upstream);
+    assert_eq!((1, 1), (branches[1].ahead, branches[1].behind));
+
+    assert_eq!("  main", branches[0].to_string());
+    assert_eq!("* topic [main: ahead 1, behind 1]", branches[1].to_string());
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let ans = GitBranch::select("Branch", repo.path())
+        .unwrap()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("topic", ans.value.name);
+}
+
+#[test]
+fn tags_are_listed_with_their_subject() {
+    let repo = Repo::new("tags");
+    repo.commit("Initial commit", "ada");
+    repo.git(&["tag", "v1"]);
+    repo.git(&["tag", "-a", "v1.1", "-m", "Release 1.1"]);
+
+    let mut tags = GitTag::list(repo.path()).unwrap();
+    tags.sort_by(|a, b| a.name.cmp(&b.name));
+
+    assert_eq!(2, tags.len());
+    assert_eq!("v1   Initial commit", tags[0].to_string());
+    assert_eq!("v1.1 Release 1.1", tags[1].to_string());
+}
+
+#[test]
+fn commits_are_rendered_in_columns_and_selected() {
+    let repo = Repo::new("commits");
+    repo.commit("Add parser", "ada");
+    repo.commit(
+        "Fix off-by-one error in the pagination of long lists of options",
+        "grace",
+    );
+
+    let commits = GitCommit::list(repo.path(), 10).unwrap();
+    assert_eq!(2, commits.len());
+    assert_eq!("grace", commits[0].author);
+    assert!(commits[0].hash.starts_with(&commits[0].short_hash));
+
+    let mut backend = Backend::new(
+        vec![Key::Down(KeyModifiers::NONE), Key::Enter].into_iter(),
+        CrosstermTerminal::new_in_memory_output(),
+        RenderConfig::empty(),
+    )
+    .unwrap();
+
+    let ans = GitCommit::select("Commit:", repo.path(), 10)
+        .unwrap()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("Add parser", ans.value.subject);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains(&format!(
+        "{} Fix off-by-one error in the pagination of long li… grace",
+        commits[0].short_hash
+    )));
+    assert!(output.contains(&format!(
+        "{} Add parser{} ada",
+        commits[1].short_hash,
+        " ".repeat(40)
+    )));
+    assert!(output.contains(&format!("? Commit: {} Add parser", commits[1].short_hash)));
+}
+
+#[test]
+fn several_commits_can_be_selected() {
+    let repo = Repo::new("multi");
+    repo.commit("One", "ada");
+    repo.commit("Two", "ada");
+    repo.commit("Three", "ada");
+
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = GitCommit::multi_select("Commits", repo.path(), 10)
+        .unwrap()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let subjects: Vec<&str> = ans
+        .iter()
+        .map(|commit| commit.value.subject.as_str())
+        .collect();
+    assert_eq!(vec!["Three", "One"], subjects);
+}
+
+#[test]
+fn git_errors_are_reported() {
+    let dir = std::env::temp_dir().join(format!("inquire-git-none-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let result = GitBranch::list(&dir);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,102 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+};
+
+use super::{ChallengeDifficulty, ChallengeKind};
+
+/// Characters of the strings typed back by the user, without the ones easily
+/// mistaken for each other, such as `0` and `O`.
+const ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+/// Question displayed to the user, along with its expected answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Challenge {
+    pub question: String,
+    pub answer: String,
+}
+
+impl Challenge {
+    pub fn generate(kind: ChallengeKind, difficulty: ChallengeDifficulty, seed: u64) -> Self {
+        let mut rng = XorShift::new(seed);
+
+        match kind {
+            ChallengeKind::TypeBack => {
+                let len = match difficulty {
+                    ChallengeDifficulty::Easy => 4,
+                    ChallengeDifficulty::Medium => 6,
+                    ChallengeDifficulty::Hard => 8,
+                };
+                let code: String = (0..len)
+                    .map(|_| ALPHABET[rng.below(ALPHABET.len() as u64) as usize] as char)
+                    .collect();
+
+                Self {
+                    question: format!("Type {} to continue:", code),
+                    answer: code,
+                }
+            }
+            ChallengeKind::Arithmetic => {
+                let (question, answer) = match difficulty {
+                    ChallengeDifficulty::Easy => {
+                        let (a, b) = (rng.between(1, 9), rng.between(1, 9));
+                        (format!("{} + {}", a, b), a + b)
+                    }
+                    ChallengeDifficulty::Medium => {
+                        let (a, b) = (rng.between(10, 99), rng.between(10, 99));
+                        match rng.below(2) {
+                            0 => (format!("{} + {}", a, b), a + b),
+                            _ => (format!("{} - {}", a.max(b), a.min(b)), a.max(b) - a.min(b)),
+                        }
+                    }
+                    ChallengeDifficulty::Hard => {
+                        let (a, b, c) =
+                            (rng.between(3, 12), rng.between(3, 12), rng.between(10, 99));
+                        (format!("{} × {} + {}", a, b, c), a * b + c)
+                    }
+                };
+
+                Self {
+                    question: format!("What is {}?", question),
+                    answer: answer.to_string(),
+                }
+            }
+        }
+    }
+
+    /// Whether the input of the user matches the answer, ignoring case and
+    /// surrounding whitespace.
+    pub fn is_answered_by(&self, input: &str) -> bool {
+        input.trim().eq_ignore_ascii_case(&self.answer)
+    }
+}
+
+/// Seed for challenges generated when none is provided, random for each call.
+pub(crate) fn random_seed() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+/// Small xorshift generator, good enough to vary challenges between prompts.
+struct XorShift(u64);
+
+impl XorShift {
+    fn new(seed: u64) -> Self {
+        // the state must never be zero
+        Self(seed | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    fn between(&mut self, min: u64, max: u64) -> u64 {
+        min + self.below(max - min + 1)
+    }
+}
@@ -0,0 +1,234 @@
+mod challenge;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, TextBackend},
+    validator::{ErrorMessage, Validation},
+    Text,
+};
+
+use self::challenge::{random_seed, Challenge};
+
+/// Kind of challenge presented by a [HumanCheck] prompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChallengeKind {
+    /// The user types back a random string of letters and digits.
+    TypeBack,
+    /// The user solves a trivial arithmetic question.
+    Arithmetic,
+}
+
+/// Difficulty of the challenge presented by a [HumanCheck] prompt.
+///
+/// Strings typed back are 4, 6 or 8 characters long, while arithmetic
+/// questions go from adding two digits to a multiplication followed by an
+/// addition.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChallengeDifficulty {
+    /// Quick to answer, the default.
+    Easy,
+    /// Takes a few seconds to answer.
+    Medium,
+    /// Takes a deliberate effort to answer.
+    Hard,
+}
+
+/// Prompt asking the user to pass a simple challenge, e.g. as a guard before destructive operations, returning whether they passed it.
+///
+/// The challenge is either a random string the user has to type back, or a trivial arithmetic question, displayed after the prompt message. Wrong answers display an error and let the user try again, until the maximum number of attempts is reached, in which case the prompt returns `false`.
+///
+/// This is not a security measure, as anyone can read the expected answer on the screen: it only makes sure the user pauses before doing something they can not undo, and that the command is not run unattended by accident.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Challenge kind**: Typing back a random string or solving an arithmetic question, the former by default.
+/// - **Difficulty**: Length of the random string or complexity of the question, easy by default.
+/// - **Max attempts**: Number of wrong answers after which the check fails, 3 by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{ChallengeKind, HumanCheck};
+///
+/// let passed = HumanCheck::new("This will drop the production database.")
+///     .with_kind(ChallengeKind::Arithmetic)
+///     .prompt();
+///
+/// match passed {
+///     Ok(true) => println!("Dropping the database..."),
+///     Ok(false) => println!("Check failed, nothing was dropped"),
+///     Err(_) => println!("Operation aborted"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct HumanCheck<'a> {
+    /// Message to be presented to the user, followed by the challenge.
+    pub message: &'a str,
+
+    /// Kind of challenge presented to the user.
+    pub kind: ChallengeKind,
+
+    /// Difficulty of the challenge.
+    pub difficulty: ChallengeDifficulty,
+
+    /// Number of wrong answers after which the check fails.
+    pub max_attempts: usize,
+
+    /// Seed of the challenge, random when not set.
+    pub seed: Option<u64>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> HumanCheck<'a> {
+    /// Default kind of challenge.
+    pub const DEFAULT_KIND: ChallengeKind = ChallengeKind::TypeBack;
+
+    /// Default difficulty.
+    pub const DEFAULT_DIFFICULTY: ChallengeDifficulty = ChallengeDifficulty::Easy;
+
+    /// Default number of wrong answers after which the check fails.
+    pub const DEFAULT_MAX_ATTEMPTS: usize = 3;
+
+    /// Error message displayed after a wrong answer.
+    const WRONG_ANSWER_MESSAGE: &'static str = "Wrong answer, please try again";
+
+    /// Creates a [HumanCheck] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            kind: Self::DEFAULT_KIND,
+            difficulty: Self::DEFAULT_DIFFICULTY,
+            max_attempts: Self::DEFAULT_MAX_ATTEMPTS,
+            seed: None,
+            help_message: None,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the kind of challenge.
+    pub fn with_kind(mut self, kind: ChallengeKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets the difficulty of the challenge.
+    pub fn with_difficulty(mut self, difficulty: ChallengeDifficulty) -> Self {
+        self.difficulty = difficulty;
+        self
+    }
+
+    /// Sets the number of wrong answers after which the check fails.
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Sets the seed of the challenge, so that the same challenge is presented
+    /// every time, e.g. in tests.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<bool>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns whether the user passed the check.
+    pub fn prompt(self) -> InquireResult<bool> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: TextBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<bool> {
+        let challenge = Challenge::generate(
+            self.kind,
+            self.difficulty,
+            self.seed.unwrap_or_else(random_seed),
+        );
+        let message = format!("{} {}", self.message, challenge.question);
+
+        let validator = move |input: &str| match challenge.is_answered_by(input) {
+            true => Ok(Validation::Valid),
+            false => Ok(Validation::Invalid(ErrorMessage::Custom(
+                Self::WRONG_ANSWER_MESSAGE.into(),
+            ))),
+        };
+
+        let mut text = Text::new(&message)
+            .with_validator(validator)
+            .with_max_attempts(self.max_attempts)
+            .with_render_config(self.render_config);
+        text.help_message = self.help_message;
+
+        match text.prompt_with_backend(backend) {
+            Ok(_) => Ok(true),
+            Err(InquireError::TooManyAttempts) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    error::InquireError,
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    ChallengeDifficulty, ChallengeKind, HumanCheck,
+};
+
+use super::challenge::Challenge;
+
+const SEED: u64 = 42;
+
+fn keys(text: &str) -> Vec<Key> {
+    let mut keys: Vec<Key> = text
+        .chars()
+        .map(|c| Key::Char(c, KeyModifiers::NONE))
+        .collect();
+    keys.push(Key::Enter);
+    keys
+}
+
+fn answer(kind: ChallengeKind, difficulty: ChallengeDifficulty) -> String {
+    Challenge::generate(kind, difficulty, SEED).answer
+}
+
+#[test]
+fn typed_back_string_passes_the_check() {
+    let code = answer(ChallengeKind::TypeBack, ChallengeDifficulty::Easy);
+    let mut backend = fake_backend(keys(&code.to_lowercase()));
+
+    let passed = HumanCheck::new("Delete everything?")
+        .with_seed(SEED)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(passed);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains(&format!("Delete everything? Type {} to continue:", code)));
+}
+
+#[test]
+fn arithmetic_question_is_solved_after_a_wrong_answer() {
+    let mut events = keys("-1");
+    events.extend([Key::Backspace, Key::Backspace]);
+    events.extend(keys(&answer(
+        ChallengeKind::Arithmetic,
+        ChallengeDifficulty::Hard,
+    )));
+    let mut backend = fake_backend(events);
+
+    let passed = HumanCheck::new("Delete everything?")
+        .with_kind(ChallengeKind::Arithmetic)
+        .with_difficulty(ChallengeDifficulty::Hard)
+        .with_seed(SEED)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(passed);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("Wrong answer, please try again"));
+}
+
+#[test]
+fn check_fails_after_max_attempts() {
+    let mut events = keys("nope");
+    events.extend(keys("nope"));
+    let mut backend = fake_backend(events);
+
+    let passed = HumanCheck::new("Delete everything?")
+        .with_max_attempts(2)
+        .with_seed(SEED)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(!passed);
+}
+
+#[test]
+fn escape_cancels_the_check() {
+    let mut backend = fake_backend(vec![Key::Escape]);
+
+    let ans = HumanCheck::new("Delete everything?").prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+}
+
+#[test]
+fn challenges_match_their_difficulty() {
+    for seed in 0..50 {
+        let easy = Challenge::generate(ChallengeKind::TypeBack, ChallengeDifficulty::Easy, seed);
+        let hard = Challenge::generate(ChallengeKind::TypeBack, ChallengeDifficulty::Hard, seed);
+        assert_eq!(4, easy.answer.len());
+        assert_eq!(8, hard.answer.len());
+
+        let sum = Challenge::generate(ChallengeKind::Arithmetic, ChallengeDifficulty::Easy, seed);
+        let operands: Vec<u64> = sum
+            .question
+            .trim_start_matches("What is ")
+            .trim_end_matches('?')
+            .split(" + ")
+            .map(|n| n.parse().unwrap())
+            .collect();
+        assert_eq!(operands.iter().sum::<u64>().to_string(), sum.answer);
+
+        let medium =
+            Challenge::generate(ChallengeKind::Arithmetic, ChallengeDifficulty::Medium, seed);
+        assert!(medium.answer.parse::<u64>().is_ok());
+    }
+
+    assert_ne!(
+        Challenge::generate(ChallengeKind::TypeBack, ChallengeDifficulty::Medium, 1),
+        Challenge::generate(ChallengeKind::TypeBack, ChallengeDifficulty::Medium, 2)
+    );
+}
@@ -0,0 +1,31 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
+};
+
+use super::config::IpAddressConfig;
+
+/// Set of actions for an IpAddressPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IpAddressPromptAction {
+    /// Action on the value text input handler.
+    ValueInput(InputAction),
+    /// Increments the number under the cursor, e.g. an octet or the prefix length.
+    IncrementSegment,
+    /// Decrements the number under the cursor, e.g. an octet or the prefix length.
+    DecrementSegment,
+}
+
+impl InnerAction for IpAddressPromptAction {
+    type Config = IpAddressConfig;
+
+    fn from_key(key: Key, _config: &IpAddressConfig) -> Option<Self> {
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) => Self::IncrementSegment,
+            Key::Down(KeyModifiers::NONE) => Self::DecrementSegment,
+            key => Self::ValueInput(InputAction::from_key(key, &())?),
+        };
+
+        Some(action)
+    }
+}
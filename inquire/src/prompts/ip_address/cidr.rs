@@ -0,0 +1,70 @@
+use std::{fmt, net::IpAddr, str::FromStr};
+
+/// IP address followed by the length of its network prefix, in CIDR notation,
+/// e.g. `10.0.0.0/8` or `2001:db8::/32`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IpCidr {
+    address: IpAddr,
+    prefix_len: u8,
+}
+
+impl IpCidr {
+    /// Creates an [IpCidr], returning `None` if the prefix length is greater
+    /// than the length of the address, 32 bits for IPv4 and 128 for IPv6.
+    pub fn new(address: IpAddr, prefix_len: u8) -> Option<Self> {
+        let max_prefix_len = match address {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+
+        if prefix_len > max_prefix_len {
+            return None;
+        }
+
+        Some(Self {
+            address,
+            prefix_len,
+        })
+    }
+
+    /// Address, as typed by the user.
+    pub fn address(&self) -> IpAddr {
+        self.address
+    }
+
+    /// Length of the network prefix, in bits.
+    pub fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+}
+
+impl fmt::Display for IpCidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.address, self.prefix_len)
+    }
+}
+
+/// Error returned when parsing an [IpCidr] fails.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct IpCidrParseError;
+
+impl fmt::Display for IpCidrParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid CIDR notation")
+    }
+}
+
+impl std::error::Error for IpCidrParseError {}
+
+impl FromStr for IpCidr {
+    type Err = IpCidrParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (address, prefix_len) = s.split_once('/').ok_or(IpCidrParseError)?;
+
+        let address = address.parse().or(Err(IpCidrParseError))?;
+        let prefix_len = prefix_len.parse().or(Err(IpCidrParseError))?;
+
+        Self::new(address, prefix_len).ok_or(IpCidrParseError)
+    }
+}
@@ -0,0 +1,10 @@
+use crate::IpAddress;
+
+/// Configuration settings used in the execution of an IpAddressPrompt.
+pub struct IpAddressConfig {}
+
+impl<T> From<&IpAddress<'_, T>> for IpAddressConfig {
+    fn from(_value: &IpAddress<'_, T>) -> Self {
+        Self {}
+    }
+}
@@ -0,0 +1,266 @@
+mod action;
+mod cidr;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+pub use cidr::*;
+
+use std::{
+    fmt::Display,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+};
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, CustomTypeBackend, RenderConfig},
+    validator::CustomTypeValidator,
+};
+
+use self::prompt::IpAddressPrompt;
+
+/// Values that can be typed in an [IpAddress] prompt, along with the
+/// notations they accept.
+pub trait IpValue: Clone + FromStr + Display {
+    /// Whether IPv4 addresses are accepted.
+    const IPV4: bool;
+
+    /// Whether IPv6 addresses are accepted.
+    const IPV6: bool;
+
+    /// Whether the address is followed by a prefix length, in CIDR notation.
+    const CIDR: bool;
+}
+
+impl IpValue for Ipv4Addr {
+    const IPV4: bool = true;
+    const IPV6: bool = false;
+    const CIDR: bool = false;
+}
+
+impl IpValue for Ipv6Addr {
+    const IPV4: bool = false;
+    const IPV6: bool = true;
+    const CIDR: bool = false;
+}
+
+impl IpValue for IpAddr {
+    const IPV4: bool = true;
+    const IPV6: bool = true;
+    const CIDR: bool = false;
+}
+
+impl IpValue for IpCidr {
+    const IPV4: bool = true;
+    const IPV6: bool = true;
+    const CIDR: bool = true;
+}
+
+/// Text prompt for IP addresses and CIDR blocks, returning typed values: [`Ipv4Addr`], [`Ipv6Addr`], [`IpAddr`] or [`IpCidr`].
+///
+/// The input is validated while it is typed: as soon as it can no longer be completed into a valid value, e.g. after typing an octet greater than 255, the error message is displayed above the prompt. The up and down arrows increment and decrement the number under the cursor, be it an octet, a group of an IPv6 address or the prefix length.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Starting input**: Initial value of the prompt's text input.
+/// - **Default value**: Default value returned when the user submits an empty response.
+/// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+/// - **Validators**: Custom validators to make sure a given submitted input pass the specified requirements, e.g. only allowing private addresses.
+/// - **Error message**: Message displayed when the input is not a valid value, "Invalid IP address" by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::net::IpAddr;
+/// use inquire::{IpAddress, IpCidr};
+///
+/// let gateway: IpAddr = IpAddress::new("Gateway:")
+///     .with_placeholder("192.168.0.1")
+///     .prompt()
+///     .unwrap();
+///
+/// let subnet = IpAddress::<IpCidr>::new("Subnet:")
+///     .with_starting_input("10.0.0.0/8")
+///     .prompt()
+///     .unwrap();
+/// ```
+pub struct IpAddress<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Initial value of the prompt's text input.
+    pub starting_input: Option<&'a str>,
+
+    /// Default value, returned when the user input is empty.
+    pub default: Option<T>,
+
+    /// Short hint that describes the expected value of the input.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: CustomTypeFormatter<'a, T>,
+
+    /// Collection of validators to apply to the user input.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+
+    /// Error message displayed when the input is not a valid value.
+    pub error_message: String,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> IpAddress<'a, T>
+where
+    T: IpValue,
+{
+    /// Default validators added to the [IpAddress] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn CustomTypeValidator<T>>> = vec![];
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to change the number under the cursor");
+
+    /// Creates an [IpAddress] with the provided message and default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_input: None,
+            default: None,
+            placeholder: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: &|val| val.to_string(),
+            validators: Self::DEFAULT_VALIDATORS,
+            error_message: "Invalid IP address".into(),
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the initial value of the prompt's text input.
+    pub fn with_starting_input(mut self, starting_input: &'a str) -> Self {
+        self.starting_input = Some(starting_input);
+        self
+    }
+
+    /// Sets the default value, returned when the user input is empty.
+    pub fn with_default(mut self, default: T) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the parsed user's answer.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: CustomTypeValidator<T> + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Sets the error message displayed when the input is not a valid value.
+    pub fn with_error_message(mut self, error_message: &'a str) -> Self {
+        self.error_message = String::from(error_message);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<T> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<T> {
+        IpAddressPrompt::from(self).prompt(backend)
+    }
+}
@@ -0,0 +1,302 @@
+use crate::{
+    error::InquireResult,
+    formatter::CustomTypeFormatter,
+    input::Input,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::CustomTypeBackend,
+    validator::{CustomTypeValidator, ErrorMessage, Validation},
+    InquireError, IpAddress,
+};
+
+use super::{action::IpAddressPromptAction, config::IpAddressConfig, IpValue};
+
+pub struct IpAddressPrompt<'a, T> {
+    message: &'a str,
+    config: IpAddressConfig,
+    error: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    default: Option<T>,
+    input: Input,
+    formatter: CustomTypeFormatter<'a, T>,
+    validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+    error_message: String,
+}
+
+impl<'a, T> From<IpAddress<'a, T>> for IpAddressPrompt<'a, T>
+where
+    T: IpValue,
+{
+    fn from(ipo: IpAddress<'a, T>) -> Self {
+        let input = Input::new_with(ipo.starting_input.unwrap_or_default());
+        let input = if let Some(placeholder) = ipo.placeholder {
+            input.with_placeholder(placeholder)
+        } else {
+            input
+        };
+
+        Self {
+            message: ipo.message,
+            config: (&ipo).into(),
+            error: None,
+            help_message: ipo.help_message,
+            default: ipo.default,
+            input,
+            formatter: ipo.formatter,
+            validators: ipo.validators,
+            error_message: ipo.error_message,
+        }
+    }
+}
+
+impl<'a, T> IpAddressPrompt<'a, T>
+where
+    T: IpValue,
+{
+    fn validate_current_answer(&self, value: &T) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator.validate(value) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    fn get_final_answer(&self) -> Result<T, String> {
+        match &self.default {
+            Some(val) if self.input.content().is_empty() => return Ok(val.clone()),
+            _ => {}
+        }
+
+        match self.input.content().parse::<T>() {
+            Ok(val) => Ok(val),
+            Err(_) => Err(self.error_message.clone()),
+        }
+    }
+
+    /// Displays the error message as soon as the input can no longer be
+    /// completed into a valid value, and hides it once it can again.
+    fn validate_live(&mut self) {
+        self.error = match can_complete::<T>(self.input.content()) {
+            true => None,
+            false => Some(ErrorMessage::Custom(self.error_message.clone())),
+        };
+    }
+
+    /// Increments or decrements the number under the cursor, delimited by
+    /// dots, colons or slashes, within the bounds of its kind.
+    fn step_segment(&mut self, increment: bool) -> ActionResult {
+        let content = self.input.content();
+        if !content.is_ascii() {
+            return ActionResult::Clean;
+        }
+
+        let is_delimiter = |c: char| matches!(c, '.' | ':' | '/');
+        let cursor = self.input.cursor();
+        let start = match content[..cursor].rfind(is_delimiter) {
+            Some(index) => index + 1,
+            None => 0,
+        };
+        let end = match content[cursor..].find(is_delimiter) {
+            Some(index) => cursor + index,
+            None => content.len(),
+        };
+
+        let segment = &content[start..end];
+        let dotted = content[..start].ends_with('.') || content[end..].starts_with('.');
+
+        let (radix, max) = match content.find('/') {
+            Some(slash) if start > slash => match content[..slash].contains(':') {
+                true => (10, 128),
+                false => (10, 32),
+            },
+            _ if !dotted && content.contains(':') => (16, 0xffff),
+            _ => (10, 255),
+        };
+
+        let Ok(value) = u32::from_str_radix(segment, radix) else {
+            return ActionResult::Clean;
+        };
+
+        let value = match increment {
+            true => (value + 1).min(max),
+            false => value.saturating_sub(1).min(max),
+        };
+
+        let value = match radix {
+            16 => format!("{:x}", value),
+            _ => value.to_string(),
+        };
+
+        if value == segment {
+            return ActionResult::Clean;
+        }
+
+        let cursor = start + value.len();
+        let content = format!("{}{}{}", &content[..start], value, &content[end..]);
+
+        let input = Input::new_with(content).with_cursor(cursor);
+        self.input = match self.input.placeholder() {
+            Some(placeholder) => input.with_placeholder(placeholder),
+            None => input,
+        };
+
+        ActionResult::NeedsRedraw
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for IpAddressPrompt<'a, T>
+where
+    Backend: CustomTypeBackend,
+    T: IpValue,
+{
+    type Config = IpAddressConfig;
+    type InnerAction = IpAddressPromptAction;
+    type Output = T;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &IpAddressConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &T) -> String {
+        (self.formatter)(answer.clone())
+    }
+
+    fn fallback_answer(&self) -> Option<T> {
+        self.default.clone()
+    }
+
+    fn can_submit(&self) -> InquireResult<bool> {
+        match self.get_final_answer() {
+            Ok(answer) => Ok(self.validate_current_answer(&answer)? == Validation::Valid),
+            Err(_) => Ok(false),
+        }
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<T>> {
+        let answer = match self.get_final_answer() {
+            Ok(answer) => match self.validate_current_answer(&answer)? {
+                Validation::Valid => Some(answer),
+                Validation::Invalid(msg) => {
+                    self.error = Some(msg);
+                    None
+                }
+            },
+            Err(message) => {
+                self.error = Some(message.into());
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: IpAddressPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            IpAddressPromptAction::ValueInput(input_action) => {
+                self.input.handle(input_action).into()
+            }
+            IpAddressPromptAction::IncrementSegment => self.step_segment(true),
+            IpAddressPromptAction::DecrementSegment => self.step_segment(false),
+        };
+
+        if result == ActionResult::NeedsRedraw {
+            self.validate_live();
+        }
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        let default_message = self.default.as_ref().map(|val| val.to_string());
+
+        backend.render_prompt(self.message, None, default_message.as_deref(), &self.input)?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether the input can still be completed into a valid value of `T`.
+fn can_complete<T: IpValue>(input: &str) -> bool {
+    let (address, prefix_len) = match input.split_once('/') {
+        Some((address, prefix_len)) => (address, Some(prefix_len)),
+        None => (input, None),
+    };
+
+    let ipv6 = address.contains(':') || !T::IPV4;
+
+    let valid_address = match ipv6 {
+        true => T::IPV6 && can_complete_ipv6(address),
+        false => can_complete_ipv4(address) || (T::IPV6 && can_complete_ipv6(address)),
+    };
+
+    let Some(prefix_len) = prefix_len else {
+        return valid_address;
+    };
+
+    let max_prefix_len = match ipv6 {
+        true => 128,
+        false => 32,
+    };
+
+    valid_address
+        && T::CIDR
+        && prefix_len.len() <= 3
+        && prefix_len.chars().all(|c| c.is_ascii_digit())
+        && match prefix_len.parse::<u32>() {
+            Ok(prefix_len) => prefix_len <= max_prefix_len,
+            Err(_) => prefix_len.is_empty(),
+        }
+}
+
+fn can_complete_ipv4(address: &str) -> bool {
+    let octets: Vec<&str> = address.split('.').collect();
+
+    octets.len() <= 4
+        && octets.iter().all(|octet| {
+            octet.len() <= 3
+                && octet.chars().all(|c| c.is_ascii_digit())
+                && match octet.parse::<u32>() {
+                    Ok(octet) => octet <= 255,
+                    Err(_) => octet.is_empty(),
+                }
+        })
+}
+
+fn can_complete_ipv6(address: &str) -> bool {
+    let groups: Vec<&str> = address.split(':').collect();
+    let (last, groups) = match groups.split_last() {
+        Some(split) => split,
+        None => return true,
+    };
+
+    // the last group may be an embedded IPv4 address, e.g. ::ffff:10.0.0.1
+    let valid_last = match last.contains('.') {
+        true => !groups.is_empty() && can_complete_ipv4(last),
+        false => is_hex_group(last),
+    };
+
+    groups.len() <= 8
+        && address.matches("::").count() <= 1
+        && !address.contains(":::")
+        && groups.iter().all(|group| is_hex_group(group))
+        && valid_last
+}
+
+fn is_hex_group(group: &str) -> bool {
+    group.len() <= 4 && group.chars().all(|c| c.is_ascii_hexdigit())
+}
@@ -0,0 +1,115 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::ErrorMessage,
+    IpAddress, IpCidr,
+};
+
+fn text_keys(text: &str) -> Vec<Key> {
+    text.chars()
+        .map(|c| Key::Char(c, KeyModifiers::NONE))
+        .collect()
+}
+
+fn keys(text: &str, then: &[Key]) -> Vec<Key> {
+    let mut keys = text_keys(text);
+    keys.extend_from_slice(then);
+    keys
+}
+
+fn keys_then_submit(keys: &[Key], text: &str) -> Vec<Key> {
+    let mut keys = keys.to_vec();
+    keys.extend(text_keys(text));
+    keys.push(Key::Enter);
+    keys
+}
+
+const UP: Key = Key::Up(KeyModifiers::NONE);
+const DOWN: Key = Key::Down(KeyModifiers::NONE);
+const LEFT: Key = Key::Left(KeyModifiers::NONE);
+
+#[test]
+fn typed_addresses_are_parsed_into_their_type() {
+    let mut backend = FakeBackend::new(keys("192.168.0.1", &[Key::Enter]));
+    let ans: IpAddr = IpAddress::new("Address")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)), ans);
+
+    let mut backend = FakeBackend::new(keys("2001:db8::1", &[Key::Enter]));
+    let ans: Ipv6Addr = IpAddress::new("Address")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), ans);
+}
+
+#[test]
+fn cidr_blocks_are_parsed_with_their_prefix_length() {
+    let mut backend = FakeBackend::new(keys("10.0.0.0/8", &[Key::Enter]));
+
+    let ans = IpAddress::<IpCidr>::new("Subnet")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), ans.address());
+    assert_eq!(8, ans.prefix_len());
+    assert_eq!("10.0.0.0/8", ans.to_string());
+}
+
+#[test]
+fn arrows_step_the_number_under_the_cursor_within_its_bounds() {
+    let mut backend = FakeBackend::new(keys(
+        "10.0.254.9/31",
+        &[UP, UP, LEFT, LEFT, LEFT, LEFT, UP, UP, Key::Enter],
+    ));
+
+    let ans = IpAddress::<IpCidr>::new("Subnet")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("10.0.254.11/32", ans.to_string());
+}
+
+#[test]
+fn arrows_step_ipv6_groups_in_hexadecimal() {
+    let mut backend = FakeBackend::new(keys("fe80::9", &[UP, DOWN, UP, Key::Enter]));
+
+    let ans: Ipv6Addr = IpAddress::new("Address")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 0xa), ans);
+}
+
+#[test]
+fn error_is_displayed_as_soon_as_the_input_can_not_be_completed() {
+    let mut backend =
+        FakeBackend::new(keys("10.300", &keys_then_submit(&[Key::Backspace], ".0.1")));
+
+    let ans: Ipv4Addr = IpAddress::new("Address")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(Ipv4Addr::new(10, 30, 0, 1), ans);
+
+    let error = Token::ErrorMessage(ErrorMessage::Custom("Invalid IP address".into()));
+    assert!(!backend.frames()[5].has_token(&error));
+    assert!(backend.frames()[6].has_token(&error));
+    assert!(!backend.frames()[7].has_token(&error));
+}
+
+#[test]
+fn prefix_lengths_are_rejected_for_plain_addresses() {
+    let mut backend =
+        FakeBackend::new(keys("10.0.0.1/", &[Key::Enter, Key::Backspace, Key::Enter]));
+
+    let ans: IpAddr = IpAddress::new("Address")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), ans);
+}
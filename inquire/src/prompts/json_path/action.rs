@@ -0,0 +1,55 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
+};
+
+use super::config::JsonPathPickerConfig;
+
+/// Set of actions for a JsonPathPickerPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum JsonPathPickerPromptAction {
+    /// Action on the value text input handler.
+    FilterInput(InputAction),
+    /// Moves the cursor to the node above.
+    MoveUp,
+    /// Moves the cursor to the node below.
+    MoveDown,
+    /// Moves the cursor to the page above.
+    PageUp,
+    /// Moves the cursor to the page below.
+    PageDown,
+    /// Moves the cursor to the start of the list.
+    MoveToStart,
+    /// Moves the cursor to the end of the list.
+    MoveToEnd,
+    /// Expands the highlighted node, or moves to its first child if it is already expanded.
+    Expand,
+    /// Collapses the highlighted node, or moves to its parent if it is not expanded.
+    Collapse,
+}
+
+impl InnerAction for JsonPathPickerPromptAction {
+    type Config = JsonPathPickerConfig;
+
+    fn from_key(key: Key, _config: &JsonPathPickerConfig) -> Option<Self> {
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::PageUp(_) => Self::PageUp,
+            Key::Home => Self::MoveToStart,
+
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::PageDown(_) => Self::PageDown,
+            Key::End => Self::MoveToEnd,
+
+            Key::Right(KeyModifiers::NONE) => Self::Expand,
+            Key::Left(KeyModifiers::NONE) => Self::Collapse,
+
+            key => match InputAction::from_key(key, &()) {
+                Some(action) => Self::FilterInput(action),
+                None => return None,
+            },
+        };
+
+        Some(action)
+    }
+}
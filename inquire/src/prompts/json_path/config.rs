@@ -0,0 +1,19 @@
+use crate::JsonPathPicker;
+
+/// Configuration settings used in the execution of a JsonPathPickerPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct JsonPathPickerConfig {
+    /// Page size of the list of nodes.
+    pub page_size: usize,
+    /// Whether only leaf values can be submitted.
+    pub leaves_only: bool,
+}
+
+impl From<&JsonPathPicker<'_>> for JsonPathPickerConfig {
+    fn from(value: &JsonPathPicker<'_>) -> Self {
+        Self {
+            page_size: value.page_size,
+            leaves_only: value.leaves_only,
+        }
+    }
+}
@@ -0,0 +1,216 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use serde_json::Value;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::StringFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, SelectBackend},
+};
+
+use self::prompt::JsonPathPickerPrompt;
+
+/// Prompt that lets the user navigate the tree of a JSON value and select one of its nodes, returning the [JSON pointer](https://www.rfc-editor.org/rfc/rfc6901) to it, e.g. `/servers/0/port`. Handy for CLIs that edit configuration files.
+///
+/// Nodes are rendered as an indented tree, starting collapsed. The right arrow expands the highlighted node and the left arrow collapses it or moves to its parent. Typing filters the nodes by key, displaying every match with its full path regardless of the tree expansion.
+///
+/// The returned pointer can be passed to [`Value::pointer`](serde_json::Value::pointer) or [`Value::pointer_mut`](serde_json::Value::pointer_mut) to access the selected node. YAML, TOML and other documents can be navigated by converting them into a `serde_json::Value` first, e.g. with `serde_json::to_value`.
+///
+/// This prompt requires the `json` feature and a JSON value that is a **non-empty** object or array.
+/// - Otherwise, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Value**: JSON value to navigate, required when creating the prompt.
+/// - **Leaves only**: Whether only leaf values can be submitted. When enabled, pressing enter on an object or array toggles its expansion instead. Defaults to false.
+/// - **Starting filter input**: Sets the initial value of the filter section of the prompt.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the JSON pointer by default.
+/// - **Page size**: Number of nodes displayed at once, 7 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::JsonPathPicker;
+/// use serde_json::json;
+///
+/// let config = json!({
+///     "database": { "host": "localhost", "port": 5432 },
+///     "servers": [{ "name": "alpha" }, { "name": "beta" }],
+/// });
+///
+/// let pointer = JsonPathPicker::new("Which setting do you want to edit?", &config)
+///     .with_leaves_only(true)
+///     .prompt();
+///
+/// match pointer {
+///     Ok(pointer) => println!("{} is currently {}", pointer, config.pointer(&pointer).unwrap()),
+///     Err(_) => println!("Nothing was edited"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct JsonPathPicker<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// JSON value navigated by the user.
+    pub value: &'a Value,
+
+    /// Whether only leaf values can be submitted.
+    pub leaves_only: bool,
+
+    /// Starting filter input.
+    pub starting_filter_input: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the nodes displayed to the user.
+    pub page_size: usize,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: StringFormatter<'a>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> JsonPathPicker<'a> {
+    /// Default formatter, prints the JSON pointer to the selected node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::JsonPathPicker;
+    ///
+    /// let formatter = JsonPathPicker::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("/servers/0/name"), formatter("/servers/0/name"));
+    /// ```
+    pub const DEFAULT_FORMATTER: StringFormatter<'a> = &|pointer| pointer.to_owned();
+
+    /// Default value of the leaves only flag.
+    pub const DEFAULT_LEAVES_ONLY: bool = false;
+
+    /// Default page size, equal to the global default page size [config::DEFAULT_PAGE_SIZE]
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, → to expand, ← to collapse, enter to select, type to filter");
+
+    /// Creates a [JsonPathPicker] with the provided message and value, along with default configuration values.
+    pub fn new(message: &'a str, value: &'a Value) -> Self {
+        Self {
+            message,
+            value,
+            leaves_only: Self::DEFAULT_LEAVES_ONLY,
+            starting_filter_input: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets whether only leaf values can be submitted.
+    pub fn with_leaves_only(mut self, leaves_only: bool) -> Self {
+        self.leaves_only = leaves_only;
+        self
+    }
+
+    /// Sets the starting filter input.
+    pub fn with_starting_filter_input(mut self, starting_filter_input: &'a str) -> Self {
+        self.starting_filter_input = Some(starting_filter_input);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: StringFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<String>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the JSON pointer to the selected node.
+    pub fn prompt(self) -> InquireResult<String> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<String> {
+        JsonPathPickerPrompt::new(self)?.prompt(backend)
+    }
+}
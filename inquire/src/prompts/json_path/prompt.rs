@@ -0,0 +1,337 @@
+use serde_json::Value;
+
+use crate::{
+    error::InquireResult,
+    formatter::StringFormatter,
+    input::{Input, InputActionResult},
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::SelectBackend,
+    utils::paginate,
+    InquireError, JsonPathPicker,
+};
+
+use super::{action::JsonPathPickerPromptAction, config::JsonPathPickerConfig};
+
+struct JsonNode {
+    pointer: String,
+    key: String,
+    summary: String,
+    depth: usize,
+    parent: Option<usize>,
+    has_children: bool,
+}
+
+/// Escapes a key as a reference token of a JSON pointer, as defined by RFC 6901.
+pub(super) fn escape_token(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+fn summarize(value: &Value) -> String {
+    match value {
+        Value::Object(map) => format!("{{{}}}", map.len()),
+        Value::Array(array) => format!("[{}]", array.len()),
+        value => value.to_string(),
+    }
+}
+
+/// Flattens the tree in pre-order, so that the descendants of a node are
+/// always the nodes right after it.
+fn flatten(
+    value: &Value,
+    pointer: &str,
+    depth: usize,
+    parent: Option<usize>,
+    nodes: &mut Vec<JsonNode>,
+) {
+    let children: Vec<(String, &Value)> = match value {
+        Value::Object(map) => map.iter().map(|(k, v)| (k.clone(), v)).collect(),
+        Value::Array(array) => array
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (i.to_string(), v))
+            .collect(),
+        _ => return,
+    };
+
+    for (key, child) in children {
+        let child_pointer = format!("{}/{}", pointer, escape_token(&key));
+        let idx = nodes.len();
+
+        nodes.push(JsonNode {
+            pointer: child_pointer.clone(),
+            key,
+            summary: summarize(child),
+            depth,
+            parent,
+            has_children: false,
+        });
+
+        flatten(child, &child_pointer, depth + 1, Some(idx), nodes);
+        nodes[idx].has_children = nodes.len() > idx + 1;
+    }
+}
+
+pub struct JsonPathPickerPrompt<'a> {
+    message: &'a str,
+    config: JsonPathPickerConfig,
+    nodes: Vec<JsonNode>,
+    expanded: Vec<bool>,
+    visible_nodes: Vec<usize>,
+    help_message: Option<&'a str>,
+    cursor_index: usize,
+    input: Input,
+    formatter: StringFormatter<'a>,
+}
+
+impl<'a> JsonPathPickerPrompt<'a> {
+    pub fn new(jp: JsonPathPicker<'a>) -> InquireResult<Self> {
+        let mut nodes = vec![];
+        flatten(jp.value, "", 0, None, &mut nodes);
+
+        if nodes.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Value must be a non-empty object or array".into(),
+            ));
+        }
+
+        let expanded = vec![false; nodes.len()];
+
+        let mut prompt = Self {
+            message: jp.message,
+            config: (&jp).into(),
+            nodes,
+            expanded,
+            visible_nodes: vec![],
+            help_message: jp.help_message,
+            cursor_index: 0,
+            input: Input::new_with(jp.starting_filter_input.unwrap_or_default()),
+            formatter: jp.formatter,
+        };
+        prompt.refresh_visible_nodes();
+
+        Ok(prompt)
+    }
+
+    fn refresh_visible_nodes(&mut self) {
+        let filter = self.input.content().to_lowercase();
+
+        self.visible_nodes = if filter.is_empty() {
+            let mut visible = vec![false; self.nodes.len()];
+            for (i, node) in self.nodes.iter().enumerate() {
+                visible[i] = node
+                    .parent
+                    .map(|p| visible[p] && self.expanded[p])
+                    .unwrap_or(true);
+            }
+            (0..self.nodes.len()).filter(|i| visible[*i]).collect()
+        } else {
+            (0..self.nodes.len())
+                .filter(|i| self.nodes[*i].key.to_lowercase().contains(&filter))
+                .collect()
+        };
+    }
+
+    fn highlighted_node(&self) -> Option<usize> {
+        self.visible_nodes.get(self.cursor_index).copied()
+    }
+
+    fn focus_node(&mut self, node: usize) -> ActionResult {
+        match self.visible_nodes.iter().position(|n| *n == node) {
+            Some(position) => self.update_cursor_position(position),
+            None => ActionResult::Clean,
+        }
+    }
+
+    fn set_expanded(&mut self, node: usize, expanded: bool) -> ActionResult {
+        if !self.nodes[node].has_children || self.expanded[node] == expanded {
+            return ActionResult::Clean;
+        }
+
+        self.expanded[node] = expanded;
+        self.refresh_visible_nodes();
+        let _ = self.focus_node(node);
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn expand(&mut self) -> ActionResult {
+        let node = match self.highlighted_node() {
+            Some(node) => node,
+            None => return ActionResult::Clean,
+        };
+
+        if self.nodes[node].has_children && self.expanded[node] {
+            self.focus_node(node + 1)
+        } else {
+            self.set_expanded(node, true)
+        }
+    }
+
+    fn collapse(&mut self) -> ActionResult {
+        let node = match self.highlighted_node() {
+            Some(node) => node,
+            None => return ActionResult::Clean,
+        };
+
+        if self.nodes[node].has_children && self.expanded[node] {
+            self.set_expanded(node, false)
+        } else if let Some(parent) = self.nodes[node].parent {
+            self.focus_node(parent)
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let new_position = if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.visible_nodes.len().saturating_sub(after_wrap))
+        } else {
+            self.cursor_index.saturating_sub(qty)
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let mut new_position = self.cursor_index.saturating_add(qty);
+
+        if new_position >= self.visible_nodes.len() {
+            new_position = if self.visible_nodes.is_empty() {
+                0
+            } else if wrap {
+                new_position % self.visible_nodes.len()
+            } else {
+                self.visible_nodes.len().saturating_sub(1)
+            }
+        }
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn node_label(&self, node: usize, filtering: bool) -> String {
+        let node_ref = &self.nodes[node];
+
+        let marker = match (node_ref.has_children, self.expanded[node]) {
+            (true, true) => "▾ ",
+            (true, false) => "▸ ",
+            (false, _) => "  ",
+        };
+
+        let (indent, name) = match filtering {
+            true => (0, node_ref.pointer.as_str()),
+            false => (node_ref.depth * 2, node_ref.key.as_str()),
+        };
+
+        let separator = if node_ref.has_children { " " } else { ": " };
+
+        format!(
+            "{:indent$}{}{}{}{}",
+            "",
+            marker,
+            name,
+            separator,
+            node_ref.summary,
+            indent = indent
+        )
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for JsonPathPickerPrompt<'a>
+where
+    Backend: SelectBackend,
+{
+    type Config = JsonPathPickerConfig;
+    type InnerAction = JsonPathPickerPromptAction;
+    type Output = String;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &JsonPathPickerConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &String) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<String>> {
+        let node = match self.highlighted_node() {
+            Some(node) => node,
+            None => return Ok(None),
+        };
+
+        if self.config.leaves_only && self.nodes[node].has_children {
+            let expanded = self.expanded[node];
+            let _ = self.set_expanded(node, !expanded);
+            return Ok(None);
+        }
+
+        Ok(Some(self.nodes[node].pointer.clone()))
+    }
+
+    fn handle(&mut self, action: JsonPathPickerPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            JsonPathPickerPromptAction::MoveUp => self.move_cursor_up(1, true),
+            JsonPathPickerPromptAction::MoveDown => self.move_cursor_down(1, true),
+            JsonPathPickerPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
+            JsonPathPickerPromptAction::PageDown => {
+                self.move_cursor_down(self.config.page_size, false)
+            }
+            JsonPathPickerPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
+            JsonPathPickerPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            JsonPathPickerPromptAction::Expand => self.expand(),
+            JsonPathPickerPromptAction::Collapse => self.collapse(),
+            JsonPathPickerPromptAction::FilterInput(input_action) => {
+                let result = self.input.handle(input_action);
+
+                if let InputActionResult::ContentChanged = result {
+                    self.refresh_visible_nodes();
+                    let _ = self.update_cursor_position(0);
+                }
+
+                result.into()
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.render_select_prompt(prompt, Some(&self.input))?;
+
+        let filtering = !self.input.content().is_empty();
+
+        let choices = self
+            .visible_nodes
+            .iter()
+            .map(|node| ListOption::new(*node, self.node_label(*node, filtering)))
+            .collect::<Vec<ListOption<String>>>();
+
+        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+
+        backend.render_options(page, &[], &[])?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
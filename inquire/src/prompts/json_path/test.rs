@@ -0,0 +1,90 @@
+use serde_json::json;
+
+use crate::{
+    error::InquireError,
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    JsonPathPicker,
+};
+
+#[test]
+fn expanding_and_selecting_returns_pointer() {
+    let value = json!({ "db": { "host": "localhost" }, "servers": [{ "name": "alpha" }] });
+
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = JsonPathPicker::new("Question", &value)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("/servers/0/name", ans);
+}
+
+#[test]
+fn collapse_moves_to_parent() {
+    let value = json!({ "db": { "host": "localhost", "port": 5432 } });
+
+    let mut backend = fake_backend(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = JsonPathPicker::new("Question", &value)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("/db", ans);
+}
+
+#[test]
+fn filter_matches_collapsed_keys() {
+    let value = json!({ "db": { "port": 5432 }, "cache": { "port": 6379 } });
+
+    let mut backend = fake_backend(vec![
+        Key::Char('p', KeyModifiers::NONE),
+        Key::Char('o', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = JsonPathPicker::new("Question", &value)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("/db/port", ans);
+}
+
+#[test]
+fn leaves_only_expands_containers_on_submit() {
+    let value = json!({ "a/b": { "c~d": true } });
+
+    let mut backend = fake_backend(vec![Key::Enter, Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = JsonPathPicker::new("Question", &value)
+        .with_leaves_only(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("/a~1b/c~0d", ans);
+}
+
+#[test]
+fn scalar_value_is_an_invalid_configuration() {
+    let value = json!(42);
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = JsonPathPicker::new("Question", &value).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
@@ -0,0 +1,48 @@
+use crate::{
+    ui::{Key, KeyHelp, KeyModifiers},
+    InnerAction, InputAction,
+};
+
+use super::config::KeyValueConfig;
+
+/// Set of actions for a KeyValuePrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KeyValuePromptAction {
+    /// Action on the text input of the key or value being entered. When an
+    /// entry is highlighted, deleting characters deletes the entry instead.
+    ValueInput(InputAction),
+    /// Highlights the entry above, or the last one when none is highlighted.
+    MoveUp,
+    /// Highlights the entry below, or none when the last one is highlighted.
+    MoveDown,
+    /// Finishes the prompt, saving the value being entered, if any.
+    Finish,
+}
+
+impl InnerAction for KeyValuePromptAction {
+    type Config = KeyValueConfig;
+
+    fn from_key(key: Key, _config: &KeyValueConfig) -> Option<Self> {
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::Char('s', KeyModifiers::CONTROL) => Self::Finish,
+            key => match InputAction::from_key(key, &()) {
+                Some(action) => Self::ValueInput(action),
+                None => return None,
+            },
+        };
+
+        Some(action)
+    }
+
+    fn key_help(_config: &KeyValueConfig) -> Vec<KeyHelp> {
+        vec![
+            KeyHelp::new(
+                [Key::Up(KeyModifiers::NONE), Key::Down(KeyModifiers::NONE)],
+                "highlight an entry to edit or delete",
+            ),
+            KeyHelp::new([Key::Char('s', KeyModifiers::CONTROL)], "finish"),
+        ]
+    }
+}
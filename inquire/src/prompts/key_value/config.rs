@@ -0,0 +1,16 @@
+use crate::KeyValue;
+
+/// Configuration settings used in the execution of a KeyValuePrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct KeyValueConfig {
+    /// Page size of the list of entries.
+    pub page_size: usize,
+}
+
+impl From<&KeyValue<'_>> for KeyValueConfig {
+    fn from(value: &KeyValue<'_>) -> Self {
+        Self {
+            page_size: value.page_size,
+        }
+    }
+}
@@ -0,0 +1,200 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::{self, KeyValueFormatter},
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, SelectBackend},
+};
+
+use self::prompt::KeyValuePrompt;
+
+/// Prompt to enter a list of key-value pairs, e.g. labels or headers, returning them in the order they were entered.
+///
+/// Each entry is entered by typing its key, pressing enter, and then typing its value and pressing enter again. The entries added so far are listed below the input, and submitting an empty key, or pressing Ctrl-S at any time, finishes the prompt. Entering a key that was already added edits the value of that entry instead of adding a new one.
+///
+/// The arrow keys highlight one of the listed entries: pressing enter edits its value, while pressing backspace or delete removes it. Pressing ESC while entering a value discards it, and pressing it while entering a key cancels the whole prompt.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Entries**: Entries listed when the prompt is first rendered.
+/// - **Placeholders**: Short hints displayed in the empty input of keys and values.
+/// - **Help message**: Message displayed at the line below the prompt while entering a key.
+/// - **Page size**: Number of entries displayed at once, 7 by default.
+/// - **Formatter**: Custom formatter in case you need to pre-process the entries before showing them as the final answer.
+///   - Formats to `key=value` pairs separated by commas by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::KeyValue;
+///
+/// let labels = KeyValue::new("Labels:")
+///     .with_entries(&[("team", "payments")])
+///     .prompt();
+///
+/// match labels {
+///     Ok(labels) => println!("Applying {} labels", labels.len()),
+///     Err(_) => println!("No labels applied"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct KeyValue<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Entries listed when the prompt is first rendered.
+    pub entries: Vec<(String, String)>,
+
+    /// Placeholder displayed in the empty input of keys.
+    pub key_placeholder: &'a str,
+
+    /// Placeholder displayed in the empty input of values.
+    pub value_placeholder: &'a str,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the entries displayed to the user.
+    pub page_size: usize,
+
+    /// Function that formats the entries and presents them to the user as the final rendering of the prompt.
+    pub formatter: KeyValueFormatter<'a>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> KeyValue<'a> {
+    /// Default formatter, set to [DEFAULT_KEY_VALUE_FORMATTER](crate::formatter::DEFAULT_KEY_VALUE_FORMATTER)
+    pub const DEFAULT_FORMATTER: KeyValueFormatter<'a> = formatter::DEFAULT_KEY_VALUE_FORMATTER;
+
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default placeholder of the key input.
+    pub const DEFAULT_KEY_PLACEHOLDER: &'a str = "key";
+
+    /// Default placeholder of the value input.
+    pub const DEFAULT_VALUE_PLACEHOLDER: &'a str = "value";
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to highlight, empty key or ctrl-s to finish");
+
+    /// Creates a [KeyValue] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            entries: vec![],
+            key_placeholder: Self::DEFAULT_KEY_PLACEHOLDER,
+            value_placeholder: Self::DEFAULT_VALUE_PLACEHOLDER,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the entries listed when the prompt is first rendered.
+    pub fn with_entries(mut self, entries: &[(&str, &str)]) -> Self {
+        self.entries = entries
+            .iter()
+            .map(|&(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        self
+    }
+
+    /// Sets the placeholders of the key and value inputs.
+    pub fn with_placeholders(mut self, key: &'a str, value: &'a str) -> Self {
+        self.key_placeholder = key;
+        self.value_placeholder = value;
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: KeyValueFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Vec<(String, String)>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<Vec<(String, String)>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<(String, String)>> {
+        KeyValuePrompt::from(self).prompt(backend)
+    }
+}
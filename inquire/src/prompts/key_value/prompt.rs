@@ -0,0 +1,277 @@
+use std::fmt;
+
+use crate::{
+    error::InquireResult,
+    formatter::KeyValueFormatter,
+    input::Input,
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::{width::str_width, AmbiguousWidth, SelectBackend},
+    utils::paginate,
+    InputAction, KeyValue,
+};
+
+use super::{action::KeyValuePromptAction, config::KeyValueConfig};
+
+/// Entry of the list rendered below the prompt, along with the width its key
+/// is padded to, so that the values are aligned in a column.
+struct EntryRow<'e> {
+    key: &'e str,
+    value: &'e str,
+    key_width: usize,
+}
+
+impl fmt::Display for EntryRow<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let padding = self
+            .key_width
+            .saturating_sub(str_width(self.key, AmbiguousWidth::Narrow));
+        write!(f, "{}{} = {}", self.key, " ".repeat(padding), self.value)
+    }
+}
+
+/// Part of an entry being entered.
+enum Stage {
+    Key,
+    Value {
+        key: String,
+        /// Index of the entry whose value is being edited, if any.
+        editing: Option<usize>,
+    },
+}
+
+pub struct KeyValuePrompt<'a> {
+    message: &'a str,
+    config: KeyValueConfig,
+    entries: Vec<(String, String)>,
+    stage: Stage,
+    input: Input,
+    cursor: Option<usize>,
+    finished: bool,
+    key_placeholder: &'a str,
+    value_placeholder: &'a str,
+    help_message: Option<&'a str>,
+    formatter: KeyValueFormatter<'a>,
+}
+
+impl<'a> From<KeyValue<'a>> for KeyValuePrompt<'a> {
+    fn from(kvo: KeyValue<'a>) -> Self {
+        Self {
+            message: kvo.message,
+            config: (&kvo).into(),
+            entries: kvo.entries,
+            stage: Stage::Key,
+            input: Input::new().with_placeholder(kvo.key_placeholder),
+            cursor: None,
+            finished: false,
+            key_placeholder: kvo.key_placeholder,
+            value_placeholder: kvo.value_placeholder,
+            help_message: kvo.help_message,
+            formatter: kvo.formatter,
+        }
+    }
+}
+
+impl<'a> KeyValuePrompt<'a> {
+    fn start_key(&mut self) {
+        self.stage = Stage::Key;
+        self.input = Input::new().with_placeholder(self.key_placeholder);
+    }
+
+    fn start_value(&mut self, key: String, editing: Option<usize>) {
+        let value = editing
+            .and_then(|idx| self.entries.get(idx))
+            .map(|(_, value)| value.as_str())
+            .unwrap_or_default();
+
+        self.input = Input::new_with(value).with_placeholder(self.value_placeholder);
+        self.stage = Stage::Value { key, editing };
+        self.cursor = None;
+    }
+
+    /// Saves the value being entered, if any, going back to entering a key.
+    fn save_value(&mut self) {
+        if let Stage::Value { key, editing } = &self.stage {
+            let value = self.input.content().to_string();
+
+            match editing {
+                Some(idx) => self.entries[*idx].1 = value,
+                None => self.entries.push((key.clone(), value)),
+            }
+        }
+
+        self.start_key();
+    }
+
+    fn move_cursor(&mut self, up: bool) -> ActionResult {
+        if !matches!(self.stage, Stage::Key) || self.entries.is_empty() {
+            return ActionResult::Clean;
+        }
+
+        let last = self.entries.len() - 1;
+        self.cursor = match (self.cursor, up) {
+            (None, true) => Some(last),
+            (None, false) => None,
+            (Some(0), true) => Some(0),
+            (Some(idx), true) => Some(idx - 1),
+            (Some(idx), false) if idx == last => None,
+            (Some(idx), false) => Some(idx + 1),
+        };
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn delete_highlighted_entry(&mut self, idx: usize) -> ActionResult {
+        self.entries.remove(idx);
+        self.cursor = match self.entries.len() {
+            0 => None,
+            len => Some(idx.min(len - 1)),
+        };
+
+        ActionResult::NeedsRedraw
+    }
+}
+
+impl<'a, B> Prompt<B> for KeyValuePrompt<'a>
+where
+    B: SelectBackend,
+{
+    type Config = KeyValueConfig;
+    type InnerAction = KeyValuePromptAction;
+    type Output = Vec<(String, String)>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &KeyValueConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &Vec<(String, String)>) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn should_auto_submit(&self) -> bool {
+        self.finished
+    }
+
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        if matches!(self.stage, Stage::Value { .. }) {
+            self.start_key();
+            return Ok(false);
+        }
+
+        if self.cursor.is_some() {
+            self.cursor = None;
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Vec<(String, String)>>> {
+        if self.finished {
+            return Ok(Some(std::mem::take(&mut self.entries)));
+        }
+
+        if matches!(self.stage, Stage::Value { .. }) {
+            self.save_value();
+            return Ok(None);
+        }
+
+        if let Some(idx) = self.cursor {
+            let key = self.entries[idx].0.clone();
+            self.start_value(key, Some(idx));
+            return Ok(None);
+        }
+
+        let key = self.input.content().trim().to_string();
+        if key.is_empty() {
+            return Ok(Some(std::mem::take(&mut self.entries)));
+        }
+
+        let existing = self.entries.iter().position(|(k, _)| *k == key);
+        self.start_value(key, existing);
+
+        Ok(None)
+    }
+
+    fn handle(&mut self, action: KeyValuePromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            KeyValuePromptAction::MoveUp => self.move_cursor(true),
+            KeyValuePromptAction::MoveDown => self.move_cursor(false),
+            KeyValuePromptAction::Finish => {
+                if matches!(self.stage, Stage::Value { .. }) {
+                    self.save_value();
+                }
+                self.finished = true;
+                ActionResult::NeedsRedraw
+            }
+            KeyValuePromptAction::ValueInput(input_action) => match self.cursor {
+                Some(idx) if matches!(input_action, InputAction::Delete(_, _)) => {
+                    self.delete_highlighted_entry(idx)
+                }
+                Some(_) => {
+                    self.cursor = None;
+                    self.input.handle(input_action);
+                    ActionResult::NeedsRedraw
+                }
+                None => self.input.handle(input_action).into(),
+            },
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut B) -> InquireResult<()> {
+        let prompt = match &self.stage {
+            Stage::Key => self.message.to_string(),
+            Stage::Value { key, .. } => format!("{} {} =", self.message, key),
+        };
+
+        backend.render_select_prompt(&prompt, Some(&self.input))?;
+
+        let key_width = self
+            .entries
+            .iter()
+            .map(|(key, _)| str_width(key, AmbiguousWidth::Narrow))
+            .max()
+            .unwrap_or(0);
+        let rows: Vec<ListOption<EntryRow<'_>>> = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(idx, (key, value))| {
+                ListOption::new(
+                    idx,
+                    EntryRow {
+                        key,
+                        value,
+                        key_width,
+                    },
+                )
+            })
+            .collect();
+
+        if !rows.is_empty() {
+            backend.render_options(
+                paginate(self.config.page_size, &rows, self.cursor),
+                &[],
+                &[],
+            )?;
+        }
+
+        let help_message = match (&self.stage, self.cursor) {
+            (Stage::Value { .. }, _) => Some("enter to save, esc to discard"),
+            (Stage::Key, Some(_)) => Some("enter to edit, backspace to delete, esc to go back"),
+            (Stage::Key, None) => self.help_message,
+        };
+
+        if let Some(help_message) = help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{
+    error::InquireError,
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    KeyValue,
+};
+
+const UP: Key = Key::Up(KeyModifiers::NONE);
+const CTRL_S: Key = Key::Char('s', KeyModifiers::CONTROL);
+
+fn text(text: &str) -> Vec<Key> {
+    text.chars()
+        .map(|c| Key::Char(c, KeyModifiers::NONE))
+        .collect()
+}
+
+fn entries(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|&(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+fn entry_keys(key: &str, value: &str) -> Vec<Key> {
+    let mut keys = text(key);
+    keys.push(Key::Enter);
+    keys.extend(text(value));
+    keys.push(Key::Enter);
+    keys
+}
+
+#[test]
+fn entries_are_returned_in_order_after_an_empty_key() {
+    let mut keys = entry_keys("env", "prod");
+    keys.extend(entry_keys("region", "eu"));
+    keys.push(Key::Enter);
+    let mut backend = fake_backend(keys);
+
+    let ans = KeyValue::new("Labels")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(entries(&[("env", "prod"), ("region", "eu")]), ans);
+}
+
+#[test]
+fn ctrl_s_saves_the_value_being_entered_and_finishes() {
+    let mut keys = text("env");
+    keys.push(Key::Enter);
+    keys.extend(text("prod"));
+    keys.push(CTRL_S);
+    let mut backend = fake_backend(keys);
+
+    let ans = KeyValue::new("Labels")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(entries(&[("env", "prod")]), ans);
+}
+
+#[test]
+fn existing_key_edits_its_entry() {
+    let mut keys = text("env");
+    keys.extend([Key::Enter, Key::Backspace, Key::Backspace]);
+    keys.extend(text("v"));
+    keys.extend([Key::Enter, Key::Enter]);
+    let mut backend = fake_backend(keys);
+
+    let ans = KeyValue::new("Labels")
+        .with_entries(&[("env", "dev"), ("team", "payments")])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(entries(&[("env", "dv"), ("team", "payments")]), ans);
+}
+
+#[test]
+fn highlighted_entry_is_edited_on_enter() {
+    let mut keys = vec![UP, Key::Enter, Key::Backspace];
+    keys.extend(text("t"));
+    keys.extend([Key::Enter, Key::Enter]);
+    let mut backend = fake_backend(keys);
+
+    let ans = KeyValue::new("Labels")
+        .with_entries(&[("env", "dev"), ("team", "payments")])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(entries(&[("env", "dev"), ("team", "paymentt")]), ans);
+}
+
+#[test]
+fn highlighted_entry_is_deleted_on_backspace() {
+    let mut backend = fake_backend(vec![UP, UP, Key::Backspace, Key::Escape, Key::Enter]);
+
+    let ans = KeyValue::new("Labels")
+        .with_entries(&[("env", "dev"), ("team", "payments")])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(entries(&[("team", "payments")]), ans);
+}
+
+#[test]
+fn escape_discards_the_value_being_entered() {
+    let mut keys = text("env");
+    keys.push(Key::Enter);
+    keys.extend(text("prod"));
+    keys.extend([Key::Escape, Key::Enter]);
+    let mut backend = fake_backend(keys);
+
+    let ans = KeyValue::new("Labels")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(ans.is_empty());
+}
+
+#[test]
+fn escape_while_entering_a_key_cancels_the_prompt() {
+    let mut backend = fake_backend(vec![Key::Escape]);
+
+    let ans = KeyValue::new("Labels")
+        .with_entries(&[("env", "dev")])
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+}
+
+#[test]
+fn entries_are_listed_aligned_and_answer_is_formatted() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    KeyValue::new("Labels")
+        .with_entries(&[("env", "dev"), ("team", "payments")])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+
+    assert!(output.contains("env  = dev"));
+    assert!(output.contains("team = payments"));
+    assert!(output.contains("env=dev, team=payments"));
+}
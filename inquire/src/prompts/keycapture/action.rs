@@ -0,0 +1,16 @@
+use crate::{ui::Key, InnerAction};
+
+/// Set of actions for a KeyCapturePrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KeyCapturePromptAction {
+    /// Captures the pressed key as the answer.
+    Capture(Key),
+}
+
+impl InnerAction for KeyCapturePromptAction {
+    type Config = ();
+
+    fn from_key(key: Key, _config: &()) -> Option<Self> {
+        Some(Self::Capture(key))
+    }
+}
@@ -0,0 +1,156 @@
+mod action;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, Key, KeyCaptureBackend, RenderConfig},
+};
+
+use self::prompt::KeyCapturePrompt;
+
+/// Prompt that waits for the user to press a key or a key combination and returns it, useful for CLIs that let users configure their own keybindings.
+///
+/// Any key is captured as the answer, including Enter, with the only exceptions being Esc, which cancels the prompt, and Ctrl+C, which interrupts it.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - By default, prints the key and its modifiers, e.g. `Ctrl+Shift+Up`.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::KeyCapture;
+///
+/// let key = KeyCapture::new("Press the key to toggle the sidebar:").prompt();
+///
+/// match key {
+///     Ok(key) => println!("Sidebar is now toggled with {}", key),
+///     Err(_) => println!("Keybinding left unchanged"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct KeyCapture<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: CustomTypeFormatter<'a, Key>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> KeyCapture<'a> {
+    /// Default formatter, prints the key and its modifiers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::ui::{Key, KeyModifiers};
+    /// use inquire::KeyCapture;
+    ///
+    /// let formatter = KeyCapture::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("Enter"), formatter(Key::Enter));
+    /// assert_eq!(String::from("Ctrl+s"), formatter(Key::Char('s', KeyModifiers::CONTROL)));
+    /// ```
+    pub const DEFAULT_FORMATTER: CustomTypeFormatter<'a, Key> = &|key| key.to_string();
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("press any key or combination, esc to cancel");
+
+    /// Creates a [KeyCapture] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, Key>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Key>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<Key> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: KeyCaptureBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Key> {
+        KeyCapturePrompt::from(self).prompt(backend)
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{
+    error::InquireResult,
+    formatter::CustomTypeFormatter,
+    prompts::prompt::{lifecycle_event, report_resolution, ActionResult, Prompt, Resolution},
+    ui::{Key, KeyCaptureBackend, KeyModifiers},
+    InquireError, KeyCapture,
+};
+
+use super::action::KeyCapturePromptAction;
+
+pub struct KeyCapturePrompt<'a> {
+    message: &'a str,
+    help_message: Option<&'a str>,
+    formatter: CustomTypeFormatter<'a, Key>,
+    captured: Option<Key>,
+}
+
+impl<'a> From<KeyCapture<'a>> for KeyCapturePrompt<'a> {
+    fn from(kc: KeyCapture<'a>) -> Self {
+        Self {
+            message: kc.message,
+            help_message: kc.help_message,
+            formatter: kc.formatter,
+            captured: None,
+        }
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for KeyCapturePrompt<'a>
+where
+    Backend: KeyCaptureBackend,
+{
+    type Config = ();
+    type InnerAction = KeyCapturePromptAction;
+    type Output = Key;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &() {
+        &()
+    }
+
+    fn format_answer(&self, answer: &Key) -> String {
+        (self.formatter)(*answer)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Key>> {
+        Ok(self.captured.take())
+    }
+
+    fn handle(&mut self, action: KeyCapturePromptAction) -> InquireResult<ActionResult> {
+        match action {
+            KeyCapturePromptAction::Capture(key) => self.captured = Some(key),
+        }
+
+        Ok(ActionResult::Clean)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        backend.render_key_capture_prompt(self.message)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+
+    /// Keys such as Enter must be capturable, so the usual mapping of keys
+    /// to submit/cancel actions does not apply here: only Esc cancels and
+    /// Ctrl+C interrupts, every other key is the answer.
+    fn prompt(mut self, backend: &mut Backend) -> InquireResult<Key> {
+        backend.start_prompt(self.message)?;
+
+        let started = std::time::Instant::now();
+        lifecycle_event!(debug, "prompt {:?} started", self.message);
+
+        backend.frame_setup()?;
+        Prompt::<Backend>::render(&self, backend)?;
+        backend.frame_finish(false)?;
+
+        let answer = loop {
+            let key = backend.read_key()?;
+            lifecycle_event!(trace, "prompt {:?} received key {:?}", self.message, key);
+
+            match key {
+                Key::Escape => {
+                    lifecycle_event!(
+                        debug,
+                        "prompt {:?} canceled after {:?}",
+                        self.message,
+                        started.elapsed()
+                    );
+                    backend.frame_setup()?;
+                    backend.render_canceled_prompt(self.message)?;
+                    backend.frame_finish(true)?;
+                    report_resolution(self.message, started, 0, Resolution::Canceled);
+                    return Err(InquireError::OperationCanceled);
+                }
+                Key::Char('c', KeyModifiers::CONTROL) => {
+                    lifecycle_event!(
+                        debug,
+                        "prompt {:?} interrupted after {:?}",
+                        self.message,
+                        started.elapsed()
+                    );
+                    backend.frame_setup()?;
+                    backend.render_canceled_prompt(self.message)?;
+                    backend.frame_finish(true)?;
+                    report_resolution(self.message, started, 0, Resolution::Canceled);
+                    return Err(InquireError::OperationInterrupted);
+                }
+                key => {
+                    Prompt::<Backend>::handle(&mut self, KeyCapturePromptAction::Capture(key))?;
+                    if let Some(answer) = Prompt::<Backend>::submit(&mut self)? {
+                        break answer;
+                    }
+                }
+            }
+        };
+
+        lifecycle_event!(
+            debug,
+            "prompt {:?} answered after {:?}",
+            self.message,
+            started.elapsed()
+        );
+
+        let formatted = Prompt::<Backend>::format_answer(&self, &answer);
+
+        backend.frame_setup()?;
+        backend.render_prompt_with_answer(self.message, &formatted)?;
+        backend.frame_finish(true)?;
+        report_resolution(self.message, started, 1, Resolution::Answered);
+
+        Ok(answer)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{
+    error::InquireError,
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    KeyCapture,
+};
+
+#[test]
+fn enter_is_captured_instead_of_submitting() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = KeyCapture::new("Question")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(Key::Enter, ans);
+}
+
+#[test]
+fn chords_are_captured_with_modifiers() {
+    let mut backend = fake_backend(vec![Key::Char('s', KeyModifiers::CONTROL)]);
+
+    let ans = KeyCapture::new("Question")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(Key::Char('s', KeyModifiers::CONTROL), ans);
+}
+
+#[test]
+fn escape_cancels_the_prompt() {
+    let mut backend = fake_backend(vec![Key::Escape]);
+
+    let ans = KeyCapture::new("Question").prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+}
@@ -0,0 +1,79 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::LikertConfig;
+
+/// Set of actions for a LikertPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LikertPromptAction {
+    /// Moves the cursor to the statement above.
+    MoveUp,
+    /// Moves the cursor to the statement below.
+    MoveDown,
+    /// Moves the cursor to the page above.
+    PageUp,
+    /// Moves the cursor to the page below.
+    PageDown,
+    /// Moves the cursor to the first statement.
+    MoveToStart,
+    /// Moves the cursor to the last statement.
+    MoveToEnd,
+    /// Chooses the point of the scale on the left for the current statement,
+    /// or the middle point if the statement is unanswered.
+    ChooseLeft,
+    /// Chooses the point of the scale on the right for the current statement,
+    /// or the middle point if the statement is unanswered.
+    ChooseRight,
+    /// Chooses the point of the scale at the given index for the current
+    /// statement and moves the cursor to the statement below.
+    Choose(usize),
+}
+
+impl InnerAction for LikertPromptAction {
+    type Config = LikertConfig;
+
+    fn from_key(key: Key, config: &LikertConfig) -> Option<Self> {
+        if config.vim_mode {
+            let action = match key {
+                Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
+                Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
+                Key::Char('h', KeyModifiers::NONE) => Some(Self::ChooseLeft),
+                Key::Char('l', KeyModifiers::NONE) => Some(Self::ChooseRight),
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::PageUp(_) => Self::PageUp,
+            Key::Home => Self::MoveToStart,
+
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::PageDown(_) => Self::PageDown,
+            Key::End => Self::MoveToEnd,
+
+            Key::Left(KeyModifiers::NONE) | Key::Char('b', KeyModifiers::CONTROL) => {
+                Self::ChooseLeft
+            }
+            Key::Right(KeyModifiers::NONE) | Key::Char('f', KeyModifiers::CONTROL) => {
+                Self::ChooseRight
+            }
+
+            Key::Char(c, KeyModifiers::NONE) => match c.to_digit(10) {
+                Some(digit) if digit >= 1 && digit as usize <= config.scale_len => {
+                    Self::Choose(digit as usize - 1)
+                }
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
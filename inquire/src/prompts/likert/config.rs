@@ -0,0 +1,22 @@
+use crate::Likert;
+
+/// Configuration settings used in the execution of a LikertPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct LikertConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// Page size of the list of statements.
+    pub page_size: usize,
+    /// Number of points of the scale.
+    pub scale_len: usize,
+}
+
+impl<S> From<&Likert<'_, S>> for LikertConfig {
+    fn from(value: &Likert<'_, S>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            page_size: value.page_size,
+            scale_len: value.scale.len(),
+        }
+    }
+}
@@ -0,0 +1,257 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use std::fmt::Display;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::LikertFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    type_aliases::LikertAnswer,
+    ui::{Backend, CheckboxMatrixBackend, RenderConfig},
+};
+
+use self::prompt::LikertPrompt;
+
+/// Prompt suitable for surveys, where the user rates several statements on the same answer scale, from "Strongly disagree" to "Strongly agree" by default.
+///
+/// Statements are rendered one per line, below a header with the labels of the scale, and each one has a mark under the point chosen for it. The user moves between statements with the up and down arrows and chooses a point with the left and right arrows, starting from the middle of the scale. Pressing a number chooses the point at that position and moves to the next statement, which makes filling a long survey quicker.
+///
+/// Every statement must be answered before submitting. Submitting with unanswered statements displays an error and moves the cursor to the first of them.
+///
+/// This prompt requires a prompt message and a **non-empty** `Vec` of statements, which can be of any type as long as they implement the `Display` trait.
+/// - If the list is empty, or the scale has less than two points, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+///
+/// The answer pairs every statement, in the original order, with the point of the scale chosen for it, as a [ListOption](crate::list_option::ListOption) with its index and label.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Statements**: Items rated by the user. Must be **non-empty**.
+/// - **Scale**: Labels of the points of the scale, from the most negative to the most positive.
+/// - **Default answers**: Points chosen for each statement when the prompt is first rendered. If any of them is out-of-range, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - By default, prints the chosen labels separated by commas.
+/// - **Page size**: Number of statements displayed at once, 7 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Likert;
+///
+/// let statements = vec![
+///     "The documentation was easy to follow",
+///     "The installation was quick",
+///     "I would recommend this tool",
+/// ];
+///
+/// let ans = Likert::new("How much do you agree with the following?", statements).prompt();
+///
+/// match ans {
+///     Ok(answers) => {
+///         for (statement, point) in answers {
+///             println!("{}: {} ({})", statement, point.value, point.index + 1);
+///         }
+///     }
+///     Err(_) => println!("The survey could not be processed"),
+/// }
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct Likert<'a, S> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Statements rated by the user.
+    pub statements: Vec<S>,
+
+    /// Labels of the points of the scale, from the most negative to the most positive.
+    pub scale: &'a [&'a str],
+
+    /// Points of the scale chosen for each statement from the start.
+    pub default: Option<Vec<Option<usize>>>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the statements displayed to the user.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// move between statements and points using hjkl.
+    pub vim_mode: bool,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: LikertFormatter<'a, S>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, S> Likert<'a, S>
+where
+    S: Display,
+{
+    /// String formatter used by default in [Likert](crate::Likert) prompts.
+    /// Prints the labels chosen for each statement separated by commas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::Likert;
+    ///
+    /// let formatter = Likert::<&str>::DEFAULT_FORMATTER;
+    ///
+    /// let ans = vec![(&"Fast", "Agree"), (&"Cheap", "Neutral")];
+    /// assert_eq!(String::from("Agree, Neutral"), formatter(&ans));
+    /// ```
+    pub const DEFAULT_FORMATTER: LikertFormatter<'a, S> = &|ans| {
+        ans.iter()
+            .map(|(_, label)| *label)
+            .collect::<Vec<&str>>()
+            .join(", ")
+    };
+
+    /// Default scale, with five points from "Strongly disagree" to "Strongly agree".
+    pub const DEFAULT_SCALE: &'static [&'static str] = &[
+        "Strongly disagree",
+        "Disagree",
+        "Neutral",
+        "Agree",
+        "Strongly agree",
+    ];
+
+    /// Default page size, equal to the global default page size [config::DEFAULT_PAGE_SIZE]
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of vim mode, equal to the global default value [config::DEFAULT_VIM_MODE]
+    pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, ←→ or numbers to answer, enter to submit");
+
+    /// Creates a [Likert] with the provided message and statements, along with default configuration values.
+    pub fn new(message: &'a str, statements: Vec<S>) -> Self {
+        Self {
+            message,
+            statements,
+            scale: Self::DEFAULT_SCALE,
+            default: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the labels of the points of the scale, from the most negative to the most positive.
+    pub fn with_scale(mut self, scale: &'a [&'a str]) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the points of the scale chosen for each statement by default,
+    /// `None` leaving a statement unanswered.
+    pub fn with_default(mut self, default: &[Option<usize>]) -> Self {
+        self.default = Some(default.to_vec());
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: LikertFormatter<'a, S>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns every statement paired with the point of the scale chosen for it.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<LikertAnswer<'a, S>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns every statement paired with the point of the scale chosen for it.
+    pub fn prompt(self) -> InquireResult<LikertAnswer<'a, S>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: CheckboxMatrixBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<LikertAnswer<'a, S>> {
+        LikertPrompt::new(self)?.prompt(backend)
+    }
+}
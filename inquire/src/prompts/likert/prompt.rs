@@ -0,0 +1,244 @@
+use std::{collections::BTreeSet, fmt::Display};
+
+use crate::{
+    error::InquireResult,
+    formatter::LikertFormatter,
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    type_aliases::LikertAnswer,
+    ui::{width::str_width, CheckboxMatrixBackend},
+    utils::paginate,
+    validator::ErrorMessage,
+    InquireError, Likert,
+};
+
+use super::{action::LikertPromptAction, config::LikertConfig};
+
+pub struct LikertPrompt<'a, S> {
+    message: &'a str,
+    config: LikertConfig,
+    statements: Vec<S>,
+    scale: &'a [&'a str],
+    answers: Vec<Option<usize>>,
+    help_message: Option<&'a str>,
+    cursor: usize,
+    statement_width: usize,
+    formatter: LikertFormatter<'a, S>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a, S> LikertPrompt<'a, S>
+where
+    S: Display,
+{
+    pub fn new(lo: Likert<'a, S>) -> InquireResult<Self> {
+        if lo.statements.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available statements can not be empty".into(),
+            ));
+        }
+        if lo.scale.len() < 2 {
+            return Err(InquireError::InvalidConfiguration(
+                "The scale must have at least two points".into(),
+            ));
+        }
+
+        let mut answers = vec![None; lo.statements.len()];
+        if let Some(default) = &lo.default {
+            if default.len() != answers.len() {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "{} default answers were provided for {} statements",
+                    default.len(),
+                    answers.len()
+                )));
+            }
+            if let Some(point) = default.iter().flatten().find(|p| **p >= lo.scale.len()) {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Default answer {} is out-of-bounds for a scale of {} points",
+                    point,
+                    lo.scale.len()
+                )));
+            }
+
+            answers.copy_from_slice(default);
+        }
+
+        let statement_width = lo
+            .statements
+            .iter()
+            .map(|s| str_width(&s.to_string(), lo.render_config.ambiguous_width))
+            .max()
+            .unwrap_or_default();
+
+        Ok(Self {
+            message: lo.message,
+            config: (&lo).into(),
+            statements: lo.statements,
+            scale: lo.scale,
+            answers,
+            help_message: lo.help_message,
+            cursor: 0,
+            statement_width,
+            formatter: lo.formatter,
+            error: None,
+        })
+    }
+
+    fn move_cursor_to(&mut self, new_position: usize) -> ActionResult {
+        let new_position = new_position.min(self.statements.len() - 1);
+
+        if new_position != self.cursor {
+            self.cursor = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn choose(&mut self, point: usize) -> ActionResult {
+        self.error = None;
+        self.answers[self.cursor] = Some(point);
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn middle_point(&self) -> usize {
+        (self.scale.len() - 1) / 2
+    }
+
+    fn get_final_answer(&mut self) -> LikertAnswer<'a, S> {
+        let scale = self.scale;
+
+        self.statements
+            .drain(..)
+            .zip(&self.answers)
+            .map(|(statement, point)| {
+                let point = point.unwrap_or_default();
+                (statement, ListOption::new(point, scale[point]))
+            })
+            .collect()
+    }
+}
+
+impl<'a, Backend, S> Prompt<Backend> for LikertPrompt<'a, S>
+where
+    Backend: CheckboxMatrixBackend,
+    S: Display,
+{
+    type Config = LikertConfig;
+    type InnerAction = LikertPromptAction;
+    type Output = LikertAnswer<'a, S>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &LikertConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &LikertAnswer<'a, S>) -> String {
+        let refs: Vec<(&S, &str)> = answer
+            .iter()
+            .map(|(statement, point)| (statement, point.value))
+            .collect();
+        (self.formatter)(&refs)
+    }
+
+    fn can_submit(&self) -> InquireResult<bool> {
+        Ok(self.answers.iter().all(Option::is_some))
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<LikertAnswer<'a, S>>> {
+        if let Some(unanswered) = self.answers.iter().position(Option::is_none) {
+            self.cursor = unanswered;
+            self.error = Some(ErrorMessage::Custom(
+                "Please answer every statement".to_string(),
+            ));
+            return Ok(None);
+        }
+
+        Ok(Some(self.get_final_answer()))
+    }
+
+    fn handle(&mut self, action: LikertPromptAction) -> InquireResult<ActionResult> {
+        let last = self.statements.len() - 1;
+
+        let result = match action {
+            LikertPromptAction::MoveUp => self.move_cursor_to(match self.cursor {
+                0 => last,
+                cursor => cursor - 1,
+            }),
+            LikertPromptAction::MoveDown => self.move_cursor_to(match self.cursor {
+                cursor if cursor == last => 0,
+                cursor => cursor + 1,
+            }),
+            LikertPromptAction::PageUp => {
+                self.move_cursor_to(self.cursor.saturating_sub(self.config.page_size))
+            }
+            LikertPromptAction::PageDown => {
+                self.move_cursor_to(self.cursor.saturating_add(self.config.page_size))
+            }
+            LikertPromptAction::MoveToStart => self.move_cursor_to(0),
+            LikertPromptAction::MoveToEnd => self.move_cursor_to(last),
+            LikertPromptAction::ChooseLeft => match self.answers[self.cursor] {
+                Some(0) => ActionResult::Clean,
+                Some(point) => self.choose(point - 1),
+                None => self.choose(self.middle_point()),
+            },
+            LikertPromptAction::ChooseRight => match self.answers[self.cursor] {
+                Some(point) if point + 1 == self.scale.len() => ActionResult::Clean,
+                Some(point) => self.choose(point + 1),
+                None => self.choose(self.middle_point()),
+            },
+            LikertPromptAction::Choose(point) => {
+                self.choose(point);
+                self.move_cursor_to(self.cursor + 1);
+                ActionResult::NeedsRedraw
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        backend.render_checkbox_matrix_prompt(prompt)?;
+
+        let statements = self
+            .statements
+            .iter()
+            .enumerate()
+            .map(|(i, statement)| ListOption::new(i, statement))
+            .collect::<Vec<ListOption<&S>>>();
+
+        let page = paginate(self.config.page_size, &statements, Some(self.cursor));
+
+        let checked: BTreeSet<(usize, usize)> = self
+            .answers
+            .iter()
+            .enumerate()
+            .filter_map(|(statement, point)| point.map(|point| (statement, point)))
+            .collect();
+        let point_cursor = self.answers[self.cursor].unwrap_or_else(|| self.middle_point());
+
+        backend.render_checkbox_matrix(
+            page,
+            self.scale,
+            &checked,
+            point_cursor,
+            self.statement_width,
+        )?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
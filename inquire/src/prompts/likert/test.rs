@@ -0,0 +1,150 @@
+use crate::{
+    error::InquireError,
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    Likert,
+};
+
+const DOWN: Key = Key::Down(KeyModifiers::NONE);
+const LEFT: Key = Key::Left(KeyModifiers::NONE);
+const RIGHT: Key = Key::Right(KeyModifiers::NONE);
+
+fn survey() -> Likert<'static, &'static str> {
+    Likert::new("Question", vec!["fast", "cheap", "safe"])
+}
+
+fn labels(ans: &[(&str, crate::list_option::ListOption<&str>)]) -> Vec<(usize, &'static str)> {
+    ans.iter()
+        .map(|(_, point)| (point.index, Likert::<&str>::DEFAULT_SCALE[point.index]))
+        .collect()
+}
+
+#[test]
+fn arrows_choose_points_starting_from_the_middle() {
+    let mut backend = fake_backend(vec![
+        RIGHT, // fast: neutral
+        RIGHT, // fast: agree
+        DOWN,
+        LEFT, // cheap: neutral
+        LEFT, // cheap: disagree
+        DOWN,
+        RIGHT, // safe: neutral
+        Key::Enter,
+    ]);
+
+    let ans = survey().prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!(
+        vec![(3, "Agree"), (1, "Disagree"), (2, "Neutral")],
+        labels(&ans)
+    );
+    assert_eq!(
+        vec!["fast", "cheap", "safe"],
+        ans.iter().map(|(s, _)| *s).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn numbers_choose_points_and_move_to_the_next_statement() {
+    let mut backend = fake_backend(vec![
+        Key::Char('5', KeyModifiers::NONE),
+        Key::Char('1', KeyModifiers::NONE),
+        Key::Char('4', KeyModifiers::NONE),
+        Key::Char('6', KeyModifiers::NONE), // out of the scale, ignored
+        Key::Enter,
+    ]);
+
+    let ans = survey().prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!(
+        vec![
+            (4, "Strongly agree"),
+            (0, "Strongly disagree"),
+            (3, "Agree")
+        ],
+        labels(&ans)
+    );
+}
+
+#[test]
+fn unanswered_statements_block_submission() {
+    let mut backend = fake_backend(vec![
+        Key::Char('4', KeyModifiers::NONE),
+        Key::Enter, // cursor moves back to "cheap"
+        Key::Char('2', KeyModifiers::NONE),
+        Key::Char('3', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = survey().prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!(
+        vec![(3, "Agree"), (1, "Disagree"), (2, "Neutral")],
+        labels(&ans)
+    );
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("Please answer every statement"));
+}
+
+#[test]
+fn default_answers_are_preselected() {
+    let mut backend = fake_backend(vec![DOWN, DOWN, LEFT, Key::Enter]);
+
+    let ans = survey()
+        .with_default(&[Some(0), Some(4), None])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            (0, "Strongly disagree"),
+            (4, "Strongly agree"),
+            (2, "Neutral")
+        ],
+        labels(&ans)
+    );
+}
+
+#[test]
+fn custom_scale_labels_are_rendered_and_returned() {
+    let mut backend = fake_backend(vec![
+        RIGHT,
+        RIGHT,
+        DOWN,
+        RIGHT,
+        RIGHT,
+        DOWN,
+        LEFT,
+        Key::Enter,
+    ]);
+
+    let ans = survey()
+        .with_scale(&["No", "Yes"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let chosen: Vec<&str> = ans.iter().map(|(_, point)| point.value).collect();
+    assert_eq!(vec!["Yes", "Yes", "No"], chosen);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("\r\n         No   "));
+    assert!(output.contains("Yes, Yes, No"));
+}
+
+#[test]
+fn invalid_configurations_are_rejected() {
+    let ans =
+        Likert::<&str>::new("Question", vec![]).prompt_with_backend(&mut fake_backend(vec![]));
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+
+    let ans = survey()
+        .with_scale(&["Only"])
+        .prompt_with_backend(&mut fake_backend(vec![]));
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+
+    let ans = survey()
+        .with_default(&[Some(5), None, None])
+        .prompt_with_backend(&mut fake_backend(vec![]));
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
@@ -0,0 +1,245 @@
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+use std::io::BufRead;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::MultiOptionFormatter,
+    terminal::get_default_terminal,
+    ui::{Backend, MultiSelectBackend, RenderConfig},
+    validator::MultiOptionValidator,
+    MultiSelect,
+};
+
+/// Prompt that loads lines from a reader, such as a file or piped stdin, and lets the user
+/// fuzzy-filter and select any number of them, similar to `fzf` but as a library call.
+///
+/// Lines are streamed from the reader until it is exhausted or the line cap is reached,
+/// whichever comes first. Lines past the cap are never read. Every line becomes an option of a
+/// [MultiSelect](crate::MultiSelect) prompt, and the selected lines are returned in their original order.
+///
+/// When reading from piped stdin, e.g. `mytool | pick`, key events are still read from the
+/// controlling terminal.
+///
+/// - If the reader does not yield any line, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+/// - If reading from the reader fails, e.g. on invalid UTF-8, the prompt operation will fail with an `InquireError::IO` error.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Reader**: Source of the lines, required when creating the prompt.
+/// - **Max lines**: Maximum amount of lines loaded from the reader, 10000 by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the selected lines joined using a comma as the separator by default.
+/// - **Validator**: Custom validator to make sure a given submitted input pass the specified requirements, e.g. not allowing 0 selected lines.
+///   - No validators are on by default.
+/// - **Page size**: Number of lines displayed at once, 7 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::LinePicker;
+///
+/// let stdin = std::io::stdin();
+/// let lines = LinePicker::new("Pick the lines to keep:", stdin.lock())
+///     .with_max_lines(1000)
+///     .prompt();
+///
+/// match lines {
+///     Ok(lines) => lines.iter().for_each(|line| println!("{}", line)),
+///     Err(_) => eprintln!("No lines were picked"),
+/// }
+/// ```
+pub struct LinePicker<'a, R> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Reader the lines are loaded from.
+    pub reader: R,
+
+    /// Maximum amount of lines loaded from the reader.
+    pub max_lines: usize,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the lines displayed to the user.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the lines using hjkl.
+    pub vim_mode: bool,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: MultiOptionFormatter<'a, String>,
+
+    /// Validator to apply to the user input.
+    ///
+    /// In case of error, the message is displayed one line above the prompt.
+    pub validator: Option<Box<dyn MultiOptionValidator<String>>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, R> LinePicker<'a, R>
+where
+    R: BufRead,
+{
+    /// Default maximum amount of lines loaded from the reader.
+    pub const DEFAULT_MAX_LINES: usize = 10_000;
+
+    /// Default formatter, equal to the default formatter of [MultiSelect](crate::MultiSelect) prompts.
+    pub const DEFAULT_FORMATTER: MultiOptionFormatter<'a, String> =
+        MultiSelect::<String>::DEFAULT_FORMATTER;
+
+    /// Default page size, equal to the global default page size [config::DEFAULT_PAGE_SIZE]
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of vim mode, equal to the global default value [config::DEFAULT_VIM_MODE]
+    pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
+
+    /// Default help message, equal to the default help message of [MultiSelect](crate::MultiSelect) prompts.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = MultiSelect::<String>::DEFAULT_HELP_MESSAGE;
+
+    /// Creates a [LinePicker] with the provided message and reader, along with default configuration values.
+    pub fn new(message: &'a str, reader: R) -> Self {
+        Self {
+            message,
+            reader,
+            max_lines: Self::DEFAULT_MAX_LINES,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            formatter: Self::DEFAULT_FORMATTER,
+            validator: None,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the maximum amount of lines loaded from the reader.
+    pub fn with_max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = max_lines;
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: MultiOptionFormatter<'a, String>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the validator to apply to the user input. You might want to use this feature
+    /// in case you need to limit the user to specific choices, such as at least one line.
+    ///
+    /// In case of error, the message is displayed one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: MultiOptionValidator<String> + 'static,
+    {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Loads the lines from the reader and prompts the CLI user to select them
+    /// according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Vec<String>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Loads the lines from the reader and prompts the CLI user to select them
+    /// according to the defined rules.
+    ///
+    /// Returns the selected lines, in the order they were read.
+    pub fn prompt(self) -> InquireResult<Vec<String>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: MultiSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<String>> {
+        self.into_multi_select()?
+            .prompt_with_backend(backend)
+            .map(|answer| answer.into_iter().map(|option| option.value).collect())
+    }
+
+    fn into_multi_select(self) -> InquireResult<MultiSelect<'a, String>> {
+        let lines = self
+            .reader
+            .lines()
+            .take(self.max_lines)
+            .collect::<Result<Vec<String>, _>>()?;
+
+        let mut multi_select = MultiSelect::new(self.message, lines);
+        multi_select.help_message = self.help_message;
+        multi_select.page_size = self.page_size;
+        multi_select.vim_mode = self.vim_mode;
+        multi_select.formatter = self.formatter;
+        multi_select.validator = self.validator;
+        multi_select.render_config = self.render_config;
+
+        Ok(multi_select)
+    }
+}
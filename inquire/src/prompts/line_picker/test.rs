@@ -0,0 +1,63 @@
+use std::io::Cursor;
+
+use crate::{
+    error::InquireError,
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    LinePicker,
+};
+
+#[test]
+fn selected_lines_are_returned_in_original_order() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let reader = Cursor::new("first\nsecond\r\nthird\n");
+
+    let ans = LinePicker::new("Question", reader)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["first", "third"], ans);
+}
+
+#[test]
+fn lines_past_the_cap_are_not_loaded() {
+    let mut backend = fake_backend(vec![Key::Right(KeyModifiers::NONE), Key::Enter]);
+
+    let reader = Cursor::new("1\n2\n3\n4\n5\n");
+
+    let ans = LinePicker::new("Question", reader)
+        .with_max_lines(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["1", "2"], ans);
+}
+
+#[test]
+fn empty_reader_is_an_invalid_configuration() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = LinePicker::new("Question", Cursor::new("")).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn invalid_utf8_is_an_io_error() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let reader = Cursor::new(vec![b'o', b'k', b'\n', 0xff, 0xfe]);
+
+    let ans = LinePicker::new("Question", reader).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::IO(_))));
+}
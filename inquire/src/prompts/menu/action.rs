@@ -0,0 +1,47 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::MenuConfig;
+
+/// Set of actions for a MenuPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MenuPromptAction {
+    /// Moves the cursor to the option above.
+    MoveUp,
+    /// Moves the cursor to the option below.
+    MoveDown,
+    /// Moves the cursor to the page above.
+    PageUp,
+    /// Moves the cursor to the page below.
+    PageDown,
+    /// Moves the cursor to the start of the list.
+    MoveToStart,
+    /// Moves the cursor to the end of the list.
+    MoveToEnd,
+    /// Selects the option with the given shortcut, if any, and submits it.
+    Shortcut(char),
+}
+
+impl InnerAction for MenuPromptAction {
+    type Config = MenuConfig;
+
+    fn from_key(key: Key, _config: &MenuConfig) -> Option<Self> {
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::PageUp(_) => Self::PageUp,
+            Key::Home => Self::MoveToStart,
+
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::PageDown(_) => Self::PageDown,
+            Key::End => Self::MoveToEnd,
+
+            Key::Char(c, KeyModifiers::NONE | KeyModifiers::SHIFT) => Self::Shortcut(c),
+
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
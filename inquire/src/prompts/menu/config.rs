@@ -0,0 +1,16 @@
+use crate::Menu;
+
+/// Configuration settings used in the execution of a MenuPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct MenuConfig {
+    /// Page size of the list of options.
+    pub page_size: usize,
+}
+
+impl<T> From<&Menu<'_, T>> for MenuConfig {
+    fn from(value: &Menu<'_, T>) -> Self {
+        Self {
+            page_size: value.page_size,
+        }
+    }
+}
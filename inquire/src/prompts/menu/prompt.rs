@@ -0,0 +1,220 @@
+use std::fmt::Display;
+
+use crate::{
+    error::InquireResult,
+    formatter::OptionFormatter,
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::SelectBackend,
+    utils::paginate,
+    InquireError, Menu,
+};
+
+use super::{action::MenuPromptAction, config::MenuConfig};
+
+pub struct MenuPrompt<'a, T> {
+    message: &'a str,
+    config: MenuConfig,
+    options: Vec<T>,
+    shortcuts: Vec<char>,
+    labels: Vec<String>,
+    help_message: Option<&'a str>,
+    cursor_index: usize,
+    shortcut_pressed: bool,
+    formatter: OptionFormatter<'a, T>,
+}
+
+fn normalize_shortcut(shortcut: char) -> char {
+    shortcut.to_lowercase().next().unwrap_or(shortcut)
+}
+
+/// Renders the shortcut inline with the option, e.g. `[d]eploy`, or before
+/// it when the option text does not contain the shortcut, e.g. `[q] exit`.
+pub(super) fn shortcut_label(shortcut: char, option: &str) -> String {
+    let position = option
+        .char_indices()
+        .find(|(_, c)| normalize_shortcut(*c) == shortcut);
+
+    match position {
+        Some((idx, c)) => {
+            let (before, after) = option.split_at(idx);
+            format!("{}[{}]{}", before, c, &after[c.len_utf8()..])
+        }
+        None => format!("[{}] {}", shortcut, option),
+    }
+}
+
+impl<'a, T> MenuPrompt<'a, T>
+where
+    T: Display,
+{
+    pub fn new(mo: Menu<'a, T>) -> InquireResult<Self> {
+        if mo.options.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        if mo.starting_cursor >= mo.options.len() {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Starting cursor index {} is out-of-bounds for length {} of options",
+                mo.starting_cursor,
+                &mo.options.len()
+            )));
+        }
+
+        let config = (&mo).into();
+        let mut options = Vec::with_capacity(mo.options.len());
+        let mut shortcuts: Vec<char> = Vec::with_capacity(mo.options.len());
+        let mut labels = Vec::with_capacity(mo.options.len());
+
+        for (shortcut, option) in mo.options {
+            let shortcut = normalize_shortcut(shortcut);
+
+            if shortcuts.contains(&shortcut) {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Shortcut '{}' is assigned to more than one option",
+                    shortcut
+                )));
+            }
+
+            labels.push(shortcut_label(shortcut, &option.to_string()));
+            shortcuts.push(shortcut);
+            options.push(option);
+        }
+
+        Ok(Self {
+            message: mo.message,
+            config,
+            options,
+            shortcuts,
+            labels,
+            help_message: mo.help_message,
+            cursor_index: mo.starting_cursor,
+            shortcut_pressed: false,
+            formatter: mo.formatter,
+        })
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let new_position = if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.options.len().saturating_sub(after_wrap))
+        } else {
+            self.cursor_index.saturating_sub(qty)
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let mut new_position = self.cursor_index.saturating_add(qty);
+
+        if new_position >= self.options.len() {
+            new_position = if wrap {
+                new_position % self.options.len()
+            } else {
+                self.options.len().saturating_sub(1)
+            }
+        }
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn get_final_answer(&mut self) -> ListOption<T> {
+        let index = self.cursor_index;
+        let value = self.options.swap_remove(index);
+
+        ListOption::new(index, value)
+    }
+
+    fn shortcut_index(&self, shortcut: char) -> Option<usize> {
+        let shortcut = normalize_shortcut(shortcut);
+        self.shortcuts.iter().position(|s| *s == shortcut)
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for MenuPrompt<'a, T>
+where
+    Backend: SelectBackend,
+    T: Display,
+{
+    type Config = MenuConfig;
+    type InnerAction = MenuPromptAction;
+    type Output = ListOption<T>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &MenuConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &ListOption<T>) -> String {
+        (self.formatter)(answer.as_ref())
+    }
+
+    /// Pressing a shortcut submits the prompt right away.
+    fn should_auto_submit(&self) -> bool {
+        self.shortcut_pressed
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
+        Ok(Some(self.get_final_answer()))
+    }
+
+    fn handle(&mut self, action: MenuPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            MenuPromptAction::MoveUp => self.move_cursor_up(1, true),
+            MenuPromptAction::MoveDown => self.move_cursor_down(1, true),
+            MenuPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
+            MenuPromptAction::PageDown => self.move_cursor_down(self.config.page_size, false),
+            MenuPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
+            MenuPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            MenuPromptAction::Shortcut(shortcut) => match self.shortcut_index(shortcut) {
+                Some(index) => {
+                    self.shortcut_pressed = true;
+                    self.update_cursor_position(index)
+                }
+                None => ActionResult::Clean,
+            },
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.render_select_prompt(prompt, None)?;
+
+        let choices = self
+            .labels
+            .iter()
+            .enumerate()
+            .map(|(i, label)| ListOption::new(i, label))
+            .collect::<Vec<ListOption<&String>>>();
+
+        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+
+        backend.render_options(page, &[], &[])?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    error::InquireError,
+    list_option::ListOption,
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    Menu,
+};
+
+fn options() -> Vec<(char, &'static str)> {
+    vec![('d', "deploy"), ('r', "rollback"), ('l', "logs")]
+}
+
+#[test]
+fn shortcut_selects_immediately() {
+    let mut backend = fake_backend(vec![Key::Char('r', KeyModifiers::NONE)]);
+
+    let ans = Menu::new("Question", options())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "rollback"), ans);
+}
+
+#[test]
+fn shortcuts_are_case_insensitive() {
+    let mut backend = fake_backend(vec![Key::Char('L', KeyModifiers::SHIFT)]);
+
+    let ans = Menu::new("Question", options())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "logs"), ans);
+}
+
+#[test]
+fn arrows_and_enter_still_work() {
+    let mut backend = fake_backend(vec![
+        Key::Char('x', KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Menu::new("Question", options())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "logs"), ans);
+}
+
+#[test]
+fn duplicate_shortcuts_are_rejected() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Menu::new("Question", vec![('d', "deploy"), ('D', "delete")])
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn shortcut_label_falls_back_to_prefix() {
+    assert_eq!("[d]eploy", super::prompt::shortcut_label('d', "deploy"));
+    assert_eq!("Ro[l]lback", super::prompt::shortcut_label('l', "Rollback"));
+    assert_eq!("[q] exit", super::prompt::shortcut_label('q', "exit"));
+}
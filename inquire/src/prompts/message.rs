@@ -0,0 +1,111 @@
+use crate::{
+    config::get_configuration,
+    error::InquireResult,
+    terminal::get_default_terminal,
+    ui::{Backend, MessageBackend, MessageLevel},
+};
+
+/// Prints an informational line, styled like the prompts around it.
+///
+/// The line is styled with the `info_message` setting of the global
+/// [`RenderConfig`](crate::ui::RenderConfig), set through
+/// [`set_global_render_config`](crate::set_global_render_config).
+///
+/// # Example
+///
+/// ``` no_run
+/// # use inquire::{*, error::*};
+/// let name = Text::new("Project name:").prompt()?;
+/// info(format!("Creating {} in the current directory", name))?;
+/// # InquireResult::Ok(())
+/// ```
+///
+/// # Errors
+///
+/// This function will return an error if there is a problem interacting with the terminal.
+pub fn info<M>(message: M) -> InquireResult<()>
+where
+    M: AsRef<str>,
+{
+    print_message(MessageLevel::Info, message.as_ref())
+}
+
+/// Prints a warning line, styled like the prompts around it.
+///
+/// The line is styled with the `warning_message` setting of the global
+/// [`RenderConfig`](crate::ui::RenderConfig), set through
+/// [`set_global_render_config`](crate::set_global_render_config).
+///
+/// # Errors
+///
+/// This function will return an error if there is a problem interacting with the terminal.
+pub fn warn<M>(message: M) -> InquireResult<()>
+where
+    M: AsRef<str>,
+{
+    print_message(MessageLevel::Warning, message.as_ref())
+}
+
+/// Prints a success line, styled like the prompts around it.
+///
+/// The line is styled with the `success_message` setting of the global
+/// [`RenderConfig`](crate::ui::RenderConfig), set through
+/// [`set_global_render_config`](crate::set_global_render_config).
+///
+/// # Errors
+///
+/// This function will return an error if there is a problem interacting with the terminal.
+pub fn success<M>(message: M) -> InquireResult<()>
+where
+    M: AsRef<str>,
+{
+    print_message(MessageLevel::Success, message.as_ref())
+}
+
+fn print_message(level: MessageLevel, message: &str) -> InquireResult<()> {
+    let (input_reader, terminal) = get_default_terminal()?;
+    let mut backend = Backend::new(input_reader, terminal, get_configuration())?;
+    render_message(&mut backend, level, message)
+}
+
+pub(crate) fn render_message<B: MessageBackend>(
+    backend: &mut B,
+    level: MessageLevel,
+    message: &str,
+) -> InquireResult<()> {
+    backend.frame_setup()?;
+    backend.render_message(level, message)?;
+    backend.frame_finish(true)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        terminal::crossterm::CrosstermTerminal,
+        ui::{Backend, Key, MessageLevel, RenderConfig},
+    };
+
+    use super::render_message;
+
+    fn render(level: MessageLevel, message: &str) -> String {
+        let mut backend = Backend::new(
+            Vec::<Key>::new().into_iter(),
+            CrosstermTerminal::new_in_memory_output(),
+            RenderConfig::empty(),
+        )
+        .unwrap();
+
+        render_message(&mut backend, level, message).unwrap();
+
+        String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap()
+    }
+
+    #[test]
+    fn messages_are_rendered_with_their_level_prefix() {
+        assert!(render(MessageLevel::Info, "Fetching index").contains("i Fetching index"));
+        assert!(render(MessageLevel::Warning, "Disk almost full").contains("! Disk almost full"));
+        assert!(render(MessageLevel::Success, "Deployed").contains("✓ Deployed"));
+    }
+}
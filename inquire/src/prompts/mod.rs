@@ -1,29 +1,103 @@
 mod action;
+mod batch_confirm;
+mod checkboxmatrix;
 mod confirm;
+#[cfg(feature = "countries")]
+mod country_select;
 mod custom_type;
 #[cfg(feature = "date")]
 mod dateselect;
+mod diff_approval;
 #[cfg(feature = "editor")]
 mod editor;
+mod email;
+mod env_editor;
+#[cfg(feature = "fuzzy")]
+mod fuzzy_filter;
+#[cfg(feature = "git")]
+mod git;
+mod human_check;
+mod ip_address;
+#[cfg(feature = "json")]
+mod json_path;
+mod key_value;
+mod keycapture;
+mod likert;
+mod line_picker;
+mod menu;
+mod message;
+#[cfg(feature = "date")]
+mod multidateselect;
 mod multiselect;
 mod one_liners;
+#[cfg(feature = "async")]
+mod option_stream;
+mod pager;
 mod password;
 mod prompt;
+mod quantity_input;
+mod rating;
+#[cfg(feature = "regex")]
+mod regex_filter;
+mod section;
 mod select;
+#[cfg(feature = "ssh-keys")]
+mod ssh_key_select;
+mod template;
 #[cfg(test)]
 pub(crate) mod test;
 mod text;
+#[cfg(feature = "date")]
+mod timeselect;
+mod transferlist;
+mod url_input;
+#[cfg(feature = "semver")]
+mod version_bump;
 
 pub use action::*;
+pub use batch_confirm::*;
+pub use checkboxmatrix::*;
 pub use confirm::*;
+#[cfg(feature = "countries")]
+pub use country_select::*;
 pub use custom_type::*;
 #[cfg(feature = "date")]
 pub use dateselect::*;
+pub use diff_approval::*;
 #[cfg(feature = "editor")]
 pub use editor::*;
+pub use email::*;
+pub use env_editor::*;
+#[cfg(feature = "git")]
+pub use git::*;
+pub use human_check::*;
+pub use ip_address::*;
+#[cfg(feature = "json")]
+pub use json_path::*;
+pub use key_value::*;
+pub use keycapture::*;
+pub use likert::*;
+pub use line_picker::*;
+pub use menu::*;
+pub use message::*;
+#[cfg(feature = "date")]
+pub use multidateselect::*;
 pub use multiselect::*;
 #[cfg(feature = "one-liners")]
 pub use one_liners::*;
+pub use pager::*;
 pub use password::*;
+pub use quantity_input::*;
+pub use rating::*;
+pub use section::*;
 pub use select::*;
+#[cfg(feature = "ssh-keys")]
+pub use ssh_key_select::*;
+pub use template::*;
 pub use text::*;
+#[cfg(feature = "date")]
+pub use timeselect::*;
+pub use transferlist::*;
+pub use url_input::*;
+#[cfg(feature = "semver")]
+pub use version_bump::*;
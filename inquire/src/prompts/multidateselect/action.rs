@@ -0,0 +1,32 @@
+use crate::{
+    prompts::dateselect::config::DateSelectConfig,
+    ui::{Key, KeyHelp, KeyModifiers},
+    DateSelectPromptAction, InnerAction,
+};
+
+/// Set of actions for a MultiDateSelectPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MultiDateSelectPromptAction {
+    /// Moves the day cursor, just like in a [`DateSelect`](crate::DateSelect) prompt.
+    Move(DateSelectPromptAction),
+    /// Checks or unchecks the date under the cursor.
+    ToggleCurrentDate,
+}
+
+impl InnerAction for MultiDateSelectPromptAction {
+    type Config = DateSelectConfig;
+
+    fn from_key(key: Key, config: &DateSelectConfig) -> Option<Self> {
+        match key {
+            Key::Char(' ', KeyModifiers::NONE) => Some(Self::ToggleCurrentDate),
+            key => DateSelectPromptAction::from_key(key, config).map(Self::Move),
+        }
+    }
+
+    fn key_help(_config: &DateSelectConfig) -> Vec<KeyHelp> {
+        vec![KeyHelp::new(
+            [Key::Char(' ', KeyModifiers::NONE)],
+            "check or uncheck the current date",
+        )]
+    }
+}
@@ -0,0 +1,237 @@
+mod action;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use chrono::NaiveDate;
+
+use crate::{
+    config::get_configuration,
+    date_utils::get_current_date,
+    error::{InquireError, InquireResult},
+    formatter::{self, MultiDateFormatter},
+    prompts::{dateselect::config::DateSelectConfig, prompt::Prompt},
+    terminal::get_default_terminal,
+    ui::{date::DateSelectBackend, Backend, RenderConfig},
+};
+
+use self::prompt::MultiDateSelectPrompt;
+
+/// Prompt that allows user to check several dates (time not supported) in an interactive calendar, e.g. for scheduling tools. Available via the `date` feature.
+///
+/// The calendar is navigated just like in a [`DateSelect`](crate::DateSelect) prompt. Pressing space checks or unchecks the date under the cursor, checked dates being rendered with a marker, and pressing enter returns the checked dates in chronological order.
+///
+/// `MultiDateSelect` prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Starting date**: Date under the cursor when the calendar is displayed. Current date by default.
+/// - **Default dates**: Dates checked when the calendar is displayed.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Formats to "Month Day, Year" separated by semicolons by default.
+/// - **Week start**: Which day of the week should be displayed in the first column of the calendar, Sunday by default.
+/// - **Min and max date**: Inclusive boundaries of allowed dates in the interactive calendar. Days out of the range are dimmed and can not be checked.
+/// - **Min and max selections**: Number of dates that must be checked to submit the prompt, and that can be checked at most.
+///
+/// # Example
+///
+/// ```no_run
+/// use chrono::NaiveDate;
+/// use inquire::MultiDateSelect;
+///
+/// let dates = MultiDateSelect::new("Which days can you attend?")
+///     .with_min_date(NaiveDate::from_ymd(2021, 8, 1))
+///     .with_max_date(NaiveDate::from_ymd(2021, 8, 31))
+///     .with_min_selections(1)
+///     .prompt();
+/// ```
+#[derive(Clone)]
+pub struct MultiDateSelect<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Date under the cursor when the calendar is displayed.
+    pub starting_date: NaiveDate,
+
+    /// Dates checked when the calendar is displayed.
+    pub default: Vec<NaiveDate>,
+
+    /// Min date allowed to be checked.
+    pub min_date: Option<NaiveDate>,
+
+    /// Max date allowed to be checked.
+    pub max_date: Option<NaiveDate>,
+
+    /// Number of dates that must be checked to submit the prompt.
+    pub min_selections: usize,
+
+    /// Number of dates that can be checked at most.
+    pub max_selections: Option<usize>,
+
+    /// Starting day of the week.
+    pub week_start: chrono::Weekday,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: MultiDateFormatter<'a>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> MultiDateSelect<'a> {
+    /// Default formatter, set to [DEFAULT_MULTI_DATE_FORMATTER](crate::formatter::DEFAULT_MULTI_DATE_FORMATTER)
+    pub const DEFAULT_FORMATTER: MultiDateFormatter<'a> = formatter::DEFAULT_MULTI_DATE_FORMATTER;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("arrows to move, []{} move months and years, space to check, enter to submit");
+
+    /// Default week start.
+    pub const DEFAULT_WEEK_START: chrono::Weekday = chrono::Weekday::Sun;
+
+    /// Creates a [MultiDateSelect] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_date: get_current_date(),
+            default: vec![],
+            min_date: None,
+            max_date: None,
+            min_selections: 0,
+            max_selections: None,
+            week_start: Self::DEFAULT_WEEK_START,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the dates checked when the calendar is displayed.
+    pub fn with_default(mut self, default: &[NaiveDate]) -> Self {
+        self.default = default.to_vec();
+        self
+    }
+
+    /// Sets the date under the cursor when the calendar is displayed.
+    pub fn with_starting_date(mut self, starting_date: NaiveDate) -> Self {
+        self.starting_date = starting_date;
+        self
+    }
+
+    /// Sets the week start.
+    pub fn with_week_start(mut self, week_start: chrono::Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    /// Sets the min date, inclusive. Earlier days are dimmed and can not be checked.
+    pub fn with_min_date(mut self, min_date: NaiveDate) -> Self {
+        self.min_date = Some(min_date);
+        self
+    }
+
+    /// Sets the max date, inclusive. Later days are dimmed and can not be checked.
+    pub fn with_max_date(mut self, max_date: NaiveDate) -> Self {
+        self.max_date = Some(max_date);
+        self
+    }
+
+    /// Sets the number of dates that must be checked to submit the prompt.
+    pub fn with_min_selections(mut self, min_selections: usize) -> Self {
+        self.min_selections = min_selections;
+        self
+    }
+
+    /// Sets the number of dates that can be checked at most. Once reached,
+    /// other dates can only be checked after unchecking one.
+    pub fn with_max_selections(mut self, max_selections: usize) -> Self {
+        self.max_selections = Some(max_selections);
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: MultiDateFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Vec<NaiveDate>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<Vec<NaiveDate>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: DateSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<NaiveDate>> {
+        MultiDateSelectPrompt::new(self)?.prompt(backend)
+    }
+}
+
+impl From<&MultiDateSelect<'_>> for DateSelectConfig {
+    fn from(value: &MultiDateSelect<'_>) -> Self {
+        Self {
+            min_date: value.min_date,
+            max_date: value.max_date,
+            week_start: value.week_start,
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::BTreeSet;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::{
+    date_utils::{get_current_date, get_month},
+    error::InquireResult,
+    formatter::MultiDateFormatter,
+    prompts::{
+        dateselect::config::DateSelectConfig,
+        prompt::{ActionResult, Prompt},
+    },
+    ui::date::DateSelectBackend,
+    validator::ErrorMessage,
+    InquireError, MultiDateSelect,
+};
+
+use super::action::MultiDateSelectPromptAction;
+
+pub struct MultiDateSelectPrompt<'a> {
+    message: &'a str,
+    config: DateSelectConfig,
+    current_date: NaiveDate,
+    checked: BTreeSet<NaiveDate>,
+    min_selections: usize,
+    max_selections: Option<usize>,
+    help_message: Option<&'a str>,
+    formatter: MultiDateFormatter<'a>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a> MultiDateSelectPrompt<'a> {
+    pub fn new(mdo: MultiDateSelect<'a>) -> InquireResult<Self> {
+        let config: DateSelectConfig = (&mdo).into();
+
+        if let (Some(min_date), Some(max_date)) = (mdo.min_date, mdo.max_date) {
+            if min_date > max_date {
+                return Err(InquireError::InvalidConfiguration(
+                    "Min date can not be greater than max date".into(),
+                ));
+            }
+        }
+        if config.clamp(mdo.starting_date) != mdo.starting_date {
+            return Err(InquireError::InvalidConfiguration(
+                "Starting date must be between the min and max dates".into(),
+            ));
+        }
+        if mdo.default.iter().any(|date| config.clamp(*date) != *date) {
+            return Err(InquireError::InvalidConfiguration(
+                "Default dates must be between the min and max dates".into(),
+            ));
+        }
+
+        let checked: BTreeSet<NaiveDate> = mdo.default.iter().copied().collect();
+
+        if let Some(max_selections) = mdo.max_selections {
+            if mdo.min_selections > max_selections {
+                return Err(InquireError::InvalidConfiguration(
+                    "Min selections can not be greater than max selections".into(),
+                ));
+            }
+            if checked.len() > max_selections {
+                return Err(InquireError::InvalidConfiguration(
+                    "Default dates can not exceed the max selections".into(),
+                ));
+            }
+        }
+
+        Ok(Self {
+            message: mdo.message,
+            config,
+            current_date: mdo.starting_date,
+            checked,
+            min_selections: mdo.min_selections,
+            max_selections: mdo.max_selections,
+            help_message: mdo.help_message,
+            formatter: mdo.formatter,
+            error: None,
+        })
+    }
+
+    fn update_date(&mut self, new_date: NaiveDate) -> ActionResult {
+        let new_date = self.config.clamp(new_date);
+
+        if self.current_date == new_date {
+            return ActionResult::Clean;
+        }
+
+        self.current_date = new_date;
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn toggle_current_date(&mut self) -> ActionResult {
+        if self.checked.remove(&self.current_date) {
+            return ActionResult::NeedsRedraw;
+        }
+
+        if matches!(self.max_selections, Some(max) if self.checked.len() >= max) {
+            return ActionResult::Clean;
+        }
+
+        self.checked.insert(self.current_date);
+
+        ActionResult::NeedsRedraw
+    }
+}
+
+impl<'a, B> Prompt<B> for MultiDateSelectPrompt<'a>
+where
+    B: DateSelectBackend,
+{
+    type Config = DateSelectConfig;
+    type InnerAction = MultiDateSelectPromptAction;
+    type Output = Vec<NaiveDate>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn format_answer(&self, answer: &Vec<NaiveDate>) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn config(&self) -> &DateSelectConfig {
+        &self.config
+    }
+
+    fn can_submit(&self) -> InquireResult<bool> {
+        Ok(self.checked.len() >= self.min_selections)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Vec<NaiveDate>>> {
+        if self.checked.len() < self.min_selections {
+            self.error = Some(ErrorMessage::Custom(format!(
+                "Please check at least {} dates",
+                self.min_selections
+            )));
+            return Ok(None);
+        }
+
+        Ok(Some(self.checked.iter().copied().collect()))
+    }
+
+    fn handle(&mut self, action: MultiDateSelectPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            MultiDateSelectPromptAction::Move(action) => {
+                self.update_date(action.move_from(self.current_date))
+            }
+            MultiDateSelectPromptAction::ToggleCurrentDate => self.toggle_current_date(),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut B) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        backend.render_calendar_prompt(prompt)?;
+
+        backend.render_calendar(
+            get_month(self.current_date.month()),
+            self.current_date.year(),
+            self.config.week_start,
+            get_current_date(),
+            self.current_date,
+            &self.checked,
+            self.config.min_date,
+            self.config.max_date,
+        )?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+use chrono::NaiveDate;
+
+use crate::{
+    error::InquireResult,
+    terminal::crossterm::CrosstermTerminal,
+    ui::{
+        test::{FakeBackend, Token},
+        Backend, Key, KeyModifiers, RenderConfig,
+    },
+    InquireError, MultiDateSelect,
+};
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2023, 1, day).unwrap()
+}
+
+const SPACE: Key = Key::Char(' ', KeyModifiers::NONE);
+const RIGHT: Key = Key::Right(KeyModifiers::NONE);
+
+#[test]
+fn space_toggles_dates_and_enter_returns_them_in_order() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        SPACE,
+        RIGHT,
+        RIGHT,
+        SPACE,
+        Key::Left(KeyModifiers::NONE),
+        SPACE,
+        SPACE,
+        Key::Up(KeyModifiers::NONE),
+        SPACE,
+        Key::Enter,
+    ]);
+
+    let answer = MultiDateSelect::new("Question")
+        .with_starting_date(date(10))
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(vec![date(4), date(10), date(12)], answer);
+
+    Ok(())
+}
+
+#[test]
+fn checked_dates_are_passed_to_the_backend() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![RIGHT, SPACE, Key::Enter]);
+
+    MultiDateSelect::new("Question")
+        .with_starting_date(date(10))
+        .with_default(&[date(2)])
+        .prompt_with_backend(&mut backend)?;
+
+    let last_calendar = backend.frames()[2]
+        .tokens()
+        .iter()
+        .find_map(|t| match t {
+            Token::Calendar { marked_dates, .. } => Some(marked_dates.clone()),
+            _ => None,
+        })
+        .unwrap();
+
+    assert_eq!(
+        vec![date(2), date(11)],
+        last_calendar.into_iter().collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn checked_dates_are_rendered_with_a_marker() {
+    let mut backend = Backend::new(
+        vec![SPACE, Key::Enter].into_iter(),
+        CrosstermTerminal::new_in_memory_output(),
+        RenderConfig::empty(),
+    )
+    .unwrap();
+
+    MultiDateSelect::new("Question")
+        .with_starting_date(date(10))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+
+    assert!(output.contains(">  8  9 10 11 12 13 14"));
+    assert!(output.contains(">  8  9*10 11 12 13 14"));
+}
+
+#[test]
+fn max_selections_can_not_be_exceeded() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![SPACE, RIGHT, SPACE, RIGHT, SPACE, Key::Enter]);
+
+    let answer = MultiDateSelect::new("Question")
+        .with_starting_date(date(10))
+        .with_max_selections(2)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(vec![date(10), date(11)], answer);
+
+    Ok(())
+}
+
+#[test]
+fn min_selections_are_required_to_submit() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter, SPACE, Key::Enter]);
+
+    let answer = MultiDateSelect::new("Question")
+        .with_starting_date(date(10))
+        .with_min_selections(1)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(vec![date(10)], answer);
+
+    Ok(())
+}
+
+#[test]
+fn default_dates_out_of_range_are_rejected() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let answer = MultiDateSelect::new("Question")
+        .with_starting_date(date(10))
+        .with_min_date(date(5))
+        .with_default(&[date(4)])
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(answer, Err(InquireError::InvalidConfiguration(_))));
+}
@@ -1,10 +1,41 @@
 use crate::{
-    ui::{Key, KeyModifiers},
+    ui::{Key, KeyHelp, KeyModifiers},
     InnerAction, InputAction,
 };
 
 use super::config::MultiSelectConfig;
 
+/// Subset of the options listed by a [MultiSelect](crate::MultiSelect) prompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MultiSelectView {
+    /// Every option matching the filter.
+    All,
+    /// Only the checked options matching the filter, e.g. to prune the selection.
+    Checked,
+    /// Only the unchecked options matching the filter.
+    Unchecked,
+}
+
+impl MultiSelectView {
+    /// Returns the view following this one, cycling back to [`All`](Self::All).
+    pub fn next(self) -> Self {
+        match self {
+            Self::All => Self::Checked,
+            Self::Checked => Self::Unchecked,
+            Self::Unchecked => Self::All,
+        }
+    }
+
+    /// Whether an option that is checked or not is listed in this view.
+    pub fn lists(self, checked: bool) -> bool {
+        match self {
+            Self::All => true,
+            Self::Checked => checked,
+            Self::Unchecked => !checked,
+        }
+    }
+}
+
 /// Set of actions for a MultiSelectPrompt.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum MultiSelectPromptAction {
@@ -22,19 +53,41 @@ pub enum MultiSelectPromptAction {
     MoveToStart,
     /// Moves the cursor to the end of the list.
     MoveToEnd,
+    /// Completes the filter to the longest common prefix of the matching options.
+    CompleteFilter,
+    /// Replaces the empty filter with the previous filter used while checking
+    /// options, or the one before the recalled filter.
+    RecallPreviousFilter,
+    /// Replaces the recalled filter with the next one used while checking
+    /// options, or with an empty filter after the last one.
+    RecallNextFilter,
     /// Toggles the selection of the current option.
     ToggleCurrentOption,
     /// Selects all options.
     SelectAll,
     /// Deselects all options.
     ClearSelections,
+    /// Switches to the next view, listing all, only the checked or only the
+    /// unchecked options.
+    CycleView,
+    /// Starts typing a search query.
+    StartSearch,
+    /// Action on the search query input handler.
+    SearchInput(InputAction),
+    /// Moves the cursor to the next option matching the search query.
+    NextMatch,
+    /// Moves the cursor to the previous option matching the search query.
+    PreviousMatch,
+    /// Toggles the interpretation of the filter input as a regular expression.
+    #[cfg(feature = "regex")]
+    ToggleRegexFilter,
 }
 
 impl InnerAction for MultiSelectPromptAction {
     type Config = MultiSelectConfig;
 
     fn from_key(key: Key, config: &MultiSelectConfig) -> Option<Self> {
-        if config.vim_mode {
+        if config.vim_mode && !config.searching {
             let action = match key {
                 Key::Char('h', KeyModifiers::NONE) => Some(Self::ClearSelections),
                 Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
@@ -56,10 +109,24 @@ impl InnerAction for MultiSelectPromptAction {
             Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
             Key::PageDown(_) => Self::PageDown,
             Key::End => Self::MoveToEnd,
+            Key::Tab => Self::CompleteFilter,
+            Key::Up(KeyModifiers::ALT) => Self::RecallPreviousFilter,
+            Key::Down(KeyModifiers::ALT) => Self::RecallNextFilter,
+            #[cfg(feature = "regex")]
+            Key::Char('r', KeyModifiers::CONTROL) => Self::ToggleRegexFilter,
+
+            key if config.searching => match InputAction::from_key(key, &()) {
+                Some(action) => Self::SearchInput(action),
+                None => return None,
+            },
+            Key::Char('/', KeyModifiers::NONE) if config.search_mode => Self::StartSearch,
+            Key::Char('n', KeyModifiers::NONE) if config.search_mode => Self::NextMatch,
+            Key::Char('N', _) if config.search_mode => Self::PreviousMatch,
 
             Key::Char(' ', KeyModifiers::NONE) => Self::ToggleCurrentOption,
             Key::Right(KeyModifiers::NONE) => Self::SelectAll,
             Key::Left(KeyModifiers::NONE) => Self::ClearSelections,
+            Key::Char('t', KeyModifiers::CONTROL) => Self::CycleView,
             key => match InputAction::from_key(key, &()) {
                 Some(action) => Self::FilterInput(action),
                 None => return None,
@@ -68,4 +135,75 @@ impl InnerAction for MultiSelectPromptAction {
 
         Some(action)
     }
+
+    fn key_help(config: &MultiSelectConfig) -> Vec<KeyHelp> {
+        let mut up = vec![
+            Key::Up(KeyModifiers::NONE),
+            Key::Char('p', KeyModifiers::CONTROL),
+        ];
+        let mut down = vec![
+            Key::Down(KeyModifiers::NONE),
+            Key::Char('n', KeyModifiers::CONTROL),
+        ];
+        if config.vim_mode {
+            up.push(Key::Char('k', KeyModifiers::NONE));
+            down.push(Key::Char('j', KeyModifiers::NONE));
+        }
+
+        let mut help = vec![
+            KeyHelp::new(up, "move up"),
+            KeyHelp::new(down, "move down"),
+            KeyHelp::new(
+                [
+                    Key::PageUp(KeyModifiers::NONE),
+                    Key::PageDown(KeyModifiers::NONE),
+                ],
+                "move by page",
+            ),
+            KeyHelp::new([Key::Home, Key::End], "move to the first or last option"),
+            KeyHelp::new(
+                [Key::Char(' ', KeyModifiers::NONE)],
+                "toggle the current option",
+            ),
+        ];
+
+        let mut select_all = vec![Key::Right(KeyModifiers::NONE)];
+        let mut clear = vec![Key::Left(KeyModifiers::NONE)];
+        if config.vim_mode {
+            select_all.push(Key::Char('l', KeyModifiers::NONE));
+            clear.push(Key::Char('h', KeyModifiers::NONE));
+        }
+        help.push(KeyHelp::new(select_all, "select all options"));
+        help.push(KeyHelp::new(clear, "clear the selection"));
+        help.push(KeyHelp::new(
+            [Key::Char('t', KeyModifiers::CONTROL)],
+            "list all, only checked or only unchecked options",
+        ));
+        if config.search_mode {
+            help.push(KeyHelp::new(
+                [Key::Char('/', KeyModifiers::NONE)],
+                "search the options",
+            ));
+            help.push(KeyHelp::new(
+                [
+                    Key::Char('n', KeyModifiers::NONE),
+                    Key::Char('N', KeyModifiers::NONE),
+                ],
+                "move to the next or previous match",
+            ));
+        } else {
+            help.push(KeyHelp::new([Key::Tab], "complete the filter"));
+            help.push(KeyHelp::new(
+                [Key::Up(KeyModifiers::ALT), Key::Down(KeyModifiers::ALT)],
+                "recall the previous or next filter",
+            ));
+            #[cfg(feature = "regex")]
+            help.push(KeyHelp::new(
+                [Key::Char('r', KeyModifiers::CONTROL)],
+                "toggle the regular expression filter",
+            ));
+        }
+
+        help
+    }
 }
@@ -7,10 +7,30 @@ pub struct MultiSelectConfig {
     pub vim_mode: bool,
     /// Page size of the list of options.
     pub page_size: usize,
+    /// Number of options displayed at once, defaulting to the page size.
+    pub max_visible_options: Option<usize>,
+    /// Number of options kept visible around the cursor, centering it when `None`.
+    pub scrolloff: Option<usize>,
+    /// Whether to render the filter text and match count above the options.
+    pub show_filter_header: bool,
+    /// Whether to hide the filter input from the prompt line.
+    pub hide_filter_input: bool,
     /// Whether to keep the filter text when an option is selected.
     pub keep_filter: bool,
     /// Whether to reset the cursor to the first option on filter input change.
     pub reset_cursor: bool,
+    /// Whether to submit untouched default selections hidden by the filter.
+    pub keep_default_selections_hidden_by_filter: bool,
+    /// Minimum score of the options listed while filtering.
+    pub score_threshold: Option<i64>,
+    /// Maximum number of options listed while filtering.
+    pub max_displayed_matches: Option<usize>,
+    /// Whether `/` starts a search moving the cursor between the matching
+    /// options, instead of the options being filtered as the user types.
+    pub search_mode: bool,
+    /// Whether the user is currently typing a search query, in which case
+    /// most keys are forwarded to the search input.
+    pub searching: bool,
 }
 
 impl<T> From<&MultiSelect<'_, T>> for MultiSelectConfig {
@@ -18,8 +38,30 @@ impl<T> From<&MultiSelect<'_, T>> for MultiSelectConfig {
         Self {
             vim_mode: value.vim_mode,
             page_size: value.page_size,
+            max_visible_options: value.max_visible_options,
+            scrolloff: value.scrolloff,
+            show_filter_header: value.show_filter_header,
+            hide_filter_input: value.hide_filter_input,
             keep_filter: value.keep_filter,
             reset_cursor: value.reset_cursor,
+            keep_default_selections_hidden_by_filter: value
+                .keep_default_selections_hidden_by_filter,
+            score_threshold: value.score_threshold,
+            max_displayed_matches: value.max_displayed_matches,
+            search_mode: value.search_mode,
+            searching: false,
         }
     }
 }
+
+impl MultiSelectConfig {
+    /// Number of options displayed at once.
+    pub fn visible_options(&self) -> usize {
+        self.max_visible_options.unwrap_or(self.page_size)
+    }
+
+    /// Whether an option with the given score is listed while filtering.
+    pub fn meets_score_threshold(&self, score: i64) -> bool {
+        !matches!(self.score_threshold, Some(threshold) if score < threshold)
+    }
+}
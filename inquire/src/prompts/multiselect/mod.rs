@@ -7,32 +7,41 @@ mod test;
 
 pub use action::*;
 
-use std::fmt::Display;
+use std::{collections::BTreeSet, fmt::Display, time::Duration};
 
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
-    formatter::MultiOptionFormatter,
-    list_option::ListOption,
-    prompts::prompt::Prompt,
+    formatter::{
+        FilteredMultiOptionFormatter, LiveOptionFormatter, MultiOptionContextFormatter,
+        MultiOptionFormatter,
+    },
+    list_option::{FilteredAnswer, ListOption, QuizAnswer},
+    persistence::SelectionStore,
+    prompts::prompt::{lifecycle_event, Prompt},
     terminal::get_default_terminal,
-    type_aliases::Scorer,
-    ui::{Backend, MultiSelectBackend, RenderConfig},
+    type_aliases::{OptionKey, OptionProvider, Scorer, SelectionChangeCallback, SelectionFooter},
+    ui::{Backend, MultiSelectBackend, QuizBackend, RenderConfig},
+    utils::option_keys,
     validator::MultiOptionValidator,
 };
 
 use self::prompt::MultiSelectPrompt;
 
 #[cfg(feature = "fuzzy")]
-use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
-#[cfg(feature = "fuzzy")]
-use once_cell::sync::Lazy;
-#[cfg(feature = "fuzzy")]
-static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::default().ignore_case());
+use crate::prompts::fuzzy_filter;
+#[cfg(feature = "async")]
+use crate::prompts::option_stream::OptionStream;
+#[cfg(not(feature = "fuzzy"))]
+use crate::utils::extended_filter_score;
+#[cfg(feature = "async")]
+use futures_core::Stream;
 /// Prompt suitable for when you need the user to select many options (including none if applicable) among a list of them.
 ///
 /// The user can select (or deselect) the current highlighted option by pressing space, clean all selections by pressing the left arrow and select all options by pressing the right arrow.
 ///
+/// Pressing Ctrl+T cycles between listing all options, only the checked ones, e.g. to quickly prune the selection, and only the unchecked ones. The filter applies to every view, and the cursor stays on the highlighted option whenever it is listed in the new view.
+///
 /// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait. It is required that the `Vec` is moved to the prompt, as the prompt will return the ownership of the `Vec` after the user submits, with only the selected options inside it.
 /// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
 ///
@@ -43,17 +52,39 @@ static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::defaul
 /// - **Prompt message**: Required when creating the prompt.
 /// - **Options list**: Options displayed to the user. Must be **non-empty**.
 /// - **Default selections**: Options that are selected by default when the prompt is first rendered. The user can unselect them. If any of the indices is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Default selections hidden by filter**: Whether default selections the user did not touch are submitted even when the current filter hides them. True by default.
 /// - **Starting cursor**: Index of the cursor when the prompt is first rendered. Default is 0 (first option). If the index is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
 /// - **Starting filter input**: Sets the initial value of the filter section of the prompt.
 /// - **Help message**: Message displayed at the line below the prompt.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 ///   - Prints the selected options string value, joined using a comma as the separator, by default.
+///   - A filter formatter can be set instead to also receive the filter text typed by the user.
+///   - A context formatter can be set instead to also receive every option of the prompt, e.g. to print `3/10 modules selected (1, 4, 7)`.
+/// - **Option formatter**: Function controlling how each option is displayed while the prompt is live, receiving whether it is highlighted and checked, e.g. to add badges or color options by status. Independent of the formatter of the final answer.
 /// - **Validator**: Custom validator to make sure a given submitted input pass the specified requirements, e.g. not allowing 0 selected options or limiting the number of options that the user is allowed to select.
 ///   - No validators are on by default.
-/// - **Page size**: Number of options displayed at once, 7 by default.
+/// - **Page size**: Number of options displayed at once, 7 by default. Also the distance covered by the page up and page down keys.
+/// - **Max visible options**: Number of options displayed at once, overriding the page size for rendering purposes only. The list scrolls within these rows, keeping the cursor centered, while page up and page down still move by the page size.
+/// - **Filter header**: Whether to render a line between the prompt and the options showing the current filter text and the number of matching options, so the user knows why the list looks the way it does. Disabled by default.
+/// - **Hide filter input**: Whether to hide the filter input from the prompt line while keeping type-to-filter active. The filter text is then surfaced in the filter header, rendered as soon as the user types. Disabled by default.
+/// - **Scrolloff**: Number of options kept visible above and below the cursor while scrolling, like vim's `scrolloff`. When set, the list only scrolls once the cursor gets that close to an edge, instead of keeping the cursor centered.
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
 /// - **Scorer function**: Function that defines the order of options and if displayed as all.
+/// - **Score threshold**: Minimum score of the options listed while filtering, to prune low-quality matches. No threshold by default.
+/// - **Max displayed matches**: Maximum number of options listed while filtering, keeping the best-scored ones. Unlimited by default.
+/// - **Selection store**: Store used to remember the selections across invocations, keyed by a prompt ID. The selections saved in a previous run take the place of the default selections.
+/// - **Selection change callback**: Function called with the checked options whenever they change, e.g. to update an external preview of the selection.
+/// - **Footer**: Function computing a line rendered below the options from the checked options, e.g. `Total download: 182 MB`, kept up to date as options are toggled.
+/// - **Fuzzy filter**: Whether the options are filtered by the built-in fuzzy matcher instead of the scorer, ranked skim-style with the matched characters highlighted using the `option_match` style of the `RenderConfig`. Requires the `fuzzy` feature, disabled by default.
+/// - **Regex filter**: Whether the filter input is interpreted as a case-insensitive regular expression, e.g. `^/var/log/.*\.log$`, instead of going through the scorer. The user can toggle it with Ctrl+R. Requires the `regex` feature, disabled by default.
+/// - **Search mode**: Whether `/` starts a search instead of the options being filtered as the user types. Once confirmed, `n` and `N` jump between the matching options while every option stays listed, to see the context around the matches.
+/// - **Skip option**: Label of an extra choice rendered below the options, e.g. `(none)`. Choosing it returns an empty selection.
 /// - **Keep filter flag**: Whether the current filter input should be cleared or not after a selection is made. Defaults to true.
+/// - **Option key**: Function computing a stable key identifying each option, defaulting to its string value. Keys identify the options across refreshes and in the selection store, and can set the default selections.
+/// - **Refresh**: Function periodically called to replace the options while the prompt is displayed, e.g. a pod picker updating statuses. The cursor and the checked options stay on the same options, identified by their keys, as long as they are still listed.
+/// - **Timeout**: Maximum time to wait for an answer, after which the prompt fails with [`InquireError::Timeout`](crate::InquireError::Timeout). The time left can optionally be rendered below the prompt.
+/// - **Elapsed time**: Whether to render the time elapsed since the prompt was displayed below it, ticking every second, e.g. for time-boxed decisions.
+/// - **Correct answers**: Indexes of the correct options, for quizzes. When prompted with [`MultiSelect::quiz_prompt`], the options are listed again after submission with the correct ones and the wrongly chosen ones marked.
 ///
 /// # Example
 ///
@@ -71,12 +102,33 @@ pub struct MultiSelect<'a, T> {
     /// Default indexes of options to be selected from the start.
     pub default: Option<Vec<usize>>,
 
+    /// Keys of the options to be selected from the start, in addition to the
+    /// default indexes.
+    pub default_keys: Option<Vec<String>>,
+
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
     /// Page size of the options displayed to the user.
     pub page_size: usize,
 
+    /// Number of options displayed at once, independently of the page size
+    /// used by page up and page down. Defaults to the page size when `None`.
+    pub max_visible_options: Option<usize>,
+
+    /// Number of options kept visible above and below the cursor while
+    /// scrolling. When `None`, the default, the cursor is kept centered.
+    pub scrolloff: Option<usize>,
+
+    /// Whether to render a line between the prompt and the options showing the
+    /// current filter text and the number of matching options.
+    pub show_filter_header: bool,
+
+    /// Whether to hide the filter input from the prompt line while keeping
+    /// type-to-filter active. The filter header is rendered whenever the
+    /// hidden filter is not empty.
+    pub hide_filter_input: bool,
+
     /// Whether vim mode is enabled. When enabled, the user can
     /// navigate through the options using hjkl.
     pub vim_mode: bool,
@@ -96,22 +148,109 @@ pub struct MultiSelect<'a, T> {
     /// Defaults to true.
     pub filter_input_enabled: bool,
 
+    /// Whether `/` starts a search moving the cursor between the matching
+    /// options with `n` and `N`, listing every option, instead of the options
+    /// being filtered as the user types.
+    pub search_mode: bool,
+
+    /// Whether the options are filtered by the built-in fuzzy matcher, with
+    /// the matched characters highlighted, instead of going through the scorer.
+    #[cfg(feature = "fuzzy")]
+    pub fuzzy_filter: bool,
+
+    /// Whether the filter input is interpreted as a regular expression
+    /// instead of going through the scorer.
+    #[cfg(feature = "regex")]
+    pub regex_filter: bool,
+
+    /// Label of an extra choice rendered below the options, letting the user
+    /// skip the prompt.
+    pub skip_option: Option<&'a str>,
+
     /// Function called with the current user input to score the provided
     /// options.
     /// The list of options is sorted in descending order (highest score first)
     pub scorer: Scorer<'a, T>,
 
+    /// Minimum score of the options listed while a filter is typed, pruning
+    /// low-quality matches. Options are not pruned when `None`.
+    pub score_threshold: Option<i64>,
+
+    /// Maximum number of options listed while a filter is typed, keeping the
+    /// best-scored ones. Every match is listed when `None`.
+    pub max_displayed_matches: Option<usize>,
+
     /// Whether the current filter typed by the user is kept or cleaned after a selection is made.
     pub keep_filter: bool,
 
+    /// Whether default selections that the user did not touch are part of the
+    /// answer even when the current filter hides them at submission time.
+    ///
+    /// Defaults to true. When false, only the untouched default selections that
+    /// are visible with the current filter are submitted. Options explicitly
+    /// selected by the user are always submitted.
+    pub keep_default_selections_hidden_by_filter: bool,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: MultiOptionFormatter<'a, T>,
 
+    /// Function that formats the user input along with the filter text typed by
+    /// the user. When set, it is used in place of [`formatter`](Self::formatter).
+    pub filter_formatter: Option<FilteredMultiOptionFormatter<'a, T>>,
+
+    /// Function that formats the user input along with the context of the
+    /// prompt, such as all of its options. When set, it is used in place of
+    /// both [`formatter`](Self::formatter) and [`filter_formatter`](Self::filter_formatter).
+    pub context_formatter: Option<MultiOptionContextFormatter<'a, T>>,
+
+    /// Function that formats each option while the prompt is displayed. Options
+    /// are displayed by their string value when `None`.
+    pub option_formatter: Option<LiveOptionFormatter<'a, T>>,
+
     /// Validator to apply to the user input.
     ///
     /// In case of error, the message is displayed one line above the prompt.
     pub validator: Option<Box<dyn MultiOptionValidator<T>>>,
 
+    /// Prompt ID and store used to remember the selections across invocations.
+    ///
+    /// When set, the selections previously saved for the prompt ID take the
+    /// place of the default selections, and the submitted selections are saved.
+    pub selection_store: Option<(&'a str, &'a dyn SelectionStore)>,
+
+    /// Function called with the checked options whenever they change.
+    pub on_selection_change: Option<SelectionChangeCallback<'a, T>>,
+
+    /// Function computing the footer line rendered below the options from
+    /// the checked options.
+    pub footer: Option<SelectionFooter<'a, T>>,
+
+    /// Function computing the stable key identifying each option. Defaults to
+    /// the string value of the option when `None`.
+    pub option_key: Option<OptionKey<'a, T>>,
+
+    /// Interval and provider used to refresh the options while the prompt
+    /// is displayed.
+    pub refresh: Option<(Duration, OptionProvider<'a, T>)>,
+
+    /// Stream of options appended to the list as they arrive, set with
+    /// [`with_option_stream`](Self::with_option_stream).
+    #[cfg(feature = "async")]
+    option_stream: Option<OptionStream<'a, T>>,
+
+    /// Maximum time to wait for an answer before failing with [`InquireError::Timeout`].
+    pub timeout: Option<Duration>,
+
+    /// Whether to render the time left until the timeout elapses.
+    pub show_remaining_time: bool,
+
+    /// Whether to render the time elapsed since the prompt was displayed, or the
+    /// time left when a timeout is set.
+    pub show_elapsed_time: bool,
+
+    /// Indexes of the correct options, used by [`quiz_prompt`](Self::quiz_prompt).
+    pub correct_answers: Option<Vec<usize>>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -157,6 +296,9 @@ where
     /// Default scoring function, which will create a score for the current option using the input value.
     /// The return will be sorted in Descending order, leaving options with None as a score.
     ///
+    /// Space-separated terms are all required to match, summing their scores, while terms
+    /// prefixed by `!` exclude the options containing them, e.g. `prod !east`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -176,21 +318,23 @@ where
     /// assert_eq!(None,     scorer("sa", &"Austin",        "Austin",        10));
     /// assert_eq!(None,     scorer("sa", &"Jacksonville",  "Jacksonville",  11));
     /// assert_eq!(Some(49), scorer("sa", &"San Jose",      "San Jose",      12));
+    ///
+    /// assert_eq!(None,     scorer("sa !an", &"San Jose",  "San Jose",      12));
+    /// assert_eq!(Some(0),  scorer("!jose",  &"Austin",    "Austin",        10));
     /// ```
     #[cfg(feature = "fuzzy")]
     pub const DEFAULT_SCORER: Scorer<'a, T> =
         &|input, _option, string_value, _idx| -> Option<i64> {
-            DEFAULT_MATCHER.fuzzy_match(string_value, input)
+            fuzzy_filter::score(input, string_value)
         };
 
     #[cfg(not(feature = "fuzzy"))]
     pub const DEFAULT_SCORER: Scorer<'a, T> =
         &|input, _option, string_value, _idx| -> Option<i64> {
-            let filter = input.to_lowercase();
-            match string_value.to_lowercase().contains(&filter) {
-                true => Some(0),
-                false => None,
-            }
+            let value = string_value.to_lowercase();
+            extended_filter_score(input, string_value, |term| {
+                value.contains(&term.to_lowercase()).then_some(0)
+            })
         };
 
     /// Default page size, equal to the global default page size [config::DEFAULT_PAGE_SIZE]
@@ -213,6 +357,9 @@ where
     /// Default behavior of keeping or cleaning the current filter value.
     pub const DEFAULT_KEEP_FILTER: bool = true;
 
+    /// Default behavior of submitting default selections hidden by the filter.
+    pub const DEFAULT_KEEP_DEFAULT_SELECTIONS_HIDDEN_BY_FILTER: bool = true;
+
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
         Some("↑↓ to move, space to select one, → to all, ← to none, type to filter");
@@ -223,17 +370,46 @@ where
             message,
             options,
             default: None,
+            default_keys: None,
             help_message: Self::DEFAULT_HELP_MESSAGE,
             page_size: Self::DEFAULT_PAGE_SIZE,
+            max_visible_options: None,
+            scrolloff: None,
+            show_filter_header: false,
+            hide_filter_input: false,
             vim_mode: Self::DEFAULT_VIM_MODE,
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
             starting_filter_input: None,
             reset_cursor: Self::DEFAULT_RESET_CURSOR,
             filter_input_enabled: Self::DEFAULT_FILTER_INPUT_ENABLED,
+            search_mode: false,
+            #[cfg(feature = "fuzzy")]
+            fuzzy_filter: false,
+            #[cfg(feature = "regex")]
+            regex_filter: false,
+            skip_option: None,
             keep_filter: Self::DEFAULT_KEEP_FILTER,
+            keep_default_selections_hidden_by_filter:
+                Self::DEFAULT_KEEP_DEFAULT_SELECTIONS_HIDDEN_BY_FILTER,
             scorer: Self::DEFAULT_SCORER,
+            score_threshold: None,
+            max_displayed_matches: None,
             formatter: Self::DEFAULT_FORMATTER,
+            filter_formatter: None,
+            context_formatter: None,
+            option_formatter: None,
             validator: None,
+            selection_store: None,
+            on_selection_change: None,
+            footer: None,
+            option_key: None,
+            refresh: None,
+            #[cfg(feature = "async")]
+            option_stream: None,
+            timeout: None,
+            show_remaining_time: false,
+            show_elapsed_time: false,
+            correct_answers: None,
             render_config: get_configuration(),
         }
     }
@@ -256,6 +432,44 @@ where
         self
     }
 
+    /// Sets the number of options displayed at once, leaving the page size to
+    /// only define how far page up and page down move the cursor.
+    pub fn with_max_visible_options(mut self, max_visible_options: usize) -> Self {
+        self.max_visible_options = Some(max_visible_options);
+        self
+    }
+
+    /// Sets the number of options kept visible above and below the cursor while
+    /// scrolling. The list then only scrolls once the cursor gets that close to
+    /// one of its edges, instead of keeping the cursor centered.
+    ///
+    /// Values larger than half the visible options keep the cursor centered.
+    pub fn with_scrolloff(mut self, scrolloff: usize) -> Self {
+        self.scrolloff = Some(scrolloff);
+        self
+    }
+
+    /// Sets whether to render a line between the prompt and the options showing
+    /// the current filter text and the number of matching options.
+    ///
+    /// The line is styled with the `filter_header` style sheet of the render
+    /// config, and is not rendered when the filter input is disabled.
+    pub fn with_show_filter_header(mut self, show_filter_header: bool) -> Self {
+        self.show_filter_header = show_filter_header;
+        self
+    }
+
+    /// Sets whether to hide the filter input from the prompt line while still
+    /// filtering the options as the user types.
+    ///
+    /// The filter text is then only surfaced in the filter header, which is
+    /// rendered whenever the hidden filter is not empty, regardless of
+    /// [`with_show_filter_header`](Self::with_show_filter_header).
+    pub fn with_hide_filter_input(mut self, hide_filter_input: bool) -> Self {
+        self.hide_filter_input = hide_filter_input;
+        self
+    }
+
     /// Enables or disables vim_mode.
     pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
         self.vim_mode = vim_mode;
@@ -268,18 +482,74 @@ where
         self
     }
 
+    /// Sets whether untouched default selections hidden by the current filter
+    /// are submitted. Defaults to true.
+    pub fn with_keep_default_selections_hidden_by_filter(mut self, keep: bool) -> Self {
+        self.keep_default_selections_hidden_by_filter = keep;
+        self
+    }
+
     /// Sets the scoring function.
     pub fn with_scorer(mut self, scorer: Scorer<'a, T>) -> Self {
         self.scorer = scorer;
         self
     }
 
+    /// Sets the minimum score of the options listed while a filter is typed,
+    /// pruning noisy low-quality matches, e.g. from a fuzzy scorer.
+    ///
+    /// The threshold applies to the scores of the scorer, or of the fuzzy
+    /// filter when enabled, and is ignored while the filter is empty.
+    pub fn with_score_threshold(mut self, score_threshold: i64) -> Self {
+        self.score_threshold = Some(score_threshold);
+        self
+    }
+
+    /// Sets the maximum number of options listed while a filter is typed,
+    /// keeping only the best-scored matches so that giant result sets do not
+    /// bury them under pages of poor matches.
+    ///
+    /// Only the list is capped: default selections matching the filter remain
+    /// part of the answer even when they are not listed.
+    pub fn with_max_displayed_matches(mut self, max_displayed_matches: usize) -> Self {
+        self.max_displayed_matches = Some(max_displayed_matches);
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: MultiOptionFormatter<'a, T>) -> Self {
         self.formatter = formatter;
         self
     }
 
+    /// Sets a formatter that also receives the filter text typed by the user at
+    /// the time of submission, used in place of the regular formatter.
+    pub fn with_filter_formatter(mut self, formatter: FilteredMultiOptionFormatter<'a, T>) -> Self {
+        self.filter_formatter = Some(formatter);
+        self
+    }
+
+    /// Sets a formatter that also receives the context of the prompt, such as
+    /// the total number of options and the filter text, used in place of the
+    /// regular and filter formatters.
+    ///
+    /// The selected options keep their original indexes, e.g. to print
+    /// `3/10 modules selected (1, 4, 7)`.
+    pub fn with_context_formatter(mut self, formatter: MultiOptionContextFormatter<'a, T>) -> Self {
+        self.context_formatter = Some(formatter);
+        self
+    }
+
+    /// Sets the formatter controlling how each option is displayed while the
+    /// prompt is live, independently of the formatter of the final answer.
+    ///
+    /// The style of the returned text is applied on top of the option style of
+    /// the render config. Fuzzy matches are not highlighted in formatted options.
+    pub fn with_option_formatter(mut self, formatter: LiveOptionFormatter<'a, T>) -> Self {
+        self.option_formatter = Some(formatter);
+        self
+    }
+
     /// Sets the validator to apply to the user input. You might want to use this feature
     /// in case you need to limit the user to specific choices, such as limiting the number
     /// of selections.
@@ -293,12 +563,80 @@ where
         self
     }
 
+    /// Remembers the selections of the user across invocations, saving them to
+    /// the store under the given prompt ID. The selections saved in a previous
+    /// run take the place of the default selections.
+    ///
+    /// Failures to load or save the selections do not fail the prompt: they
+    /// are logged, and the prompt falls back to its default selections or
+    /// still returns the user's answer.
+    pub fn with_selection_store(
+        mut self,
+        prompt_id: &'a str,
+        store: &'a dyn SelectionStore,
+    ) -> Self {
+        self.selection_store = Some((prompt_id, store));
+        self
+    }
+
+    /// Sets the function called with the checked options whenever they
+    /// change, by toggling an option or by selecting or clearing all of them.
+    pub fn with_on_selection_change(mut self, callback: SelectionChangeCallback<'a, T>) -> Self {
+        self.on_selection_change = Some(callback);
+        self
+    }
+
+    /// Sets the function computing the footer line rendered below the options
+    /// from the checked options, e.g. a running total of their sizes.
+    pub fn with_footer(mut self, footer: SelectionFooter<'a, T>) -> Self {
+        self.footer = Some(footer);
+        self
+    }
+
     /// Sets the indexes to be selected by default.
     ///
     /// The values should be valid indexes for the given option list. Any
     /// numbers larger than the option list or duplicates will be ignored.
-    pub fn with_default(mut self, default: &'a [usize]) -> Self {
-        self.default = Some(default.to_vec());
+    pub fn with_default(self, default: &'a [usize]) -> Self {
+        self.with_starting_selections(default.iter().copied())
+    }
+
+    /// Sets the indexes to be selected by default from any collection of
+    /// indexes, such as a `Vec`, a `HashSet` or a range.
+    ///
+    /// The values should be valid indexes for the given option list, otherwise
+    /// the prompt fails with an [`InquireError::InvalidConfiguration`] error.
+    /// Duplicates are ignored.
+    ///
+    /// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+    pub fn with_starting_selections<I>(mut self, selections: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        self.default = Some(selections.into_iter().collect());
+        self
+    }
+
+    /// Sets the keys of the options to be selected by default, in addition to
+    /// the default indexes. Keys matching none of the options are ignored.
+    ///
+    /// Options are identified by their [keys](MultiSelect::with_option_key),
+    /// defaulting to their string value.
+    pub fn with_default_keys<S>(mut self, keys: &[S]) -> Self
+    where
+        S: AsRef<str>,
+    {
+        self.default_keys = Some(keys.iter().map(|key| key.as_ref().to_owned()).collect());
+        self
+    }
+
+    /// Sets the function computing the stable key identifying each option,
+    /// used in place of its string value to keep the cursor and the checked
+    /// options on the same options across refreshes, to select the
+    /// [default keys](MultiSelect::with_default_keys) and to save the
+    /// selections in the selection store.
+    pub fn with_option_key(mut self, option_key: OptionKey<'a, T>) -> Self {
+        self.option_key = Some(option_key);
         self
     }
 
@@ -335,6 +673,44 @@ where
         self
     }
 
+    /// Sets whether `/` starts a search instead of the options being filtered
+    /// as the user types, disabling the filter input.
+    ///
+    /// Once the query is confirmed with enter, `n` and `N` move the cursor to
+    /// the next and previous options matching it, according to the scorer,
+    /// while every option stays listed to keep the context around the matches.
+    pub fn with_search_mode(mut self, search_mode: bool) -> Self {
+        self.search_mode = search_mode;
+        self
+    }
+
+    /// Filters the options with the built-in fuzzy matcher instead of the
+    /// scorer, ranking them skim-style by how well they match, and highlights
+    /// the matched characters of each listed option.
+    ///
+    /// Like the default scorer, space-separated terms are all required to
+    /// match while terms prefixed by `!` exclude the options containing them.
+    /// The highlighting style is the `option_match` style sheet of the
+    /// [RenderConfig].
+    #[cfg(feature = "fuzzy")]
+    pub fn with_fuzzy_filter(mut self) -> Self {
+        self.fuzzy_filter = true;
+        self
+    }
+
+    /// Sets whether the filter input starts out interpreted as a
+    /// case-insensitive regular expression, matched against the string value
+    /// of each option instead of going through the scorer.
+    ///
+    /// The user can toggle it at any time with Ctrl+R. While the pattern being
+    /// typed is invalid, the previous expression stays in use and the filter
+    /// header flags the pattern.
+    #[cfg(feature = "regex")]
+    pub fn with_regex_filter(mut self, regex_filter: bool) -> Self {
+        self.regex_filter = regex_filter;
+        self
+    }
+
     /// Disables the filter input, which means the user will not be able to filter the options
     /// by typing.
     ///
@@ -345,6 +721,90 @@ where
         self
     }
 
+    /// Adds a choice rendered below the options, e.g. `(none)`, for questions
+    /// whose answer is optional.
+    ///
+    /// Choosing it renders the label as the answer and skips the prompt,
+    /// bypassing the validators: [`prompt_skippable`](MultiSelect::prompt_skippable)
+    /// returns `Ok(None)`, while the other prompt methods fail with
+    /// [`InquireError::OperationSkipped`]. The label is also rendered for empty
+    /// selections submitted from the other options.
+    pub fn with_skip_option(mut self, label: &'a str) -> Self {
+        self.skip_option = Some(label);
+        self
+    }
+
+    /// Replaces the options with the ones returned by the provider every
+    /// `interval`, without waiting for the user to press a key.
+    ///
+    /// Options are identified by their [keys](MultiSelect::with_option_key)
+    /// across refreshes, keeping the cursor and the checked options on them as
+    /// long as they are still listed. The indexes of the answer refer to the
+    /// options of the latest refresh.
+    ///
+    /// Refreshes can only happen while waiting for a key when using the
+    /// `crossterm` backend. Other backends refresh on the next key press.
+    pub fn with_refresh(mut self, interval: Duration, provider: OptionProvider<'a, T>) -> Self {
+        self.refresh = Some((interval, provider));
+        self
+    }
+
+    /// Appends the options yielded by the stream to the list as they arrive,
+    /// rendering a loading indicator until the stream ends. The list of options
+    /// can be empty when a stream is set.
+    ///
+    /// The stream is polled with a no-op waker on every tick of the prompt
+    /// rather than on an executor, so futures needing a runtime context, e.g.
+    /// tokio timers or sockets, never complete. It must be driven by something
+    /// else, e.g. a channel fed by another thread or by a task spawned on the
+    /// caller's runtime. While the stream is loading, it takes the place of the
+    /// [refresh](MultiSelect::with_refresh).
+    ///
+    /// The stream can only be consumed once: a clone of this prompt lists the
+    /// options given upfront only.
+    ///
+    /// Options can only be received while waiting for a key when using the
+    /// `crossterm` backend. Other backends receive them on the next key press.
+    #[cfg(feature = "async")]
+    pub fn with_option_stream<S>(mut self, stream: S) -> Self
+    where
+        S: Stream<Item = T> + 'a,
+    {
+        self.option_stream = Some(OptionStream::new(stream));
+        self
+    }
+
+    /// Sets the maximum time to wait for an answer, after which the prompt
+    /// fails with [`InquireError::Timeout`].
+    ///
+    /// The deadline can only interrupt a pending key read when using the
+    /// `crossterm` backend. Other backends notice it on the next key press.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to render the time left until the timeout elapses.
+    pub fn with_show_remaining_time(mut self, show_remaining_time: bool) -> Self {
+        self.show_remaining_time = show_remaining_time;
+        self
+    }
+
+    /// Sets whether to render the time elapsed since the prompt was displayed,
+    /// ticking every second. When a timeout is set, the time left is rendered
+    /// instead.
+    pub fn with_show_elapsed_time(mut self, show_elapsed_time: bool) -> Self {
+        self.show_elapsed_time = show_elapsed_time;
+        self
+    }
+
+    /// Sets the indexes of the correct options, turning the prompt into a quiz
+    /// when prompted with [`quiz_prompt`](MultiSelect::quiz_prompt).
+    pub fn with_correct_answers(mut self, indexes: &[usize]) -> Self {
+        self.correct_answers = Some(indexes.to_vec());
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -366,13 +826,14 @@ where
     /// This method is intended for flows where the user skipping/cancelling
     /// the prompt - by pressing ESC - is considered normal behavior. In this case,
     /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    /// The same goes for choosing the skip option, if any.
     ///
     /// Meanwhile, if the user does submit an answer, the method wraps the return
     /// type with `Some`.
     pub fn prompt_skippable(self) -> InquireResult<Option<Vec<T>>> {
         match self.prompt() {
             Ok(answer) => Ok(Some(answer)),
-            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(InquireError::OperationCanceled | InquireError::OperationSkipped) => Ok(None),
             Err(err) => Err(err),
         }
     }
@@ -395,13 +856,14 @@ where
     /// This method is intended for flows where the user skipping/cancelling
     /// the prompt - by pressing ESC - is considered normal behavior. In this case,
     /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    /// The same goes for choosing the skip option, if any.
     ///
     /// Meanwhile, if the user does submit an answer, the method wraps the return
     /// type with `Some`.
     pub fn raw_prompt_skippable(self) -> InquireResult<Option<Vec<ListOption<T>>>> {
         match self.raw_prompt() {
             Ok(answer) => Ok(Some(answer)),
-            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(InquireError::OperationCanceled | InquireError::OperationSkipped) => Ok(None),
             Err(err) => Err(err),
         }
     }
@@ -412,15 +874,130 @@ where
     /// Returns a [`ListOption`](crate::list_option::ListOption) containing
     /// the index of the selection and the owned object selected by the user.
     pub fn raw_prompt(self) -> InquireResult<Vec<ListOption<T>>> {
+        self.raw_prompt_with_filter()
+            .map(|filtered| filtered.answer)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a [`FilteredAnswer`] containing the [`ListOption`]s selected by
+    /// the user and the filter text typed at the time of submission.
+    pub fn raw_prompt_with_filter(self) -> InquireResult<FilteredAnswer<Vec<ListOption<T>>>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.filtered_prompt_with_backend(&mut backend)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules, as a quiz.
+    ///
+    /// After submission, every option is listed again below the answer, with
+    /// the correct options and the wrongly chosen ones marked and styled
+    /// according to the [`QuizRenderConfig`](crate::ui::QuizRenderConfig).
+    ///
+    /// Returns a [`QuizAnswer`] containing the [`ListOption`]s selected by the
+    /// user and whether they are exactly the correct ones. Fails with an
+    /// [`InquireError::InvalidConfiguration`] error if the correct answers are
+    /// not set or out-of-range.
+    pub fn quiz_prompt(self) -> InquireResult<QuizAnswer<Vec<ListOption<T>>>> {
         let (input_reader, terminal) = get_default_terminal()?;
         let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
-        self.prompt_with_backend(&mut backend)
+        self.quiz_prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn quiz_prompt_with_backend<B: MultiSelectBackend + QuizBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<QuizAnswer<Vec<ListOption<T>>>> {
+        let correct: BTreeSet<usize> = match &self.correct_answers {
+            Some(correct) => correct.iter().copied().collect(),
+            None => {
+                return Err(InquireError::InvalidConfiguration(
+                    "The correct answers must be set to prompt a quiz".into(),
+                ))
+            }
+        };
+        if let Some(index) = correct.iter().find(|idx| **idx >= self.options.len()) {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Correct answer {} is out-of-range of the {} options",
+                index,
+                self.options.len()
+            )));
+        }
+        let options: Vec<String> = self.options.iter().map(T::to_string).collect();
+
+        let answer = self.prompt_with_backend(backend)?;
+        let chosen: BTreeSet<usize> = answer.iter().map(|option| option.index).collect();
+
+        backend.frame_setup()?;
+        backend.render_quiz_result(&options, &chosen, &correct)?;
+        backend.frame_finish(true)?;
+
+        let is_correct = chosen == correct;
+        Ok(QuizAnswer::new(answer, is_correct))
     }
 
     pub(crate) fn prompt_with_backend<B: MultiSelectBackend>(
         self,
         backend: &mut B,
     ) -> InquireResult<Vec<ListOption<T>>> {
-        MultiSelectPrompt::new(self)?.prompt(backend)
+        self.filtered_prompt_with_backend(backend)
+            .map(|filtered| filtered.answer)
+    }
+
+    pub(crate) fn filtered_prompt_with_backend<B: MultiSelectBackend>(
+        mut self,
+        backend: &mut B,
+    ) -> InquireResult<FilteredAnswer<Vec<ListOption<T>>>> {
+        let selection_store = self.selection_store;
+
+        if let Some((prompt_id, store)) = selection_store {
+            match store.load(prompt_id) {
+                Ok(Some(previous)) => {
+                    let default = option_keys(&self.options, self.option_key)
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, key)| previous.contains(key))
+                        .map(|(idx, _)| idx)
+                        .collect();
+                    self.default = Some(default);
+                    self.default_keys = None;
+                }
+                Ok(None) => {}
+                Err(_err) => {
+                    lifecycle_event!(
+                        warn,
+                        "selections of prompt {:?} not loaded: {}",
+                        prompt_id,
+                        _err
+                    );
+                }
+            }
+        }
+
+        let option_key = self.option_key;
+        let answer = MultiSelectPrompt::new(self)?.prompt(backend)?;
+
+        if let Some((prompt_id, store)) = selection_store {
+            let selections: Vec<String> = answer
+                .answer
+                .iter()
+                .map(|o| match option_key {
+                    Some(option_key) => option_key(&o.value),
+                    None => o.value.to_string(),
+                })
+                .collect();
+            if let Err(_err) = store.save(prompt_id, &selections) {
+                lifecycle_event!(
+                    warn,
+                    "selections of prompt {:?} not saved: {}",
+                    prompt_id,
+                    _err
+                );
+            }
+        }
+
+        Ok(answer)
     }
 }
@@ -1,33 +1,82 @@
-use std::{cmp::Reverse, collections::BTreeSet, fmt::Display};
+use std::{
+    cmp::{min, Reverse},
+    collections::BTreeSet,
+    fmt::Display,
+    time::Duration,
+};
 
 use crate::{
+    ansi::AnsiStrippable,
     error::InquireResult,
-    formatter::MultiOptionFormatter,
+    formatter::{
+        FilteredMultiOptionFormatter, LiveOptionFormatter, MultiOptionContextFormatter,
+        MultiOptionFormatter,
+    },
     input::{Input, InputActionResult},
-    list_option::ListOption,
+    list_option::{FilteredAnswer, ListOption},
     prompts::prompt::{ActionResult, Prompt},
-    type_aliases::Scorer,
+    type_aliases::{OptionKey, OptionProvider, Scorer, SelectionChangeCallback, SelectionFooter},
     ui::MultiSelectBackend,
-    utils::paginate,
-    validator::{ErrorMessage, MultiOptionValidator, Validation},
+    utils::{
+        format_page, longest_common_prefix, option_keys, paginate, paginate_from,
+        scroll_window_start,
+    },
+    validator::{ErrorMessage, MultiOptionContext, MultiOptionValidator, Validation},
     InquireError, MultiSelect,
 };
 
-use super::{action::MultiSelectPromptAction, config::MultiSelectConfig};
+#[cfg(feature = "fuzzy")]
+use crate::prompts::fuzzy_filter;
+#[cfg(feature = "async")]
+use crate::prompts::option_stream::{OptionStream, OPTION_STREAM_POLL_INTERVAL};
+#[cfg(feature = "regex")]
+use crate::prompts::regex_filter::RegexFilter;
+
+use super::{
+    action::{MultiSelectPromptAction, MultiSelectView},
+    config::MultiSelectConfig,
+};
 
 pub struct MultiSelectPrompt<'a, T> {
     message: &'a str,
+    timeout: Option<Duration>,
+    show_remaining_time: bool,
+    show_elapsed_time: bool,
     config: MultiSelectConfig,
     options: Vec<T>,
     string_options: Vec<String>,
+    keys: Vec<String>,
     help_message: Option<&'a str>,
+    skip_option: Option<&'a str>,
     cursor_index: usize,
+    scroll_window_start: usize,
     checked: BTreeSet<usize>,
+    untouched_defaults: BTreeSet<usize>,
     input: Option<Input>,
+    filter_history: Vec<String>,
+    filter_history_position: Option<usize>,
+    search_input: Input,
     scored_options: Vec<usize>,
     scorer: Scorer<'a, T>,
+    #[cfg(feature = "fuzzy")]
+    fuzzy_filter: bool,
+    #[cfg(feature = "regex")]
+    regex_filter: RegexFilter,
     formatter: MultiOptionFormatter<'a, T>,
+    filter_formatter: Option<FilteredMultiOptionFormatter<'a, T>>,
+    context_formatter: Option<MultiOptionContextFormatter<'a, T>>,
+    context_formatted_answer: Option<String>,
+    option_formatter: Option<LiveOptionFormatter<'a, T>>,
     validator: Option<Box<dyn MultiOptionValidator<T>>>,
+    on_selection_change: Option<SelectionChangeCallback<'a, T>>,
+    footer: Option<SelectionFooter<'a, T>>,
+    option_key: Option<OptionKey<'a, T>>,
+    refresh: Option<(Duration, OptionProvider<'a, T>)>,
+    #[cfg(feature = "async")]
+    option_stream: Option<OptionStream<'a, T>>,
+    #[cfg(feature = "async")]
+    loading_ticks: usize,
+    view: MultiSelectView,
     error: Option<ErrorMessage>,
 }
 
@@ -36,7 +85,12 @@ where
     T: Display,
 {
     pub fn new(mso: MultiSelect<'a, T>) -> InquireResult<Self> {
-        if mso.options.is_empty() {
+        #[cfg(feature = "async")]
+        let streaming = mso.option_stream.is_some();
+        #[cfg(not(feature = "async"))]
+        let streaming = false;
+
+        if mso.options.is_empty() && !streaming {
             return Err(InquireError::InvalidConfiguration(
                 "Available options can not be empty".into(),
             ));
@@ -53,9 +107,10 @@ where
             }
         }
 
-        let string_options = mso.options.iter().map(T::to_string).collect();
+        let string_options = to_string_options(&mso.options);
+        let keys = option_keys(&mso.options, mso.option_key);
         let scored_options = (0..mso.options.len()).collect();
-        let checked_options = mso
+        let mut checked_options: BTreeSet<usize> = mso
             .default
             .as_ref()
             .map(|d| {
@@ -66,7 +121,16 @@ where
             })
             .unwrap_or_default();
 
-        let input = match mso.filter_input_enabled {
+        if let Some(default_keys) = &mso.default_keys {
+            checked_options.extend(
+                keys.iter()
+                    .enumerate()
+                    .filter(|(_, key)| default_keys.contains(key))
+                    .map(|(idx, _)| idx),
+            );
+        }
+
+        let input = match mso.filter_input_enabled && !mso.search_mode {
             true => Some(Input::new_with(
                 mso.starting_filter_input.unwrap_or_default(),
             )),
@@ -75,17 +139,44 @@ where
 
         Ok(Self {
             message: mso.message,
+            timeout: mso.timeout,
+            show_remaining_time: mso.show_remaining_time,
+            show_elapsed_time: mso.show_elapsed_time,
             config: (&mso).into(),
             options: mso.options,
             string_options,
+            keys,
             scored_options,
             help_message: mso.help_message,
+            skip_option: mso.skip_option,
             cursor_index: mso.starting_cursor,
+            scroll_window_start: 0,
             input,
+            filter_history: vec![],
+            filter_history_position: None,
+            search_input: Input::new(),
             scorer: mso.scorer,
+            #[cfg(feature = "fuzzy")]
+            fuzzy_filter: mso.fuzzy_filter,
+            #[cfg(feature = "regex")]
+            regex_filter: RegexFilter::new(mso.regex_filter),
             formatter: mso.formatter,
+            filter_formatter: mso.filter_formatter,
+            context_formatter: mso.context_formatter,
+            context_formatted_answer: None,
+            option_formatter: mso.option_formatter,
             validator: mso.validator,
+            on_selection_change: mso.on_selection_change,
+            footer: mso.footer,
+            option_key: mso.option_key,
+            refresh: mso.refresh,
+            #[cfg(feature = "async")]
+            option_stream: mso.option_stream,
+            #[cfg(feature = "async")]
+            loading_ticks: 0,
+            view: MultiSelectView::All,
             error: None,
+            untouched_defaults: checked_options.clone(),
             checked: checked_options,
         })
     }
@@ -95,7 +186,7 @@ where
             let after_wrap = qty.saturating_sub(self.cursor_index);
             self.cursor_index
                 .checked_sub(qty)
-                .unwrap_or_else(|| self.scored_options.len().saturating_sub(after_wrap))
+                .unwrap_or_else(|| self.choice_count().saturating_sub(after_wrap))
         } else {
             self.cursor_index.saturating_sub(qty)
         };
@@ -106,19 +197,38 @@ where
     fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
         let mut new_position = self.cursor_index.saturating_add(qty);
 
-        if new_position >= self.scored_options.len() {
-            new_position = if self.scored_options.is_empty() {
+        if new_position >= self.choice_count() {
+            new_position = if self.choice_count() == 0 {
                 0
             } else if wrap {
-                new_position % self.scored_options.len()
+                new_position % self.choice_count()
             } else {
-                self.scored_options.len().saturating_sub(1)
+                self.choice_count().saturating_sub(1)
             }
         }
 
         self.update_cursor_position(new_position)
     }
 
+    /// Number of choices the cursor moves through, the options matching the
+    /// filter followed by the skip choice, if any.
+    fn choice_count(&self) -> usize {
+        self.scored_options.len() + usize::from(self.skip_option.is_some())
+    }
+
+    fn is_skip_option_highlighted(&self) -> bool {
+        self.skip_option.is_some() && self.cursor_index == self.scored_options.len()
+    }
+
+    /// Cursor over the options matching the filter, placed on the last one
+    /// when the skip choice is highlighted, to keep the end of the list visible.
+    fn option_cursor(&self) -> usize {
+        min(
+            self.cursor_index,
+            self.scored_options.len().saturating_sub(1),
+        )
+    }
+
     fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
         if new_position != self.cursor_index {
             self.cursor_index = new_position;
@@ -139,6 +249,49 @@ where
         } else {
             self.checked.insert(*idx);
         }
+        self.untouched_defaults.remove(idx);
+
+        ActionResult::NeedsRedraw
+    }
+
+    /// Records the current filter in the history of the filters used while
+    /// checking options, moving it to the end when it was already recorded.
+    fn record_filter(&mut self) {
+        let content = match &self.input {
+            Some(input) if !input.is_empty() => input.content().to_owned(),
+            _ => return,
+        };
+
+        self.filter_history.retain(|filter| *filter != content);
+        self.filter_history.push(content);
+        self.filter_history_position = None;
+    }
+
+    /// Replaces the filter with an older or a newer one from the history.
+    /// Older filters are only recalled from an empty filter or from another
+    /// recalled one, so that a filter being typed is never lost.
+    fn recall_filter(&mut self, older: bool) -> ActionResult {
+        let input = match &mut self.input {
+            Some(input) => input,
+            None => return ActionResult::Clean,
+        };
+
+        let history_len = self.filter_history.len();
+        let position = match (self.filter_history_position, older) {
+            (None, true) if input.is_empty() => history_len.checked_sub(1),
+            (Some(position), true) => Some(position.saturating_sub(1)),
+            (Some(position), false) => Some(position + 1).filter(|next| *next < history_len),
+            (None, _) => return ActionResult::Clean,
+        };
+
+        let content = match position {
+            Some(position) => self.filter_history[position].clone(),
+            None => String::new(),
+        };
+
+        self.filter_history_position = position;
+        *input = Input::new_with(content);
+        self.run_scorer();
 
         ActionResult::NeedsRedraw
     }
@@ -169,61 +322,382 @@ where
         }
     }
 
+    /// Every option of the prompt, checked or not, along with its index.
+    fn all_options(&self) -> Vec<ListOption<&T>> {
+        self.options
+            .iter()
+            .enumerate()
+            .map(|(idx, opt)| ListOption::new(idx, opt))
+            .collect()
+    }
+
+    fn checked_options(&self) -> Vec<ListOption<&T>> {
+        self.checked
+            .iter()
+            .map(|idx| ListOption::new(*idx, &self.options[*idx]))
+            .collect()
+    }
+
+    /// Indexes of the options that are part of the answer if submitted now.
+    fn answer_indexes(&self) -> BTreeSet<usize> {
+        if self.config.keep_default_selections_hidden_by_filter {
+            return self.checked.clone();
+        }
+
+        self.checked
+            .iter()
+            .copied()
+            .filter(|idx| {
+                !self.untouched_defaults.contains(idx) || self.filter_score(*idx).is_some()
+            })
+            .collect()
+    }
+
     fn validate_current_answer(&self) -> InquireResult<Validation> {
         if let Some(validator) = &self.validator {
+            let answer_indexes = self.answer_indexes();
             let selected_options = self
                 .options
                 .iter()
                 .enumerate()
-                .filter_map(|(idx, opt)| match &self.checked.contains(&idx) {
+                .filter_map(|(idx, opt)| match answer_indexes.contains(&idx) {
                     true => Some(ListOption::new(idx, opt)),
                     false => None,
                 })
                 .collect::<Vec<_>>();
 
-            let res = validator.validate(&selected_options)?;
+            let all_options = self.all_options();
+            let mut context = MultiOptionContext::new(self.message, &all_options);
+            if let Some(input) = &self.input {
+                context = context.with_filter(input.content());
+            }
+
+            let res = validator.validate_with_context(&selected_options, &context)?;
             Ok(res)
         } else {
             Ok(Validation::Valid)
         }
     }
 
-    fn get_final_answer(&mut self) -> Vec<ListOption<T>> {
+    fn get_final_answer(&mut self) -> FilteredAnswer<Vec<ListOption<T>>> {
+        // the context formatter needs every option, which are moved out below
+        if let Some(formatter) = self.context_formatter {
+            let selected = self
+                .answer_indexes()
+                .into_iter()
+                .map(|idx| ListOption::new(idx, &self.options[idx]))
+                .collect::<Vec<_>>();
+            let all_options = self.all_options();
+            let mut context = MultiOptionContext::new(self.message, &all_options);
+            if let Some(input) = &self.input {
+                context = context.with_filter(input.content());
+            }
+
+            let formatted = formatter(&selected, &context);
+            self.context_formatted_answer = Some(formatted);
+        }
+
         let mut answer = vec![];
 
         // by iterating in descending order, we can safely
         // swap remove because the elements to the right
         // that we did not remove will not matter anymore.
-        for index in self.checked.iter().rev() {
-            let index = *index;
+        for index in self.answer_indexes().into_iter().rev() {
             let value = self.options.swap_remove(index);
             let lo = ListOption::new(index, value);
             answer.push(lo);
         }
         answer.reverse();
 
-        answer
+        let filter = self
+            .input
+            .as_ref()
+            .map(|input| input.content().to_owned())
+            .unwrap_or_default();
+
+        FilteredAnswer::new(answer, filter)
     }
 
-    fn run_scorer(&mut self) {
-        let content = match &self.input {
-            Some(input) => input.content(),
-            None => return,
+    fn complete_filter(&mut self) -> ActionResult {
+        let input = match &self.input {
+            Some(input) => input,
+            None => return ActionResult::Clean,
         };
 
-        let mut options = self
-            .options
+        let matches = self
+            .scored_options
             .iter()
-            .enumerate()
-            .filter_map(|(i, opt)| {
-                (self.scorer)(content, opt, self.string_options.get(i).unwrap(), i)
-                    .map(|score| (i, score))
+            .map(|idx| self.string_options[*idx].as_str())
+            .collect::<Vec<&str>>();
+        let prefix = longest_common_prefix(&matches);
+
+        let extends_filter = prefix
+            .to_lowercase()
+            .starts_with(&input.content().to_lowercase());
+
+        if !extends_filter || prefix.chars().count() <= input.content().chars().count() {
+            return ActionResult::Clean;
+        }
+
+        self.input = Some(Input::new_with(prefix));
+        self.run_scorer();
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn matches_search(&self, position: usize) -> bool {
+        let idx = self.scored_options[position];
+        let query = self.search_input.content();
+
+        (self.scorer)(query, &self.options[idx], &self.string_options[idx], idx).is_some()
+    }
+
+    /// Finds the position of the first option matching the search query
+    /// starting at `from`, wrapping around the list.
+    fn find_match(&self, from: usize, forward: bool) -> Option<usize> {
+        let len = self.scored_options.len();
+        if self.search_input.is_empty() || len == 0 {
+            return None;
+        }
+
+        (0..len)
+            .map(|i| match forward {
+                true => (from + i) % len,
+                false => (from + len - (i % len)) % len,
             })
+            .find(|position| self.matches_search(*position))
+    }
+
+    fn jump_to_match(&mut self, from: usize, forward: bool) -> ActionResult {
+        match self.find_match(from, forward) {
+            Some(position) => self.update_cursor_position(position),
+            None => ActionResult::Clean,
+        }
+    }
+
+    fn set_searching(&mut self, searching: bool) -> ActionResult {
+        if self.config.searching == searching {
+            return ActionResult::Clean;
+        }
+
+        self.config.searching = searching;
+        ActionResult::NeedsRedraw
+    }
+
+    fn update_scroll_window(&mut self) {
+        if let Some(scrolloff) = self.config.scrolloff {
+            self.scroll_window_start = scroll_window_start(
+                self.config.visible_options(),
+                self.scored_options.len(),
+                Some(self.option_cursor()),
+                scrolloff,
+                self.scroll_window_start,
+            );
+        }
+    }
+
+    /// Replaces the options with the ones returned by the option provider,
+    /// keeping the cursor and the checked options on the same options,
+    /// identified by their keys, as long as they are still listed.
+    fn refresh_options(&mut self) -> InquireResult<ActionResult> {
+        let provider = match self.refresh {
+            Some((_, provider)) => provider,
+            None => return Ok(ActionResult::Clean),
+        };
+
+        self.options = provider()?;
+
+        Ok(self.update_options())
+    }
+
+    /// Appends the options received from the stream since the last tick,
+    /// advancing the loading indicator.
+    #[cfg(feature = "async")]
+    fn receive_streamed_options(&mut self) -> ActionResult {
+        let stream = match &mut self.option_stream {
+            Some(stream) => stream,
+            None => return ActionResult::Clean,
+        };
+
+        let received = stream.poll();
+        if !stream.is_loading() {
+            self.option_stream = None;
+        }
+
+        self.loading_ticks += 1;
+        if !received.is_empty() {
+            self.options.extend(received);
+            let _ = self.update_options();
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    /// Recomputes the state derived from the options after they changed,
+    /// keeping the cursor and the checked options on the same keys.
+    fn update_options(&mut self) -> ActionResult {
+        let string_options = to_string_options(&self.options);
+        let keys = option_keys(&self.options, self.option_key);
+
+        if string_options == self.string_options && keys == self.keys {
+            return ActionResult::Clean;
+        }
+
+        let skip_option_highlighted = self.is_skip_option_highlighted();
+        let highlighted = self
+            .scored_options
+            .get(self.cursor_index)
+            .map(|idx| self.keys[*idx].clone());
+
+        let remap = |indexes: &BTreeSet<usize>| -> BTreeSet<usize> {
+            let previous_keys: BTreeSet<&str> =
+                indexes.iter().map(|idx| self.keys[*idx].as_str()).collect();
+
+            keys.iter()
+                .enumerate()
+                .filter(|(_, key)| previous_keys.contains(key.as_str()))
+                .map(|(idx, _)| idx)
+                .collect()
+        };
+        let checked = remap(&self.checked);
+        let untouched_defaults = remap(&self.untouched_defaults);
+        let lost_checked_options = checked.len() < self.checked.len();
+
+        self.checked = checked;
+        self.untouched_defaults = untouched_defaults;
+        self.string_options = string_options;
+        self.keys = keys;
+        self.scored_options = (0..self.options.len()).collect();
+        self.run_scorer();
+
+        let restored_cursor = match (skip_option_highlighted, highlighted) {
+            (true, _) => Some(self.scored_options.len()),
+            (false, Some(highlighted)) => self
+                .scored_options
+                .iter()
+                .position(|idx| self.keys[*idx] == highlighted),
+            (false, None) => None,
+        };
+
+        self.cursor_index = restored_cursor
+            .unwrap_or_else(|| min(self.cursor_index, self.choice_count().saturating_sub(1)));
+        self.update_scroll_window();
+
+        if let (Some(callback), true) = (self.on_selection_change, lost_checked_options) {
+            callback(&self.checked_options());
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    /// Score of the option at `idx` against the filter input, or `None` when
+    /// the filter rules it out, regardless of the current view and of how many
+    /// matches are displayed.
+    fn filter_score(&self, idx: usize) -> Option<i64> {
+        let content = match &self.input {
+            Some(input) => input.content(),
+            None => return Some(0),
+        };
+        let string_option = self.string_options.get(idx).unwrap();
+
+        #[cfg(feature = "regex")]
+        if self.regex_filter.is_enabled() {
+            return self.regex_filter.score(string_option);
+        }
+
+        let opt = &self.options[idx];
+
+        #[cfg(feature = "fuzzy")]
+        let score = match self.fuzzy_filter {
+            true => fuzzy_filter::score(content, string_option),
+            false => (self.scorer)(content, opt, string_option, idx),
+        };
+        #[cfg(not(feature = "fuzzy"))]
+        let score = (self.scorer)(content, opt, string_option, idx);
+
+        score.filter(|score| content.is_empty() || self.config.meets_score_threshold(*score))
+    }
+
+    /// Options listed in the current view that match the filter, in the order
+    /// they are displayed.
+    fn score_options(&self) -> Vec<usize> {
+        let listed = (0..self.options.len()).filter(|i| self.view.lists(self.checked.contains(i)));
+
+        let content = match &self.input {
+            Some(input) => input.content(),
+            None => return listed.collect(),
+        };
+
+        let mut options = listed
+            .filter_map(|i| self.filter_score(i).map(|score| (i, score)))
             .collect::<Vec<(usize, i64)>>();
 
         options.sort_unstable_by_key(|(_idx, score)| Reverse(*score));
 
-        let new_scored_options = options.iter().map(|(idx, _)| *idx).collect::<Vec<usize>>();
+        if let (false, Some(max)) = (content.is_empty(), self.config.max_displayed_matches) {
+            options.truncate(max);
+        }
+
+        options.iter().map(|(idx, _)| *idx).collect()
+    }
+
+    /// Lists the options of the current view, keeping the cursor on the
+    /// highlighted option when it is still listed, or on the same row otherwise.
+    fn update_view(&mut self) -> ActionResult {
+        let skip_option_highlighted = self.is_skip_option_highlighted();
+        let highlighted = self.scored_options.get(self.cursor_index).copied();
+
+        let new_scored_options = self.score_options();
+        if self.scored_options == new_scored_options {
+            return ActionResult::Clean;
+        }
+        self.scored_options = new_scored_options;
+
+        let restored_cursor = match (skip_option_highlighted, highlighted) {
+            (true, _) => Some(self.scored_options.len()),
+            (false, Some(highlighted)) => self
+                .scored_options
+                .iter()
+                .position(|idx| *idx == highlighted),
+            (false, None) => None,
+        };
+
+        self.cursor_index = restored_cursor
+            .unwrap_or_else(|| min(self.cursor_index, self.choice_count().saturating_sub(1)));
+
+        ActionResult::NeedsRedraw
+    }
+
+    /// Characters of each listed option matched by the fuzzy filter, to be
+    /// highlighted, or nothing when the fuzzy filter is not in use.
+    #[cfg(feature = "fuzzy")]
+    fn page_matches(&self, options: &[ListOption<&T>]) -> Vec<Vec<usize>> {
+        #[cfg(feature = "regex")]
+        if self.regex_filter.is_enabled() {
+            return vec![];
+        }
+
+        match (self.fuzzy_filter, &self.input) {
+            (true, Some(input)) if !input.is_empty() => options
+                .iter()
+                .map(|option| {
+                    fuzzy_filter::matched_indices(
+                        input.content(),
+                        &self.string_options[option.index],
+                    )
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    fn run_scorer(&mut self) {
+        #[cfg(feature = "regex")]
+        if let (true, Some(input)) = (self.regex_filter.is_enabled(), &self.input) {
+            self.regex_filter.update(input.content());
+        }
+
+        let new_scored_options = self.score_options();
 
         if self.scored_options == new_scored_options {
             return;
@@ -233,8 +707,8 @@ where
 
         if self.config.reset_cursor {
             let _ = self.update_cursor_position(0);
-        } else if self.scored_options.len() <= self.cursor_index {
-            let _ = self.update_cursor_position(self.scored_options.len().saturating_sub(1));
+        } else if self.choice_count() <= self.cursor_index {
+            let _ = self.update_cursor_position(self.choice_count().saturating_sub(1));
         }
     }
 }
@@ -246,7 +720,7 @@ where
 {
     type Config = MultiSelectConfig;
     type InnerAction = MultiSelectPromptAction;
-    type Output = Vec<ListOption<T>>;
+    type Output = FilteredAnswer<Vec<ListOption<T>>>;
 
     fn message(&self) -> &str {
         self.message
@@ -256,17 +730,91 @@ where
         &self.config
     }
 
-    fn format_answer(&self, answer: &Vec<ListOption<T>>) -> String {
-        let refs: Vec<ListOption<&T>> = answer.iter().map(ListOption::as_ref).collect();
-        (self.formatter)(&refs)
+    fn format_answer(&self, answer: &FilteredAnswer<Vec<ListOption<T>>>) -> String {
+        if let (Some(skip_option), true) = (self.skip_option, answer.answer.is_empty()) {
+            return skip_option.to_owned();
+        }
+
+        let refs: Vec<ListOption<&T>> = answer.answer.iter().map(ListOption::as_ref).collect();
+
+        if let Some(formatted) = &self.context_formatted_answer {
+            return formatted.clone();
+        }
+
+        match self.filter_formatter {
+            Some(formatter) => formatter(&refs, &answer.filter),
+            None => (self.formatter)(&refs),
+        }
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    fn show_remaining_time(&self) -> bool {
+        self.show_remaining_time
+    }
+
+    fn show_elapsed_time(&self) -> bool {
+        self.show_elapsed_time
+    }
+
+    fn tick_interval(&self) -> Option<Duration> {
+        #[cfg(feature = "async")]
+        if self.option_stream.is_some() {
+            return Some(OPTION_STREAM_POLL_INTERVAL);
+        }
+
+        self.refresh.map(|(interval, _)| interval)
+    }
+
+    fn tick(&mut self) -> InquireResult<ActionResult> {
+        #[cfg(feature = "async")]
+        if self.option_stream.is_some() {
+            return Ok(self.receive_streamed_options());
+        }
+
+        self.refresh_options()
     }
 
     fn setup(&mut self) -> InquireResult<()> {
         self.run_scorer();
+        self.update_scroll_window();
         Ok(())
     }
 
-    fn submit(&mut self) -> InquireResult<Option<Vec<ListOption<T>>>> {
+    /// Esc while typing a search query only leaves the search.
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        if self.config.searching {
+            let _ = self.set_searching(false);
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    fn selected_skip_option(&self) -> Option<&str> {
+        match self.is_skip_option_highlighted() && !self.config.searching {
+            true => self.skip_option,
+            false => None,
+        }
+    }
+
+    fn can_submit(&self) -> InquireResult<bool> {
+        if self.config.searching {
+            return Ok(true);
+        }
+
+        Ok(self.validate_current_answer()? == Validation::Valid)
+    }
+
+    /// Enter while typing a search query only confirms the search.
+    fn submit(&mut self) -> InquireResult<Option<FilteredAnswer<Vec<ListOption<T>>>>> {
+        if self.config.searching {
+            let _ = self.set_searching(false);
+            return Ok(None);
+        }
+
         let answer = match self.validate_current_answer()? {
             Validation::Valid => Some(self.get_final_answer()),
             Validation::Invalid(msg) => {
@@ -279,6 +827,8 @@ where
     }
 
     fn handle(&mut self, action: MultiSelectPromptAction) -> InquireResult<ActionResult> {
+        let previously_checked = self.on_selection_change.map(|_| self.checked.clone());
+
         let result = match action {
             MultiSelectPromptAction::MoveUp => self.move_cursor_up(1, true),
             MultiSelectPromptAction::MoveDown => self.move_cursor_down(1, true),
@@ -294,17 +844,55 @@ where
                 for idx in &self.scored_options {
                     self.checked.insert(*idx);
                 }
+                self.untouched_defaults.clear();
                 ActionResult::NeedsRedraw
             }
             MultiSelectPromptAction::ClearSelections => {
                 self.checked.clear();
+                self.untouched_defaults.clear();
+                ActionResult::NeedsRedraw
+            }
+            MultiSelectPromptAction::CycleView => {
+                self.view = self.view.next();
+                self.update_view();
                 ActionResult::NeedsRedraw
             }
+            MultiSelectPromptAction::CompleteFilter => self.complete_filter(),
+            MultiSelectPromptAction::RecallPreviousFilter => self.recall_filter(true),
+            MultiSelectPromptAction::RecallNextFilter => self.recall_filter(false),
+            #[cfg(feature = "regex")]
+            MultiSelectPromptAction::ToggleRegexFilter => match &self.input {
+                Some(input) => {
+                    self.regex_filter.toggle(input.content());
+                    self.run_scorer();
+                    ActionResult::NeedsRedraw
+                }
+                None => ActionResult::Clean,
+            },
+            MultiSelectPromptAction::StartSearch => {
+                self.search_input = Input::new();
+                self.set_searching(true)
+            }
+            MultiSelectPromptAction::SearchInput(input_action) => {
+                let result = self.search_input.handle(input_action);
+
+                if let InputActionResult::ContentChanged = result {
+                    let _ = self.jump_to_match(self.cursor_index, true);
+                }
+
+                result.into()
+            }
+            MultiSelectPromptAction::NextMatch => self.jump_to_match(self.cursor_index + 1, true),
+            MultiSelectPromptAction::PreviousMatch => {
+                let from = self.cursor_index + self.scored_options.len().saturating_sub(1);
+                self.jump_to_match(from, false)
+            }
             MultiSelectPromptAction::FilterInput(input_action) => match self.input.as_mut() {
                 Some(input) => {
                     let result = input.handle(input_action);
 
                     if let InputActionResult::ContentChanged = result {
+                        self.filter_history_position = None;
                         self.run_scorer();
                     }
 
@@ -314,7 +902,37 @@ where
             },
         };
 
-        let result = self.clear_input_if_needed(action).merge(result);
+        // checking or unchecking options is what makes a filter worth recalling
+        if matches!(
+            action,
+            MultiSelectPromptAction::ToggleCurrentOption
+                | MultiSelectPromptAction::SelectAll
+                | MultiSelectPromptAction::ClearSelections
+        ) {
+            self.record_filter();
+        }
+
+        let mut result = self.clear_input_if_needed(action).merge(result);
+
+        // checking or unchecking options moves them in or out of the view
+        if matches!(
+            action,
+            MultiSelectPromptAction::ToggleCurrentOption
+                | MultiSelectPromptAction::SelectAll
+                | MultiSelectPromptAction::ClearSelections
+        ) {
+            result = result.merge(self.update_view());
+        }
+
+        self.update_scroll_window();
+
+        if let (Some(callback), Some(previously_checked)) =
+            (self.on_selection_change, previously_checked)
+        {
+            if previously_checked != self.checked {
+                callback(&self.checked_options());
+            }
+        }
 
         Ok(result)
     }
@@ -326,7 +944,44 @@ where
             backend.render_error_message(err)?;
         }
 
-        backend.render_multiselect_prompt(prompt, self.input.as_ref())?;
+        let visible_input = match self.config.hide_filter_input {
+            true => None,
+            false => self.input.as_ref(),
+        };
+        backend.render_multiselect_prompt(prompt, visible_input)?;
+
+        if let Some(input) = &self.input {
+            let hidden_filter = self.config.hide_filter_input && !input.is_empty();
+            let show_filter_header = self.config.show_filter_header || hidden_filter;
+
+            #[cfg(feature = "regex")]
+            let show_filter_header = show_filter_header && !self.regex_filter.is_enabled();
+
+            #[cfg(feature = "regex")]
+            if self.regex_filter.is_enabled() {
+                backend.render_regex_filter_header(
+                    input.content(),
+                    self.scored_options.len(),
+                    self.regex_filter.is_invalid(),
+                )?;
+            }
+
+            if show_filter_header {
+                backend.render_filter_header(input.content(), self.scored_options.len())?;
+            }
+        }
+
+        if self.config.searching || !self.search_input.is_empty() {
+            let matches = (0..self.scored_options.len())
+                .filter(|position| self.matches_search(*position))
+                .count();
+
+            backend.render_search_header(&self.search_input, self.config.searching, matches)?;
+        }
+
+        if self.view != MultiSelectView::All {
+            backend.render_view_header(self.view, self.scored_options.len())?;
+        }
 
         let choices = self
             .scored_options
@@ -335,9 +990,52 @@ where
             .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
             .collect::<Vec<ListOption<&T>>>();
 
-        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+        let mut page = match self.config.scrolloff {
+            Some(_) => paginate_from(
+                self.config.visible_options(),
+                &choices,
+                Some(self.option_cursor()),
+                self.scroll_window_start,
+            ),
+            None => paginate(
+                self.config.visible_options(),
+                &choices,
+                Some(self.option_cursor()),
+            ),
+        };
+
+        let skip_option_highlighted = self.is_skip_option_highlighted();
+        if skip_option_highlighted {
+            page.cursor = None;
+        }
+
+        #[cfg(feature = "fuzzy")]
+        let matches = self.page_matches(page.content);
+        #[cfg(not(feature = "fuzzy"))]
+        let matches = vec![];
 
-        backend.render_options(page, &self.checked)?;
+        match self.option_formatter {
+            Some(formatter) => {
+                let (formatted, styles) =
+                    format_page(&page, formatter, |idx| self.checked.contains(&idx));
+                let page = page.with_content(&formatted);
+                backend.render_options(page, &self.checked, &[], &styles)?;
+            }
+            None => backend.render_options(page, &self.checked, &matches, &[])?,
+        }
+
+        #[cfg(feature = "async")]
+        if self.option_stream.is_some() {
+            backend.render_loading_indicator(self.loading_ticks)?;
+        }
+
+        if let Some(skip_option) = self.skip_option {
+            backend.render_skip_option(skip_option, skip_option_highlighted)?;
+        }
+
+        if let Some(footer) = self.footer {
+            backend.render_footer(&footer(&self.checked_options()))?;
+        }
 
         if let Some(help_message) = self.help_message {
             backend.render_help_message(help_message)?;
@@ -346,3 +1044,10 @@ where
         Ok(())
     }
 }
+
+fn to_string_options<T: Display>(options: &[T]) -> Vec<String> {
+    options
+        .iter()
+        .map(|option| option.to_string().ansi_stripped_chars().collect())
+        .collect()
+}
@@ -255,3 +255,720 @@ fn keep_filter_false_should_reset_option_list() {
     let expected_answer = vec![ListOption::new(0, 1), ListOption::new(2, 3)];
     assert_eq!(expected_answer, ans);
 }
+
+#[derive(Default)]
+struct MemorySelectionStore(std::cell::RefCell<Vec<(String, Vec<String>)>>);
+
+impl crate::persistence::SelectionStore for MemorySelectionStore {
+    fn load(&self, prompt_id: &str) -> Result<Option<Vec<String>>, crate::CustomUserError> {
+        Ok(self
+            .0
+            .borrow()
+            .iter()
+            .find(|(id, _)| id == prompt_id)
+            .map(|(_, selections)| selections.clone()))
+    }
+
+    fn save(&self, prompt_id: &str, selections: &[String]) -> Result<(), crate::CustomUserError> {
+        let mut entries = self.0.borrow_mut();
+        entries.retain(|(id, _)| id != prompt_id);
+        entries.push((prompt_id.to_string(), selections.to_vec()));
+        Ok(())
+    }
+}
+
+#[test]
+fn selections_are_remembered_across_invocations() {
+    let store = MemorySelectionStore::default();
+
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec!["a", "b", "c"])
+        .with_selection_store("id", &store)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(vec![ListOption::new(1, "b")], ans);
+
+    // options changed between runs, selections are matched by their string value
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = MultiSelect::new("Question", vec!["b", "c", "d"])
+        .with_default(&[2])
+        .with_selection_store("id", &store)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(vec![ListOption::new(0, "b")], ans);
+}
+
+struct BrokenSelectionStore;
+
+impl crate::persistence::SelectionStore for BrokenSelectionStore {
+    fn load(&self, _prompt_id: &str) -> Result<Option<Vec<String>>, crate::CustomUserError> {
+        Err("unreadable".into())
+    }
+
+    fn save(&self, _prompt_id: &str, _selections: &[String]) -> Result<(), crate::CustomUserError> {
+        Err("read-only".into())
+    }
+}
+
+#[test]
+fn selection_store_failures_do_not_replace_the_answer() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec!["a", "b", "c"])
+        .with_default(&[0])
+        .with_selection_store("id", &BrokenSelectionStore)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, "a"), ListOption::new(1, "b")], ans);
+}
+
+#[test]
+fn starting_selections_accept_any_collection_of_indexes() {
+    let options = vec!["a", "b", "c", "d"];
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let selections: std::collections::HashSet<usize> = [3, 1].iter().copied().collect();
+    let ans = MultiSelect::new("Question", options.clone())
+        .with_starting_selections(selections)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(vec![ListOption::new(1, "b"), ListOption::new(3, "d")], ans);
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let ans = MultiSelect::new("Question", options)
+        .with_starting_selections(0..2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(vec![ListOption::new(0, "a"), ListOption::new(1, "b")], ans);
+}
+
+#[test]
+fn default_selections_hidden_by_filter_are_kept_by_default() {
+    let mut backend = fake_backend(vec![
+        Key::Char('1', KeyModifiers::NONE), // filter hides the default selections
+        Key::Char(' ', KeyModifiers::NONE), // select option 1
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec![1, 2, 3])
+        .with_starting_selections(vec![1, 2])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let expected_answer = vec![
+        ListOption::new(0, 1),
+        ListOption::new(1, 2),
+        ListOption::new(2, 3),
+    ];
+    assert_eq!(expected_answer, ans);
+}
+
+#[test]
+fn default_selections_hidden_by_filter_are_dropped_when_disabled() {
+    let mut backend = fake_backend(vec![
+        Key::Char('2', KeyModifiers::NONE), // filter to option 2
+        Key::Char(' ', KeyModifiers::NONE), // unselect and
+        Key::Char(' ', KeyModifiers::NONE), // reselect option 2
+        Key::Backspace,
+        Key::Char('1', KeyModifiers::NONE), // filter to option 1, hiding 2 and 3
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec![1, 2, 3])
+        .with_starting_selections(vec![1, 2])
+        .with_keep_default_selections_hidden_by_filter(false)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // option 3 was never touched by the user and is hidden, option 2 was
+    // explicitly selected
+    assert_eq!(vec![ListOption::new(1, 2)], ans);
+}
+
+#[test]
+fn validator_receives_prompt_context() {
+    use crate::validator::{MultiOptionContext, MultiOptionContextValidator, Validation};
+
+    let mut backend = fake_backend(vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE), // select one of the two matches
+        Key::Enter,
+        Key::Right(KeyModifiers::NONE), // select all matches
+        Key::Enter,
+    ]);
+
+    let validator = MultiOptionContextValidator::new(
+        |input: &[ListOption<&&str>], context: &MultiOptionContext<'_, &str>| {
+            assert_eq!("Animals:", context.message);
+            assert_eq!(3, context.options.len());
+
+            let matching = context
+                .options
+                .iter()
+                .filter(|o| o.value.contains(context.filter.unwrap_or_default()))
+                .count();
+
+            match input.len() == matching {
+                true => Ok(Validation::Valid),
+                false => Ok(Validation::Invalid(
+                    format!("Select all {} matching options", matching).into(),
+                )),
+            }
+        },
+    );
+
+    let ans = MultiSelect::new("Animals:", vec!["ant", "bee", "axolotl"])
+        .with_keep_filter(true)
+        .with_validator(validator)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![ListOption::new(0, "ant"), ListOption::new(2, "axolotl")],
+        ans
+    );
+}
+
+#[test]
+fn filter_text_is_returned_and_passed_to_filter_formatter() {
+    let events = vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE), // select all matches
+        Key::Enter,
+    ];
+    let terminal = crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output();
+    let mut backend = crate::ui::Backend::new(
+        events.into_iter(),
+        terminal,
+        crate::ui::RenderConfig::empty(),
+    )
+    .unwrap();
+
+    let ans = MultiSelect::new("Animals:", vec!["ant", "bee", "cat"])
+        .with_filter_formatter(&|opts, filter| format!("{} matching '{}'", opts.len(), filter))
+        .filtered_prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![ListOption::new(0, "ant"), ListOption::new(2, "cat")],
+        ans.answer
+    );
+    assert_eq!("a", ans.filter);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("Animals: 2 matching 'a'"));
+}
+
+#[test]
+fn filter_header_shows_filter_text_and_match_count() {
+    let events = vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Char('n', KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let terminal = crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output();
+    let mut backend = crate::ui::Backend::new(
+        events.into_iter(),
+        terminal,
+        crate::ui::RenderConfig::empty(),
+    )
+    .unwrap();
+
+    MultiSelect::new("Animals:", vec!["ant", "antelope", "bee"])
+        .with_show_filter_header(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("3 options"));
+    assert!(output.contains("Filter: a (2 matches)"));
+    assert!(output.contains("Filter: an (2 matches)"));
+}
+
+#[test]
+fn tab_completes_filter_to_longest_common_prefix() {
+    // "pod/" matches the first two options, which are then all selected
+    let mut backend = fake_backend(vec![
+        Key::Char('p', KeyModifiers::NONE),
+        Key::Tab,
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["pod/web-1", "pod/web-2", "service/web"];
+
+    let ans = MultiSelect::new("Resources:", options)
+        .with_scorer(&|input, _, value, _| value.starts_with(input).then_some(0))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(0, "pod/web-1"),
+            ListOption::new(1, "pod/web-2")
+        ],
+        ans
+    );
+}
+
+#[test]
+fn skip_option_skips_the_prompt() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Toppings:", vec!["cheese", "olives"])
+        .with_skip_option("(none)")
+        .with_validator(crate::min_length!(1))
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(crate::InquireError::OperationSkipped)));
+}
+
+#[test]
+fn selection_change_callback_receives_checked_options() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let changes = std::cell::RefCell::new(Vec::<Vec<&str>>::new());
+    let on_change = |checked: &[ListOption<&&'static str>]| {
+        let checked = checked.iter().map(|o| *o.value).collect::<Vec<_>>();
+        changes.borrow_mut().push(checked);
+    };
+
+    MultiSelect::new("Toppings:", vec!["cheese", "olives", "onions"])
+        .with_on_selection_change(&on_change)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // moving the cursor and selecting all twice do not change the selection
+    assert_eq!(
+        vec![
+            vec!["cheese"],
+            vec!["cheese", "onions"],
+            vec!["cheese", "olives", "onions"],
+            vec![],
+        ],
+        changes.into_inner()
+    );
+}
+
+#[test]
+fn footer_is_computed_from_checked_options() {
+    let keys = vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let mut backend = crate::ui::Backend::new(
+        keys.into_iter(),
+        crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output(),
+        crate::ui::RenderConfig::empty(),
+    )
+    .unwrap();
+
+    let sizes = [120, 62, 17];
+    let footer = |checked: &[ListOption<&&str>]| {
+        let total: u32 = checked.iter().map(|option| sizes[option.index]).sum();
+        format!("Total download: {} MB", total)
+    };
+
+    MultiSelect::new("Packages:", vec!["gcc", "make", "curl"])
+        .with_footer(&footer)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+
+    assert!(output.contains("Total download: 0 MB"));
+    assert!(output.contains("Total download: 120 MB"));
+    assert!(output.contains("Total download: 182 MB"));
+}
+
+#[test]
+fn quiz_is_correct_only_when_exactly_the_correct_options_are_chosen() {
+    let space = Key::Char(' ', KeyModifiers::NONE);
+    let down = Key::Down(KeyModifiers::NONE);
+    let options = vec!["2", "3", "4", "5"];
+
+    let mut backend = fake_backend(vec![space, down, space, Key::Enter]);
+    let ans = MultiSelect::new("Which are prime?", options.clone())
+        .with_correct_answers(&[0, 1, 3])
+        .quiz_prompt_with_backend(&mut backend)
+        .unwrap();
+    assert!(!ans.correct);
+
+    let mut backend = fake_backend(vec![space, down, space, down, down, space, Key::Enter]);
+    let ans = MultiSelect::new("Which are prime?", options)
+        .with_correct_answers(&[0, 1, 3])
+        .quiz_prompt_with_backend(&mut backend)
+        .unwrap();
+    assert!(ans.correct);
+    assert_eq!(
+        vec!["2", "3", "5"],
+        ans.answer.iter().map(|o| o.value).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn quiz_marks_the_correct_and_the_wrongly_chosen_options() {
+    let mut backend = crate::ui::Backend::new(
+        vec![
+            Key::Down(KeyModifiers::NONE),
+            Key::Down(KeyModifiers::NONE),
+            Key::Char(' ', KeyModifiers::NONE),
+            Key::Enter,
+        ]
+        .into_iter(),
+        crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output(),
+        crate::ui::RenderConfig::empty(),
+    )
+    .unwrap();
+
+    MultiSelect::new("Which are prime?", vec!["2", "3", "4"])
+        .with_correct_answers(&[0, 1])
+        .quiz_prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("  ✓ 2"));
+    assert!(output.contains("  ✓ 3"));
+    assert!(output.contains("> ✗ 4"));
+}
+
+#[test]
+fn refresh_keeps_the_cursor_and_the_checked_options() {
+    let mut backend = crate::prompts::test::idle_fake_backend(vec![
+        Some(Key::Down(KeyModifiers::NONE)),
+        Some(Key::Char(' ', KeyModifiers::NONE)),
+        None,
+        Some(Key::Down(KeyModifiers::NONE)),
+        Some(Key::Char(' ', KeyModifiers::NONE)),
+        Some(Key::Enter),
+    ]);
+
+    let provider: crate::type_aliases::OptionProvider<'_, &str> =
+        &|| Ok(vec!["pod-b", "pod-c", "pod-d"]);
+
+    let ans = MultiSelect::new("Pods", vec!["pod-a", "pod-b", "pod-c"])
+        .with_refresh(std::time::Duration::from_millis(200), provider)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![ListOption::new(0, "pod-b"), ListOption::new(1, "pod-c")],
+        ans
+    );
+}
+
+#[test]
+fn default_keys_and_refresh_identify_options_by_their_key() {
+    let mut backend = crate::prompts::test::idle_fake_backend(vec![None, Some(Key::Enter)]);
+
+    let option_key: crate::type_aliases::OptionKey<'_, String> =
+        &|option| option.split(' ').next().unwrap().to_string();
+    let provider: crate::type_aliases::OptionProvider<'_, String> = &|| {
+        Ok(vec![
+            "db (ready)".to_string(),
+            "worker (ready)".to_string(),
+            "api (ready)".to_string(),
+        ])
+    };
+
+    let options = vec!["api (pending)".to_string(), "worker (pending)".to_string()];
+    let ans = MultiSelect::new("Pods", options)
+        .with_option_key(option_key)
+        .with_default_keys(&["api", "cache"])
+        .with_refresh(std::time::Duration::from_millis(200), provider)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(2, "api (ready)".to_string())], ans);
+}
+
+#[test]
+fn options_and_default_keys_can_be_owned_strings() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let options: Vec<String> = (1..=3).map(|n| format!("node-{n}")).collect();
+    let default_keys = vec![options[2].clone()];
+
+    let ans = MultiSelect::new("Nodes", options)
+        .with_default_keys(&default_keys)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(2, "node-3".to_string())], ans);
+}
+
+#[test]
+fn cycling_views_lists_checked_or_unchecked_options_only() {
+    let ctrl_t = Key::Char('t', KeyModifiers::CONTROL);
+    let mut backend = fake_backend(vec![
+        ctrl_t, // "b" is not listed, the cursor stays on the second row, "c"
+        Key::Char(' ', KeyModifiers::NONE), // unchecks "c", "d" moves under the cursor
+        Key::Char(' ', KeyModifiers::NONE), // unchecks "d"
+        ctrl_t, // lists "b", "c" and "d"
+        ctrl_t, // back to all options, with the cursor still on "b"
+        Key::Char(' ', KeyModifiers::NONE), // checks "b"
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec!["a", "b", "c", "d"])
+        .with_default(&[0, 2, 3])
+        .with_starting_cursor(1)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, "a"), ListOption::new(1, "b")], ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("Checked options (3)"));
+    assert!(output.contains("Unchecked options (3)"));
+}
+
+#[test]
+fn defaults_hidden_by_the_view_stay_in_the_answer() {
+    let ctrl_t = Key::Char('t', KeyModifiers::CONTROL);
+    let mut backend = fake_backend(vec![ctrl_t, ctrl_t, Key::Enter]);
+
+    let ans = MultiSelect::new("Question", vec!["a", "b", "c"])
+        .with_default(&[0, 2])
+        .with_keep_default_selections_hidden_by_filter(false)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, "a"), ListOption::new(2, "c")], ans);
+}
+
+#[test]
+fn search_mode_jumps_between_matches_without_filtering() {
+    let mut backend = fake_backend(vec![
+        Key::Char('/', KeyModifiers::NONE),
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Enter,
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char('n', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["apple", "banana", "cherry", "avocado", "blueberry"];
+    let ans = MultiSelect::new("Fruits", options)
+        .with_search_mode(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(1, "banana"),
+            ListOption::new(4, "blueberry")
+        ],
+        ans
+    );
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn regex_filter_matches_options_against_the_pattern() {
+    let mut backend = fake_backend(vec![
+        Key::Char('.', KeyModifiers::NONE),
+        Key::Char('[', KeyModifiers::NONE),
+        Key::Char('0', KeyModifiers::NONE),
+        Key::Char('-', KeyModifiers::NONE),
+        Key::Char('9', KeyModifiers::NONE),
+        Key::Char(']', KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["app.log", "app.1", "db.log", "db.2"];
+    let ans = MultiSelect::new("Files", options)
+        .with_regex_filter(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![ListOption::new(1, "app.1"), ListOption::new(3, "db.2")],
+        ans
+    );
+}
+
+#[test]
+fn alt_arrows_recall_the_filters_used_while_checking_options() {
+    let mut keys = vec![];
+    for filter in ["ban", "che"] {
+        keys.extend(filter.chars().map(|c| Key::Char(c, KeyModifiers::NONE)));
+        keys.push(Key::Char(' ', KeyModifiers::NONE));
+    }
+    keys.extend([
+        Key::Up(KeyModifiers::ALT),         // "che"
+        Key::Up(KeyModifiers::ALT),         // "ban"
+        Key::Up(KeyModifiers::ALT),         // still "ban", the oldest filter
+        Key::Down(KeyModifiers::ALT),       // "che"
+        Key::Down(KeyModifiers::ALT),       // empty filter
+        Key::Up(KeyModifiers::ALT),         // "che"
+        Key::Up(KeyModifiers::ALT),         // "ban"
+        Key::Char(' ', KeyModifiers::NONE), // unchecks "banana"
+        Key::Enter,
+    ]);
+    let mut backend = fake_backend(keys);
+
+    let ans = MultiSelect::new("Fruits", vec!["apple", "banana", "cherry", "date"])
+        .with_keep_filter(false)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(2, "cherry")], ans);
+}
+
+#[test]
+fn max_displayed_matches_only_applies_while_filtering() {
+    let mut backend = fake_backend(vec![
+        Key::Right(KeyModifiers::NONE), // checks every option, no filter typed
+        Key::Char('x', KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),  // clears the selection
+        Key::Right(KeyModifiers::NONE), // checks the single listed match
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Files", vec!["x1", "y", "x2", "x3"])
+        .with_max_displayed_matches(1)
+        .with_keep_filter(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(1, ans.len());
+    assert!(ans[0].value.starts_with('x'));
+}
+
+#[test]
+fn defaults_matching_the_filter_stay_in_the_answer_beyond_max_displayed_matches() {
+    let mut backend = fake_backend(vec![Key::Char('x', KeyModifiers::NONE), Key::Enter]);
+
+    let ans = MultiSelect::new("Files", vec!["x1", "y", "x2", "x3"])
+        .with_default(&[1, 2, 3])
+        .with_max_displayed_matches(1)
+        .with_keep_default_selections_hidden_by_filter(false)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![ListOption::new(2, "x2"), ListOption::new(3, "x3")],
+        ans
+    );
+}
+
+#[test]
+fn context_formatter_receives_every_option_and_the_original_indexes() {
+    let events = vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::End,
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let terminal = crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output();
+    let mut backend = crate::ui::Backend::new(
+        events.into_iter(),
+        terminal,
+        crate::ui::RenderConfig::empty(),
+    )
+    .unwrap();
+
+    let formatter: crate::formatter::MultiOptionContextFormatter<'_, &str> = &|opts, context| {
+        let positions: Vec<String> = opts.iter().map(|o| (o.index + 1).to_string()).collect();
+        format!(
+            "{}/{} modules selected ({})",
+            opts.len(),
+            context.options.len(),
+            positions.join(", ")
+        )
+    };
+
+    let ans = MultiSelect::new("Modules:", vec!["core", "net", "fs", "ui"])
+        .with_context_formatter(formatter)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![ListOption::new(1, "net"), ListOption::new(3, "ui")],
+        ans
+    );
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("Modules: 2/4 modules selected (2, 4)"));
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn option_stream_appends_options_keeping_the_checked_ones() {
+    use crate::prompts::option_stream::test::Trickle;
+
+    let mut backend = crate::prompts::test::idle_fake_backend(vec![
+        Some(Key::Char(' ', KeyModifiers::NONE)),
+        None,
+        Some(Key::Down(KeyModifiers::NONE)),
+        Some(Key::Down(KeyModifiers::NONE)),
+        Some(Key::Char(' ', KeyModifiers::NONE)),
+        Some(Key::Enter),
+    ]);
+
+    let stream = Trickle(vec![Some("pod-b"), Some("pod-c")].into());
+
+    let ans = MultiSelect::new("Pods", vec!["pod-a"])
+        .with_option_stream(stream)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![ListOption::new(0, "pod-a"), ListOption::new(2, "pod-c")],
+        ans
+    );
+}
+
+#[test]
+fn option_formatter_receives_whether_options_are_checked() {
+    use crate::formatter::LiveOptionFormatter;
+    use crate::ui::Styled;
+
+    let mut backend = fake_backend(vec![Key::Char(' ', KeyModifiers::NONE), Key::Enter]);
+
+    let formatter: LiveOptionFormatter<'_, &str> = &|pod, state| match state.checked {
+        true => Styled::new(format!("{} (restarting)", pod)),
+        false => Styled::new(String::from(*pod)),
+    };
+
+    let ans = MultiSelect::new("Pods", vec!["worker", "api"])
+        .with_option_formatter(formatter)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, "worker")], ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("worker (restarting)"));
+    assert!(!output.contains("api (restarting)"));
+}
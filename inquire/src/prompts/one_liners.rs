@@ -1,4 +1,6 @@
-use crate::{error::InquireResult, Confirm, CustomType, Password, Text};
+use std::fmt::Display;
+
+use crate::{error::InquireResult, Confirm, CustomType, MultiSelect, Password, Select, Text};
 
 /// This function is a helpful one-liner to prompt the user for the confirmation of an action.
 ///
@@ -122,8 +124,8 @@ where
 /// # Returns
 ///
 /// * `InquireResult<NaiveDate>`: An enum that represents the result of the prompt operation. If the operation is successful,
-///  it returns `InquireResult::Ok(NaiveDate)` where NaiveDate's value is the date selected by the user. If the operation
-/// encounters an error, it returns `InquireResult::Err(InquireError)`.
+///   it returns `InquireResult::Ok(NaiveDate)` where NaiveDate's value is the date selected by the user. If the operation
+///   encounters an error, it returns `InquireResult::Err(InquireError)`.
 ///
 /// # Example
 ///
@@ -158,8 +160,8 @@ where
 /// # Returns
 ///
 /// * `InquireResult<f64>`: An enum that represents the result of the prompt operation. If the operation is successful,
-///  it returns `InquireResult::Ok(f64)` where f64 is the number parsed from the user's input. If the operation
-/// encounters an error, it returns `InquireResult::Err(InquireError)`.
+///   it returns `InquireResult::Ok(f64)` where f64 is the number parsed from the user's input. If the operation
+///   encounters an error, it returns `InquireResult::Err(InquireError)`.
 ///
 /// # Example
 ///
@@ -193,8 +195,8 @@ where
 /// # Returns
 ///
 /// * `InquireResult<f32>`: An enum that represents the result of the prompt operation. If the operation is successful,
-///  it returns `InquireResult::Ok(f32)` where f32 is the number parsed from the user's input. If the operation
-/// encounters an error, it returns `InquireResult::Err(InquireError)`.
+///   it returns `InquireResult::Ok(f32)` where f32 is the number parsed from the user's input. If the operation
+///   encounters an error, it returns `InquireResult::Err(InquireError)`.
 ///
 /// # Example
 ///
@@ -228,8 +230,8 @@ where
 /// # Returns
 ///
 /// * `InquireResult<u64>`: An enum that represents the result of the prompt operation. If the operation is successful,
-///  it returns `InquireResult::Ok(u64)` where u64 is the number parsed from the user's input. If the operation
-/// encounters an error, it returns `InquireResult::Err(InquireError)`.
+///   it returns `InquireResult::Ok(u64)` where u64 is the number parsed from the user's input. If the operation
+///   encounters an error, it returns `InquireResult::Err(InquireError)`.
 ///
 /// # Example
 ///
@@ -263,8 +265,8 @@ where
 /// # Returns
 ///
 /// * `InquireResult<u32>`: An enum that represents the result of the prompt operation. If the operation is successful,
-///  it returns `InquireResult::Ok(u32)` where u32 is the number parsed from the user's input. If the operation
-/// encounters an error, it returns `InquireResult::Err(InquireError)`.
+///   it returns `InquireResult::Ok(u32)` where u32 is the number parsed from the user's input. If the operation
+///   encounters an error, it returns `InquireResult::Err(InquireError)`.
 ///
 /// # Example
 ///
@@ -298,8 +300,8 @@ where
 /// # Returns
 ///
 /// * `InquireResult<usize>`: An enum that represents the result of the prompt operation. If the operation is successful,
-///  it returns `InquireResult::Ok(usize)` where usize is the number parsed from the user's input. If the operation
-/// encounters an error, it returns `InquireResult::Err(InquireError)`.
+///   it returns `InquireResult::Ok(usize)` where usize is the number parsed from the user's input. If the operation
+///   encounters an error, it returns `InquireResult::Err(InquireError)`.
 ///
 /// # Example
 ///
@@ -333,8 +335,8 @@ where
 /// # Returns
 ///
 /// * `InquireResult<u128>`: An enum that represents the result of the prompt operation. If the operation is successful,
-///  it returns `InquireResult::Ok(u128)` where u128 is the number parsed from the user's input. If the operation
-/// encounters an error, it returns `InquireResult::Err(InquireError)`.
+///   it returns `InquireResult::Ok(u128)` where u128 is the number parsed from the user's input. If the operation
+///   encounters an error, it returns `InquireResult::Err(InquireError)`.
 ///
 /// # Example
 ///
@@ -354,3 +356,147 @@ where
 {
     CustomType::<u128>::new(message.as_ref()).prompt()
 }
+
+/// This function is a helpful one-liner to prompt the user for a number and parse it to i32.
+///
+/// Under the hood, it is equivalent to calling `inquire::CustomType::<i32>::new(message).prompt()`.
+/// See the documentation for [`inquire::CustomType`] for more information on its behavior.
+///
+/// # Arguments
+///
+/// * `message`: A message that implements the `AsRef<str>` trait. This message will be displayed to the user
+///   when asking for input.
+///
+/// # Returns
+///
+/// * `InquireResult<i32>`: An enum that represents the result of the prompt operation. If the operation is successful,
+///   it returns `InquireResult::Ok(i32)` where i32 is the number parsed from the user's input. If the operation
+///   encounters an error, it returns `InquireResult::Err(InquireError)`.
+///
+/// # Example
+///
+/// ``` no_run
+/// # use inquire::{*, error::*};
+/// let offset = prompt_i32("Timezone offset (minutes):")?;
+/// # inquire::error::InquireResult::Ok(())
+/// ```
+///
+/// # Errors
+///
+/// This function will return an error if there is a problem interacting with the terminal, or if the user
+/// cancels the operation by pressing `Ctrl+C`.
+pub fn prompt_i32<M>(message: M) -> InquireResult<i32>
+where
+    M: AsRef<str>,
+{
+    CustomType::<i32>::new(message.as_ref()).prompt()
+}
+
+/// This function is a helpful one-liner to prompt the user for a number and parse it to i64.
+///
+/// Under the hood, it is equivalent to calling `inquire::CustomType::<i64>::new(message).prompt()`.
+/// See the documentation for [`inquire::CustomType`] for more information on its behavior.
+///
+/// # Arguments
+///
+/// * `message`: A message that implements the `AsRef<str>` trait. This message will be displayed to the user
+///   when asking for input.
+///
+/// # Returns
+///
+/// * `InquireResult<i64>`: An enum that represents the result of the prompt operation. If the operation is successful,
+///   it returns `InquireResult::Ok(i64)` where i64 is the number parsed from the user's input. If the operation
+///   encounters an error, it returns `InquireResult::Err(InquireError)`.
+///
+/// # Example
+///
+/// ``` no_run
+/// # use inquire::{*, error::*};
+/// let balance = prompt_i64("Balance adjustment:")?;
+/// # inquire::error::InquireResult::Ok(())
+/// ```
+///
+/// # Errors
+///
+/// This function will return an error if there is a problem interacting with the terminal, or if the user
+/// cancels the operation by pressing `Ctrl+C`.
+pub fn prompt_i64<M>(message: M) -> InquireResult<i64>
+where
+    M: AsRef<str>,
+{
+    CustomType::<i64>::new(message.as_ref()).prompt()
+}
+
+/// This function is a helpful one-liner to prompt the user to select one option from a list.
+///
+/// Under the hood, it is equivalent to calling `inquire::Select::new(message, options).prompt()`.
+/// See the documentation for [`inquire::Select`] for more information on its behavior.
+///
+/// # Arguments
+///
+/// * `message`: A message that implements the `AsRef<str>` trait. This message will be displayed to the user
+///   above the list of options.
+/// * `options`: The options displayed to the user, rendered through their `Display` implementation.
+///
+/// # Returns
+///
+/// * `InquireResult<T>`: An enum that represents the result of the prompt operation. If the operation is successful,
+///   it returns `InquireResult::Ok(T)` where T is the option selected by the user. If the operation encounters an
+///   error, it returns `InquireResult::Err(InquireError)`.
+///
+/// # Example
+///
+/// ``` no_run
+/// # use inquire::{*, error::*};
+/// let fruit = prompt_select("Fruit:", vec!["Apple", "Banana", "Cherry"])?;
+/// # inquire::error::InquireResult::Ok(())
+/// ```
+///
+/// # Errors
+///
+/// This function will return an error if there is a problem interacting with the terminal, if the list of options
+/// is empty, or if the user cancels the operation by pressing `Ctrl+C`.
+pub fn prompt_select<M, T>(message: M, options: Vec<T>) -> InquireResult<T>
+where
+    M: AsRef<str>,
+    T: Display,
+{
+    Select::new(message.as_ref(), options).prompt()
+}
+
+/// This function is a helpful one-liner to prompt the user to select any number of options from a list.
+///
+/// Under the hood, it is equivalent to calling `inquire::MultiSelect::new(message, options).prompt()`.
+/// See the documentation for [`inquire::MultiSelect`] for more information on its behavior.
+///
+/// # Arguments
+///
+/// * `message`: A message that implements the `AsRef<str>` trait. This message will be displayed to the user
+///   above the list of options.
+/// * `options`: The options displayed to the user, rendered through their `Display` implementation.
+///
+/// # Returns
+///
+/// * `InquireResult<Vec<T>>`: An enum that represents the result of the prompt operation. If the operation is
+///   successful, it returns `InquireResult::Ok(Vec<T>)` with the options selected by the user, in their original
+///   order. If the operation encounters an error, it returns `InquireResult::Err(InquireError)`.
+///
+/// # Example
+///
+/// ``` no_run
+/// # use inquire::{*, error::*};
+/// let toppings = prompt_multiselect("Toppings:", vec!["Cheese", "Olives", "Mushrooms"])?;
+/// # inquire::error::InquireResult::Ok(())
+/// ```
+///
+/// # Errors
+///
+/// This function will return an error if there is a problem interacting with the terminal, if the list of options
+/// is empty, or if the user cancels the operation by pressing `Ctrl+C`.
+pub fn prompt_multiselect<M, T>(message: M, options: Vec<T>) -> InquireResult<Vec<T>>
+where
+    M: AsRef<str>,
+    T: Display,
+{
+    MultiSelect::new(message.as_ref(), options).prompt()
+}
@@ -0,0 +1,125 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures_core::Stream;
+use futures_task::noop_waker_ref;
+
+/// Interval at which a pending stream of options is polled for new items.
+pub const OPTION_STREAM_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+type BoxedStream<'a, T> = Pin<Box<dyn Stream<Item = T> + 'a>>;
+
+/// Stream of options received while a list prompt is displayed.
+///
+/// The stream is polled with a no-op waker on every tick of the prompt, not on
+/// an executor, so wake-ups are ignored and futures needing a runtime context,
+/// e.g. tokio timers or sockets, never complete. It must be driven by something
+/// else, e.g. a channel fed by another thread or by a task running on the
+/// caller's runtime.
+///
+/// A stream can only be consumed once: clones are already ended.
+pub struct OptionStream<'a, T> {
+    stream: Option<BoxedStream<'a, T>>,
+}
+
+impl<'a, T> OptionStream<'a, T> {
+    pub fn new<S>(stream: S) -> Self
+    where
+        S: Stream<Item = T> + 'a,
+    {
+        Self {
+            stream: Some(Box::pin(stream)),
+        }
+    }
+
+    /// Whether the stream may still yield options.
+    pub fn is_loading(&self) -> bool {
+        self.stream.is_some()
+    }
+
+    /// Options yielded by the stream since the last poll, without blocking.
+    pub fn poll(&mut self) -> Vec<T> {
+        let mut received = vec![];
+        let mut cx = Context::from_waker(noop_waker_ref());
+
+        let mut exhausted = false;
+        if let Some(stream) = self.stream.as_mut() {
+            loop {
+                match stream.as_mut().poll_next(&mut cx) {
+                    Poll::Ready(Some(option)) => received.push(option),
+                    Poll::Ready(None) => {
+                        exhausted = true;
+                        break;
+                    }
+                    Poll::Pending => break,
+                }
+            }
+        }
+
+        if exhausted {
+            self.stream = None;
+        }
+
+        received
+    }
+}
+
+impl<'a, T> Clone for OptionStream<'a, T> {
+    fn clone(&self) -> Self {
+        Self { stream: None }
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use std::{
+        collections::VecDeque,
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    use futures_core::Stream;
+
+    use super::OptionStream;
+
+    /// Stream yielding the given items, where `None` stands for a poll in
+    /// which nothing has arrived yet.
+    pub struct Trickle<T>(pub VecDeque<Option<T>>);
+
+    impl<T: Unpin> Stream for Trickle<T> {
+        type Item = T;
+
+        fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<T>> {
+            match self.0.pop_front() {
+                Some(Some(item)) => Poll::Ready(Some(item)),
+                Some(None) => Poll::Pending,
+                None => Poll::Ready(None),
+            }
+        }
+    }
+
+    #[test]
+    fn poll_returns_the_options_received_so_far() {
+        let mut stream =
+            OptionStream::new(Trickle(vec![Some("a"), Some("b"), None, Some("c")].into()));
+
+        assert_eq!(vec!["a", "b"], stream.poll());
+        assert!(stream.is_loading());
+        assert_eq!(vec!["c"], stream.poll());
+        assert!(!stream.is_loading());
+        assert!(stream.poll().is_empty());
+    }
+
+    #[test]
+    fn clones_are_already_ended() {
+        let stream = OptionStream::new(Trickle(vec![Some("a")].into()));
+        let mut clone = stream.clone();
+
+        assert!(!clone.is_loading());
+        assert!(clone.poll().is_empty());
+        assert!(stream.is_loading());
+    }
+}
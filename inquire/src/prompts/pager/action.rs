@@ -0,0 +1,65 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
+};
+
+use super::config::PagerConfig;
+
+/// Set of actions for a PagerPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PagerPromptAction {
+    /// Scrolls the text one line up.
+    ScrollUp,
+    /// Scrolls the text one line down.
+    ScrollDown,
+    /// Scrolls the text one page up.
+    PageUp,
+    /// Scrolls the text one page down.
+    PageDown,
+    /// Scrolls to the start of the text.
+    ScrollToStart,
+    /// Scrolls to the end of the text.
+    ScrollToEnd,
+    /// Starts typing a search query.
+    StartSearch,
+    /// Action on the search query input handler.
+    SearchInput(InputAction),
+    /// Scrolls to the next line matching the search query.
+    NextMatch,
+    /// Scrolls to the previous line matching the search query.
+    PreviousMatch,
+}
+
+impl InnerAction for PagerPromptAction {
+    type Config = PagerConfig;
+
+    fn from_key(key: Key, config: &PagerConfig) -> Option<Self> {
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) => Self::ScrollUp,
+            Key::Down(KeyModifiers::NONE) => Self::ScrollDown,
+            Key::PageUp(_) => Self::PageUp,
+            Key::PageDown(_) => Self::PageDown,
+
+            key if config.searching => match InputAction::from_key(key, &()) {
+                Some(action) => Self::SearchInput(action),
+                None => return None,
+            },
+
+            Key::Char('k', KeyModifiers::NONE) if config.vim_mode => Self::ScrollUp,
+            Key::Char('j', KeyModifiers::NONE) if config.vim_mode => Self::ScrollDown,
+            Key::Char('g', KeyModifiers::NONE) if config.vim_mode => Self::ScrollToStart,
+            Key::Char('G', _) if config.vim_mode => Self::ScrollToEnd,
+
+            Key::Char(' ', KeyModifiers::NONE) => Self::PageDown,
+            Key::Home => Self::ScrollToStart,
+            Key::End => Self::ScrollToEnd,
+            Key::Char('/', KeyModifiers::NONE) => Self::StartSearch,
+            Key::Char('n', KeyModifiers::NONE) => Self::NextMatch,
+            Key::Char('N', _) => Self::PreviousMatch,
+
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,23 @@
+use crate::Pager;
+
+/// Configuration settings used in the execution of a PagerPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct PagerConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// Number of lines displayed at once.
+    pub page_size: usize,
+    /// Whether the user is currently typing a search query, in which case
+    /// most keys are forwarded to the search input.
+    pub searching: bool,
+}
+
+impl From<&Pager<'_>> for PagerConfig {
+    fn from(value: &Pager<'_>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            page_size: value.page_size,
+            searching: false,
+        }
+    }
+}
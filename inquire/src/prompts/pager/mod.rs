@@ -0,0 +1,196 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, CustomTypeBackend, PagerBackend, RenderConfig},
+    Confirm,
+};
+
+use self::prompt::PagerPrompt;
+
+/// Pager for long read-only texts, such as license terms or reports, optionally followed by a [Confirm] prompt, e.g. "Accept terms?".
+///
+/// The user scrolls through the text with the up and down arrows, page up, page down, space, home and end, just like in `less`. Pressing `/` starts a case-insensitive search: the text scrolls to the first matching line as the query is typed, enter confirms the search and esc leaves it. Afterwards, `n` and `N` jump to the next and previous matches.
+///
+/// Pressing enter closes the pager. When a confirm prompt is set, it is displayed right after and its answer is returned. Otherwise, the pager returns `true`.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt, displayed as the title of the pager.
+/// - **Text**: Text displayed to the user, required when creating the prompt.
+/// - **Confirm**: Confirm prompt displayed after the pager is closed. None by default.
+/// - **Help message**: Message displayed at the line below the pager.
+/// - **Page size**: Number of lines displayed at once, 20 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{Confirm, Pager};
+///
+/// let license = "Permission is hereby granted, free of charge, to any person obtaining a copy...";
+///
+/// let accepted = Pager::new("MIT License", license)
+///     .with_confirm(Confirm::new("Do you accept the terms?").with_default(false))
+///     .prompt();
+///
+/// match accepted {
+///     Ok(true) => println!("Installing..."),
+///     _ => println!("The terms must be accepted to continue"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Pager<'a> {
+    /// Message to be presented to the user as the title of the pager.
+    pub message: &'a str,
+
+    /// Text displayed to the user.
+    pub text: &'a str,
+
+    /// Confirm prompt displayed after the pager is closed.
+    ///
+    /// It is rendered with the render config of the pager, in place of its own.
+    pub confirm: Option<Confirm<'a>>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Number of lines displayed at once.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// scroll with j and k and jump to the start and end with g and G.
+    pub vim_mode: bool,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> Pager<'a> {
+    /// Default number of lines displayed at once.
+    pub const DEFAULT_PAGE_SIZE: usize = 20;
+
+    /// Default value of vim mode, equal to the global default value [config::DEFAULT_VIM_MODE]
+    pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to scroll, / to search, n/N for next/previous match, enter to continue");
+
+    /// Creates a [Pager] with the provided message and text, along with default configuration values.
+    pub fn new(message: &'a str, text: &'a str) -> Self {
+        Self {
+            message,
+            text,
+            confirm: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the confirm prompt displayed after the pager is closed.
+    ///
+    /// The confirm prompt is rendered with the render config of the pager, in
+    /// place of its own.
+    pub fn with_confirm(mut self, confirm: Confirm<'a>) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the number of lines displayed at once.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<bool>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the answer to the confirm prompt, or `true` when there is none.
+    pub fn prompt(self) -> InquireResult<bool> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: PagerBackend + CustomTypeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<bool> {
+        let config = (&self).into();
+        PagerPrompt::new(self.message, self.text, self.help_message, config).prompt(backend)?;
+
+        match self.confirm {
+            Some(confirm) => confirm
+                .with_render_config(self.render_config)
+                .prompt_with_backend(backend),
+            None => Ok(true),
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use crate::{
+    error::InquireResult,
+    input::{Input, InputActionResult},
+    prompts::prompt::{ActionResult, Prompt},
+    ui::PagerBackend,
+};
+
+use super::{action::PagerPromptAction, config::PagerConfig};
+
+pub struct PagerPrompt<'a> {
+    message: &'a str,
+    config: PagerConfig,
+    lines: Vec<&'a str>,
+    lowercase_lines: Vec<String>,
+    help_message: Option<&'a str>,
+    scroll_offset: usize,
+    search_input: Input,
+    current_match: Option<usize>,
+}
+
+impl<'a> PagerPrompt<'a> {
+    pub fn new(
+        message: &'a str,
+        text: &'a str,
+        help_message: Option<&'a str>,
+        config: PagerConfig,
+    ) -> Self {
+        let lines: Vec<&'a str> = text.lines().collect();
+        let lowercase_lines = lines.iter().map(|l| l.to_lowercase()).collect();
+
+        Self {
+            message,
+            config,
+            lines,
+            lowercase_lines,
+            help_message,
+            scroll_offset: 0,
+            search_input: Input::new(),
+            current_match: None,
+        }
+    }
+
+    fn max_scroll_offset(&self) -> usize {
+        self.lines.len().saturating_sub(self.config.page_size)
+    }
+
+    fn scroll_up(&mut self, qty: usize) -> ActionResult {
+        self.update_scroll_offset(self.scroll_offset.saturating_sub(qty))
+    }
+
+    fn scroll_down(&mut self, qty: usize) -> ActionResult {
+        self.update_scroll_offset(self.scroll_offset.saturating_add(qty))
+    }
+
+    fn update_scroll_offset(&mut self, new_offset: usize) -> ActionResult {
+        let new_offset = new_offset.min(self.max_scroll_offset());
+
+        if new_offset != self.scroll_offset {
+            self.scroll_offset = new_offset;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    /// Finds the first line matching the search query starting at `from`,
+    /// wrapping around the text.
+    fn find_match(&self, from: usize, forward: bool) -> Option<usize> {
+        let query = self.search_input.content().to_lowercase();
+        if query.is_empty() || self.lines.is_empty() {
+            return None;
+        }
+
+        let len = self.lines.len();
+        (0..len)
+            .map(|i| match forward {
+                true => (from + i) % len,
+                false => (from + len - (i % len)) % len,
+            })
+            .find(|i| self.lowercase_lines[*i].contains(&query))
+    }
+
+    fn jump_to_match(&mut self, from: usize, forward: bool) -> ActionResult {
+        let new_match = self.find_match(from, forward);
+        let changed = new_match != self.current_match;
+        self.current_match = new_match;
+
+        let scrolled = match new_match {
+            Some(line) => self.update_scroll_offset(line),
+            None => ActionResult::Clean,
+        };
+
+        match changed {
+            true => ActionResult::NeedsRedraw,
+            false => scrolled,
+        }
+    }
+
+    fn set_searching(&mut self, searching: bool) -> ActionResult {
+        if self.config.searching == searching {
+            return ActionResult::Clean;
+        }
+
+        self.config.searching = searching;
+        ActionResult::NeedsRedraw
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for PagerPrompt<'a>
+where
+    Backend: PagerBackend,
+{
+    type Config = PagerConfig;
+    type InnerAction = PagerPromptAction;
+    type Output = ();
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &PagerConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, _answer: &()) -> String {
+        String::new()
+    }
+
+    /// Esc while typing a search query only leaves the search.
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        if self.config.searching {
+            let _ = self.set_searching(false);
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    /// Enter while typing a search query only confirms the search.
+    fn submit(&mut self) -> InquireResult<Option<()>> {
+        if self.config.searching {
+            let _ = self.set_searching(false);
+            return Ok(None);
+        }
+
+        Ok(Some(()))
+    }
+
+    fn handle(&mut self, action: PagerPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            PagerPromptAction::ScrollUp => self.scroll_up(1),
+            PagerPromptAction::ScrollDown => self.scroll_down(1),
+            PagerPromptAction::PageUp => self.scroll_up(self.config.page_size),
+            PagerPromptAction::PageDown => self.scroll_down(self.config.page_size),
+            PagerPromptAction::ScrollToStart => self.scroll_up(usize::MAX),
+            PagerPromptAction::ScrollToEnd => self.scroll_down(usize::MAX),
+            PagerPromptAction::StartSearch => {
+                self.search_input = Input::new();
+                self.current_match = None;
+                self.set_searching(true)
+            }
+            PagerPromptAction::SearchInput(input_action) => {
+                let result = self.search_input.handle(input_action);
+
+                if let InputActionResult::ContentChanged = result {
+                    let _ = self.jump_to_match(self.scroll_offset, true);
+                }
+
+                result.into()
+            }
+            PagerPromptAction::NextMatch => {
+                let from = self
+                    .current_match
+                    .map(|line| line + 1)
+                    .unwrap_or(self.scroll_offset);
+                self.jump_to_match(from, true)
+            }
+            PagerPromptAction::PreviousMatch => {
+                let from = self
+                    .current_match
+                    .map(|line| line + self.lines.len() - 1)
+                    .unwrap_or(self.scroll_offset);
+                self.jump_to_match(from, false)
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        backend.render_pager_prompt(self.message)?;
+
+        let last = self
+            .scroll_offset
+            .saturating_add(self.config.page_size)
+            .min(self.lines.len());
+
+        let highlighted = self
+            .current_match
+            .filter(|line| (self.scroll_offset..last).contains(line))
+            .map(|line| line - self.scroll_offset);
+
+        backend.render_pager_lines(&self.lines[self.scroll_offset..last], highlighted)?;
+
+        let search = match self.config.searching {
+            true => Some(&self.search_input),
+            false => None,
+        };
+        let not_found = !self.search_input.is_empty() && self.current_match.is_none();
+
+        backend.render_pager_status(
+            self.scroll_offset,
+            last,
+            self.lines.len(),
+            search,
+            not_found,
+        )?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    error::InquireError,
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    Confirm, Pager,
+};
+
+const TEXT: &str = "one\ntwo\nthree\nfour\nfive\nsix";
+
+#[test]
+fn enter_closes_pager_without_confirm() {
+    let mut backend = fake_backend(vec![Key::PageDown(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Pager::new("Question", TEXT)
+        .with_page_size(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(ans);
+}
+
+#[test]
+fn confirm_answer_is_returned() {
+    let mut backend = fake_backend(vec![
+        Key::Enter,
+        Key::Char('n', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Pager::new("Question", TEXT)
+        .with_confirm(Confirm::new("Accept terms?"))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(!ans);
+}
+
+#[test]
+fn enter_and_escape_while_searching_do_not_close_pager() {
+    let mut backend = fake_backend(vec![
+        Key::Char('/', KeyModifiers::NONE),
+        Key::Char('f', KeyModifiers::NONE),
+        Key::Enter,
+        Key::Char('n', KeyModifiers::NONE),
+        Key::Char('/', KeyModifiers::NONE),
+        Key::Escape,
+        Key::Enter,
+        Key::Char('y', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Pager::new("Question", TEXT)
+        .with_page_size(2)
+        .with_confirm(Confirm::new("Accept terms?"))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(ans);
+}
+
+#[test]
+fn escape_cancels_pager() {
+    let mut backend = fake_backend(vec![Key::Escape]);
+
+    let ans = Pager::new("Question", TEXT).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+}
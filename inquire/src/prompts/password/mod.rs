@@ -7,14 +7,18 @@ mod test;
 
 pub use action::*;
 
+use std::time::Duration;
+
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::StringFormatter,
+    persistence::SecretStore,
     prompts::prompt::Prompt,
     terminal::get_default_terminal,
-    ui::{Backend, PasswordBackend, RenderConfig},
+    ui::{Backend, CustomTypeBackend, PasswordBackend, RenderConfig},
     validator::StringValidator,
+    Confirm,
 };
 
 use self::prompt::PasswordPrompt;
@@ -55,6 +59,11 @@ pub enum PasswordDisplayMode {
 ///   - By default, it prints eight asterisk characters: `********`.
 /// - **Validators**: Custom validators to make sure a given submitted input pass the specified requirements, e.g. not allowing empty inputs or requiring special characters.
 ///   - No validators are on by default.
+/// - **Timeout**: Maximum time to wait for an answer, after which the prompt fails with [`InquireError::Timeout`](crate::InquireError::Timeout). The time left can optionally be rendered below the prompt.
+/// - **Elapsed time**: Whether to render the time elapsed since the prompt was displayed below it, ticking every second, e.g. for time-boxed decisions.
+/// - **Secret store**: A [`SecretStore`](crate::persistence::SecretStore) such as the keyring of the operating system, along with the name of the service the secret belongs to.
+///   - When a secret is saved for the service, the user is first asked whether to use it, in which case it is returned without prompting for a new one.
+///   - Otherwise, once the user enters a secret, they are asked whether to save it for the service.
 ///
 /// Remember that for CLI applications it is standard to not allow use any display modes other than `Hidden` and to not allow the user to see the text input in any way. _Use the customization options at your discretion_.
 ///
@@ -118,6 +127,21 @@ pub struct Password<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn StringValidator>>,
 
+    /// Maximum time to wait for an answer before failing with [`InquireError::Timeout`].
+    pub timeout: Option<Duration>,
+
+    /// Whether to render the time left until the timeout elapses.
+    pub show_remaining_time: bool,
+
+    /// Whether to render the time elapsed since the prompt was displayed, or the
+    /// time left when a timeout is set.
+    pub show_elapsed_time: bool,
+
+    /// Name of the service the secret belongs to and store of the saved
+    /// secrets. When set, the user may use the secret saved for the service
+    /// instead of entering one, and may save the entered one.
+    pub secret_store: Option<(&'a str, &'a dyn SecretStore)>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -160,6 +184,10 @@ impl<'a> Password<'a> {
             help_message: Self::DEFAULT_HELP_MESSAGE,
             formatter: Self::DEFAULT_FORMATTER,
             validators: Self::DEFAULT_VALIDATORS,
+            timeout: None,
+            show_remaining_time: false,
+            show_elapsed_time: false,
+            secret_store: None,
             render_config: get_configuration(),
         }
     }
@@ -236,6 +264,41 @@ impl<'a> Password<'a> {
         self
     }
 
+    /// Sets the maximum time to wait for an answer, after which the prompt
+    /// fails with [`InquireError::Timeout`].
+    ///
+    /// The deadline can only interrupt a pending key read when using the
+    /// `crossterm` backend. Other backends notice it on the next key press.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to render the time left until the timeout elapses.
+    pub fn with_show_remaining_time(mut self, show_remaining_time: bool) -> Self {
+        self.show_remaining_time = show_remaining_time;
+        self
+    }
+
+    /// Sets whether to render the time elapsed since the prompt was displayed,
+    /// ticking every second. When a timeout is set, the time left is rendered
+    /// instead.
+    pub fn with_show_elapsed_time(mut self, show_elapsed_time: bool) -> Self {
+        self.show_elapsed_time = show_elapsed_time;
+        self
+    }
+
+    /// Sets the service the secret belongs to and the store of the saved secrets.
+    ///
+    /// When a secret is saved for the service, the user is asked whether to use
+    /// it before being prompted. Once the user enters a secret, they are asked
+    /// whether to save it for the service, replacing the previous one. Canceling
+    /// that question keeps the secret unsaved and still returns it.
+    pub fn with_secret_store(mut self, service: &'a str, store: &'a dyn SecretStore) -> Self {
+        self.secret_store = Some((service, store));
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -274,10 +337,43 @@ impl<'a> Password<'a> {
         self.prompt_with_backend(&mut backend)
     }
 
-    pub(crate) fn prompt_with_backend<B: PasswordBackend>(
+    pub(crate) fn prompt_with_backend<B: PasswordBackend + CustomTypeBackend>(
         self,
         backend: &mut B,
     ) -> InquireResult<String> {
-        PasswordPrompt::from(self).prompt(backend)
+        let secret_store = self.secret_store;
+        let render_config = self.render_config;
+
+        if let Some((service, store)) = secret_store {
+            if let Some(saved) = store.load(service)? {
+                let use_saved = Confirm::new(&format!("Use saved secret for {}?", service))
+                    .with_default(true)
+                    .with_render_config(render_config)
+                    .prompt_with_backend(backend)?;
+
+                if use_saved {
+                    return Ok(saved);
+                }
+            }
+        }
+
+        let secret = PasswordPrompt::from(self).prompt(backend)?;
+
+        if let Some((service, store)) = secret_store {
+            let save = Confirm::new(&format!("Save secret for {}?", service))
+                .with_default(false)
+                .with_render_config(render_config)
+                .prompt_with_backend(backend);
+            let save = match save {
+                Err(InquireError::OperationCanceled) => false,
+                save => save?,
+            };
+
+            if save {
+                store.save(service, &secret)?;
+            }
+        }
+
+        Ok(secret)
     }
 }
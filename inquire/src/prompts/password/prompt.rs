@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::{
     error::InquireResult,
     formatter::StringFormatter,
@@ -24,6 +26,9 @@ struct PasswordConfirmation<'a> {
 
 pub struct PasswordPrompt<'a> {
     message: &'a str,
+    timeout: Option<Duration>,
+    show_remaining_time: bool,
+    show_elapsed_time: bool,
     config: PasswordConfig,
     help_message: Option<&'a str>,
     input: Input,
@@ -50,6 +55,9 @@ impl<'a> From<Password<'a>> for PasswordPrompt<'a> {
 
         Self {
             message: so.message,
+            timeout: so.timeout,
+            show_remaining_time: so.show_remaining_time,
+            show_elapsed_time: so.show_elapsed_time,
             config: (&so).into(),
             help_message: so.help_message,
             current_mode: so.display_mode,
@@ -156,6 +164,18 @@ where
         (self.formatter)(answer)
     }
 
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    fn show_remaining_time(&self) -> bool {
+        self.show_remaining_time
+    }
+
+    fn show_elapsed_time(&self) -> bool {
+        self.show_elapsed_time
+    }
+
     fn pre_cancel(&mut self) -> InquireResult<bool> {
         if let Some(confirmation) = &mut self.confirmation {
             if self.confirmation_stage {
@@ -168,6 +188,10 @@ where
         Ok(true)
     }
 
+    fn can_submit(&self) -> InquireResult<bool> {
+        Ok(self.validate_current_answer()? == Validation::Valid)
+    }
+
     fn submit(&mut self) -> InquireResult<Option<String>> {
         if let Validation::Invalid(msg) = self.validate_current_answer()? {
             self.error = Some(msg);
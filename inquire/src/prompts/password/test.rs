@@ -254,3 +254,104 @@ password_test!(
     "anor",
     Password::new("").with_display_mode(crate::PasswordDisplayMode::Masked)
 );
+
+#[derive(Default)]
+struct MemorySecretStore(std::cell::RefCell<std::collections::HashMap<String, String>>);
+
+impl crate::persistence::SecretStore for MemorySecretStore {
+    fn load(&self, service: &str) -> Result<Option<String>, crate::CustomUserError> {
+        Ok(self.0.borrow().get(service).cloned())
+    }
+
+    fn save(&self, service: &str, secret: &str) -> Result<(), crate::CustomUserError> {
+        self.0
+            .borrow_mut()
+            .insert(service.to_string(), secret.to_string());
+        Ok(())
+    }
+}
+
+#[test]
+fn saved_secret_is_used_when_accepted() {
+    let store = MemorySecretStore::default();
+    store
+        .0
+        .borrow_mut()
+        .insert("github".into(), "hunter2".into());
+
+    let mut backend = crate::prompts::test::fake_backend(vec![Key::Enter]);
+
+    let ans = Password::new("Token:")
+        .with_secret_store("github", &store)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("hunter2", ans);
+}
+
+#[test]
+fn new_secret_is_saved_when_accepted() {
+    let store = MemorySecretStore::default();
+    store
+        .0
+        .borrow_mut()
+        .insert("github".into(), "hunter2".into());
+
+    let mut events: Vec<Key> = text_to_events!("n");
+    events.push(Key::Enter);
+    events.append(&mut text_to_events!("s3cret"));
+    events.push(Key::Enter);
+    events.append(&mut text_to_events!("y"));
+    events.push(Key::Enter);
+    let mut backend = crate::prompts::test::fake_backend(events);
+
+    let ans = Password::new("Token:")
+        .without_confirmation()
+        .with_secret_store("github", &store)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("s3cret", ans);
+    assert_eq!(
+        Some("s3cret"),
+        store.0.borrow().get("github").map(String::as_str)
+    );
+}
+
+#[test]
+fn new_secret_is_not_saved_by_default() {
+    let store = MemorySecretStore::default();
+
+    let mut events: Vec<Key> = text_to_events!("s3cret");
+    events.push(Key::Enter);
+    events.push(Key::Enter);
+    let mut backend = crate::prompts::test::fake_backend(events);
+
+    let ans = Password::new("Token:")
+        .without_confirmation()
+        .with_secret_store("github", &store)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("s3cret", ans);
+    assert!(store.0.borrow().is_empty());
+}
+
+#[test]
+fn new_secret_is_not_saved_when_canceled() {
+    let store = MemorySecretStore::default();
+
+    let mut events: Vec<Key> = text_to_events!("s3cret");
+    events.push(Key::Enter);
+    events.push(Key::Escape);
+    let mut backend = crate::prompts::test::fake_backend(events);
+
+    let ans = Password::new("Token:")
+        .without_confirmation()
+        .with_secret_store("github", &store)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("s3cret", ans);
+    assert!(store.0.borrow().is_empty());
+}
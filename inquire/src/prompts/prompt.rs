@@ -1,9 +1,52 @@
 //! Definitions of common behavior shared amongst all different prompt types.
 
-use crate::{error::InquireResult, input::InputActionResult, ui::CommonBackend, InquireError};
+use std::time::{Duration, Instant};
+
+use crate::{
+    config::{get_key_bindings, report_telemetry, PromptTelemetry},
+    error::InquireResult,
+    input::InputActionResult,
+    ui::CommonBackend,
+    InquireError,
+};
 
 use super::action::{Action, InnerAction};
 
+/// Emits an event of the prompt lifecycle through the `log` facade, under the
+/// `inquire` target, when the `log` feature is enabled.
+macro_rules! lifecycle_event {
+    ($level:ident, $($arg:tt)+) => {
+        #[cfg(feature = "log")]
+        log::$level!(target: "inquire", $($arg)+);
+    };
+}
+
+pub(crate) use lifecycle_event;
+
+/// How a prompt was resolved.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Resolution {
+    Answered,
+    Skipped,
+    Canceled,
+}
+
+/// Reports the resolution of a prompt to the global telemetry hook.
+pub(crate) fn report_resolution(
+    message: &str,
+    started: Instant,
+    attempts: usize,
+    resolution: Resolution,
+) {
+    report_telemetry(|| PromptTelemetry {
+        prompt_id: message.to_owned(),
+        duration: started.elapsed(),
+        attempts,
+        canceled: resolution == Resolution::Canceled,
+        skipped: resolution == Resolution::Skipped,
+    });
+}
+
 /// Represents the result of an action on the prompt.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ActionResult {
@@ -69,6 +112,72 @@ where
     /// * `answer` - Answer returned by the prompt.
     fn format_answer(&self, answer: &Self::Output) -> String;
 
+    /// Maximum time the prompt waits for an answer before failing with
+    /// [`InquireError::Timeout`]. No timeout by default.
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Whether the time left until the timeout elapses is rendered below the prompt.
+    fn show_remaining_time(&self) -> bool {
+        false
+    }
+
+    /// Whether the time elapsed since the prompt was displayed is rendered
+    /// below the prompt. When a timeout is set, the time left is rendered instead.
+    fn show_elapsed_time(&self) -> bool {
+        false
+    }
+
+    /// Interval between the calls to [`tick`](Prompt::tick), letting the
+    /// prompt update itself while no key is pressed. No ticks by default.
+    fn tick_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Hook called every [`tick_interval`](Prompt::tick_interval), e.g. to
+    /// refresh the options of the prompt. Returns whether the prompt needs
+    /// to be re-rendered.
+    fn tick(&mut self) -> InquireResult<ActionResult> {
+        Ok(ActionResult::Clean)
+    }
+
+    /// Whether the prompt should be submitted right after handling the last
+    /// action, without waiting for the user to press the submit key.
+    fn should_auto_submit(&self) -> bool {
+        false
+    }
+
+    /// Whether the current answer passes the validators of the prompt,
+    /// checked before submitting when the key bindings require a valid input.
+    ///
+    /// Unlike [`submit`](Prompt::submit), this must not change the state of the prompt.
+    fn can_submit(&self) -> InquireResult<bool> {
+        Ok(true)
+    }
+
+    /// Maximum number of submissions rejected by [`submit`](Prompt::submit),
+    /// after which the prompt gives up.
+    fn max_attempts(&self) -> Option<usize> {
+        None
+    }
+
+    /// Answer returned once the prompt gives up after too many rejected
+    /// submissions. When `None`, the prompt fails with
+    /// [`InquireError::TooManyAttempts`] instead.
+    fn fallback_answer(&self) -> Option<Self::Output> {
+        None
+    }
+
+    /// Label of the skip choice of the prompt, when it is the answer the
+    /// user would submit right now.
+    ///
+    /// Submitting then finishes the prompt with the label rendered as its
+    /// answer, failing with [`InquireError::OperationSkipped`].
+    fn selected_skip_option(&self) -> Option<&str> {
+        None
+    }
+
     /// Hook called when a prompt is first started, before the first
     /// draw happens.
     fn setup(&mut self) -> InquireResult<()> {
@@ -117,50 +226,265 @@ where
     /// unless the situation really warrants it.
     fn prompt(mut self, backend: &mut Backend) -> InquireResult<Self::Output> {
         self.setup()?;
+        backend.start_prompt(self.message())?;
+
+        let started = Instant::now();
+        lifecycle_event!(debug, "prompt {:?} started", self.message());
+
+        let deadline = self.timeout().map(|timeout| started + timeout);
+        let show_remaining_time =
+            deadline.is_some() && (self.show_remaining_time() || self.show_elapsed_time());
+        let show_elapsed_time = deadline.is_none() && self.show_elapsed_time();
+
+        let tick_interval = self.tick_interval();
+        let mut next_tick = tick_interval.map(|interval| started + interval);
+
+        let key_bindings = backend.key_bindings().unwrap_or_else(get_key_bindings);
+        let mut show_key_help = false;
+
+        let frame_interval = backend.frame_interval();
+        let mut last_frame: Option<Instant> = None;
+
+        let mut attempts = 0;
+        let mut rejected_attempts = 0;
 
         let mut last_handle = ActionResult::NeedsRedraw;
         let final_answer = loop {
-            if last_handle.needs_redraw() {
+            if let (Some(interval), Some(tick)) = (tick_interval, next_tick) {
+                if tick <= Instant::now() {
+                    last_handle = last_handle.merge(self.tick()?);
+                    next_tick = Some(Instant::now() + interval);
+                }
+            }
+
+            // redraws too close to the previous one are delayed, coalescing
+            // the changes made by the keys pressed in the meantime
+            let next_frame = match (last_handle.needs_redraw(), frame_interval, last_frame) {
+                (true, Some(interval), Some(last_frame)) => {
+                    Some(last_frame + interval).filter(|next| *next > Instant::now())
+                }
+                _ => None,
+            };
+
+            if last_handle.needs_redraw() && next_frame.is_none() {
                 backend.frame_setup()?;
                 self.render(backend)?;
+                if show_key_help {
+                    let mut entries = Self::InnerAction::key_help(self.config());
+                    entries.append(&mut key_bindings.key_help());
+                    backend.render_key_help(&entries)?;
+                }
+                if let (Some(deadline), true) = (deadline, show_remaining_time) {
+                    backend.render_remaining_time(
+                        deadline.saturating_duration_since(Instant::now()),
+                    )?;
+                }
+                if show_elapsed_time {
+                    backend.render_elapsed_time(started.elapsed())?;
+                }
                 backend.frame_finish(false)?;
+                last_frame = Some(Instant::now());
                 last_handle = ActionResult::Clean;
             }
 
-            let key = backend.read_key()?;
-            let action = Action::from_key(key, self.config());
+            let mut wait = next_frame.map(|next| next.saturating_duration_since(Instant::now()));
+
+            if let Some(deadline) = deadline {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+
+                if remaining.is_zero() {
+                    lifecycle_event!(debug, "prompt {:?} timed out", self.message());
+                    backend.frame_setup()?;
+                    backend.render_canceled_prompt(self.message())?;
+                    backend.frame_finish(true)?;
+                    report_resolution(self.message(), started, attempts, Resolution::Canceled);
+                    return Err(InquireError::Timeout);
+                }
+
+                // wake up every second to keep the countdown up to date
+                let deadline_wait = match show_remaining_time {
+                    true => remaining.min(Duration::from_secs(1)),
+                    false => remaining,
+                };
+
+                wait = match wait {
+                    Some(wait) => Some(wait.min(deadline_wait)),
+                    None => Some(deadline_wait),
+                };
+            }
+
+            if show_elapsed_time {
+                // wake up when the next second elapses to keep the clock up to date
+                let elapsed_nanos =
+                    Duration::from_nanos(u64::from(started.elapsed().subsec_nanos()));
+                let clock_wait = Duration::from_secs(1) - elapsed_nanos;
+
+                wait = match wait {
+                    Some(wait) => Some(wait.min(clock_wait)),
+                    None => Some(clock_wait),
+                };
+            }
+
+            if let Some(tick) = next_tick {
+                let tick_wait = tick.saturating_duration_since(Instant::now());
+
+                wait = match wait {
+                    Some(wait) => Some(wait.min(tick_wait)),
+                    None => Some(tick_wait),
+                };
+            }
+
+            let key = match wait {
+                Some(wait) => {
+                    let Some(key) = backend.read_key_timeout(wait)? else {
+                        if show_remaining_time || show_elapsed_time {
+                            last_handle = ActionResult::NeedsRedraw;
+                        }
+                        continue;
+                    };
+
+                    key
+                }
+                None => backend.read_key()?,
+            };
+
+            // any key dismisses the help overlay
+            if show_key_help {
+                show_key_help = false;
+                last_handle = ActionResult::NeedsRedraw;
+                continue;
+            }
+
+            let action = Action::from_key_with_bindings(key, &key_bindings, self.config());
+            lifecycle_event!(
+                trace,
+                "prompt {:?} received key {:?}{}",
+                self.message(),
+                key,
+                if action.is_some() { "" } else { " (unbound)" }
+            );
+
+            if let (None, Some(handler)) = (action, key_bindings.unhandled_key_handler) {
+                handler(key);
+            }
+
+            if let (Some(Action::Submit), Some(skip_option)) = (action, self.selected_skip_option())
+            {
+                backend.frame_setup()?;
+                backend.render_prompt_with_answer(self.message(), skip_option)?;
+                backend.frame_finish(true)?;
+                report_resolution(self.message(), started, attempts + 1, Resolution::Skipped);
+                return Err(InquireError::OperationSkipped);
+            }
 
             if let Some(action) = action {
-                last_handle = match action {
+                let mut submitting = false;
+
+                let result = match action {
+                    Action::Submit
+                        if key_bindings.submit_requires_valid_input && !self.can_submit()? =>
+                    {
+                        ActionResult::Clean
+                    }
                     Action::Submit => {
-                        if let Some(answer) = self.submit()? {
-                            break answer;
-                        }
-                        ActionResult::NeedsRedraw
+                        submitting = true;
+                        ActionResult::Clean
                     }
                     Action::Cancel => {
                         let pre_cancel_result = self.pre_cancel()?;
 
                         if pre_cancel_result {
+                            lifecycle_event!(
+                                debug,
+                                "prompt {:?} canceled after {:?}",
+                                self.message(),
+                                started.elapsed()
+                            );
                             backend.frame_setup()?;
                             backend.render_canceled_prompt(self.message())?;
                             backend.frame_finish(true)?;
+                            report_resolution(
+                                self.message(),
+                                started,
+                                attempts,
+                                Resolution::Canceled,
+                            );
                             return Err(InquireError::OperationCanceled);
                         }
 
                         ActionResult::NeedsRedraw
                     }
-                    Action::Interrupt => return Err(InquireError::OperationInterrupted),
-                    Action::Inner(inner_action) => self.handle(inner_action)?,
+                    Action::Interrupt => {
+                        lifecycle_event!(
+                            debug,
+                            "prompt {:?} interrupted after {:?}",
+                            self.message(),
+                            started.elapsed()
+                        );
+                        backend.frame_setup()?;
+                        backend.render_canceled_prompt(self.message())?;
+                        backend.frame_finish(true)?;
+                        report_resolution(self.message(), started, attempts, Resolution::Canceled);
+                        return Err(InquireError::OperationInterrupted);
+                    }
+                    Action::Help => {
+                        show_key_help = true;
+                        ActionResult::NeedsRedraw
+                    }
+                    Action::Inner(inner_action) => {
+                        let result = self.handle(inner_action)?;
+                        submitting = self.should_auto_submit();
+
+                        result
+                    }
                 };
+
+                // keeps redraws delayed by the frame rate cap pending
+                last_handle = last_handle.merge(result);
+
+                if submitting {
+                    attempts += 1;
+                    if let Some(answer) = self.submit()? {
+                        break answer;
+                    }
+
+                    rejected_attempts += 1;
+                    lifecycle_event!(
+                        debug,
+                        "prompt {:?} rejected submission attempt {}",
+                        self.message(),
+                        rejected_attempts
+                    );
+                    if matches!(self.max_attempts(), Some(max) if rejected_attempts >= max) {
+                        if let Some(answer) = self.fallback_answer() {
+                            break answer;
+                        }
+
+                        backend.frame_setup()?;
+                        backend.render_canceled_prompt(self.message())?;
+                        backend.frame_finish(true)?;
+                        report_resolution(self.message(), started, attempts, Resolution::Canceled);
+                        return Err(InquireError::TooManyAttempts);
+                    }
+
+                    last_handle = ActionResult::NeedsRedraw;
+                }
             }
         };
 
+        lifecycle_event!(
+            debug,
+            "prompt {:?} answered after {:?}",
+            self.message(),
+            started.elapsed()
+        );
+
         let formatted = self.format_answer(&final_answer);
 
         backend.frame_setup()?;
         backend.render_prompt_with_answer(self.message(), &formatted)?;
         backend.frame_finish(true)?;
+        report_resolution(self.message(), started, attempts, Resolution::Answered);
 
         Ok(final_answer)
     }
@@ -0,0 +1,221 @@
+mod prompt;
+mod quantity;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use quantity::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, CustomTypeBackend, RenderConfig},
+    validator::CustomTypeValidator,
+};
+
+use self::prompt::QuantityInputPrompt;
+
+/// Text prompt for quantities typed with a unit suffix, such as sizes (`512Mi`, `1.5GB`), percentages (`25%`) or durations (`1h30m`), returning a typed [`Quantity`].
+///
+/// While the user types, the value normalized to the canonical unit of the quantity is echoed on the line below the prompt, e.g. `= 536870912 B`, and it is also the final rendering of the answer by default.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Starting input**: Initial value of the prompt's text input.
+/// - **Default value**: Default value returned when the user submits an empty response.
+/// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Help message**: Message displayed at the line below the prompt while the input is not a valid quantity.
+/// - **Min and max values**: Inclusive range of values the user may submit.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Formats to the canonical unit of the quantity by default.
+/// - **Validators**: Custom validators to make sure a given submitted input pass the specified requirements.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{ByteSize, QuantityInput};
+///
+/// let memory = QuantityInput::<ByteSize>::new("Memory limit:")
+///     .with_min(ByteSize(64 << 20))
+///     .with_max(ByteSize(16 << 30))
+///     .with_placeholder("512Mi")
+///     .prompt();
+///
+/// match memory {
+///     Ok(size) => println!("Limiting memory to {} bytes", size.0),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+pub struct QuantityInput<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Initial value of the prompt's text input.
+    pub starting_input: Option<&'a str>,
+
+    /// Default value, returned when the user input is empty.
+    pub default: Option<T>,
+
+    /// Short hint that describes the expected value of the input.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Min value allowed to be submitted, inclusive.
+    pub min: Option<T>,
+
+    /// Max value allowed to be submitted, inclusive.
+    pub max: Option<T>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: CustomTypeFormatter<'a, T>,
+
+    /// Collection of validators to apply to the user input.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> QuantityInput<'a, T>
+where
+    T: Quantity,
+{
+    /// Default validators added to the [QuantityInput] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn CustomTypeValidator<T>>> = vec![];
+
+    /// Creates a [QuantityInput] with the provided message and default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_input: None,
+            default: None,
+            placeholder: None,
+            help_message: None,
+            min: None,
+            max: None,
+            formatter: &|val| val.to_canonical_string(),
+            validators: Self::DEFAULT_VALIDATORS,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the initial value of the prompt's text input.
+    pub fn with_starting_input(mut self, starting_input: &'a str) -> Self {
+        self.starting_input = Some(starting_input);
+        self
+    }
+
+    /// Sets the default value, returned when the user input is empty.
+    pub fn with_default(mut self, default: T) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets the min value, inclusive.
+    pub fn with_min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the max value, inclusive.
+    pub fn with_max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the parsed user's answer.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: CustomTypeValidator<T> + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<T> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<T> {
+        QuantityInputPrompt::new(self)?.prompt(backend)
+    }
+}
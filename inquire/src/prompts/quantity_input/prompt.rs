@@ -0,0 +1,183 @@
+use crate::{
+    error::InquireResult,
+    formatter::CustomTypeFormatter,
+    input::Input,
+    prompts::{
+        custom_type::config::CustomTypeConfig,
+        prompt::{ActionResult, Prompt},
+    },
+    ui::CustomTypeBackend,
+    validator::{CustomTypeValidator, ErrorMessage, Validation},
+    CustomTypePromptAction, InquireError, QuantityInput,
+};
+
+use super::Quantity;
+
+pub struct QuantityInputPrompt<'a, T> {
+    message: &'a str,
+    config: CustomTypeConfig,
+    error: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    default: Option<T>,
+    input: Input,
+    min: Option<T>,
+    max: Option<T>,
+    formatter: CustomTypeFormatter<'a, T>,
+    validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+}
+
+impl<'a, T> QuantityInputPrompt<'a, T>
+where
+    T: Quantity,
+{
+    pub fn new(qo: QuantityInput<'a, T>) -> InquireResult<Self> {
+        if let (Some(min), Some(max)) = (&qo.min, &qo.max) {
+            if min > max {
+                return Err(InquireError::InvalidConfiguration(
+                    "Min value can not be greater than max value".into(),
+                ));
+            }
+        }
+
+        let input = Input::new_with(qo.starting_input.unwrap_or_default());
+        let input = if let Some(placeholder) = qo.placeholder {
+            input.with_placeholder(placeholder)
+        } else {
+            input
+        };
+
+        Ok(Self {
+            message: qo.message,
+            config: CustomTypeConfig {},
+            error: None,
+            help_message: qo.help_message,
+            default: qo.default,
+            input,
+            min: qo.min,
+            max: qo.max,
+            formatter: qo.formatter,
+            validators: qo.validators,
+        })
+    }
+
+    fn validate_current_answer(&self, value: &T) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator.validate(value) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    /// Parses the input and checks it against the min and max values.
+    fn get_final_answer(&self) -> Result<T, String> {
+        match &self.default {
+            Some(val) if self.input.content().trim().is_empty() => return Ok(val.clone()),
+            _ => {}
+        }
+
+        let value = T::parse_quantity(self.input.content())?;
+
+        match (&self.min, &self.max) {
+            (Some(min), _) if value < *min => Err(format!(
+                "The value must be at least {}",
+                min.to_canonical_string()
+            )),
+            (_, Some(max)) if value > *max => Err(format!(
+                "The value must be at most {}",
+                max.to_canonical_string()
+            )),
+            _ => Ok(value),
+        }
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for QuantityInputPrompt<'a, T>
+where
+    Backend: CustomTypeBackend,
+    T: Quantity,
+{
+    type Config = CustomTypeConfig;
+    type InnerAction = CustomTypePromptAction;
+    type Output = T;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &CustomTypeConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &T) -> String {
+        (self.formatter)(answer.clone())
+    }
+
+    fn fallback_answer(&self) -> Option<T> {
+        self.default.clone()
+    }
+
+    fn can_submit(&self) -> InquireResult<bool> {
+        match self.get_final_answer() {
+            Ok(answer) => Ok(self.validate_current_answer(&answer)? == Validation::Valid),
+            Err(_) => Ok(false),
+        }
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<T>> {
+        let answer = match self.get_final_answer() {
+            Ok(answer) => match self.validate_current_answer(&answer)? {
+                Validation::Valid => Some(answer),
+                Validation::Invalid(msg) => {
+                    self.error = Some(msg);
+                    None
+                }
+            },
+            Err(message) => {
+                self.error = Some(message.into());
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: CustomTypePromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            CustomTypePromptAction::ValueInput(input_action) => {
+                self.input.handle(input_action).into()
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        let default_message = self.default.as_ref().map(T::to_canonical_string);
+
+        backend.render_prompt(self.message, None, default_message.as_deref(), &self.input)?;
+
+        // echoes the normalized value while the input is a valid quantity
+        let normalized = match self.input.content().trim() {
+            "" => None,
+            content => T::parse_quantity(content).ok(),
+        };
+
+        match (normalized, self.help_message) {
+            (Some(value), _) => {
+                backend.render_help_message(&format!("= {}", value.to_canonical_string()))?;
+            }
+            (None, Some(message)) => backend.render_help_message(message)?,
+            (None, None) => {}
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+use std::{convert::TryFrom, fmt, time::Duration};
+
+/// Value typed with a unit suffix in a [`QuantityInput`](crate::QuantityInput) prompt,
+/// normalized to a canonical unit.
+///
+/// Implemented for [`ByteSize`], [`Percentage`] and [`Duration`], and open to
+/// implementations for other kinds of quantities.
+pub trait Quantity: Sized + Clone + PartialOrd {
+    /// Parses the input typed by the user, e.g. `512Mi`. The error message is
+    /// displayed to the user when the input can not be parsed.
+    fn parse_quantity(input: &str) -> Result<Self, String>;
+
+    /// Formats the quantity in the canonical unit, e.g. `536870912 B`.
+    fn to_canonical_string(&self) -> String;
+}
+
+/// Size in bytes, typed with decimal (`KB`, `MB`, `GB`, `TB`, `PB`) or binary
+/// (`Ki`, `Mi`, `Gi`, `Ti`, `Pi`, with an optional `B`) unit suffixes,
+/// compared case-insensitively. Sizes without suffix are in bytes, and
+/// fractional sizes are rounded to the nearest byte.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{ByteSize, Quantity};
+///
+/// assert_eq!(Ok(ByteSize(536_870_912)), ByteSize::parse_quantity("512Mi"));
+/// assert_eq!(Ok(ByteSize(1_500_000_000)), ByteSize::parse_quantity("1.5 GB"));
+/// assert_eq!("1024 B", ByteSize(1024).to_string());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteSize(pub u64);
+
+impl Quantity for ByteSize {
+    fn parse_quantity(input: &str) -> Result<Self, String> {
+        let error = || String::from("Please type a size like 512Mi or 1.5GB");
+
+        let (number, unit) = split_unit(input.trim());
+        let multiplier: u128 = match unit.to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            "k" | "kb" => 1_000,
+            "ki" | "kib" => 1 << 10,
+            "m" | "mb" => 1_000_000,
+            "mi" | "mib" => 1 << 20,
+            "g" | "gb" => 1_000_000_000,
+            "gi" | "gib" => 1 << 30,
+            "t" | "tb" => 1_000_000_000_000,
+            "ti" | "tib" => 1 << 40,
+            "p" | "pb" => 1_000_000_000_000_000,
+            "pi" | "pib" => 1 << 50,
+            _ => return Err(error()),
+        };
+
+        let bytes = scale(number, multiplier).ok_or_else(error)?;
+
+        u64::try_from(bytes)
+            .map(ByteSize)
+            .map_err(|err| format!("Size too large: {}", err))
+    }
+
+    fn to_canonical_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} B", self.0)
+    }
+}
+
+/// Percentage, typed with or without a `%` suffix, e.g. `25%` or `12.5`.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{Percentage, Quantity};
+///
+/// let percentage = Percentage::parse_quantity("25%").unwrap();
+///
+/// assert_eq!(Percentage(25.0), percentage);
+/// assert_eq!(0.25, percentage.ratio());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Percentage(pub f64);
+
+impl Percentage {
+    /// Ratio represented by the percentage, e.g. `0.25` for `25%`.
+    pub fn ratio(self) -> f64 {
+        self.0 / 100.0
+    }
+}
+
+impl Quantity for Percentage {
+    fn parse_quantity(input: &str) -> Result<Self, String> {
+        let input = input.trim();
+        let number = input.strip_suffix('%').unwrap_or(input).trim_end();
+
+        match number.parse::<f64>() {
+            Ok(value) if value.is_finite() => Ok(Percentage(value)),
+            _ => Err(String::from("Please type a percentage like 25%")),
+        }
+    }
+
+    fn to_canonical_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for Percentage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+
+/// Durations are typed as a sequence of amounts with a unit suffix, `ns`,
+/// `us` (or `µs`), `ms`, `s`, `m` (or `min`), `h` or `d`, e.g. `1h30m` or
+/// `1.5s`, and are normalized to seconds.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use inquire::Quantity;
+///
+/// assert_eq!(Ok(Duration::from_secs(5400)), Duration::parse_quantity("1h 30m"));
+/// assert_eq!("0.25s", Duration::from_millis(250).to_canonical_string());
+/// ```
+impl Quantity for Duration {
+    fn parse_quantity(input: &str) -> Result<Self, String> {
+        let error = || String::from("Please type a duration like 90s, 1h30m or 250ms");
+
+        let mut rest = input.trim();
+        if rest.is_empty() {
+            return Err(error());
+        }
+
+        let mut nanos: u128 = 0;
+        while !rest.is_empty() {
+            let number_end = rest
+                .find(|c: char| !c.is_ascii_digit() && c != '.')
+                .unwrap_or(rest.len());
+            let (number, tail) = rest.split_at(number_end);
+            let unit_end = tail
+                .find(|c: char| c.is_ascii_digit() || c == '.' || c.is_whitespace())
+                .unwrap_or(tail.len());
+            let (unit, tail) = tail.split_at(unit_end);
+
+            let multiplier: u128 = match unit {
+                "ns" => 1,
+                "us" | "µs" => 1_000,
+                "ms" => 1_000_000,
+                "s" | "sec" => 1_000_000_000,
+                "m" | "min" => 60_000_000_000,
+                "h" => 3_600_000_000_000,
+                "d" => 86_400_000_000_000,
+                _ => return Err(error()),
+            };
+
+            let amount = scale(number, multiplier).ok_or_else(error)?;
+            nanos = nanos.checked_add(amount).ok_or_else(error)?;
+            rest = tail.trim_start();
+        }
+
+        let secs = u64::try_from(nanos / 1_000_000_000)
+            .map_err(|err| format!("Duration too long: {}", err))?;
+
+        Ok(Duration::new(secs, (nanos % 1_000_000_000) as u32))
+    }
+
+    fn to_canonical_string(&self) -> String {
+        match self.subsec_nanos() {
+            0 => format!("{}s", self.as_secs()),
+            nanos => {
+                let fraction = format!("{:09}", nanos);
+                format!("{}.{}s", self.as_secs(), fraction.trim_end_matches('0'))
+            }
+        }
+    }
+}
+
+/// Splits the input into its leading number and its unit suffix, possibly
+/// separated by whitespace.
+fn split_unit(input: &str) -> (&str, &str) {
+    let number_end = input
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(input.len());
+    let (number, unit) = input.split_at(number_end);
+
+    (number, unit.trim_start())
+}
+
+/// Multiplies a non-negative decimal number by the multiplier of its unit,
+/// rounding the result to the nearest integer without going through floats.
+fn scale(number: &str, multiplier: u128) -> Option<u128> {
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+    let all_digits = |digits: &str| digits.bytes().all(|b| b.is_ascii_digit());
+    if (integer.is_empty() && fraction.is_empty()) || !all_digits(integer) || !all_digits(fraction)
+    {
+        return None;
+    }
+
+    let parse = |digits: &str| match digits {
+        "" => Some(0),
+        digits => digits.parse::<u128>().ok(),
+    };
+
+    // digits beyond 18 decimals can not change the rounded result of any unit
+    let fraction = &fraction[..fraction.len().min(18)];
+    let denominator = 10u128.pow(fraction.len() as u32);
+
+    let integer = parse(integer)?.checked_mul(multiplier)?;
+    let fraction = (parse(fraction)? * multiplier + denominator / 2) / denominator;
+
+    integer.checked_add(fraction)
+}
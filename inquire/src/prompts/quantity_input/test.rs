@@ -0,0 +1,162 @@
+use std::time::Duration;
+
+use crate::{
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::ErrorMessage,
+    ByteSize, InquireError, Percentage, Quantity, QuantityInput,
+};
+
+fn keys(inputs: &[&str]) -> Vec<Key> {
+    let mut keys = vec![];
+    for (i, input) in inputs.iter().enumerate() {
+        if i > 0 {
+            keys.extend(std::iter::repeat(Key::Backspace).take(inputs[i - 1].chars().count()));
+        }
+        keys.extend(input.chars().map(|c| Key::Char(c, KeyModifiers::NONE)));
+        keys.push(Key::Enter);
+    }
+    keys
+}
+
+fn error(message: &str) -> Token {
+    Token::ErrorMessage(ErrorMessage::Custom(message.into()))
+}
+
+#[test]
+fn byte_sizes_are_parsed_with_decimal_and_binary_units() {
+    let cases = [
+        ("512Mi", 536_870_912),
+        ("1.5GB", 1_500_000_000),
+        ("1.5 gib", 1_610_612_736),
+        ("42", 42),
+        ("3 KiB", 3_072),
+        ("0.0001KB", 0),
+        ("0.0005KB", 1),
+    ];
+
+    for (input, bytes) in cases {
+        assert_eq!(
+            Ok(ByteSize(bytes)),
+            ByteSize::parse_quantity(input),
+            "{}",
+            input
+        );
+    }
+
+    for input in ["", "Mi", "1.2.3MB", "-5MB", "5 parsecs", "20000000Pi"] {
+        assert!(ByteSize::parse_quantity(input).is_err(), "{}", input);
+    }
+}
+
+#[test]
+fn durations_are_parsed_from_several_components() {
+    let cases = [
+        ("90s", Duration::from_secs(90)),
+        ("1h30m", Duration::from_secs(5400)),
+        ("1h 30min", Duration::from_secs(5400)),
+        ("1.5s", Duration::from_millis(1500)),
+        ("250ms", Duration::from_millis(250)),
+        ("2d", Duration::from_secs(172_800)),
+        ("3µs 4ns", Duration::from_nanos(3_004)),
+    ];
+
+    for (input, duration) in cases {
+        assert_eq!(Ok(duration), Duration::parse_quantity(input), "{}", input);
+    }
+
+    for input in ["", "90", "1h30", "5 fortnights", "h"] {
+        assert!(Duration::parse_quantity(input).is_err(), "{}", input);
+    }
+
+    assert_eq!("5400s", Duration::from_secs(5400).to_canonical_string());
+    assert_eq!("1.5s", Duration::from_millis(1500).to_canonical_string());
+}
+
+#[test]
+fn percentages_are_parsed_with_or_without_suffix() {
+    assert_eq!(Ok(Percentage(25.0)), Percentage::parse_quantity("25%"));
+    assert_eq!(Ok(Percentage(12.5)), Percentage::parse_quantity(" 12.5 % "));
+    assert_eq!(Ok(Percentage(-3.0)), Percentage::parse_quantity("-3"));
+    assert!(Percentage::parse_quantity("a lot").is_err());
+    assert!(Percentage::parse_quantity("inf%").is_err());
+}
+
+#[test]
+fn normalized_value_is_echoed_and_returned() {
+    let mut backend = FakeBackend::new(keys(&["512Mi"]));
+
+    let ans = QuantityInput::<ByteSize>::new("Memory")
+        .with_help_message("e.g. 512Mi")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ByteSize(536_870_912), ans);
+
+    let frames = backend.frames();
+    assert!(frames[0].has_token(&Token::HelpMessage("e.g. 512Mi".into())));
+    assert!(frames[4].has_token(&Token::HelpMessage("= 512000000 B".into())));
+    assert!(frames[5].has_token(&Token::HelpMessage("= 536870912 B".into())));
+    assert!(frames.last().unwrap().has_token(&Token::AnsweredPrompt(
+        "Memory".into(),
+        "536870912 B".into()
+    )));
+}
+
+#[test]
+fn values_out_of_range_are_rejected() {
+    let mut backend = FakeBackend::new(keys(&["5%", "150%", "75%"]));
+
+    let ans = QuantityInput::new("Threshold")
+        .with_min(Percentage(10.0))
+        .with_max(Percentage(100.0))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(Percentage(75.0), ans);
+
+    let frames = backend.frames();
+    assert!(frames[3].has_token(&error("The value must be at least 10%")));
+    assert!(frames[10].has_token(&error("The value must be at most 100%")));
+}
+
+#[test]
+fn invalid_input_shows_the_error_of_the_quantity() {
+    let mut backend = FakeBackend::new(keys(&["soon", "2m"]));
+
+    let ans = QuantityInput::<Duration>::new("Timeout")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(Duration::from_secs(120), ans);
+    assert!(
+        backend.frames()[5].has_token(&error("Please type a duration like 90s, 1h30m or 250ms"))
+    );
+}
+
+#[test]
+fn empty_input_returns_the_default() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = QuantityInput::new("Timeout")
+        .with_default(Duration::from_secs(30))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(Duration::from_secs(30), ans);
+    assert!(backend.frames()[0].has_token(&Token::DefaultValue("30s".into())));
+}
+
+#[test]
+fn min_greater_than_max_is_rejected() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = QuantityInput::new("Memory")
+        .with_min(ByteSize(2))
+        .with_max(ByteSize(1))
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
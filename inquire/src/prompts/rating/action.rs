@@ -0,0 +1,58 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::RatingConfig;
+
+/// Set of actions for a RatingPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RatingPromptAction {
+    /// Decreases the current value by one.
+    Decrease,
+    /// Increases the current value by one.
+    Increase,
+    /// Sets the current value to the lowest of the scale.
+    SetMin,
+    /// Sets the current value to the highest of the scale.
+    SetMax,
+    /// Types a digit, appended to the digits typed right before it when the
+    /// resulting number is within the scale, e.g. 1 then 0 for 10.
+    TypeDigit(u8),
+}
+
+impl InnerAction for RatingPromptAction {
+    type Config = RatingConfig;
+
+    fn from_key(key: Key, config: &RatingConfig) -> Option<Self> {
+        if config.vim_mode {
+            let action = match key {
+                Key::Char('h', KeyModifiers::NONE) => Some(Self::Decrease),
+                Key::Char('l', KeyModifiers::NONE) => Some(Self::Increase),
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
+        let action = match key {
+            Key::Left(KeyModifiers::NONE)
+            | Key::Down(KeyModifiers::NONE)
+            | Key::Char('b', KeyModifiers::CONTROL) => Self::Decrease,
+            Key::Right(KeyModifiers::NONE)
+            | Key::Up(KeyModifiers::NONE)
+            | Key::Char('f', KeyModifiers::CONTROL) => Self::Increase,
+            Key::Home => Self::SetMin,
+            Key::End => Self::SetMax,
+            Key::Char(c, KeyModifiers::NONE) => match c.to_digit(10) {
+                Some(digit) => Self::TypeDigit(digit as u8),
+                None => return None,
+            },
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
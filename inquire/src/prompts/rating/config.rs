@@ -0,0 +1,19 @@
+use crate::Rating;
+
+/// Configuration settings used in the execution of a RatingPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct RatingConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// Highest value of the scale.
+    pub max: u8,
+}
+
+impl From<&Rating<'_>> for RatingConfig {
+    fn from(value: &Rating<'_>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            max: value.max,
+        }
+    }
+}
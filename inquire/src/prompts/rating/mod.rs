@@ -0,0 +1,235 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::RatingFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RatingBackend, RenderConfig},
+};
+
+use self::prompt::RatingPrompt;
+
+/// Display modes of the scale of a rating prompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RatingDisplayMode {
+    /// One symbol is rendered per point of the scale, filled up to the current value.
+    /// The symbols are configured in the render config.
+    Stars,
+
+    /// Every number of the scale is rendered, with the current value highlighted.
+    Numeric,
+}
+
+/// Prompt for ratings in a 1 to N scale, common in feedback forms.
+///
+/// The user adjusts the value with the left and right arrows, or jumps straight to a value by typing its digits, e.g. 1 then 0 for 10. The value is rendered as a row of filled and empty stars, or as the full numeric scale with the current value highlighted.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Maximum value**: Highest value of the scale, 5 by default. The lowest value is always 1.
+/// - **Starting value**: Value selected when the prompt is first rendered. Defaults to the middle of the scale. If it is out of the scale, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Display mode**: Whether to render the scale as stars or numbers, stars by default.
+/// - **Labels**: Optional labels rendered next to the lowest and highest ends of the scale, e.g. "Poor" and "Excellent".
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - By default, prints the value and the maximum of the scale, e.g. `4/5`.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{Rating, RatingDisplayMode};
+///
+/// let ans = Rating::new("How likely are you to recommend us?")
+///     .with_max(10)
+///     .with_display_mode(RatingDisplayMode::Numeric)
+///     .with_labels("Not likely", "Very likely")
+///     .prompt();
+///
+/// match ans {
+///     Ok(score) => println!("Thanks for the {}!", score),
+///     Err(_) => println!("Maybe next time"),
+/// }
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct Rating<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Highest value of the scale.
+    pub max: u8,
+
+    /// Value selected when the prompt is first rendered.
+    pub starting_value: Option<u8>,
+
+    /// How the scale is rendered.
+    pub display_mode: RatingDisplayMode,
+
+    /// Labels rendered next to the lowest and highest ends of the scale.
+    pub labels: Option<(&'a str, &'a str)>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// adjust the value using h and l.
+    pub vim_mode: bool,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: RatingFormatter<'a>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> Rating<'a> {
+    /// Default formatter, prints the value and the maximum of the scale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::Rating;
+    ///
+    /// let formatter = Rating::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("4/5"), formatter(4, 5));
+    /// ```
+    pub const DEFAULT_FORMATTER: RatingFormatter<'a> = &|value, max| format!("{}/{}", value, max);
+
+    /// Default highest value of the scale.
+    pub const DEFAULT_MAX: u8 = 5;
+
+    /// Default display mode.
+    pub const DEFAULT_DISPLAY_MODE: RatingDisplayMode = RatingDisplayMode::Stars;
+
+    /// Default value of vim mode, equal to the global default value [config::DEFAULT_VIM_MODE]
+    pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = Some("←→ to adjust, type a number to jump");
+
+    /// Creates a [Rating] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            max: Self::DEFAULT_MAX,
+            starting_value: None,
+            display_mode: Self::DEFAULT_DISPLAY_MODE,
+            labels: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the highest value of the scale.
+    pub fn with_max(mut self, max: u8) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the value selected when the prompt is first rendered.
+    pub fn with_starting_value(mut self, starting_value: u8) -> Self {
+        self.starting_value = Some(starting_value);
+        self
+    }
+
+    /// Sets the display mode of the scale.
+    pub fn with_display_mode(mut self, display_mode: RatingDisplayMode) -> Self {
+        self.display_mode = display_mode;
+        self
+    }
+
+    /// Sets the labels rendered next to the lowest and highest ends of the scale.
+    pub fn with_labels(mut self, low: &'a str, high: &'a str) -> Self {
+        self.labels = Some((low, high));
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: RatingFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<u8>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<u8> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: RatingBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<u8> {
+        RatingPrompt::new(self)?.prompt(backend)
+    }
+}
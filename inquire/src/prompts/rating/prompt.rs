@@ -0,0 +1,136 @@
+use crate::{
+    error::InquireResult,
+    formatter::RatingFormatter,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::RatingBackend,
+    InquireError, Rating, RatingDisplayMode,
+};
+
+use super::{action::RatingPromptAction, config::RatingConfig};
+
+pub struct RatingPrompt<'a> {
+    message: &'a str,
+    config: RatingConfig,
+    value: u8,
+    typed: Option<u8>,
+    display_mode: RatingDisplayMode,
+    labels: Option<(&'a str, &'a str)>,
+    help_message: Option<&'a str>,
+    formatter: RatingFormatter<'a>,
+}
+
+impl<'a> RatingPrompt<'a> {
+    pub fn new(rating: Rating<'a>) -> InquireResult<Self> {
+        if rating.max == 0 {
+            return Err(InquireError::InvalidConfiguration(
+                "Maximum rating must be at least 1".into(),
+            ));
+        }
+
+        let value = rating
+            .starting_value
+            .unwrap_or(rating.max / 2 + rating.max % 2);
+        if value == 0 || value > rating.max {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Starting value {} is out of the 1-{} scale",
+                value, rating.max
+            )));
+        }
+
+        Ok(Self {
+            message: rating.message,
+            config: (&rating).into(),
+            value,
+            typed: None,
+            display_mode: rating.display_mode,
+            labels: rating.labels,
+            help_message: rating.help_message,
+            formatter: rating.formatter,
+        })
+    }
+
+    fn set_value(&mut self, value: u8) -> ActionResult {
+        let value = value.clamp(1, self.config.max);
+
+        if value != self.value {
+            self.value = value;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn type_digit(&mut self, digit: u8) -> ActionResult {
+        let max = u16::from(self.config.max);
+        let value = self
+            .typed
+            .map(|typed| u16::from(typed) * 10 + u16::from(digit))
+            .filter(|value| *value <= max)
+            .unwrap_or_else(|| u16::from(digit));
+
+        if value == 0 || value > max {
+            self.typed = None;
+            return ActionResult::Clean;
+        }
+
+        self.typed = Some(value as u8);
+        self.set_value(value as u8)
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for RatingPrompt<'a>
+where
+    Backend: RatingBackend,
+{
+    type Config = RatingConfig;
+    type InnerAction = RatingPromptAction;
+    type Output = u8;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &RatingConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &u8) -> String {
+        (self.formatter)(*answer, self.config.max)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<u8>> {
+        Ok(Some(self.value))
+    }
+
+    fn handle(&mut self, action: RatingPromptAction) -> InquireResult<ActionResult> {
+        if !matches!(action, RatingPromptAction::TypeDigit(_)) {
+            self.typed = None;
+        }
+
+        let result = match action {
+            RatingPromptAction::TypeDigit(digit) => self.type_digit(digit),
+            RatingPromptAction::Decrease => self.set_value(self.value.saturating_sub(1)),
+            RatingPromptAction::Increase => self.set_value(self.value.saturating_add(1)),
+            RatingPromptAction::SetMin => self.set_value(1),
+            RatingPromptAction::SetMax => self.set_value(self.config.max),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        backend.render_rating_prompt(
+            self.message,
+            self.value,
+            self.config.max,
+            self.display_mode,
+            self.labels,
+        )?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    Rating,
+};
+
+#[test]
+fn starts_at_the_middle_of_the_scale() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Rating::new("Question")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(3, ans);
+}
+
+#[test]
+fn arrows_adjust_value_within_bounds() {
+    let mut backend = fake_backend(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Rating::new("Question")
+        .with_max(4)
+        .with_starting_value(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(3, ans);
+}
+
+#[test]
+fn digits_jump_to_value_and_out_of_scale_digits_are_ignored() {
+    let mut backend = fake_backend(vec![
+        Key::Char('9', KeyModifiers::NONE),
+        Key::Char('2', KeyModifiers::NONE),
+        Key::Char('0', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Rating::new("Question")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(2, ans);
+}
+
+#[test]
+fn out_of_scale_starting_value_is_rejected() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Rating::new("Question")
+        .with_starting_value(6)
+        .prompt_with_backend(&mut backend);
+
+    assert!(ans.is_err());
+}
+
+#[test]
+fn consecutive_digits_are_typed_as_one_value() {
+    let mut backend = fake_backend(vec![
+        Key::Char('1', KeyModifiers::NONE),
+        Key::Char('0', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Rating::new("Question")
+        .with_max(10)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(10, ans);
+
+    let mut backend = fake_backend(vec![
+        Key::Char('1', KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Char('4', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Rating::new("Question")
+        .with_max(20)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(4, ans);
+}
+
+#[test]
+fn widest_scale_starts_at_its_middle() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Rating::new("Question")
+        .with_max(255)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(128, ans);
+}
@@ -0,0 +1,89 @@
+use regex::{Regex, RegexBuilder};
+
+/// Filter of the options of a list prompt interpreting the filter input as a
+/// case-insensitive regular expression, when enabled.
+#[derive(Clone, Debug, Default)]
+pub struct RegexFilter {
+    enabled: bool,
+    regex: Option<Regex>,
+    invalid: bool,
+}
+
+impl RegexFilter {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            ..Self::default()
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Whether the last pattern could not be compiled.
+    pub fn is_invalid(&self) -> bool {
+        self.enabled && self.invalid
+    }
+
+    /// Enables or disables the filter, compiling the given pattern when enabled.
+    pub fn toggle(&mut self, pattern: &str) {
+        self.enabled = !self.enabled;
+        self.regex = None;
+        self.invalid = false;
+
+        if self.enabled {
+            self.update(pattern);
+        }
+    }
+
+    /// Compiles the given pattern. Invalid patterns keep the previous
+    /// expression in use, so that the options do not flicker while a
+    /// pattern is being typed.
+    pub fn update(&mut self, pattern: &str) {
+        match RegexBuilder::new(pattern).case_insensitive(true).build() {
+            Ok(regex) => {
+                self.regex = Some(regex);
+                self.invalid = false;
+            }
+            Err(_) => self.invalid = true,
+        }
+    }
+
+    /// Score of an option, `None` when it does not match the expression.
+    pub fn score(&self, value: &str) -> Option<i64> {
+        match &self.regex {
+            Some(regex) if !regex.is_match(value) => None,
+            _ => Some(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RegexFilter;
+
+    #[test]
+    fn matches_case_insensitively() {
+        let mut filter = RegexFilter::new(true);
+        filter.update(r"^/var/log/.*\.LOG$");
+
+        assert_eq!(Some(0), filter.score("/var/log/syslog.log"));
+        assert_eq!(None, filter.score("/var/lib/syslog.log"));
+    }
+
+    #[test]
+    fn invalid_patterns_keep_the_previous_expression() {
+        let mut filter = RegexFilter::new(true);
+        filter.update("err");
+        filter.update("err(");
+
+        assert!(filter.is_invalid());
+        assert_eq!(Some(0), filter.score("error"));
+        assert_eq!(None, filter.score("warning"));
+
+        filter.update("warn");
+        assert!(!filter.is_invalid());
+        assert_eq!(Some(0), filter.score("warning"));
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{
+    config::get_configuration,
+    error::InquireResult,
+    terminal::get_default_terminal,
+    ui::{Backend, SectionBackend},
+};
+
+/// Renders a section header, a title over a horizontal rule, to visually
+/// organize a sequence of prompts into groups.
+///
+/// The header is styled with the `section_title` and `section_rule` settings
+/// of the global [`RenderConfig`](crate::ui::RenderConfig), set through
+/// [`set_global_render_config`](crate::set_global_render_config).
+///
+/// # Example
+///
+/// ``` no_run
+/// # use inquire::{*, error::*};
+/// section("Database configuration")?;
+/// let host = Text::new("Host:").prompt()?;
+/// let port = CustomType::<u16>::new("Port:").prompt()?;
+///
+/// section("Admin user")?;
+/// let user = Text::new("Username:").prompt()?;
+/// # InquireResult::Ok(())
+/// ```
+///
+/// # Errors
+///
+/// This function will return an error if there is a problem interacting with the terminal.
+pub fn section<M>(title: M) -> InquireResult<()>
+where
+    M: AsRef<str>,
+{
+    let (input_reader, terminal) = get_default_terminal()?;
+    let mut backend = Backend::new(input_reader, terminal, get_configuration())?;
+    render_section(&mut backend, Some(title.as_ref()))
+}
+
+/// Renders a horizontal rule to separate groups of prompts.
+///
+/// The rule is drawn with the `section_rule` setting of the global
+/// [`RenderConfig`](crate::ui::RenderConfig), set through
+/// [`set_global_render_config`](crate::set_global_render_config).
+///
+/// # Errors
+///
+/// This function will return an error if there is a problem interacting with the terminal.
+pub fn separator() -> InquireResult<()> {
+    let (input_reader, terminal) = get_default_terminal()?;
+    let mut backend = Backend::new(input_reader, terminal, get_configuration())?;
+    render_section(&mut backend, None)
+}
+
+pub(crate) fn render_section<B: SectionBackend>(
+    backend: &mut B,
+    title: Option<&str>,
+) -> InquireResult<()> {
+    backend.frame_setup()?;
+    backend.render_section(title)?;
+    backend.frame_finish(true)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        terminal::crossterm::CrosstermTerminal,
+        ui::{Backend, Key, RenderConfig, Styled},
+    };
+
+    use super::render_section;
+
+    fn render(title: Option<&str>) -> String {
+        let render_config = RenderConfig::empty().with_section_rule(Styled::new("="));
+        let mut backend = Backend::new(
+            Vec::<Key>::new().into_iter(),
+            CrosstermTerminal::new_in_memory_output(),
+            render_config,
+        )
+        .unwrap();
+
+        render_section(&mut backend, title).unwrap();
+
+        String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap()
+    }
+
+    #[test]
+    fn section_title_is_rendered_over_a_rule() {
+        let output = render(Some("Database"));
+
+        assert!(output.contains(&format!("== Database {}", "=".repeat(68))));
+        assert!(!output.contains(&"=".repeat(69)));
+    }
+
+    #[test]
+    fn separator_is_a_rule() {
+        let output = render(None);
+
+        assert!(output.contains(&"=".repeat(80)));
+        assert!(!output.contains(&"=".repeat(81)));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    ui::{Key, KeyModifiers},
+    ui::{Key, KeyHelp, KeyModifiers},
     InnerAction, InputAction,
 };
 
@@ -22,13 +22,26 @@ pub enum SelectPromptAction {
     MoveToStart,
     /// Moves the cursor to the end of the list.
     MoveToEnd,
+    /// Completes the filter to the longest common prefix of the matching options.
+    CompleteFilter,
+    /// Starts typing a search query.
+    StartSearch,
+    /// Action on the search query input handler.
+    SearchInput(InputAction),
+    /// Moves the cursor to the next option matching the search query.
+    NextMatch,
+    /// Moves the cursor to the previous option matching the search query.
+    PreviousMatch,
+    /// Toggles the interpretation of the filter input as a regular expression.
+    #[cfg(feature = "regex")]
+    ToggleRegexFilter,
 }
 
 impl InnerAction for SelectPromptAction {
     type Config = SelectConfig;
 
     fn from_key(key: Key, config: &SelectConfig) -> Option<Self> {
-        if config.vim_mode {
+        if config.vim_mode && !config.searching {
             let action = match key {
                 Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
                 Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
@@ -48,6 +61,17 @@ impl InnerAction for SelectPromptAction {
             Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
             Key::PageDown(_) => Self::PageDown,
             Key::End => Self::MoveToEnd,
+            Key::Tab => Self::CompleteFilter,
+            #[cfg(feature = "regex")]
+            Key::Char('r', KeyModifiers::CONTROL) => Self::ToggleRegexFilter,
+
+            key if config.searching => match InputAction::from_key(key, &()) {
+                Some(action) => Self::SearchInput(action),
+                None => return None,
+            },
+            Key::Char('/', KeyModifiers::NONE) if config.search_mode => Self::StartSearch,
+            Key::Char('n', KeyModifiers::NONE) if config.search_mode => Self::NextMatch,
+            Key::Char('N', _) if config.search_mode => Self::PreviousMatch,
 
             key => match InputAction::from_key(key, &()) {
                 Some(action) => Self::FilterInput(action),
@@ -57,4 +81,55 @@ impl InnerAction for SelectPromptAction {
 
         Some(action)
     }
+
+    fn key_help(config: &SelectConfig) -> Vec<KeyHelp> {
+        let mut up = vec![
+            Key::Up(KeyModifiers::NONE),
+            Key::Char('p', KeyModifiers::CONTROL),
+        ];
+        let mut down = vec![
+            Key::Down(KeyModifiers::NONE),
+            Key::Char('n', KeyModifiers::CONTROL),
+        ];
+        if config.vim_mode {
+            up.push(Key::Char('k', KeyModifiers::NONE));
+            down.push(Key::Char('j', KeyModifiers::NONE));
+        }
+
+        let mut help = vec![
+            KeyHelp::new(up, "move up"),
+            KeyHelp::new(down, "move down"),
+            KeyHelp::new(
+                [
+                    Key::PageUp(KeyModifiers::NONE),
+                    Key::PageDown(KeyModifiers::NONE),
+                ],
+                "move by page",
+            ),
+            KeyHelp::new([Key::Home, Key::End], "move to the first or last option"),
+        ];
+
+        if config.search_mode {
+            help.push(KeyHelp::new(
+                [Key::Char('/', KeyModifiers::NONE)],
+                "search the options",
+            ));
+            help.push(KeyHelp::new(
+                [
+                    Key::Char('n', KeyModifiers::NONE),
+                    Key::Char('N', KeyModifiers::NONE),
+                ],
+                "move to the next or previous match",
+            ));
+        } else {
+            help.push(KeyHelp::new([Key::Tab], "complete the filter"));
+            #[cfg(feature = "regex")]
+            help.push(KeyHelp::new(
+                [Key::Char('r', KeyModifiers::CONTROL)],
+                "toggle the regular expression filter",
+            ));
+        }
+
+        help
+    }
 }
@@ -6,27 +6,31 @@ mod prompt;
 mod test;
 
 pub use action::*;
-use std::fmt::Display;
+use std::{collections::BTreeSet, fmt::Display, time::Duration};
 
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
-    formatter::OptionFormatter,
-    list_option::ListOption,
-    prompts::prompt::Prompt,
+    formatter::{FilteredOptionFormatter, LiveOptionFormatter, OptionFormatter},
+    list_option::{FilteredAnswer, ListOption, QuizAnswer},
+    persistence::UsageStore,
+    prompts::prompt::{lifecycle_event, Prompt},
     terminal::get_default_terminal,
-    type_aliases::Scorer,
-    ui::{Backend, RenderConfig, SelectBackend},
+    type_aliases::{OptionKey, OptionPreview, OptionProvider, Scorer},
+    ui::{Backend, QuizBackend, RenderConfig, SelectBackend},
 };
 
 use self::prompt::SelectPrompt;
 
+#[cfg(feature = "async")]
+use futures_core::Stream;
+
 #[cfg(feature = "fuzzy")]
-use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
-#[cfg(feature = "fuzzy")]
-use once_cell::sync::Lazy;
-#[cfg(feature = "fuzzy")]
-static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::default().ignore_case());
+use crate::prompts::fuzzy_filter;
+#[cfg(feature = "async")]
+use crate::prompts::option_stream::OptionStream;
+#[cfg(not(feature = "fuzzy"))]
+use crate::utils::extended_filter_score;
 /// Prompt suitable for when you need the user to select one option among many.
 ///
 /// The user can select and submit the current highlighted option by pressing enter.
@@ -47,9 +51,29 @@ static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::defaul
 /// - **Help message**: Message displayed at the line below the prompt.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 ///   - Prints the selected option string value by default.
-/// - **Page size**: Number of options displayed at once, 7 by default.
+///   - A filter formatter can be set instead to also receive the filter text typed by the user.
+/// - **Option formatter**: Function controlling how each option is displayed while the prompt is live, receiving whether it is highlighted, e.g. to add badges or color options by status. Independent of the formatter of the final answer.
+/// - **Page size**: Number of options displayed at once, 7 by default. Also the distance covered by the page up and page down keys.
+/// - **Max visible options**: Number of options displayed at once, overriding the page size for rendering purposes only. The list scrolls within these rows, keeping the cursor centered, while page up and page down still move by the page size.
+/// - **Filter header**: Whether to render a line between the prompt and the options showing the current filter text and the number of matching options, so the user knows why the list looks the way it does. Disabled by default.
+/// - **Hide filter input**: Whether to hide the filter input from the prompt line while keeping type-to-filter active. The filter text is then surfaced in the filter header, rendered as soon as the user types. Disabled by default.
+/// - **Auto-select single match**: Whether to select the remaining option as soon as the filter narrows the list down to exactly one option, without waiting for the user to press enter. Disabled by default, in which case pressing enter selects the highlighted option, which is the single remaining one in that situation.
+/// - **Scrolloff**: Number of options kept visible above and below the cursor while scrolling, like vim's `scrolloff`. When set, the list only scrolls once the cursor gets that close to an edge, instead of keeping the cursor centered.
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
 /// - **Scorer function**: Function that defines the order of options and if displayed as all.
+/// - **Score threshold**: Minimum score of the options listed while filtering, to prune low-quality matches. No threshold by default.
+/// - **Max displayed matches**: Maximum number of options listed while filtering, keeping the best-scored ones. Unlimited by default.
+/// - **Fuzzy filter**: Whether the options are filtered by the built-in fuzzy matcher instead of the scorer, ranked skim-style with the matched characters highlighted using the `option_match` style of the `RenderConfig`. Requires the `fuzzy` feature, disabled by default.
+/// - **Regex filter**: Whether the filter input is interpreted as a case-insensitive regular expression, e.g. `^/var/log/.*\.log$`, instead of going through the scorer. The user can toggle it with Ctrl+R. Requires the `regex` feature, disabled by default.
+/// - **Search mode**: Whether `/` starts a search instead of the options being filtered as the user types. Once confirmed, `n` and `N` jump between the matching options while every option stays listed, to see the context around the matches.
+/// - **Skip option**: Label of an extra choice rendered below the options, e.g. `(none)`. Choosing it skips the prompt like pressing ESC, so [`Select::prompt_skippable`] returns `Ok(None)`.
+/// - **Usage store**: Store used to rank options by how frequently and how recently they were chosen, keyed by a prompt ID. Options are listed by descending frecency whenever the scorer does not tell them apart, e.g. before the user types a filter.
+/// - **Option key**: Function computing a stable key identifying each option, defaulting to its string value. Keys identify the options across refreshes and in the usage store, and can set the starting cursor.
+/// - **Refresh**: Function periodically called to replace the options while the prompt is displayed, e.g. a pod picker updating statuses. The cursor stays on the highlighted option, identified by its key, as long as it is still listed.
+/// - **Timeout**: Maximum time to wait for an answer, after which the prompt fails with [`InquireError::Timeout`](crate::InquireError::Timeout). The time left can optionally be rendered below the prompt.
+/// - **Elapsed time**: Whether to render the time elapsed since the prompt was displayed below it, ticking every second, e.g. for time-boxed decisions.
+/// - **Preview**: Function computing the details of the highlighted option, e.g. the full message of a commit, rendered in a pane to the right of the options and updated as the cursor moves. The width of the pane is configurable.
+/// - **Correct answer**: Index of the correct option, for quizzes. When prompted with [`Select::quiz_prompt`], the options are listed again after submission with the correct one and the wrongly chosen one marked.
 ///
 /// # Example
 ///
@@ -83,6 +107,27 @@ pub struct Select<'a, T> {
     /// Page size of the options displayed to the user.
     pub page_size: usize,
 
+    /// Number of options displayed at once, independently of the page size
+    /// used by page up and page down. Defaults to the page size when `None`.
+    pub max_visible_options: Option<usize>,
+
+    /// Number of options kept visible above and below the cursor while
+    /// scrolling. When `None`, the default, the cursor is kept centered.
+    pub scrolloff: Option<usize>,
+
+    /// Whether to render a line between the prompt and the options showing the
+    /// current filter text and the number of matching options.
+    pub show_filter_header: bool,
+
+    /// Whether to hide the filter input from the prompt line while keeping
+    /// type-to-filter active. The filter header is rendered whenever the
+    /// hidden filter is not empty.
+    pub hide_filter_input: bool,
+
+    /// Whether to select the remaining option as soon as the filter narrows
+    /// the list down to exactly one option.
+    pub auto_select_single_match: bool,
+
     /// Whether vim mode is enabled. When enabled, the user can
     /// navigate through the options using hjkl.
     pub vim_mode: bool,
@@ -90,6 +135,15 @@ pub struct Select<'a, T> {
     /// Starting cursor index of the selection.
     pub starting_cursor: usize,
 
+    /// Whether the starting cursor was set with
+    /// [`with_starting_cursor`](Self::with_starting_cursor), in which case it
+    /// is kept on its option instead of the top-ranked one of the usage store.
+    starting_cursor_set: bool,
+
+    /// Key of the option to place the starting cursor on, overriding the
+    /// starting cursor index.
+    pub starting_cursor_key: Option<&'a str>,
+
     /// Starting filter input
     pub starting_filter_input: Option<&'a str>,
 
@@ -102,13 +156,89 @@ pub struct Select<'a, T> {
     /// Defaults to true.
     pub filter_input_enabled: bool,
 
+    /// Whether `/` starts a search moving the cursor between the matching
+    /// options with `n` and `N`, listing every option, instead of the options
+    /// being filtered as the user types.
+    pub search_mode: bool,
+
+    /// Whether the options are filtered by the built-in fuzzy matcher, with
+    /// the matched characters highlighted, instead of going through the scorer.
+    #[cfg(feature = "fuzzy")]
+    pub fuzzy_filter: bool,
+
+    /// Whether the filter input is interpreted as a regular expression
+    /// instead of going through the scorer.
+    #[cfg(feature = "regex")]
+    pub regex_filter: bool,
+
+    /// Label of an extra choice rendered below the options, letting the user
+    /// skip the prompt.
+    pub skip_option: Option<&'a str>,
+
     /// Function called with the current user input to score the provided
     /// options.
     pub scorer: Scorer<'a, T>,
 
+    /// Minimum score of the options listed while a filter is typed, pruning
+    /// low-quality matches. Options are not pruned when `None`.
+    pub score_threshold: Option<i64>,
+
+    /// Maximum number of options listed while a filter is typed, keeping the
+    /// best-scored ones. Every match is listed when `None`.
+    pub max_displayed_matches: Option<usize>,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: OptionFormatter<'a, T>,
 
+    /// Function that formats the user input along with the filter text typed by
+    /// the user. When set, it is used in place of [`formatter`](Self::formatter).
+    pub filter_formatter: Option<FilteredOptionFormatter<'a, T>>,
+
+    /// Function that formats each option while the prompt is displayed. Options
+    /// are displayed by their string value when `None`.
+    pub option_formatter: Option<LiveOptionFormatter<'a, T>>,
+
+    /// Function computing the details of the highlighted option, rendered in a
+    /// preview pane to the right of the options. No pane is rendered when `None`.
+    pub preview: Option<OptionPreview<'a, T>>,
+
+    /// Width of the preview pane, in columns. The pane is narrowed down to half
+    /// of the terminal when it is not wide enough.
+    pub preview_width: usize,
+
+    /// Prompt ID and store used to rank options by how frequently and how
+    /// recently they were chosen.
+    ///
+    /// When set, the chosen option is recorded in the store, and options are
+    /// listed by descending frecency whenever the filter does not tell them apart.
+    pub usage_store: Option<(&'a str, &'a dyn UsageStore)>,
+
+    /// Function computing the stable key identifying each option. Defaults to
+    /// the string value of the option when `None`.
+    pub option_key: Option<OptionKey<'a, T>>,
+
+    /// Interval and provider used to refresh the options while the prompt
+    /// is displayed.
+    pub refresh: Option<(Duration, OptionProvider<'a, T>)>,
+
+    /// Stream of options appended to the list as they arrive, set with
+    /// [`with_option_stream`](Self::with_option_stream).
+    #[cfg(feature = "async")]
+    option_stream: Option<OptionStream<'a, T>>,
+
+    /// Maximum time to wait for an answer before failing with [`InquireError::Timeout`].
+    pub timeout: Option<Duration>,
+
+    /// Whether to render the time left until the timeout elapses.
+    pub show_remaining_time: bool,
+
+    /// Whether to render the time elapsed since the prompt was displayed, or the
+    /// time left when a timeout is set.
+    pub show_elapsed_time: bool,
+
+    /// Index of the correct option, used by [`quiz_prompt`](Self::quiz_prompt).
+    pub correct_answer: Option<usize>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -142,6 +272,9 @@ where
     /// Default scoring function, which will create a score for the current option using the input value.
     /// The return will be sorted in Descending order, leaving options with None as a score.
     ///
+    /// Space-separated terms are all required to match, summing their scores, while terms
+    /// prefixed by `!` exclude the options containing them, e.g. `prod !east`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -161,23 +294,28 @@ where
     /// assert_eq!(None,     scorer("sa", &"Austin",        "Austin",        10));
     /// assert_eq!(None,     scorer("sa", &"Jacksonville",  "Jacksonville",  11));
     /// assert_eq!(Some(49), scorer("sa", &"San Jose",      "San Jose",      12));
+    ///
+    /// assert_eq!(None,     scorer("sa !an", &"San Jose",  "San Jose",      12));
+    /// assert_eq!(Some(0),  scorer("!jose",  &"Austin",    "Austin",        10));
     /// ```
     #[cfg(feature = "fuzzy")]
     pub const DEFAULT_SCORER: Scorer<'a, T> =
         &|input, _option, string_value, _idx| -> Option<i64> {
-            DEFAULT_MATCHER.fuzzy_match(string_value, input)
+            fuzzy_filter::score(input, string_value)
         };
 
     #[cfg(not(feature = "fuzzy"))]
     pub const DEFAULT_SCORER: Scorer<'a, T> =
         &|input, _option, string_value, _idx| -> Option<i64> {
-            let filter = input.to_lowercase();
-            match string_value.to_lowercase().contains(&filter) {
-                true => Some(0),
-                false => None,
-            }
+            let value = string_value.to_lowercase();
+            extended_filter_score(input, string_value, |term| {
+                value.contains(&term.to_lowercase()).then_some(0)
+            })
         };
 
+    /// Default width of the preview pane, in columns.
+    pub const DEFAULT_PREVIEW_WIDTH: usize = 40;
+
     /// Default page size.
     pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
 
@@ -206,13 +344,41 @@ where
             options,
             help_message: Self::DEFAULT_HELP_MESSAGE,
             page_size: Self::DEFAULT_PAGE_SIZE,
+            max_visible_options: None,
+            scrolloff: None,
+            show_filter_header: false,
+            hide_filter_input: false,
+            auto_select_single_match: false,
             vim_mode: Self::DEFAULT_VIM_MODE,
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
+            starting_cursor_set: false,
             reset_cursor: Self::DEFAULT_RESET_CURSOR,
             filter_input_enabled: Self::DEFAULT_FILTER_INPUT_ENABLED,
+            search_mode: false,
+            #[cfg(feature = "fuzzy")]
+            fuzzy_filter: false,
+            #[cfg(feature = "regex")]
+            regex_filter: false,
+            skip_option: None,
             scorer: Self::DEFAULT_SCORER,
+            score_threshold: None,
+            max_displayed_matches: None,
             formatter: Self::DEFAULT_FORMATTER,
+            filter_formatter: None,
+            option_formatter: None,
+            preview: None,
+            preview_width: Self::DEFAULT_PREVIEW_WIDTH,
+            usage_store: None,
+            option_key: None,
+            refresh: None,
+            #[cfg(feature = "async")]
+            option_stream: None,
+            timeout: None,
+            show_remaining_time: false,
+            show_elapsed_time: false,
+            correct_answer: None,
             render_config: get_configuration(),
+            starting_cursor_key: None,
             starting_filter_input: None,
         }
     }
@@ -235,6 +401,52 @@ where
         self
     }
 
+    /// Sets the number of options displayed at once, leaving the page size to
+    /// only define how far page up and page down move the cursor.
+    pub fn with_max_visible_options(mut self, max_visible_options: usize) -> Self {
+        self.max_visible_options = Some(max_visible_options);
+        self
+    }
+
+    /// Sets the number of options kept visible above and below the cursor while
+    /// scrolling. The list then only scrolls once the cursor gets that close to
+    /// one of its edges, instead of keeping the cursor centered.
+    ///
+    /// Values larger than half the visible options keep the cursor centered.
+    pub fn with_scrolloff(mut self, scrolloff: usize) -> Self {
+        self.scrolloff = Some(scrolloff);
+        self
+    }
+
+    /// Sets whether to render a line between the prompt and the options showing
+    /// the current filter text and the number of matching options.
+    ///
+    /// The line is styled with the `filter_header` style sheet of the render
+    /// config, and is not rendered when the filter input is disabled.
+    pub fn with_show_filter_header(mut self, show_filter_header: bool) -> Self {
+        self.show_filter_header = show_filter_header;
+        self
+    }
+
+    /// Sets whether to hide the filter input from the prompt line while still
+    /// filtering the options as the user types.
+    ///
+    /// The filter text is then only surfaced in the filter header, which is
+    /// rendered whenever the hidden filter is not empty, regardless of
+    /// [`with_show_filter_header`](Self::with_show_filter_header).
+    pub fn with_hide_filter_input(mut self, hide_filter_input: bool) -> Self {
+        self.hide_filter_input = hide_filter_input;
+        self
+    }
+
+    /// Sets whether to select the remaining option as soon as the filter
+    /// narrows the list down to exactly one option, without waiting for the
+    /// user to press enter.
+    pub fn with_auto_select_single_match(mut self, auto_select_single_match: bool) -> Self {
+        self.auto_select_single_match = auto_select_single_match;
+        self
+    }
+
     /// Enables or disables vim_mode.
     pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
         self.vim_mode = vim_mode;
@@ -247,18 +459,141 @@ where
         self
     }
 
+    /// Sets the minimum score of the options listed while a filter is typed,
+    /// pruning noisy low-quality matches, e.g. from a fuzzy scorer.
+    ///
+    /// The threshold applies to the scores of the scorer, or of the fuzzy
+    /// filter when enabled, and is ignored while the filter is empty.
+    pub fn with_score_threshold(mut self, score_threshold: i64) -> Self {
+        self.score_threshold = Some(score_threshold);
+        self
+    }
+
+    /// Sets the maximum number of options listed while a filter is typed,
+    /// keeping only the best-scored matches so that giant result sets do not
+    /// bury them under pages of poor matches.
+    pub fn with_max_displayed_matches(mut self, max_displayed_matches: usize) -> Self {
+        self.max_displayed_matches = Some(max_displayed_matches);
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
         self.formatter = formatter;
         self
     }
 
+    /// Sets a formatter that also receives the filter text typed by the user at
+    /// the time of submission, used in place of the regular formatter.
+    pub fn with_filter_formatter(mut self, formatter: FilteredOptionFormatter<'a, T>) -> Self {
+        self.filter_formatter = Some(formatter);
+        self
+    }
+
+    /// Sets the formatter controlling how each option is displayed while the
+    /// prompt is live, independently of the formatter of the final answer.
+    ///
+    /// The style of the returned text is applied on top of the option style of
+    /// the render config. Fuzzy matches are not highlighted in formatted options.
+    pub fn with_option_formatter(mut self, formatter: LiveOptionFormatter<'a, T>) -> Self {
+        self.option_formatter = Some(formatter);
+        self
+    }
+
+    /// Renders a preview pane to the right of the options, showing the text
+    /// computed by the given function for the highlighted option. The pane is
+    /// updated as the cursor moves.
+    pub fn with_preview(mut self, preview: OptionPreview<'a, T>) -> Self {
+        self.preview = Some(preview);
+        self
+    }
+
+    /// Sets the width of the preview pane, in columns.
+    pub fn with_preview_width(mut self, width: usize) -> Self {
+        self.preview_width = width;
+        self
+    }
+
+    /// Ranks options by how frequently and how recently they were chosen,
+    /// recording the chosen option in the store under the given prompt ID.
+    ///
+    /// The store only helps ranking the options: when it fails to load or to
+    /// record, the options keep their order and the answer is returned anyway,
+    /// the error being logged when the `log` feature is enabled.
+    pub fn with_usage_store(mut self, prompt_id: &'a str, store: &'a dyn UsageStore) -> Self {
+        self.usage_store = Some((prompt_id, store));
+        self
+    }
+
+    /// Replaces the options with the ones returned by the provider every
+    /// `interval`, without waiting for the user to press a key.
+    ///
+    /// Options are identified by their [key](Select::with_option_key) across
+    /// refreshes, keeping the cursor on the highlighted option as long as it is
+    /// still listed. The index of the answer refers to the options of the
+    /// latest refresh.
+    ///
+    /// Refreshes can only happen while waiting for a key when using the
+    /// `crossterm` backend. Other backends refresh on the next key press.
+    pub fn with_refresh(mut self, interval: Duration, provider: OptionProvider<'a, T>) -> Self {
+        self.refresh = Some((interval, provider));
+        self
+    }
+
+    /// Appends the options yielded by the stream to the list as they arrive,
+    /// rendering a loading indicator until the stream ends. The list of options
+    /// can be empty when a stream is set.
+    ///
+    /// The stream is polled with a no-op waker on every tick of the prompt
+    /// rather than on an executor, so futures needing a runtime context, e.g.
+    /// tokio timers or sockets, never complete. It must be driven by something
+    /// else, e.g. a channel fed by another thread or by a task spawned on the
+    /// caller's runtime. While the stream is loading, it takes the place of the
+    /// [refresh](Select::with_refresh).
+    ///
+    /// The stream can only be consumed once: a clone of this prompt lists the
+    /// options given upfront only.
+    ///
+    /// Options can only be received while waiting for a key when using the
+    /// `crossterm` backend. Other backends receive them on the next key press.
+    #[cfg(feature = "async")]
+    pub fn with_option_stream<S>(mut self, stream: S) -> Self
+    where
+        S: Stream<Item = T> + 'a,
+    {
+        self.option_stream = Some(OptionStream::new(stream));
+        self
+    }
+
+    /// Sets the function computing the stable key identifying each option,
+    /// used in place of its string value to keep the cursor on the same option
+    /// across refreshes, to record it in the usage store, and to set the
+    /// starting cursor with [`with_starting_cursor_key`](Select::with_starting_cursor_key).
+    pub fn with_option_key(mut self, option_key: OptionKey<'a, T>) -> Self {
+        self.option_key = Some(option_key);
+        self
+    }
+
+    /// Sets the key of the option to place the starting cursor on, overriding
+    /// the starting cursor index.
+    ///
+    /// If no option has the given key, the prompt fails with an
+    /// [`InquireError::InvalidConfiguration`] error.
+    pub fn with_starting_cursor_key(mut self, key: &'a str) -> Self {
+        self.starting_cursor_key = Some(key);
+        self
+    }
+
     /// Sets the starting cursor index.
     ///
     /// This index might be overridden if the `reset_cursor` option is set to true (default)
     /// and starting_filter_input is set to something other than None.
+    ///
+    /// With a [usage store](Select::with_usage_store), the cursor starts on the
+    /// option at this index wherever it is ranked, instead of the top-ranked one.
     pub fn with_starting_cursor(mut self, starting_cursor: usize) -> Self {
         self.starting_cursor = starting_cursor;
+        self.starting_cursor_set = true;
         self
     }
 
@@ -277,6 +612,44 @@ where
         self
     }
 
+    /// Sets whether `/` starts a search instead of the options being filtered
+    /// as the user types, disabling the filter input.
+    ///
+    /// Once the query is confirmed with enter, `n` and `N` move the cursor to
+    /// the next and previous options matching it, according to the scorer,
+    /// while every option stays listed to keep the context around the matches.
+    pub fn with_search_mode(mut self, search_mode: bool) -> Self {
+        self.search_mode = search_mode;
+        self
+    }
+
+    /// Filters the options with the built-in fuzzy matcher instead of the
+    /// scorer, ranking them skim-style by how well they match, and highlights
+    /// the matched characters of each listed option.
+    ///
+    /// Like the default scorer, space-separated terms are all required to
+    /// match while terms prefixed by `!` exclude the options containing them.
+    /// The highlighting style is the `option_match` style sheet of the
+    /// [RenderConfig].
+    #[cfg(feature = "fuzzy")]
+    pub fn with_fuzzy_filter(mut self) -> Self {
+        self.fuzzy_filter = true;
+        self
+    }
+
+    /// Sets whether the filter input starts out interpreted as a
+    /// case-insensitive regular expression, matched against the string value
+    /// of each option instead of going through the scorer.
+    ///
+    /// The user can toggle it at any time with Ctrl+R. While the pattern being
+    /// typed is invalid, the previous expression stays in use and the filter
+    /// header flags the pattern.
+    #[cfg(feature = "regex")]
+    pub fn with_regex_filter(mut self, regex_filter: bool) -> Self {
+        self.regex_filter = regex_filter;
+        self
+    }
+
     /// Disables the filter input, which means the user will not be able to filter the options
     /// by typing.
     ///
@@ -287,6 +660,48 @@ where
         self
     }
 
+    /// Adds a choice rendered below the options, e.g. `(none)`, for questions
+    /// whose answer is optional.
+    ///
+    /// Choosing it renders the label as the answer and skips the prompt:
+    /// [`prompt_skippable`](Select::prompt_skippable) returns `Ok(None)`, while
+    /// the other prompt methods fail with [`InquireError::OperationSkipped`].
+    pub fn with_skip_option(mut self, label: &'a str) -> Self {
+        self.skip_option = Some(label);
+        self
+    }
+
+    /// Sets the maximum time to wait for an answer, after which the prompt
+    /// fails with [`InquireError::Timeout`].
+    ///
+    /// The deadline can only interrupt a pending key read when using the
+    /// `crossterm` backend. Other backends notice it on the next key press.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to render the time left until the timeout elapses.
+    pub fn with_show_remaining_time(mut self, show_remaining_time: bool) -> Self {
+        self.show_remaining_time = show_remaining_time;
+        self
+    }
+
+    /// Sets whether to render the time elapsed since the prompt was displayed,
+    /// ticking every second. When a timeout is set, the time left is rendered
+    /// instead.
+    pub fn with_show_elapsed_time(mut self, show_elapsed_time: bool) -> Self {
+        self.show_elapsed_time = show_elapsed_time;
+        self
+    }
+
+    /// Sets the index of the correct option, turning the prompt into a quiz
+    /// when prompted with [`quiz_prompt`](Select::quiz_prompt).
+    pub fn with_correct_answer(mut self, index: usize) -> Self {
+        self.correct_answer = Some(index);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -314,13 +729,14 @@ where
     /// This method is intended for flows where the user skipping/cancelling
     /// the prompt - by pressing ESC - is considered normal behavior. In this case,
     /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    /// The same goes for choosing the skip option, if any.
     ///
     /// Meanwhile, if the user does submit an answer, the method wraps the return
     /// type with `Some`.
     pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
         match self.prompt() {
             Ok(answer) => Ok(Some(answer)),
-            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(InquireError::OperationCanceled | InquireError::OperationSkipped) => Ok(None),
             Err(err) => Err(err),
         }
     }
@@ -331,15 +747,106 @@ where
     /// Returns a [`ListOption`](crate::list_option::ListOption) containing
     /// the index of the selection and the owned object selected by the user.
     pub fn raw_prompt(self) -> InquireResult<ListOption<T>> {
+        self.raw_prompt_with_filter()
+            .map(|filtered| filtered.answer)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a [`FilteredAnswer`] containing the [`ListOption`] selected by
+    /// the user and the filter text typed at the time of submission.
+    pub fn raw_prompt_with_filter(self) -> InquireResult<FilteredAnswer<ListOption<T>>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.filtered_prompt_with_backend(&mut backend)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules, as a quiz.
+    ///
+    /// After submission, every option is listed again below the answer, with
+    /// the correct option and the chosen one, if wrong, marked and styled
+    /// according to the [`QuizRenderConfig`](crate::ui::QuizRenderConfig).
+    ///
+    /// Returns a [`QuizAnswer`] containing the [`ListOption`] selected by the
+    /// user and whether it is the correct one. Fails with an
+    /// [`InquireError::InvalidConfiguration`] error if the correct answer is
+    /// not set or out-of-range.
+    pub fn quiz_prompt(self) -> InquireResult<QuizAnswer<ListOption<T>>> {
         let (input_reader, terminal) = get_default_terminal()?;
         let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
-        self.prompt_with_backend(&mut backend)
+        self.quiz_prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn quiz_prompt_with_backend<B: SelectBackend + QuizBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<QuizAnswer<ListOption<T>>> {
+        let correct = match self.correct_answer {
+            Some(correct) if correct < self.options.len() => correct,
+            Some(correct) => {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Correct answer {} is out-of-range of the {} options",
+                    correct,
+                    self.options.len()
+                )))
+            }
+            None => {
+                return Err(InquireError::InvalidConfiguration(
+                    "The correct answer must be set to prompt a quiz".into(),
+                ))
+            }
+        };
+        let options: Vec<String> = self.options.iter().map(T::to_string).collect();
+
+        let answer = self.prompt_with_backend(backend)?;
+
+        backend.frame_setup()?;
+        backend.render_quiz_result(
+            &options,
+            &BTreeSet::from([answer.index]),
+            &BTreeSet::from([correct]),
+        )?;
+        backend.frame_finish(true)?;
+
+        let is_correct = answer.index == correct;
+        Ok(QuizAnswer::new(answer, is_correct))
     }
 
     pub(crate) fn prompt_with_backend<B: SelectBackend>(
         self,
         backend: &mut B,
     ) -> InquireResult<ListOption<T>> {
-        SelectPrompt::new(self)?.prompt(backend)
+        self.filtered_prompt_with_backend(backend)
+            .map(|filtered| filtered.answer)
+    }
+
+    pub(crate) fn filtered_prompt_with_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<FilteredAnswer<ListOption<T>>> {
+        let usage_store = self.usage_store;
+        let option_key = self.option_key;
+
+        let answer = SelectPrompt::new(self)?.prompt(backend)?;
+
+        if let Some((prompt_id, store)) = usage_store {
+            let value = &answer.answer.value;
+            let key = match option_key {
+                Some(option_key) => option_key(value),
+                None => value.to_string(),
+            };
+            if let Err(_err) = store.record(prompt_id, &key) {
+                lifecycle_event!(
+                    warn,
+                    "usage of prompt {:?} not recorded: {}",
+                    prompt_id,
+                    _err
+                );
+            }
+        }
+
+        Ok(answer)
     }
 }
@@ -1,30 +1,73 @@
-use std::{cmp::Reverse, fmt::Display};
+use std::time::Duration;
+
+use std::{
+    cmp::{min, Reverse},
+    fmt::Display,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use crate::{
+    ansi::AnsiStrippable,
     error::InquireResult,
-    formatter::OptionFormatter,
+    formatter::{FilteredOptionFormatter, LiveOptionFormatter, OptionFormatter},
     input::{Input, InputActionResult},
-    list_option::ListOption,
-    prompts::prompt::{ActionResult, Prompt},
-    type_aliases::Scorer,
+    list_option::{FilteredAnswer, ListOption},
+    persistence::UsageEntry,
+    prompts::prompt::{lifecycle_event, ActionResult, Prompt},
+    type_aliases::{OptionKey, OptionPreview, OptionProvider, Scorer},
     ui::SelectBackend,
-    utils::paginate,
+    utils::{
+        format_page, longest_common_prefix, option_keys, paginate, paginate_from,
+        scroll_window_start,
+    },
     InquireError, Select,
 };
 
+#[cfg(feature = "fuzzy")]
+use crate::prompts::fuzzy_filter;
+#[cfg(feature = "async")]
+use crate::prompts::option_stream::{OptionStream, OPTION_STREAM_POLL_INTERVAL};
+#[cfg(feature = "regex")]
+use crate::prompts::regex_filter::RegexFilter;
+
 use super::{action::SelectPromptAction, config::SelectConfig};
 
 pub struct SelectPrompt<'a, T> {
     message: &'a str,
+    timeout: Option<Duration>,
+    show_remaining_time: bool,
+    show_elapsed_time: bool,
     config: SelectConfig,
     options: Vec<T>,
     string_options: Vec<String>,
+    keys: Vec<String>,
     scored_options: Vec<usize>,
+    frecency: Vec<u64>,
+    usage_entries: Vec<UsageEntry>,
+    usage_time: u64,
+    option_key: Option<OptionKey<'a, T>>,
+    refresh: Option<(Duration, OptionProvider<'a, T>)>,
+    #[cfg(feature = "async")]
+    option_stream: Option<OptionStream<'a, T>>,
+    #[cfg(feature = "async")]
+    loading_ticks: usize,
     help_message: Option<&'a str>,
+    skip_option: Option<&'a str>,
     cursor_index: usize,
+    scroll_window_start: usize,
+    single_match_filtered: bool,
     input: Option<Input>,
+    search_input: Input,
     scorer: Scorer<'a, T>,
+    #[cfg(feature = "fuzzy")]
+    fuzzy_filter: bool,
+    #[cfg(feature = "regex")]
+    regex_filter: RegexFilter,
     formatter: OptionFormatter<'a, T>,
+    filter_formatter: Option<FilteredOptionFormatter<'a, T>>,
+    option_formatter: Option<LiveOptionFormatter<'a, T>>,
+    preview: Option<OptionPreview<'a, T>>,
+    preview_width: usize,
 }
 
 impl<'a, T> SelectPrompt<'a, T>
@@ -32,13 +75,18 @@ where
     T: Display,
 {
     pub fn new(so: Select<'a, T>) -> InquireResult<Self> {
-        if so.options.is_empty() {
+        #[cfg(feature = "async")]
+        let streaming = so.option_stream.is_some();
+        #[cfg(not(feature = "async"))]
+        let streaming = false;
+
+        if so.options.is_empty() && !streaming {
             return Err(InquireError::InvalidConfiguration(
                 "Available options can not be empty".into(),
             ));
         }
 
-        if so.starting_cursor >= so.options.len() {
+        if !so.options.is_empty() && so.starting_cursor >= so.options.len() {
             return Err(InquireError::InvalidConfiguration(format!(
                 "Starting cursor index {} is out-of-bounds for length {} of options",
                 so.starting_cursor,
@@ -46,10 +94,42 @@ where
             )));
         }
 
-        let string_options = so.options.iter().map(T::to_string).collect();
-        let scored_options = (0..so.options.len()).collect();
+        let string_options = to_string_options(&so.options);
+        let keys = option_keys(&so.options, so.option_key);
 
-        let input = match so.filter_input_enabled {
+        let usage_entries = match so.usage_store {
+            Some((prompt_id, store)) => store.load(prompt_id).unwrap_or_else(|_err| {
+                lifecycle_event!(warn, "usage of prompt {:?} not loaded: {}", prompt_id, _err);
+                vec![]
+            }),
+            None => vec![],
+        };
+        let usage_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        let frecency = frecency(&usage_entries, usage_time, &keys);
+        let scored_options = sorted_by_frecency(&frecency);
+
+        let cursor_index = match so.starting_cursor_key {
+            Some(starting_key) => scored_options
+                .iter()
+                .position(|idx| keys[*idx] == starting_key)
+                .ok_or_else(|| {
+                    InquireError::InvalidConfiguration(format!(
+                        "Starting cursor key {} does not match any option",
+                        starting_key
+                    ))
+                })?,
+            None if so.usage_store.is_some() && !so.starting_cursor_set => 0,
+            None => scored_options
+                .iter()
+                .position(|idx| *idx == so.starting_cursor)
+                .unwrap_or_default(),
+        };
+
+        let input = match so.filter_input_enabled && !so.search_mode {
             true => Some(Input::new_with(
                 so.starting_filter_input.unwrap_or_default(),
             )),
@@ -58,15 +138,40 @@ where
 
         Ok(Self {
             message: so.message,
+            timeout: so.timeout,
+            show_remaining_time: so.show_remaining_time,
+            show_elapsed_time: so.show_elapsed_time,
             config: (&so).into(),
             options: so.options,
             string_options,
+            keys,
             scored_options,
+            frecency,
+            usage_entries,
+            usage_time,
+            option_key: so.option_key,
+            refresh: so.refresh,
+            #[cfg(feature = "async")]
+            option_stream: so.option_stream,
+            #[cfg(feature = "async")]
+            loading_ticks: 0,
             help_message: so.help_message,
-            cursor_index: so.starting_cursor,
+            skip_option: so.skip_option,
+            cursor_index,
+            scroll_window_start: 0,
+            single_match_filtered: false,
             input,
+            search_input: Input::new(),
             scorer: so.scorer,
+            #[cfg(feature = "fuzzy")]
+            fuzzy_filter: so.fuzzy_filter,
+            #[cfg(feature = "regex")]
+            regex_filter: RegexFilter::new(so.regex_filter),
             formatter: so.formatter,
+            filter_formatter: so.filter_formatter,
+            option_formatter: so.option_formatter,
+            preview: so.preview,
+            preview_width: so.preview_width,
         })
     }
 
@@ -75,7 +180,7 @@ where
             let after_wrap = qty.saturating_sub(self.cursor_index);
             self.cursor_index
                 .checked_sub(qty)
-                .unwrap_or_else(|| self.scored_options.len().saturating_sub(after_wrap))
+                .unwrap_or_else(|| self.choice_count().saturating_sub(after_wrap))
         } else {
             self.cursor_index.saturating_sub(qty)
         };
@@ -86,19 +191,38 @@ where
     fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
         let mut new_position = self.cursor_index.saturating_add(qty);
 
-        if new_position >= self.scored_options.len() {
-            new_position = if self.scored_options.is_empty() {
+        if new_position >= self.choice_count() {
+            new_position = if self.choice_count() == 0 {
                 0
             } else if wrap {
-                new_position % self.scored_options.len()
+                new_position % self.choice_count()
             } else {
-                self.scored_options.len().saturating_sub(1)
+                self.choice_count().saturating_sub(1)
             }
         }
 
         self.update_cursor_position(new_position)
     }
 
+    /// Number of choices the cursor moves through, the options matching the
+    /// filter followed by the skip choice, if any.
+    fn choice_count(&self) -> usize {
+        self.scored_options.len() + usize::from(self.skip_option.is_some())
+    }
+
+    fn is_skip_option_highlighted(&self) -> bool {
+        self.skip_option.is_some() && self.cursor_index == self.scored_options.len()
+    }
+
+    /// Cursor over the options matching the filter, placed on the last one
+    /// when the skip choice is highlighted, to keep the end of the list visible.
+    fn option_cursor(&self) -> usize {
+        min(
+            self.cursor_index,
+            self.scored_options.len().saturating_sub(1),
+        )
+    }
+
     fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
         if new_position != self.cursor_index {
             self.cursor_index = new_position;
@@ -112,14 +236,196 @@ where
         self.scored_options.get(self.cursor_index).is_some()
     }
 
-    fn get_final_answer(&mut self) -> ListOption<T> {
+    fn get_final_answer(&mut self) -> FilteredAnswer<ListOption<T>> {
         // should only be called after current cursor index is validated
         // on has_answer_highlighted
 
         let index = *self.scored_options.get(self.cursor_index).unwrap();
         let value = self.options.swap_remove(index);
+        let filter = self
+            .input
+            .as_ref()
+            .map(|input| input.content().to_owned())
+            .unwrap_or_default();
+
+        FilteredAnswer::new(ListOption::new(index, value), filter)
+    }
+
+    fn complete_filter(&mut self) -> ActionResult {
+        let input = match &self.input {
+            Some(input) => input,
+            None => return ActionResult::Clean,
+        };
+
+        let matches = self
+            .scored_options
+            .iter()
+            .map(|idx| self.string_options[*idx].as_str())
+            .collect::<Vec<&str>>();
+        let prefix = longest_common_prefix(&matches);
+
+        let extends_filter = prefix
+            .to_lowercase()
+            .starts_with(&input.content().to_lowercase());
+
+        if !extends_filter || prefix.chars().count() <= input.content().chars().count() {
+            return ActionResult::Clean;
+        }
+
+        self.input = Some(Input::new_with(prefix));
+        self.run_scorer();
+        self.single_match_filtered = self.scored_options.len() == 1;
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn matches_search(&self, position: usize) -> bool {
+        let idx = self.scored_options[position];
+        let query = self.search_input.content();
+
+        (self.scorer)(query, &self.options[idx], &self.string_options[idx], idx).is_some()
+    }
+
+    /// Finds the position of the first option matching the search query
+    /// starting at `from`, wrapping around the list.
+    fn find_match(&self, from: usize, forward: bool) -> Option<usize> {
+        let len = self.scored_options.len();
+        if self.search_input.is_empty() || len == 0 {
+            return None;
+        }
+
+        (0..len)
+            .map(|i| match forward {
+                true => (from + i) % len,
+                false => (from + len - (i % len)) % len,
+            })
+            .find(|position| self.matches_search(*position))
+    }
+
+    fn jump_to_match(&mut self, from: usize, forward: bool) -> ActionResult {
+        match self.find_match(from, forward) {
+            Some(position) => self.update_cursor_position(position),
+            None => ActionResult::Clean,
+        }
+    }
+
+    fn set_searching(&mut self, searching: bool) -> ActionResult {
+        if self.config.searching == searching {
+            return ActionResult::Clean;
+        }
+
+        self.config.searching = searching;
+        ActionResult::NeedsRedraw
+    }
+
+    fn update_scroll_window(&mut self) {
+        if let Some(scrolloff) = self.config.scrolloff {
+            self.scroll_window_start = scroll_window_start(
+                self.config.visible_options(),
+                self.scored_options.len(),
+                Some(self.option_cursor()),
+                scrolloff,
+                self.scroll_window_start,
+            );
+        }
+    }
+
+    /// Replaces the options with the ones returned by the option provider,
+    /// keeping the cursor on the highlighted option, identified by its key, as
+    /// long as it is still listed.
+    fn refresh_options(&mut self) -> InquireResult<ActionResult> {
+        let provider = match self.refresh {
+            Some((_, provider)) => provider,
+            None => return Ok(ActionResult::Clean),
+        };
+
+        self.options = provider()?;
+
+        Ok(self.update_options())
+    }
+
+    /// Appends the options received from the stream since the last tick,
+    /// advancing the loading indicator.
+    #[cfg(feature = "async")]
+    fn receive_streamed_options(&mut self) -> ActionResult {
+        let stream = match &mut self.option_stream {
+            Some(stream) => stream,
+            None => return ActionResult::Clean,
+        };
+
+        let received = stream.poll();
+        if !stream.is_loading() {
+            self.option_stream = None;
+        }
+
+        self.loading_ticks += 1;
+        if !received.is_empty() {
+            self.options.extend(received);
+            let _ = self.update_options();
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    /// Recomputes the state derived from the options after they changed,
+    /// keeping the cursor on the highlighted option when it is still listed.
+    fn update_options(&mut self) -> ActionResult {
+        let string_options = to_string_options(&self.options);
+        let keys = option_keys(&self.options, self.option_key);
+
+        if string_options == self.string_options && keys == self.keys {
+            return ActionResult::Clean;
+        }
+
+        let skip_option_highlighted = self.is_skip_option_highlighted();
+        let highlighted = self
+            .scored_options
+            .get(self.cursor_index)
+            .map(|idx| self.keys[*idx].clone());
+
+        self.frecency = frecency(&self.usage_entries, self.usage_time, &keys);
+        self.string_options = string_options;
+        self.keys = keys;
+        self.scored_options = sorted_by_frecency(&self.frecency);
+        self.run_scorer();
+
+        let restored_cursor = match (skip_option_highlighted, highlighted) {
+            (true, _) => Some(self.scored_options.len()),
+            (false, Some(highlighted)) => self
+                .scored_options
+                .iter()
+                .position(|idx| self.keys[*idx] == highlighted),
+            (false, None) => None,
+        };
+
+        self.cursor_index = restored_cursor
+            .unwrap_or_else(|| min(self.cursor_index, self.choice_count().saturating_sub(1)));
+        self.update_scroll_window();
+
+        ActionResult::NeedsRedraw
+    }
+
+    /// Characters of each listed option matched by the fuzzy filter, to be
+    /// highlighted, or nothing when the fuzzy filter is not in use.
+    #[cfg(feature = "fuzzy")]
+    fn page_matches(&self, options: &[ListOption<&T>]) -> Vec<Vec<usize>> {
+        #[cfg(feature = "regex")]
+        if self.regex_filter.is_enabled() {
+            return vec![];
+        }
 
-        ListOption::new(index, value)
+        match (self.fuzzy_filter, &self.input) {
+            (true, Some(input)) if !input.is_empty() => options
+                .iter()
+                .map(|option| {
+                    fuzzy_filter::matched_indices(
+                        input.content(),
+                        &self.string_options[option.index],
+                    )
+                })
+                .collect(),
+            _ => vec![],
+        }
     }
 
     fn run_scorer(&mut self) {
@@ -128,17 +434,45 @@ where
             None => return,
         };
 
+        #[cfg(feature = "regex")]
+        if self.regex_filter.is_enabled() {
+            self.regex_filter.update(content);
+        }
+
         let mut options = self
             .options
             .iter()
             .enumerate()
             .filter_map(|(i, opt)| {
-                (self.scorer)(content, opt, self.string_options.get(i).unwrap(), i)
+                let string_option = self.string_options.get(i).unwrap();
+
+                #[cfg(feature = "regex")]
+                if self.regex_filter.is_enabled() {
+                    return self
+                        .regex_filter
+                        .score(string_option)
+                        .map(|score| (i, score));
+                }
+
+                #[cfg(feature = "fuzzy")]
+                let score = match self.fuzzy_filter {
+                    true => fuzzy_filter::score(content, string_option),
+                    false => (self.scorer)(content, opt, string_option, i),
+                };
+                #[cfg(not(feature = "fuzzy"))]
+                let score = (self.scorer)(content, opt, string_option, i);
+
+                score
+                    .filter(|score| content.is_empty() || self.config.meets_score_threshold(*score))
                     .map(|score| (i, score))
             })
             .collect::<Vec<(usize, i64)>>();
 
-        options.sort_unstable_by_key(|(_idx, score)| Reverse(*score));
+        options.sort_by_key(|(idx, score)| (Reverse(*score), Reverse(self.frecency[*idx])));
+
+        if let (false, Some(max)) = (content.is_empty(), self.config.max_displayed_matches) {
+            options.truncate(max);
+        }
 
         let new_scored_options = options.iter().map(|(idx, _)| *idx).collect::<Vec<usize>>();
 
@@ -150,8 +484,8 @@ where
 
         if self.config.reset_cursor {
             let _ = self.update_cursor_position(0);
-        } else if self.scored_options.len() <= self.cursor_index {
-            let _ = self.update_cursor_position(self.scored_options.len().saturating_sub(1));
+        } else if self.choice_count() <= self.cursor_index {
+            let _ = self.update_cursor_position(self.choice_count().saturating_sub(1));
         }
     }
 }
@@ -163,7 +497,7 @@ where
 {
     type Config = SelectConfig;
     type InnerAction = SelectPromptAction;
-    type Output = ListOption<T>;
+    type Output = FilteredAnswer<ListOption<T>>;
 
     fn message(&self) -> &str {
         self.message
@@ -173,16 +507,75 @@ where
         &self.config
     }
 
-    fn format_answer(&self, answer: &ListOption<T>) -> String {
-        (self.formatter)(answer.as_ref())
+    fn format_answer(&self, answer: &FilteredAnswer<ListOption<T>>) -> String {
+        match self.filter_formatter {
+            Some(formatter) => formatter(answer.answer.as_ref(), &answer.filter),
+            None => (self.formatter)(answer.answer.as_ref()),
+        }
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    fn show_remaining_time(&self) -> bool {
+        self.show_remaining_time
+    }
+
+    fn show_elapsed_time(&self) -> bool {
+        self.show_elapsed_time
+    }
+
+    fn tick_interval(&self) -> Option<Duration> {
+        #[cfg(feature = "async")]
+        if self.option_stream.is_some() {
+            return Some(OPTION_STREAM_POLL_INTERVAL);
+        }
+
+        self.refresh.map(|(interval, _)| interval)
+    }
+
+    fn tick(&mut self) -> InquireResult<ActionResult> {
+        #[cfg(feature = "async")]
+        if self.option_stream.is_some() {
+            return Ok(self.receive_streamed_options());
+        }
+
+        self.refresh_options()
+    }
+
+    fn should_auto_submit(&self) -> bool {
+        self.config.auto_select_single_match && self.single_match_filtered
+    }
+
+    fn selected_skip_option(&self) -> Option<&str> {
+        self.skip_option
+            .filter(|_| self.is_skip_option_highlighted() && !self.config.searching)
     }
 
     fn setup(&mut self) -> InquireResult<()> {
         self.run_scorer();
+        self.update_scroll_window();
         Ok(())
     }
 
-    fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
+    /// Esc while typing a search query only leaves the search.
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        if self.config.searching {
+            let _ = self.set_searching(false);
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    /// Enter while typing a search query only confirms the search.
+    fn submit(&mut self) -> InquireResult<Option<FilteredAnswer<ListOption<T>>>> {
+        if self.config.searching {
+            let _ = self.set_searching(false);
+            return Ok(None);
+        }
+
         let answer = match self.has_answer_highlighted() {
             true => Some(self.get_final_answer()),
             false => None,
@@ -192,6 +585,8 @@ where
     }
 
     fn handle(&mut self, action: SelectPromptAction) -> InquireResult<ActionResult> {
+        self.single_match_filtered = false;
+
         let result = match action {
             SelectPromptAction::MoveUp => self.move_cursor_up(1, true),
             SelectPromptAction::MoveDown => self.move_cursor_down(1, true),
@@ -200,27 +595,91 @@ where
             SelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
             SelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
 
+            SelectPromptAction::CompleteFilter => self.complete_filter(),
             SelectPromptAction::FilterInput(input_action) => match self.input.as_mut() {
                 Some(input) => {
                     let result = input.handle(input_action);
 
                     if let InputActionResult::ContentChanged = result {
                         self.run_scorer();
+                        self.single_match_filtered = self.scored_options.len() == 1;
                     }
 
                     result.into()
                 }
                 None => ActionResult::Clean,
             },
+            #[cfg(feature = "regex")]
+            SelectPromptAction::ToggleRegexFilter => match &self.input {
+                Some(input) => {
+                    self.regex_filter.toggle(input.content());
+                    self.run_scorer();
+                    ActionResult::NeedsRedraw
+                }
+                None => ActionResult::Clean,
+            },
+            SelectPromptAction::StartSearch => {
+                self.search_input = Input::new();
+                self.set_searching(true)
+            }
+            SelectPromptAction::SearchInput(input_action) => {
+                let result = self.search_input.handle(input_action);
+
+                if let InputActionResult::ContentChanged = result {
+                    let _ = self.jump_to_match(self.cursor_index, true);
+                }
+
+                result.into()
+            }
+            SelectPromptAction::NextMatch => self.jump_to_match(self.cursor_index + 1, true),
+            SelectPromptAction::PreviousMatch => {
+                let from = self.cursor_index + self.scored_options.len().saturating_sub(1);
+                self.jump_to_match(from, false)
+            }
         };
 
+        self.update_scroll_window();
+
         Ok(result)
     }
 
     fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         let prompt = &self.message;
 
-        backend.render_select_prompt(prompt, self.input.as_ref())?;
+        let visible_input = match self.config.hide_filter_input {
+            true => None,
+            false => self.input.as_ref(),
+        };
+        backend.render_select_prompt(prompt, visible_input)?;
+
+        if let Some(input) = &self.input {
+            let hidden_filter = self.config.hide_filter_input && !input.is_empty();
+            let show_filter_header = self.config.show_filter_header || hidden_filter;
+
+            #[cfg(feature = "regex")]
+            let show_filter_header = show_filter_header && !self.regex_filter.is_enabled();
+
+            #[cfg(feature = "regex")]
+            if self.regex_filter.is_enabled() {
+                backend.render_regex_filter_header(
+                    input.content(),
+                    self.scored_options.len(),
+                    self.regex_filter.is_invalid(),
+                )?;
+            }
+
+            if show_filter_header {
+                backend.render_filter_header(input.content(), self.scored_options.len())?;
+            }
+        }
+
+        if self.config.searching || !self.search_input.is_empty() {
+            let matches = (0..self.scored_options.len())
+                .filter(|position| self.matches_search(*position))
+                .count();
+
+            backend.render_search_header(&self.search_input, self.config.searching, matches)?;
+        }
 
         let choices = self
             .scored_options
@@ -229,9 +688,75 @@ where
             .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
             .collect::<Vec<ListOption<&T>>>();
 
-        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+        let mut page = match self.config.scrolloff {
+            Some(_) => paginate_from(
+                self.config.visible_options(),
+                &choices,
+                Some(self.option_cursor()),
+                self.scroll_window_start,
+            ),
+            None => paginate(
+                self.config.visible_options(),
+                &choices,
+                Some(self.option_cursor()),
+            ),
+        };
+
+        let skip_option_highlighted = self.is_skip_option_highlighted();
+        if skip_option_highlighted {
+            page.cursor = None;
+        }
+
+        #[cfg(feature = "fuzzy")]
+        let matches = self.page_matches(page.content);
+        #[cfg(not(feature = "fuzzy"))]
+        let matches = vec![];
+
+        let preview = match (self.preview, page.cursor) {
+            (Some(preview), Some(cursor)) => Some(preview(page.content[cursor].value)),
+            (Some(_), None) => Some(String::new()),
+            (None, _) => None,
+        };
+        let preview = preview.as_ref().map(|preview| {
+            preview
+                .lines()
+                .take(self.config.visible_options())
+                .collect::<Vec<&str>>()
+        });
+
+        match (self.option_formatter, preview) {
+            (Some(formatter), Some(preview)) => {
+                let (formatted, styles) = format_page(&page, formatter, |_| false);
+                backend.render_options_with_preview(
+                    page.with_content(&formatted),
+                    &[],
+                    &styles,
+                    &preview,
+                    self.preview_width,
+                )?;
+            }
+            (Some(formatter), None) => {
+                let (formatted, styles) = format_page(&page, formatter, |_| false);
+                backend.render_options(page.with_content(&formatted), &[], &styles)?;
+            }
+            (None, Some(preview)) => backend.render_options_with_preview(
+                page,
+                &matches,
+                &[],
+                &preview,
+                self.preview_width,
+            )?,
+            (None, None) => backend.render_options(page, &matches, &[])?,
+        }
+
+        #[cfg(feature = "async")]
+        if self.option_stream.is_some() {
+            backend.render_loading_indicator(self.loading_ticks)?;
+        }
 
-        backend.render_options(page)?;
+        if let Some(skip_option) = self.skip_option {
+            backend.render_skip_option(skip_option, skip_option_highlighted)?;
+        }
 
         if let Some(help_message) = self.help_message {
             backend.render_help_message(help_message)?;
@@ -240,3 +765,31 @@ where
         Ok(())
     }
 }
+
+fn to_string_options<T: Display>(options: &[T]) -> Vec<String> {
+    options
+        .iter()
+        .map(|option| option.to_string().ansi_stripped_chars().collect())
+        .collect()
+}
+
+/// Frecency of each option, identified by its key, at the given moment, zero
+/// for the options missing from the usage entries.
+fn frecency(entries: &[UsageEntry], now: u64, keys: &[String]) -> Vec<u64> {
+    keys.iter()
+        .map(|key| {
+            entries
+                .iter()
+                .find(|entry| &entry.option == key)
+                .map(|entry| entry.frecency(now))
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Indexes of the options, by descending frecency.
+fn sorted_by_frecency(frecency: &[u64]) -> Vec<usize> {
+    let mut indexes: Vec<usize> = (0..frecency.len()).collect();
+    indexes.sort_by_key(|idx| Reverse(frecency[*idx]));
+    indexes
+}
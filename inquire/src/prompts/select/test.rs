@@ -1,6 +1,6 @@
 use crate::{
     formatter::OptionFormatter,
-    list_option::ListOption,
+    list_option::{LabeledOption, ListOption},
     test::fake_backend,
     ui::{Key, KeyModifiers},
     Select,
@@ -142,3 +142,936 @@ fn chars_do_not_affect_prompt_without_filtering() {
 
     assert_eq!(ListOption::new(0, "Banana"), ans);
 }
+
+#[test]
+fn labeled_options_are_filtered_by_label_and_return_data() {
+    let mut backend = fake_backend(vec![
+        Key::Char('s', KeyModifiers::NONE),
+        Key::Char('t', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec![
+        LabeledOption::new("Production", 10),
+        LabeledOption::new("Staging", 20),
+        LabeledOption::new("Development", 30),
+    ];
+
+    let ans = Select::new("Question", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(1, ans.index);
+    assert_eq!(20, ans.value.into_data());
+}
+
+#[derive(Default)]
+struct MemoryUsageStore(std::cell::RefCell<Vec<crate::persistence::UsageEntry>>);
+
+impl crate::persistence::UsageStore for MemoryUsageStore {
+    fn load(
+        &self,
+        _prompt_id: &str,
+    ) -> Result<Vec<crate::persistence::UsageEntry>, crate::CustomUserError> {
+        Ok(self.0.borrow().clone())
+    }
+
+    fn record(&self, _prompt_id: &str, option: &str) -> Result<(), crate::CustomUserError> {
+        let mut entries = self.0.borrow_mut();
+        match entries.iter_mut().find(|entry| entry.option == option) {
+            Some(entry) => entry.count += 1,
+            None => entries.push(crate::persistence::UsageEntry {
+                option: option.to_string(),
+                count: 1,
+                last_used: 0,
+            }),
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn frequently_chosen_options_are_listed_first() {
+    let store = MemoryUsageStore::default();
+    let options = vec!["a", "b", "c"];
+
+    let mut backend = fake_backend(vec![Key::End, Key::Enter]);
+    Select::new("Question", options.clone())
+        .with_usage_store("id", &store)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let ans = Select::new("Question", options)
+        .with_usage_store("id", &store)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "c"), ans);
+}
+
+struct BrokenUsageStore;
+
+impl crate::persistence::UsageStore for BrokenUsageStore {
+    fn load(
+        &self,
+        _prompt_id: &str,
+    ) -> Result<Vec<crate::persistence::UsageEntry>, crate::CustomUserError> {
+        Err("unreadable".into())
+    }
+
+    fn record(&self, _prompt_id: &str, _option: &str) -> Result<(), crate::CustomUserError> {
+        Err("read-only".into())
+    }
+}
+
+#[test]
+fn usage_store_failures_do_not_replace_the_answer() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .with_usage_store("id", &BrokenUsageStore)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "b"), ans);
+}
+
+#[test]
+fn starting_cursor_highlights_its_option_among_ranked_ones() {
+    let store = MemoryUsageStore::default();
+    let options = vec!["a", "b", "c"];
+
+    let mut backend = fake_backend(vec![Key::End, Key::Enter]);
+    Select::new("Question", options.clone())
+        .with_usage_store("id", &store)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // "c" is listed first, followed by "a" and "b"
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let ans = Select::new("Question", options)
+        .with_usage_store("id", &store)
+        .with_starting_cursor(0)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "a"), ans);
+}
+
+#[test]
+fn elapsed_timeout_fails_with_timeout_error() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Select::new("Question", vec!["a", "b"])
+        .with_timeout(std::time::Duration::ZERO)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(crate::InquireError::Timeout)));
+}
+
+#[test]
+fn hyperlinked_options_are_filtered_by_text() {
+    let link = "\x1b]8;;https://example.com/42\x1b\\Crash on startup\x1b]8;;\x1b\\";
+    let options = vec![link, "example"];
+
+    let mut input = "exa"
+        .chars()
+        .map(|c| Key::Char(c, KeyModifiers::NONE))
+        .collect::<Vec<Key>>();
+    input.push(Key::Enter);
+    let mut backend = fake_backend(input);
+
+    let ans = Select::new("Question", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "example"), ans);
+}
+
+#[test]
+fn long_options_are_truncated_when_enabled() {
+    let long_option = format!("{}END", "a".repeat(2000));
+    let options = vec![long_option.as_str(), "short"];
+
+    let render_config =
+        crate::ui::RenderConfig::empty().with_option_truncation(Some(crate::ui::Truncation::End));
+    let terminal = crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output();
+    let mut backend =
+        crate::ui::Backend::new(vec![Key::Enter].into_iter(), terminal, render_config).unwrap();
+
+    let ans = Select::new("Question", options)
+        .with_formatter(&|_| String::from("done"))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(0, ans.index);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("a…"));
+    assert!(!output.contains("END"));
+}
+
+fn render_colored_options(ansi_passthrough: bool) -> String {
+    let options = vec!["\x1b[31mred\x1b[0m", "\x1b[32mgreen\x1b[0m"];
+
+    let render_config = crate::ui::RenderConfig::empty().with_ansi_passthrough(ansi_passthrough);
+    let terminal = crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output();
+    let input = vec![
+        Key::Char('g', KeyModifiers::NONE),
+        Key::Char('r', KeyModifiers::NONE),
+        Key::Char('e', KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let mut backend = crate::ui::Backend::new(input.into_iter(), terminal, render_config).unwrap();
+
+    let ans = Select::new("Question", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(1, ans.index);
+
+    String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap()
+}
+
+#[test]
+fn ansi_styled_options_are_passed_through_when_enabled() {
+    assert!(render_colored_options(true).contains("\x1b[31mred"));
+    assert!(!render_colored_options(false).contains("\x1b[31m"));
+}
+
+#[test]
+fn filter_text_is_returned_and_passed_to_filter_formatter() {
+    let events = vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('e', KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let terminal = crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output();
+    let mut backend = crate::ui::Backend::new(
+        events.into_iter(),
+        terminal,
+        crate::ui::RenderConfig::empty(),
+    )
+    .unwrap();
+
+    let ans = Select::new("Animal:", vec!["ant", "bee", "cat"])
+        .with_filter_formatter(&|opt, filter| format!("{} (searched '{}')", opt.value, filter))
+        .filtered_prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "bee"), ans.answer);
+    assert_eq!("be", ans.filter);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("Animal: bee (searched 'be')"));
+}
+
+#[test]
+fn max_visible_options_limits_rows_without_changing_page_jumps() {
+    let options: Vec<String> = (0..10).map(|i| format!("option-{}", i)).collect();
+
+    let terminal = crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output();
+    let mut backend = crate::ui::Backend::new(
+        vec![Key::Enter].into_iter(),
+        terminal,
+        crate::ui::RenderConfig::empty(),
+    )
+    .unwrap();
+
+    let ans = Select::new("Question", options.clone())
+        .with_page_size(5)
+        .with_max_visible_options(3)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(0, ans.index);
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("option-2"));
+    assert!(!output.contains("option-3"));
+
+    let mut backend = fake_backend(vec![Key::PageDown(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Select::new("Question", options)
+        .with_page_size(5)
+        .with_max_visible_options(3)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(5, ans.index);
+}
+
+#[test]
+fn scrolloff_scrolls_only_near_the_edges() {
+    let options: Vec<String> = (0..10).map(|i| format!("option-{}", i)).collect();
+    let mut keys = vec![Key::Down(KeyModifiers::NONE); 4];
+    keys.push(Key::Enter);
+
+    let run = |select: Select<'_, String>| {
+        let terminal = crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output();
+        let mut backend = crate::ui::Backend::new(
+            keys.clone().into_iter(),
+            terminal,
+            crate::ui::RenderConfig::empty(),
+        )
+        .unwrap();
+
+        let ans = select.prompt_with_backend(&mut backend).unwrap();
+        assert_eq!(4, ans.index);
+
+        String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap()
+    };
+
+    // the centered window shows options 2 to 6 once the cursor reaches option 4
+    let output = run(Select::new("Question", options.clone()).with_page_size(5));
+    assert!(output.contains("option-6"));
+
+    // with a scrolloff of 1, the window only scrolls by one row, showing options 1 to 5
+    let output = run(Select::new("Question", options)
+        .with_page_size(5)
+        .with_scrolloff(1));
+    assert!(output.contains("option-5"));
+    assert!(!output.contains("option-6"));
+}
+
+#[test]
+fn hidden_filter_input_still_filters_and_shows_header() {
+    let events = vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('e', KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let terminal = crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output();
+    let mut backend = crate::ui::Backend::new(
+        events.into_iter(),
+        terminal,
+        crate::ui::RenderConfig::empty(),
+    )
+    .unwrap();
+
+    let ans = Select::new("Animal:", vec!["ant", "bee", "cat"])
+        .with_hide_filter_input(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "bee"), ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    // only the final answer follows the prompt message
+    assert_eq!(1, output.matches("Animal: b").count());
+    assert!(!output.contains("3 options"));
+    assert!(output.contains("Filter: be (1 match)"));
+}
+
+#[test]
+fn single_match_is_selected_automatically_when_enabled() {
+    // no enter key, the prompt is submitted as soon as a single option remains
+    let mut backend = fake_backend(vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('e', KeyModifiers::NONE),
+        Key::Char('e', KeyModifiers::NONE),
+    ]);
+
+    let ans = Select::new("Animal:", vec!["bear", "bee", "cat"])
+        .with_scorer(&|input, _, value, _| value.starts_with(input).then_some(0))
+        .with_auto_select_single_match(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "bee"), ans);
+}
+
+#[test]
+fn single_match_waits_for_enter_by_default() {
+    let mut backend = fake_backend(vec![
+        Key::Char('c', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Animal:", vec!["bear", "bee", "cat"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "cat"), ans);
+}
+
+#[test]
+fn tab_completes_filter_to_longest_common_prefix() {
+    let mut backend = fake_backend(vec![
+        Key::Char('d', KeyModifiers::NONE),
+        Key::Tab,
+        Key::Char('s', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec![
+        "deployment/api-gateway",
+        "deployment/api-server",
+        "service/api",
+    ];
+
+    let ans = Select::new("Resource:", options)
+        .with_scorer(&|input, _, value, _| value.starts_with(input).then_some(0))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "deployment/api-server"), ans);
+}
+
+#[test]
+fn tab_does_not_complete_filter_without_common_prefix() {
+    let mut backend = fake_backend(vec![Key::Tab, Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Select::new("Animal:", vec!["bear", "bee", "cat"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "bee"), ans);
+}
+
+#[test]
+fn help_overlay_lists_key_bindings_and_is_dismissed_by_any_key() {
+    use crate::ui::KeyBindings;
+
+    // the first Down only dismisses the overlay
+    let events = vec![
+        Key::F(1, KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let mut backend = crate::ui::Backend::new(
+        events.into_iter(),
+        crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output(),
+        crate::ui::RenderConfig::empty(),
+    )
+    .unwrap()
+    .with_key_bindings(KeyBindings::default().with_help_keys(&[Key::F(1, KeyModifiers::NONE)]));
+
+    let ans = Select::new("Animal:", vec!["ant", "bee", "cat"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "bee"), ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    // keys are aligned to the widest entry, "Esc, Ctrl+g, Ctrl+d"
+    let tab = format!("  {:<19}  complete the filter", "Tab");
+    let help = format!("  {:<19}  show this help", "F1");
+    assert_eq!(1, output.matches(&tab).count());
+    assert_eq!(1, output.matches(&help).count());
+}
+
+#[test]
+fn compact_layout_omits_prefixes_and_help_messages() {
+    let events = vec![Key::Down(KeyModifiers::NONE), Key::Enter];
+    // any terminal is narrower than the threshold
+    let render_config =
+        crate::ui::RenderConfig::empty().with_compact_layout_threshold(Some(u16::MAX));
+    let mut backend = crate::ui::Backend::new(
+        events.into_iter(),
+        crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output(),
+        render_config,
+    )
+    .unwrap();
+
+    let ans = Select::new("Animal:", vec!["ant", "bee", "cat"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "bee"), ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("Animal: bee"));
+    assert!(!output.contains("? Animal:"));
+    assert!(!output.contains("> Animal:"));
+    assert!(!output.contains("to move"));
+}
+
+#[test]
+fn frame_rate_cap_coalesces_redraws_but_handles_every_key() {
+    let events = vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let render_config = crate::ui::RenderConfig::empty().with_max_frame_rate(Some(1));
+    let mut backend = crate::ui::Backend::new(
+        events.into_iter(),
+        crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output(),
+        render_config,
+    )
+    .unwrap();
+
+    let ans = Select::new("Animal:", vec!["ant", "bee", "cat"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "cat"), ans);
+
+    // keys arrive faster than one frame per second, only the first frame
+    // and the answer are rendered
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("> ant"));
+    assert!(!output.contains("> bee"));
+    assert!(!output.contains("> cat"));
+}
+
+#[test]
+fn ascii_only_output_replaces_glyphs() {
+    let events = vec![Key::Enter];
+    let render_config = crate::ui::RenderConfig::empty().with_ascii_only(true);
+    let mut backend = crate::ui::Backend::new(
+        events.into_iter(),
+        crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output(),
+        render_config,
+    )
+    .unwrap();
+
+    let ans = Select::new("Animal:", vec!["gnu", "ñandú"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "gnu"), ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.is_ascii());
+    assert!(output.contains("[^v to move, enter to select, type to filter]"));
+    assert!(output.contains("?and?"));
+}
+
+#[test]
+fn skip_option_is_rendered_below_the_options_and_skips_the_prompt() {
+    let mut backend = crate::ui::Backend::new(
+        vec![Key::Up(KeyModifiers::NONE), Key::Enter].into_iter(),
+        crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output(),
+        crate::ui::RenderConfig::empty(),
+    )
+    .unwrap();
+
+    let ans = Select::new("Country:", vec!["Brazil", "Canada"])
+        .with_skip_option("(none)")
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(crate::InquireError::OperationSkipped)));
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("  Canada\r\n  (none)"));
+    assert!(output.contains("> (none)"));
+    assert!(output.contains("Country: (none)"));
+}
+
+#[test]
+fn cursor_wraps_from_the_skip_option_to_the_first_option() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Country:", vec!["Brazil", "Canada"])
+        .with_skip_option("(none)")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "Brazil"), ans);
+}
+
+#[test]
+fn quiz_marks_the_correct_and_the_wrongly_chosen_options() {
+    let mut backend = crate::ui::Backend::new(
+        vec![Key::Down(KeyModifiers::NONE), Key::Enter].into_iter(),
+        crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output(),
+        crate::ui::RenderConfig::empty(),
+    )
+    .unwrap();
+
+    let ans = Select::new(
+        "Capital of Australia?",
+        vec!["Sydney", "Melbourne", "Canberra"],
+    )
+    .with_correct_answer(2)
+    .quiz_prompt_with_backend(&mut backend)
+    .unwrap();
+
+    assert_eq!(ListOption::new(1, "Melbourne"), ans.answer);
+    assert!(!ans.correct);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("    Sydney"));
+    assert!(output.contains("> ✗ Melbourne"));
+    assert!(output.contains("  ✓ Canberra"));
+}
+
+#[test]
+fn quiz_reports_correct_answers() {
+    let mut backend = fake_backend(vec![Key::Up(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Select::new(
+        "Capital of Australia?",
+        vec!["Sydney", "Melbourne", "Canberra"],
+    )
+    .with_correct_answer(2)
+    .quiz_prompt_with_backend(&mut backend)
+    .unwrap();
+
+    assert!(ans.correct);
+}
+
+#[test]
+fn quiz_requires_a_valid_correct_answer() {
+    let ans = Select::new("Question", vec![1, 2, 3])
+        .quiz_prompt_with_backend(&mut fake_backend(vec![Key::Enter]));
+    assert!(matches!(
+        ans,
+        Err(crate::error::InquireError::InvalidConfiguration(_))
+    ));
+
+    let ans = Select::new("Question", vec![1, 2, 3])
+        .with_correct_answer(3)
+        .quiz_prompt_with_backend(&mut fake_backend(vec![Key::Enter]));
+    assert!(matches!(
+        ans,
+        Err(crate::error::InquireError::InvalidConfiguration(_))
+    ));
+}
+
+#[test]
+fn refresh_keeps_the_cursor_on_the_highlighted_option() {
+    let mut backend = crate::prompts::test::idle_fake_backend(vec![
+        Some(Key::Down(KeyModifiers::NONE)),
+        None,
+        Some(Key::Enter),
+    ]);
+
+    let provider: crate::type_aliases::OptionProvider<'_, &str> =
+        &|| Ok(vec!["pod-d", "pod-c", "pod-b"]);
+
+    let ans = Select::new("Pod", vec!["pod-a", "pod-b", "pod-c"])
+        .with_refresh(std::time::Duration::from_millis(200), provider)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "pod-b"), ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("pod-d"));
+}
+
+#[derive(Debug, PartialEq)]
+struct Pod {
+    name: &'static str,
+    status: &'static str,
+}
+
+impl std::fmt::Display for Pod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.name, self.status)
+    }
+}
+
+#[test]
+fn refresh_identifies_options_by_their_key() {
+    let mut backend = crate::prompts::test::idle_fake_backend(vec![
+        Some(Key::Down(KeyModifiers::NONE)),
+        None,
+        Some(Key::Enter),
+    ]);
+
+    let pod = |name, status| Pod { name, status };
+    let provider: crate::type_aliases::OptionProvider<'_, Pod> =
+        &|| Ok(vec![pod("worker", "Running"), pod("api", "Running")]);
+
+    let ans = Select::new("Pod", vec![pod("worker", "Pending"), pod("api", "Pending")])
+        .with_option_key(&|pod| pod.name.to_string())
+        .with_refresh(std::time::Duration::from_millis(200), provider)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, pod("api", "Running")), ans);
+}
+
+#[test]
+fn starting_cursor_key_places_the_cursor_on_the_matching_option() {
+    let pod = |name, status| Pod { name, status };
+    let options = || vec![pod("worker", "Running"), pod("api", "Pending")];
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let ans = Select::new("Pod", options())
+        .with_option_key(&|pod| pod.name.to_string())
+        .with_starting_cursor_key("api")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, pod("api", "Pending")), ans);
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let ans = Select::new("Pod", options())
+        .with_starting_cursor_key("db")
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(
+        ans,
+        Err(crate::error::InquireError::InvalidConfiguration(_))
+    ));
+}
+
+fn uncolored_backend(
+    input: Vec<Key>,
+) -> crate::ui::Backend<
+    'static,
+    impl crate::ui::InputReader,
+    crate::terminal::crossterm::CrosstermTerminal,
+> {
+    let terminal = crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output();
+    crate::ui::Backend::new(
+        input.into_iter(),
+        terminal,
+        crate::ui::RenderConfig::empty(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn search_mode_jumps_between_matches_without_filtering() {
+    let mut backend = uncolored_backend(vec![
+        Key::Char('/', KeyModifiers::NONE),
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Enter,
+        Key::Char('n', KeyModifiers::NONE),
+        Key::Char('n', KeyModifiers::NONE),
+        Key::Char('N', KeyModifiers::SHIFT),
+        Key::Enter,
+    ]);
+
+    let options = vec!["apple", "banana", "cherry", "avocado", "blueberry"];
+    let ans = Select::new("Fruit", options)
+        .with_search_mode(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(4, "blueberry"), ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("Search: b (2 matches)"));
+    assert!(output.contains("cherry"));
+}
+
+#[test]
+fn escape_leaves_the_search_without_canceling() {
+    let mut backend = uncolored_backend(vec![
+        Key::Char('/', KeyModifiers::NONE),
+        Key::Char('x', KeyModifiers::NONE),
+        Key::Escape,
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Fruit", vec!["apple", "banana"])
+        .with_search_mode(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "apple"), ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("(pattern not found)"));
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn regex_filter_matches_options_against_the_pattern() {
+    let mut backend = uncolored_backend(vec![
+        Key::Char('r', KeyModifiers::CONTROL),
+        Key::Char('^', KeyModifiers::NONE),
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('.', KeyModifiers::NONE),
+        Key::Char('*', KeyModifiers::NONE),
+        Key::Char('y', KeyModifiers::NONE),
+        Key::Char('$', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["apple", "banana", "blackberry", "strawberry", "blueberry"];
+    let ans = Select::new("Fruit", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "blackberry"), ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("Regex: ^b.*y$ (2 matches)"));
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn invalid_regex_filter_keeps_the_previous_pattern() {
+    let mut backend = uncolored_backend(vec![
+        Key::Char('n', KeyModifiers::NONE),
+        Key::Char('(', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["apple", "banana", "cherry"];
+    let ans = Select::new("Fruit", options)
+        .with_regex_filter(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "banana"), ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("Regex: n( (invalid pattern)"));
+}
+
+#[test]
+#[cfg(feature = "fuzzy")]
+fn fuzzy_filter_highlights_the_matched_characters() {
+    let render_config = crate::ui::RenderConfig::empty()
+        .with_option_match(crate::ui::StyleSheet::new().with_fg(crate::ui::Color::LightGreen));
+    let mut backend = crate::ui::Backend::new(
+        vec![
+            Key::Char('b', KeyModifiers::NONE),
+            Key::Char('r', KeyModifiers::NONE),
+            Key::Enter,
+        ]
+        .into_iter(),
+        crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output(),
+        render_config,
+    )
+    .unwrap();
+
+    let options = vec!["apple", "blueberry", "cherry"];
+    let ans = Select::new("Fruit", options)
+        .with_fuzzy_filter()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "blueberry"), ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    let green = |c: &str| format!("\u{1b}[38;5;10m{}\u{1b}[39m", c);
+    assert!(output.contains(&format!("{}luebe{}ry", green("b"), green("r"))));
+}
+
+#[test]
+fn fuzzy_filter_highlights_the_matched_characters_of_ansi_styled_options() {
+    let render_config = crate::ui::RenderConfig::empty()
+        .with_option_match(crate::ui::StyleSheet::new().with_fg(crate::ui::Color::LightGreen));
+    let mut backend = crate::ui::Backend::new(
+        vec![
+            Key::Char('b', KeyModifiers::NONE),
+            Key::Char('r', KeyModifiers::NONE),
+            Key::Enter,
+        ]
+        .into_iter(),
+        crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output(),
+        render_config,
+    )
+    .unwrap();
+
+    let options = vec!["apple", "\u{1b}[1mblue\u{1b}[0mberry", "cherry"];
+    let ans = Select::new("Fruit", options)
+        .with_fuzzy_filter()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(1, ans.index);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    let green = |c: &str| format!("\u{1b}[38;5;10m{}\u{1b}[39m", c);
+    assert!(output.contains(&format!("{}luebe{}ry", green("b"), green("r"))));
+}
+
+#[test]
+fn score_threshold_and_max_displayed_matches_prune_the_filtered_options() {
+    let scorer: crate::type_aliases::Scorer<'_, &str> =
+        &|input, _option, string_value, _idx| Some(string_value.matches(input).count() as i64);
+
+    let mut backend = uncolored_backend(vec![Key::Char('a', KeyModifiers::NONE), Key::Enter]);
+    let options = vec!["a", "aaaa", "aa", "aaa", "b"];
+    let ans = Select::new("Letters", options)
+        .with_scorer(scorer)
+        .with_score_threshold(2)
+        .with_max_displayed_matches(2)
+        .with_show_filter_header(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "aaaa"), ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("Filter: a (2 matches)"));
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn option_stream_populates_the_options_as_they_arrive() {
+    use crate::prompts::option_stream::test::Trickle;
+
+    let mut backend = crate::prompts::test::idle_fake_backend(vec![
+        None,
+        None,
+        Some(Key::Down(KeyModifiers::NONE)),
+        Some(Key::Enter),
+    ]);
+
+    let stream = Trickle(vec![Some("pod-a"), None, Some("pod-b")].into());
+
+    let ans = Select::new("Pod", vec![])
+        .with_option_stream(stream)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "pod-b"), ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("Loading options..."));
+}
+
+#[test]
+fn option_formatter_styles_each_option_by_its_state() {
+    use crate::formatter::LiveOptionFormatter;
+    use crate::ui::{Color, Styled};
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let formatter: LiveOptionFormatter<'_, &str> = &|pod, state| match pod.ends_with("(failed)") {
+        true => Styled::new(format!("{} [!]", pod)).with_fg(Color::LightRed),
+        false if state.highlighted => Styled::new(format!("{} <", pod)),
+        false => Styled::new(String::from(*pod)),
+    };
+
+    let ans = Select::new("Pod", vec!["worker", "api (failed)"])
+        .with_option_formatter(formatter)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "worker"), ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("worker <"));
+    assert!(output.contains("\u{1b}[38;5;9mapi (failed) [!]"));
+}
+
+#[test]
+fn preview_pane_follows_the_highlighted_option() {
+    use crate::type_aliases::OptionPreview;
+
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let preview: OptionPreview<'_, &str> = &|commit| format!("commit {}\nAuthor: ada", commit);
+
+    let ans = Select::new("Commit", vec!["43685e9", "61fa997"])
+        .with_preview(preview)
+        .with_preview_width(20)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "61fa997"), ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains(" │ commit 43685e9"));
+    assert!(output.contains(" │ commit 61fa997"));
+    assert!(output.contains(" │ Author: ada"));
+}
@@ -0,0 +1,239 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use super::digest::{base64_decode, base64_encode, sha256};
+
+/// Where an [`SshKey`] was found.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SshKeySource {
+    /// Identity held by the running SSH agent.
+    Agent,
+
+    /// Public key file, e.g. `~/.ssh/id_ed25519.pub`.
+    File(PathBuf),
+}
+
+/// Public SSH key, as held by an SSH agent or stored in a public key file.
+/// Available via the `ssh-keys` feature.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SshKey {
+    /// Key type, e.g. `ssh-ed25519` or `ssh-rsa`.
+    pub key_type: String,
+
+    /// Public key in the SSH wire format.
+    pub blob: Vec<u8>,
+
+    /// Comment of the key, usually identifying its owner, e.g. `ada@example.com`.
+    pub comment: String,
+
+    /// Where the key was found.
+    pub source: SshKeySource,
+}
+
+/// Request of the list of identities held by an SSH agent.
+#[cfg(unix)]
+const SSH_AGENTC_REQUEST_IDENTITIES: u8 = 11;
+
+/// Answer of an SSH agent listing the identities it holds.
+const SSH_AGENT_IDENTITIES_ANSWER: u8 = 12;
+
+/// Max length of the answers read from SSH agents, as in OpenSSH.
+#[cfg(unix)]
+const MAX_AGENT_ANSWER_LEN: usize = 256 * 1024;
+
+impl SshKey {
+    /// Creates a key from its public key in the SSH wire format, reading its
+    /// type from it. Returns `None` when the blob is malformed.
+    pub fn from_blob(blob: Vec<u8>, comment: String, source: SshKeySource) -> Option<Self> {
+        let key_type = WireReader(&blob).string()?;
+        let key_type = String::from_utf8(key_type.to_vec()).ok()?;
+
+        Some(Self {
+            key_type,
+            blob,
+            comment,
+            source,
+        })
+    }
+
+    /// Parses a public key in the OpenSSH format, e.g. the content of an
+    /// `id_ed25519.pub` file: `ssh-ed25519 AAAAC3Nza... ada@example.com`.
+    ///
+    /// Returns `None` when the line is not a valid public key.
+    pub fn parse_public_key(line: &str, source: SshKeySource) -> Option<Self> {
+        let mut parts = line.trim().splitn(3, char::is_whitespace);
+        let key_type = parts.next()?;
+        let blob = base64_decode(parts.next()?)?;
+        let comment = parts.next().unwrap_or_default().trim().to_string();
+
+        let key = Self::from_blob(blob, comment, source)?;
+
+        match key.key_type == key_type {
+            true => Some(key),
+            false => None,
+        }
+    }
+
+    /// Lists the identities held by the SSH agent listening on the socket
+    /// set in the `SSH_AUTH_SOCK` environment variable.
+    ///
+    /// Returns no keys when no agent is set, or on platforms other than Unix.
+    pub fn from_agent() -> io::Result<Vec<Self>> {
+        match std::env::var_os("SSH_AUTH_SOCK") {
+            Some(socket) => request_identities(Path::new(&socket)),
+            None => Ok(vec![]),
+        }
+    }
+
+    /// Lists the public keys stored in the `.pub` files of a directory, e.g.
+    /// `~/.ssh`, sorted by file name. Files that are not valid public keys
+    /// are skipped, and a missing directory has no keys.
+    pub fn from_dir(dir: &Path) -> io::Result<Vec<Self>> {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err),
+        };
+
+        let mut paths = vec![];
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) == Some("pub") {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut keys = vec![];
+        for path in paths {
+            let content = fs::read_to_string(&path)?;
+            let line = content.lines().next().unwrap_or_default();
+            if let Some(key) = Self::parse_public_key(line, SshKeySource::File(path)) {
+                keys.push(key);
+            }
+        }
+
+        Ok(keys)
+    }
+
+    /// SHA-256 fingerprint of the key, as displayed by OpenSSH tools, e.g.
+    /// `SHA256:qtxh/7FP3swCj3hLIb7kpKOA1D0pJ2jkYwOvE/wdNYI`.
+    pub fn fingerprint(&self) -> String {
+        format!("SHA256:{}", base64_encode(&sha256(&self.blob)))
+    }
+
+    /// Short name of the algorithm of the key, as displayed by `ssh-keygen`,
+    /// e.g. `ED25519` for `ssh-ed25519` keys.
+    pub fn algorithm(&self) -> &str {
+        match self.key_type.as_str() {
+            "ssh-ed25519" => "ED25519",
+            "ssh-rsa" => "RSA",
+            "ssh-dss" => "DSA",
+            "sk-ssh-ed25519@openssh.com" => "ED25519-SK",
+            "sk-ecdsa-sha2-nistp256@openssh.com" => "ECDSA-SK",
+            t if t.starts_with("ecdsa-sha2-") => "ECDSA",
+            t => t,
+        }
+    }
+}
+
+impl fmt::Display for SshKey {
+    /// Formats the key in the OpenSSH public key format.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.key_type, base64_encode_padded(&self.blob))?;
+        if !self.comment.is_empty() {
+            write!(f, " {}", self.comment)?;
+        }
+        Ok(())
+    }
+}
+
+fn base64_encode_padded(data: &[u8]) -> String {
+    let mut encoded = base64_encode(data);
+    while encoded.len() % 4 != 0 {
+        encoded.push('=');
+    }
+    encoded
+}
+
+/// Requests the identities held by the SSH agent listening on the socket.
+#[cfg(unix)]
+fn request_identities(socket: &Path) -> io::Result<Vec<SshKey>> {
+    use std::{
+        io::{Read, Write},
+        os::unix::net::UnixStream,
+    };
+
+    let invalid_answer = || io::Error::new(io::ErrorKind::InvalidData, "Invalid SSH agent answer");
+
+    let mut stream = UnixStream::connect(socket)?;
+    stream.write_all(&[0, 0, 0, 1, SSH_AGENTC_REQUEST_IDENTITIES])?;
+
+    let mut len = [0u8; 4];
+    stream.read_exact(&mut len)?;
+    let len = u32::from_be_bytes(len) as usize;
+    if len > MAX_AGENT_ANSWER_LEN {
+        return Err(invalid_answer());
+    }
+
+    let mut answer = vec![0u8; len];
+    stream.read_exact(&mut answer)?;
+
+    parse_identities(&answer).ok_or_else(invalid_answer)
+}
+
+#[cfg(not(unix))]
+fn request_identities(_socket: &Path) -> io::Result<Vec<SshKey>> {
+    Ok(vec![])
+}
+
+/// Parses the identities listed in the answer of an SSH agent.
+#[cfg_attr(not(unix), allow(dead_code))]
+pub(super) fn parse_identities(answer: &[u8]) -> Option<Vec<SshKey>> {
+    let mut reader = WireReader(answer);
+
+    if reader.byte()? != SSH_AGENT_IDENTITIES_ANSWER {
+        return None;
+    }
+
+    let count = reader.u32()?;
+    let mut keys = vec![];
+    for _ in 0..count {
+        let blob = reader.string()?.to_vec();
+        let comment = String::from_utf8_lossy(reader.string()?).into_owned();
+        keys.push(SshKey::from_blob(blob, comment, SshKeySource::Agent)?);
+    }
+
+    Some(keys)
+}
+
+/// Reader of the SSH wire format, where strings are prefixed by their length.
+struct WireReader<'a>(&'a [u8]);
+
+impl<'a> WireReader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.0.len() < len {
+            return None;
+        }
+
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Some(taken)
+    }
+
+    fn byte(&mut self) -> Option<u8> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        let bytes = self.take(4)?;
+        Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn string(&mut self) -> Option<&'a [u8]> {
+        let len = self.u32()? as usize;
+        self.take(len)
+    }
+}
@@ -0,0 +1,258 @@
+mod digest;
+mod key;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use key::*;
+
+use std::{fmt, path::PathBuf};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    list_option::ListOption,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, SelectBackend},
+    Select,
+};
+
+/// SSH key displayed as an option of the picker, along with the width its
+/// algorithm is padded to, so that the fingerprints are aligned in a column.
+#[derive(Clone)]
+struct SshKeyOption {
+    key: SshKey,
+    fingerprint: String,
+    algorithm_width: usize,
+}
+
+impl fmt::Display for SshKeyOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let algorithm = self.key.algorithm();
+        let padding = self.algorithm_width.saturating_sub(algorithm.width());
+
+        write!(
+            f,
+            "{}{} {}",
+            algorithm,
+            " ".repeat(padding),
+            self.fingerprint
+        )?;
+        if !self.key.comment.is_empty() {
+            write!(f, " {}", self.key.comment)?;
+        }
+        Ok(())
+    }
+}
+
+/// Searchable picker of SSH keys, returning the chosen [`SshKey`], e.g. to sign commits or to deploy with. Available via the `ssh-keys` feature.
+///
+/// Each key is displayed with its algorithm, its SHA-256 fingerprint and its comment, in aligned columns, and typing filters the keys by any of them.
+///
+/// By default, the keys are the identities held by the running SSH agent, found through the `SSH_AUTH_SOCK` environment variable, followed by the public keys stored in the `~/.ssh/*.pub` files that the agent does not hold. An agent that can not be reached is skipped.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Keys**: Keys to choose from, instead of discovering them. See [`SshKey::from_agent`] and [`SshKey::from_dir`].
+/// - **Key directory**: Directory whose public key files are listed, `~/.ssh` by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Page size**: Number of keys displayed at once, 7 by default.
+///
+/// If no keys are found, the prompt fails with an [`InquireError::InvalidConfiguration`] error.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::SshKeySelect;
+///
+/// let key = SshKeySelect::new("Signing key:").prompt();
+///
+/// match key {
+///     Ok(key) => println!("Signing with {}", key.fingerprint()),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct SshKeySelect<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Keys to choose from. Discovered from the SSH agent and the key directory when `None`.
+    pub keys: Option<Vec<SshKey>>,
+
+    /// Directory whose public key files are listed when discovering keys. `~/.ssh` when `None`.
+    pub key_dir: Option<PathBuf>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the keys displayed to the user.
+    pub page_size: usize,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> SshKeySelect<'a> {
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, enter to select, type to filter");
+
+    /// Creates a [SshKeySelect] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            keys: None,
+            key_dir: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the keys to choose from, instead of discovering them.
+    pub fn with_keys(mut self, keys: Vec<SshKey>) -> Self {
+        self.keys = Some(keys);
+        self
+    }
+
+    /// Sets the directory whose public key files are listed when discovering keys.
+    pub fn with_key_dir<P: Into<PathBuf>>(mut self, key_dir: P) -> Self {
+        self.key_dir = Some(key_dir.into());
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<SshKey>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<SshKey> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<SshKey> {
+        let keys = match self.keys {
+            Some(keys) => keys,
+            None => discover_keys(self.key_dir)?,
+        };
+
+        if keys.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "No SSH keys found".into(),
+            ));
+        }
+
+        let algorithm_width = keys
+            .iter()
+            .map(|key| key.algorithm().width())
+            .max()
+            .unwrap_or(0);
+
+        let options = keys
+            .into_iter()
+            .map(|key| SshKeyOption {
+                fingerprint: key.fingerprint(),
+                key,
+                algorithm_width,
+            })
+            .collect();
+
+        let formatter = &|option: ListOption<&SshKeyOption>| match option.value.key.comment.as_str()
+        {
+            "" => option.value.fingerprint.clone(),
+            comment => format!("{} ({})", comment, option.value.fingerprint),
+        };
+
+        let mut select = Select::new(self.message, options)
+            .with_page_size(self.page_size)
+            .with_formatter(formatter)
+            .with_render_config(self.render_config);
+        select.help_message = self.help_message;
+
+        select
+            .prompt_with_backend(backend)
+            .map(|answer| answer.value.key)
+    }
+}
+
+/// Lists the identities of the SSH agent, if it can be reached, followed by
+/// the keys of the directory it does not hold.
+fn discover_keys(key_dir: Option<PathBuf>) -> InquireResult<Vec<SshKey>> {
+    let mut keys = SshKey::from_agent().unwrap_or_default();
+
+    let key_dir =
+        key_dir.or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".ssh")));
+    if let Some(key_dir) = key_dir {
+        for key in SshKey::from_dir(&key_dir)? {
+            if !keys.iter().any(|known| known.blob == key.blob) {
+                keys.push(key);
+            }
+        }
+    }
+
+    Ok(keys)
+}
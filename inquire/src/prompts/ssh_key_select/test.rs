@@ -0,0 +1,150 @@
+use std::path::PathBuf;
+
+use crate::{
+    error::InquireError,
+    terminal::crossterm::CrosstermTerminal,
+    test::fake_backend,
+    ui::{Backend, Key, KeyModifiers, RenderConfig},
+    SshKey, SshKeySelect, SshKeySource,
+};
+
+use super::{
+    digest::{base64_decode, base64_encode, sha256},
+    key::parse_identities,
+};
+
+const ED25519_KEY: &str =
+    "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIENoJBHTSAjVWjv+zds4BGgwYyskIIZNH8Vl8a2iytI0 ada@example.com";
+const ED25519_FINGERPRINT: &str = "SHA256:qtxh/7FP3swCj3hLIb7kpKOA1D0pJ2jkYwOvE/wdNYI";
+
+/// Builds a key of the given type with an arbitrary key material.
+fn key(key_type: &str, material: &[u8], comment: &str) -> SshKey {
+    let mut blob = (key_type.len() as u32).to_be_bytes().to_vec();
+    blob.extend_from_slice(key_type.as_bytes());
+    blob.extend_from_slice(&(material.len() as u32).to_be_bytes());
+    blob.extend_from_slice(material);
+
+    SshKey::from_blob(blob, comment.into(), SshKeySource::Agent).unwrap()
+}
+
+#[test]
+fn sha256_and_base64_match_reference_values() {
+    let digest = sha256(b"abc");
+    let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+    assert_eq!(
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        hex
+    );
+    assert_eq!(
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        sha256(b"")
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<String>()
+    );
+
+    assert_eq!("Zm9vYmE", base64_encode(b"fooba"));
+    assert_eq!(Some(b"fooba".to_vec()), base64_decode("Zm9vYmE="));
+    assert_eq!(None, base64_decode("Zm9v!"));
+}
+
+#[test]
+fn public_keys_are_parsed_and_fingerprinted_like_ssh_keygen() {
+    let key = SshKey::parse_public_key(ED25519_KEY, SshKeySource::Agent).unwrap();
+
+    assert_eq!("ssh-ed25519", key.key_type);
+    assert_eq!("ED25519", key.algorithm());
+    assert_eq!("ada@example.com", key.comment);
+    assert_eq!(ED25519_FINGERPRINT, key.fingerprint());
+    assert_eq!(ED25519_KEY, key.to_string());
+
+    assert!(
+        SshKey::parse_public_key("ssh-rsa AAAAC3NzaC1lZDI1NTE5", SshKeySource::Agent).is_none()
+    );
+    assert!(SshKey::parse_public_key("not a key", SshKeySource::Agent).is_none());
+}
+
+#[test]
+fn agent_identities_are_parsed() {
+    let ed25519 = SshKey::parse_public_key(ED25519_KEY, SshKeySource::Agent).unwrap();
+
+    let mut answer = vec![12, 0, 0, 0, 1];
+    answer.extend_from_slice(&(ed25519.blob.len() as u32).to_be_bytes());
+    answer.extend_from_slice(&ed25519.blob);
+    answer.extend_from_slice(&[0, 0, 0, 3]);
+    answer.extend_from_slice(b"ada");
+
+    let keys = parse_identities(&answer).unwrap();
+    assert_eq!(1, keys.len());
+    assert_eq!(ED25519_FINGERPRINT, keys[0].fingerprint());
+    assert_eq!("ada", keys[0].comment);
+
+    assert!(parse_identities(&answer[..answer.len() - 1]).is_none());
+    assert!(parse_identities(&[5]).is_none());
+}
+
+#[test]
+fn public_key_files_are_listed_from_a_directory() {
+    let dir = std::env::temp_dir().join(format!("inquire-ssh-keys-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("id_ed25519.pub"), format!("{}\n", ED25519_KEY)).unwrap();
+    std::fs::write(dir.join("id_ed25519"), "private").unwrap();
+    std::fs::write(dir.join("broken.pub"), "garbage").unwrap();
+
+    let keys = SshKey::from_dir(&dir).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(1, keys.len());
+    assert_eq!(
+        SshKeySource::File(dir.join("id_ed25519.pub")),
+        keys[0].source
+    );
+
+    let missing = SshKey::from_dir(&PathBuf::from("/nonexistent/inquire/.ssh")).unwrap();
+    assert!(missing.is_empty());
+}
+
+#[test]
+fn keys_are_rendered_in_columns_and_filtered() {
+    let keys = vec![
+        SshKey::parse_public_key(ED25519_KEY, SshKeySource::Agent).unwrap(),
+        key("ssh-rsa", b"rsa", "deploy@ci"),
+    ];
+    let rsa_fingerprint = keys[1].fingerprint();
+
+    let mut events: Vec<Key> = "deploy"
+        .chars()
+        .map(|c| Key::Char(c, KeyModifiers::NONE))
+        .collect();
+    events.push(Key::Enter);
+
+    let mut backend = Backend::new(
+        events.into_iter(),
+        CrosstermTerminal::new_in_memory_output(),
+        RenderConfig::empty(),
+    )
+    .unwrap();
+
+    let ans = SshKeySelect::new("Key:")
+        .with_keys(keys)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("deploy@ci", ans.comment);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains(&format!("ED25519 {} ada@example.com", ED25519_FINGERPRINT)));
+    assert!(output.contains(&format!("RSA     {} deploy@ci", rsa_fingerprint)));
+    assert!(output.contains(&format!("? Key: deploy@ci ({})", rsa_fingerprint)));
+}
+
+#[test]
+fn no_keys_is_an_invalid_configuration() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = SshKeySelect::new("Key:")
+        .with_keys(vec![])
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
@@ -0,0 +1,298 @@
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, SelectBackend, TextBackend},
+    Select, Text,
+};
+
+/// Custom prompt that receives the name of a placeholder and returns its value.
+type CustomPlaceholderPrompt<'a> = Box<dyn Fn(&str) -> InquireResult<String> + 'a>;
+
+/// Sub-prompt used to fill a placeholder of a [Template].
+enum PlaceholderPrompt<'a> {
+    Text(Option<&'a str>),
+    Select(Vec<String>),
+    Custom(CustomPlaceholderPrompt<'a>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Placeholder(String),
+}
+
+/// Result of a [Template] prompt.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TemplateAnswer {
+    /// Template with every placeholder replaced by its value.
+    pub rendered: String,
+
+    /// Values captured for each placeholder, in the order they first appear in the template.
+    pub values: Vec<(String, String)>,
+}
+
+impl TemplateAnswer {
+    /// Returns the value captured for the given placeholder, if any.
+    pub fn get(&self, placeholder: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(name, _)| name == placeholder)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Prompt that walks the user through filling the placeholders of a template, such as `deploy {service} to {env}`, one sub-prompt per placeholder.
+///
+/// Placeholders are names surrounded by braces, and `{{` and `}}` are escaped braces. A placeholder used more than once in the template is only asked once. While filling a placeholder, the help message of the sub-prompt previews the template with the values captured so far.
+///
+/// Each placeholder is filled with a [Text] prompt by default. It can instead be filled by selecting one of a list of options, or by any custom prompt of your choice.
+///
+/// If the template has unbalanced braces, the prompt operation will fail with an `InquireError::InvalidConfiguration` error. If the user cancels any of the sub-prompts, the whole prompt is canceled.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{CustomType, Template};
+///
+/// let ans = Template::new("deploy {service} to {env} with {replicas} replicas")
+///     .with_options("env", vec!["staging", "production"])
+///     .with_prompt("replicas", |name| {
+///         CustomType::<u8>::new(name).prompt().map(|n| n.to_string())
+///     })
+///     .prompt();
+///
+/// match ans {
+///     Ok(ans) => println!("Running `{}` on {}", ans.rendered, ans.get("env").unwrap()),
+///     Err(_) => println!("Nothing was deployed"),
+/// }
+/// ```
+pub struct Template<'a> {
+    /// Template to be filled.
+    pub template: &'a str,
+
+    /// Sub-prompts registered for specific placeholders.
+    placeholders: Vec<(&'a str, PlaceholderPrompt<'a>)>,
+
+    /// RenderConfig to apply to the rendered interface of the sub-prompts.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> Template<'a> {
+    /// Creates a [Template] with the provided template, along with default configuration values.
+    pub fn new(template: &'a str) -> Self {
+        Self {
+            template,
+            placeholders: vec![],
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the default value of the text prompt that fills the given placeholder.
+    pub fn with_default(self, placeholder: &'a str, default: &'a str) -> Self {
+        self.with_placeholder_prompt(placeholder, PlaceholderPrompt::Text(Some(default)))
+    }
+
+    /// Fills the given placeholder by selecting one of the provided options,
+    /// either borrowed or owned, e.g. the results of a database query.
+    pub fn with_options<I>(self, placeholder: &'a str, options: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let options = options.into_iter().map(Into::into).collect();
+        self.with_placeholder_prompt(placeholder, PlaceholderPrompt::Select(options))
+    }
+
+    /// Fills the given placeholder with a custom prompt. The function receives the
+    /// name of the placeholder and returns its value.
+    pub fn with_prompt<F>(self, placeholder: &'a str, prompt: F) -> Self
+    where
+        F: Fn(&str) -> InquireResult<String> + 'a,
+    {
+        self.with_placeholder_prompt(placeholder, PlaceholderPrompt::Custom(Box::new(prompt)))
+    }
+
+    fn with_placeholder_prompt(
+        mut self,
+        placeholder: &'a str,
+        prompt: PlaceholderPrompt<'a>,
+    ) -> Self {
+        self.placeholders.retain(|(name, _)| *name != placeholder);
+        self.placeholders.push((placeholder, prompt));
+        self
+    }
+
+    /// Sets the provided color theme to the sub-prompts.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<TemplateAnswer>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<TemplateAnswer> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: TextBackend + SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<TemplateAnswer> {
+        let segments = parse_template(self.template)?;
+        let mut values: Vec<(String, String)> = vec![];
+
+        for segment in &segments {
+            let name = match segment {
+                Segment::Placeholder(name) => name,
+                Segment::Literal(_) => continue,
+            };
+
+            if values.iter().any(|(n, _)| n == name) {
+                continue;
+            }
+
+            let message = format!("{}:", name);
+            let preview = render_segments(&segments, &values);
+
+            let sub_prompt = self
+                .placeholders
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, prompt)| prompt);
+
+            let value = match sub_prompt {
+                None => Text::new(&message)
+                    .with_help_message(&preview)
+                    .with_render_config(self.render_config)
+                    .prompt_with_backend(backend)?,
+                Some(PlaceholderPrompt::Text(default)) => {
+                    let mut text = Text::new(&message)
+                        .with_help_message(&preview)
+                        .with_render_config(self.render_config);
+                    text.default = *default;
+                    text.prompt_with_backend(backend)?
+                }
+                Some(PlaceholderPrompt::Select(options)) => {
+                    Select::new(&message, options.clone())
+                        .with_help_message(&preview)
+                        .with_render_config(self.render_config)
+                        .prompt_with_backend(backend)?
+                        .value
+                }
+                Some(PlaceholderPrompt::Custom(prompt)) => prompt(name)?,
+            };
+
+            values.push((name.clone(), value));
+        }
+
+        Ok(TemplateAnswer {
+            rendered: render_segments(&segments, &values),
+            values,
+        })
+    }
+}
+
+fn parse_template(template: &str) -> InquireResult<Vec<Segment>> {
+    let mut segments = vec![];
+    let mut literal = String::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some('{') | None => {
+                            return Err(InquireError::InvalidConfiguration(format!(
+                                "Unclosed placeholder in template \"{}\"",
+                                template
+                            )))
+                        }
+                        Some(c) => name.push(c),
+                    }
+                }
+
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                }
+                segments.push(Segment::Placeholder(name));
+            }
+            '}' => {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Unmatched closing brace in template \"{}\"",
+                    template
+                )))
+            }
+            c => literal.push(c),
+        }
+    }
+
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+
+    Ok(segments)
+}
+
+/// Renders the segments, keeping the placeholders that have no value yet.
+fn render_segments(segments: &[Segment], values: &[(String, String)]) -> String {
+    segments
+        .iter()
+        .map(|segment| match segment {
+            Segment::Literal(literal) => literal.clone(),
+            Segment::Placeholder(name) => values
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, value)| value.clone())
+                .unwrap_or_else(|| format!("{{{}}}", name)),
+        })
+        .collect()
+}
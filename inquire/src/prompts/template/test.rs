@@ -0,0 +1,93 @@
+use crate::{
+    error::InquireError,
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    Template,
+};
+
+fn text_keys(text: &str) -> Vec<Key> {
+    text.chars()
+        .map(|c| Key::Char(c, KeyModifiers::NONE))
+        .collect()
+}
+
+#[test]
+fn placeholders_are_filled_in_order() {
+    let mut keys = text_keys("api");
+    keys.push(Key::Enter);
+    keys.push(Key::Down(KeyModifiers::NONE));
+    keys.push(Key::Enter);
+
+    let mut backend = fake_backend(keys);
+
+    let ans = Template::new("deploy {service} to {env}")
+        .with_options("env", vec!["staging", "production"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("deploy api to production", ans.rendered);
+    assert_eq!(Some("api"), ans.get("service"));
+    assert_eq!(Some("production"), ans.get("env"));
+}
+
+#[test]
+fn options_can_be_owned_strings() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let environments: Vec<String> = ["staging", "production"]
+        .iter()
+        .map(|env| format!("{env}-eu"))
+        .collect();
+
+    let ans = Template::new("deploy to {env}")
+        .with_options("env", environments)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("deploy to production-eu", ans.rendered);
+}
+
+#[test]
+fn repeated_placeholders_are_asked_once() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Template::new("{{{name}}} is {name}")
+        .with_default("name", "x")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("{x} is x", ans.rendered);
+    assert_eq!(vec![(String::from("name"), String::from("x"))], ans.values);
+}
+
+#[test]
+fn custom_prompts_fill_placeholders() {
+    let mut backend = fake_backend(vec![]);
+
+    let ans = Template::new("{count} items")
+        .with_prompt("count", |name| Ok(format!("{}=3", name)))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("count=3 items", ans.rendered);
+}
+
+#[test]
+fn unbalanced_braces_are_an_invalid_configuration() {
+    for template in ["deploy {service", "deploy service}"] {
+        let mut backend = fake_backend(vec![]);
+
+        let ans = Template::new(template).prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+    }
+}
+
+#[test]
+fn canceling_a_sub_prompt_cancels_the_template() {
+    let mut backend = fake_backend(vec![Key::Escape]);
+
+    let ans = Template::new("deploy {service}").prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+}
@@ -1,13 +1,17 @@
+use std::time::Duration;
+
 use crate::{
+    error::InquireResult,
+    response::ResponseProvider,
     terminal::crossterm::CrosstermTerminal,
     ui::{Backend, InputReader, Key, RenderConfig},
 };
 
-impl<T> InputReader for T
+impl<T> ResponseProvider for T
 where
     T: Iterator<Item = Key>,
 {
-    fn read_key(&mut self) -> crate::error::InquireResult<Key> {
+    fn read_key(&mut self) -> InquireResult<Key> {
         let key = self.next();
 
         match key {
@@ -15,9 +19,49 @@ where
             None => panic!("EOF"),
         }
     }
+
+    // keys are always available right away
+    fn supports_read_timeout(&self) -> bool {
+        true
+    }
 }
 
 pub fn fake_backend(input: Vec<Key>) -> Backend<'static, impl InputReader, CrosstermTerminal> {
     let output = CrosstermTerminal::new_in_memory_output();
     Backend::new(input.into_iter(), output, RenderConfig::default()).unwrap()
 }
+
+/// Keys read one by one, where `None` stands for the user staying idle until
+/// the read timeout elapses.
+pub struct IdleKeys(std::vec::IntoIter<Option<Key>>);
+
+impl ResponseProvider for IdleKeys {
+    fn read_key(&mut self) -> InquireResult<Key> {
+        match self.0.by_ref().flatten().next() {
+            Some(key) => Ok(key),
+            None => panic!("EOF"),
+        }
+    }
+
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        match self.0.next() {
+            Some(Some(key)) => Ok(Some(key)),
+            Some(None) => {
+                std::thread::sleep(timeout);
+                Ok(None)
+            }
+            None => panic!("EOF"),
+        }
+    }
+
+    fn supports_read_timeout(&self) -> bool {
+        true
+    }
+}
+
+pub fn idle_fake_backend(
+    input: Vec<Option<Key>>,
+) -> Backend<'static, impl InputReader, CrosstermTerminal> {
+    let output = CrosstermTerminal::new_in_memory_output();
+    Backend::new(IdleKeys(input.into_iter()), output, RenderConfig::default()).unwrap()
+}
@@ -1,5 +1,5 @@
 use crate::{
-    ui::{Key, KeyModifiers},
+    ui::{Key, KeyHelp, KeyModifiers},
     InnerAction, InputAction,
 };
 
@@ -48,4 +48,31 @@ impl InnerAction for TextPromptAction {
 
         Some(action)
     }
+
+    fn key_help(_config: &TextConfig) -> Vec<KeyHelp> {
+        vec![
+            KeyHelp::new(
+                [
+                    Key::Up(KeyModifiers::NONE),
+                    Key::Char('p', KeyModifiers::CONTROL),
+                ],
+                "previous suggestion",
+            ),
+            KeyHelp::new(
+                [
+                    Key::Down(KeyModifiers::NONE),
+                    Key::Char('n', KeyModifiers::CONTROL),
+                ],
+                "next suggestion",
+            ),
+            KeyHelp::new(
+                [
+                    Key::PageUp(KeyModifiers::NONE),
+                    Key::PageDown(KeyModifiers::NONE),
+                ],
+                "move suggestions by page",
+            ),
+            KeyHelp::new([Key::Tab], "use the current suggestion"),
+        ]
+    }
 }
@@ -7,15 +7,20 @@ mod test;
 
 pub use action::*;
 
+use std::{str::FromStr, time::Duration};
+
 use crate::{
     autocompletion::Autocomplete,
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::{StringFormatter, DEFAULT_STRING_FORMATTER},
+    parser::CustomTypeParser,
     prompts::prompt::Prompt,
     terminal::get_default_terminal,
+    type_aliases::{InputTransformer, StringMapper},
     ui::{Backend, RenderConfig, TextBackend},
     validator::StringValidator,
+    CustomType,
 };
 
 use self::prompt::TextPrompt;
@@ -34,9 +39,15 @@ const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete,
 /// - **Default value**: Default value returned when the user submits an empty response.
 /// - **Initial value**: Initial value of the prompt's text input, in case you want to display the prompt with something already filled in.
 /// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Skip option**: Label, e.g. `(none)`, rendered in place of an empty input, submitting which skips the prompt like pressing ESC.
 /// - **Validators**: Custom validators to the user's input, displaying an error message if the input does not pass the requirements.
+/// - **Input transformer**: Custom function applied to each character as the user types it, e.g. to lowercase hostnames or convert spaces to dashes. The transformed text is displayed immediately.
+/// - **Answer mapper**: Custom function that normalizes the answer, e.g. trimming whitespace, before validators and the formatter run. The mapped answer is the one returned.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 /// - **Suggester**: Custom function that returns a list of input suggestions based on the current text input. See more on "Autocomplete" below.
+/// - **Timeout**: Maximum time to wait for an answer, after which the prompt fails with [`InquireError::Timeout`](crate::InquireError::Timeout). The time left can optionally be rendered below the prompt.
+/// - **Elapsed time**: Whether to render the time elapsed since the prompt was displayed below it, ticking every second, e.g. for time-boxed decisions.
+/// - **Max attempts**: Maximum number of rejected answers, after which the prompt returns the default value, if any, or fails with [`InquireError::TooManyAttempts`](crate::InquireError::TooManyAttempts).
 ///
 /// ## Default behaviors
 ///
@@ -88,15 +99,29 @@ pub struct Text<'a> {
     /// Default value, returned when the user input is empty.
     pub default: Option<&'a str>,
 
+    /// Current value of the setting being edited, displayed next to the
+    /// prompt message, e.g. `Timeout [current: 30s]:`.
+    pub current_value: Option<&'a str>,
+
     /// Short hint that describes the expected value of the input.
     pub placeholder: Option<&'a str>,
 
+    /// Label rendered in place of an empty input, submitting which skips the prompt.
+    pub skip_option: Option<&'a str>,
+
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: StringFormatter<'a>,
 
+    /// Function that normalizes the answer before it is validated, formatted and returned.
+    pub answer_mapper: Option<StringMapper<'a>>,
+
+    /// Function applied to each character typed by the user, returning the
+    /// text to write in its place or `None` to ignore the character.
+    pub input_transformer: Option<InputTransformer<'a>>,
+
     /// Autocompleter responsible for handling suggestions and input completions.
     pub autocompleter: Option<Box<dyn Autocomplete>>,
 
@@ -111,6 +136,22 @@ pub struct Text<'a> {
     /// Page size of the suggestions displayed to the user, when applicable.
     pub page_size: usize,
 
+    /// Maximum time to wait for an answer before failing with [`InquireError::Timeout`].
+    pub timeout: Option<Duration>,
+
+    /// Whether to render the time left until the timeout elapses.
+    pub show_remaining_time: bool,
+
+    /// Whether to render the time elapsed since the prompt was displayed, or the
+    /// time left when a timeout is set.
+    pub show_elapsed_time: bool,
+
+    /// Maximum number of rejected answers, after which the prompt returns
+    /// the default value, if any, or fails with
+    /// [`InquireError::TooManyAttempts`]. An answer is rejected when it does
+    /// not pass the validators.
+    pub max_attempts: Option<usize>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -140,13 +181,21 @@ impl<'a> Text<'a> {
         Self {
             message,
             placeholder: None,
+            skip_option: None,
             initial_value: None,
             default: None,
+            current_value: None,
             help_message: Self::DEFAULT_HELP_MESSAGE,
             validators: Self::DEFAULT_VALIDATORS,
             formatter: Self::DEFAULT_FORMATTER,
+            answer_mapper: None,
+            input_transformer: None,
             page_size: Self::DEFAULT_PAGE_SIZE,
             autocompleter: None,
+            timeout: None,
+            show_remaining_time: false,
+            show_elapsed_time: false,
+            max_attempts: None,
             render_config: get_configuration(),
         }
     }
@@ -173,12 +222,38 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets the current value of the setting being edited, displayed next to
+    /// the prompt message, e.g. `Timeout [current: 30s]:`. It is formatted
+    /// with the formatter of the prompt, and the final answer is displayed
+    /// as `30s → 45s` when it differs from the current value.
+    ///
+    /// Unlike [`with_initial_value`](Text::with_initial_value), the input
+    /// starts empty. Unlike [`with_default`](Text::with_default), it is not
+    /// returned on empty submissions, though both can be set to the same value.
+    pub fn with_current_value(mut self, current_value: &'a str) -> Self {
+        self.current_value = Some(current_value);
+        self
+    }
+
     /// Sets the placeholder.
     pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
         self.placeholder = Some(placeholder);
         self
     }
 
+    /// Makes the answer optional, rendering the label, e.g. `(none)`, in place
+    /// of an empty input unless a placeholder is set.
+    ///
+    /// Submitting an empty input then renders the label as the answer and
+    /// skips the prompt, bypassing the validators:
+    /// [`prompt_skippable`](Text::prompt_skippable) returns `Ok(None)`, while
+    /// [`prompt`](Text::prompt) fails with [`InquireError::OperationSkipped`].
+    /// When a default is set, it is returned on empty submissions instead.
+    pub fn with_skip_option(mut self, label: &'a str) -> Self {
+        self.skip_option = Some(label);
+        self
+    }
+
     /// Sets a new autocompleter
     pub fn with_autocomplete<AC>(mut self, ac: AC) -> Self
     where
@@ -194,6 +269,27 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets the function that normalizes the answer, e.g. trimming whitespace,
+    /// canonicalizing case or resolving aliases.
+    ///
+    /// The mapper runs on submission, before the validators and the formatter,
+    /// and the mapped answer is the one returned by the prompt.
+    pub fn with_answer_mapper(mut self, answer_mapper: StringMapper<'a>) -> Self {
+        self.answer_mapper = Some(answer_mapper);
+        self
+    }
+
+    /// Sets the function applied to each character as the user types it.
+    ///
+    /// It receives the typed character and the current input, and returns the
+    /// text to write in its place, or `None` to ignore the character. Text set
+    /// by other means, such as the initial value or autocompletion, is not
+    /// transformed.
+    pub fn with_input_transformer(mut self, input_transformer: InputTransformer<'a>) -> Self {
+        self.input_transformer = Some(input_transformer);
+        self
+    }
+
     /// Sets the page size
     pub fn with_page_size(mut self, page_size: usize) -> Self {
         self.page_size = page_size;
@@ -232,6 +328,41 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets the maximum time to wait for an answer, after which the prompt
+    /// fails with [`InquireError::Timeout`].
+    ///
+    /// The deadline can only interrupt a pending key read when using the
+    /// `crossterm` backend. Other backends notice it on the next key press.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to render the time left until the timeout elapses.
+    pub fn with_show_remaining_time(mut self, show_remaining_time: bool) -> Self {
+        self.show_remaining_time = show_remaining_time;
+        self
+    }
+
+    /// Sets whether to render the time elapsed since the prompt was displayed,
+    /// ticking every second. When a timeout is set, the time left is rendered
+    /// instead.
+    pub fn with_show_elapsed_time(mut self, show_elapsed_time: bool) -> Self {
+        self.show_elapsed_time = show_elapsed_time;
+        self
+    }
+
+    /// Sets the maximum number of rejected answers, after which the prompt
+    /// returns the default value, if any, or fails with
+    /// [`InquireError::TooManyAttempts`].
+    ///
+    /// Useful for scripts that should not ask again forever, e.g. when the
+    /// input is piped in.
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -245,19 +376,78 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Turns the prompt into a [`CustomType`] prompt whose answer is parsed
+    /// into `T`, e.g. `Text::new("Port:").parse::<u16>()`. When the input
+    /// can not be parsed, an error message is displayed and the user is
+    /// asked again.
+    ///
+    /// See [`Text::parse_with`] for the settings carried over.
+    pub fn parse<T>(self) -> CustomType<'a, T>
+    where
+        T: Clone + FromStr + ToString,
+    {
+        self.parse_with(&|input| input.parse::<T>().map_err(|_e| ()))
+    }
+
+    /// Turns the prompt into a [`CustomType`] prompt whose answer is parsed
+    /// into `T` with the given parser. When the parser fails, an error message
+    /// is displayed and the user is asked again.
+    ///
+    /// The message, initial value, placeholder, help message, timeout, maximum
+    /// number of attempts and render config are carried over, as is the default value when the
+    /// parser accepts it. Settings working on strings, such as validators,
+    /// formatters and autocompletion, are not: validators of the parsed value
+    /// can be added to the returned prompt instead.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::Text;
+    ///
+    /// let percentage = Text::new("Discount:")
+    ///     .with_default("10%")
+    ///     .parse_with(&|input| input.trim_end_matches('%').parse::<u8>().map_err(|_| ()))
+    ///     .with_error_message("Please type a percentage, e.g. 15%")
+    ///     .prompt();
+    /// ```
+    pub fn parse_with<T>(self, parser: CustomTypeParser<'a, T>) -> CustomType<'a, T>
+    where
+        T: Clone + ToString,
+    {
+        CustomType {
+            message: self.message,
+            starting_input: self.initial_value,
+            default: self.default.and_then(|default| parser(default).ok()),
+            current_value: self.current_value.and_then(|current| parser(current).ok()),
+            placeholder: self.placeholder,
+            help_message: self.help_message,
+            formatter: &|val| val.to_string(),
+            default_value_formatter: &|val| val.to_string(),
+            parser,
+            validators: CustomType::DEFAULT_VALIDATORS,
+            error_message: "Invalid input".into(),
+            timeout: self.timeout,
+            show_remaining_time: self.show_remaining_time,
+            show_elapsed_time: self.show_elapsed_time,
+            max_attempts: self.max_attempts,
+            render_config: self.render_config,
+        }
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
     /// This method is intended for flows where the user skipping/cancelling
     /// the prompt - by pressing ESC - is considered normal behavior. In this case,
     /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    /// The same goes for choosing the skip option, if any.
     ///
     /// Meanwhile, if the user does submit an answer, the method wraps the return
     /// type with `Some`.
     pub fn prompt_skippable(self) -> InquireResult<Option<String>> {
         match self.prompt() {
             Ok(answer) => Ok(Some(answer)),
-            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(InquireError::OperationCanceled | InquireError::OperationSkipped) => Ok(None),
             Err(err) => Err(err),
         }
     }
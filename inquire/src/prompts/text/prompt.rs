@@ -1,4 +1,4 @@
-use std::cmp::min;
+use std::{borrow::Cow, cmp::min, time::Duration};
 
 use crate::{
     autocompletion::{NoAutoCompletion, Replacement},
@@ -7,21 +7,30 @@ use crate::{
     input::{Input, InputActionResult},
     list_option::ListOption,
     prompts::prompt::{ActionResult, Prompt},
+    type_aliases::{InputTransformer, StringMapper},
     ui::TextBackend,
-    utils::paginate,
+    utils::{format_edited_answer, paginate},
     validator::{ErrorMessage, StringValidator, Validation},
-    Autocomplete, InquireError, Text,
+    Autocomplete, InputAction, InquireError, Text,
 };
 
 use super::{action::TextPromptAction, config::TextConfig, DEFAULT_HELP_MESSAGE_WITH_AC};
 
 pub struct TextPrompt<'a> {
     message: &'a str,
+    timeout: Option<Duration>,
+    show_remaining_time: bool,
+    show_elapsed_time: bool,
+    max_attempts: Option<usize>,
     config: TextConfig,
     default: Option<&'a str>,
+    current_value: Option<&'a str>,
+    skip_option: Option<&'a str>,
     help_message: Option<&'a str>,
     input: Input,
     formatter: StringFormatter<'a>,
+    answer_mapper: Option<StringMapper<'a>>,
+    input_transformer: Option<InputTransformer<'a>>,
     validators: Vec<Box<dyn StringValidator>>,
     error: Option<ErrorMessage>,
     autocompleter: Box<dyn Autocomplete>,
@@ -32,7 +41,7 @@ pub struct TextPrompt<'a> {
 impl<'a> From<Text<'a>> for TextPrompt<'a> {
     fn from(so: Text<'a>) -> Self {
         let input = Input::new_with(so.initial_value.unwrap_or_default());
-        let input = if let Some(placeholder) = so.placeholder {
+        let input = if let Some(placeholder) = so.placeholder.or(so.skip_option) {
             input.with_placeholder(placeholder)
         } else {
             input
@@ -40,10 +49,18 @@ impl<'a> From<Text<'a>> for TextPrompt<'a> {
 
         Self {
             message: so.message,
+            timeout: so.timeout,
+            show_remaining_time: so.show_remaining_time,
+            show_elapsed_time: so.show_elapsed_time,
+            max_attempts: so.max_attempts,
             config: (&so).into(),
             default: so.default,
+            current_value: so.current_value,
+            skip_option: so.skip_option,
             help_message: so.help_message,
             formatter: so.formatter,
+            answer_mapper: so.answer_mapper,
+            input_transformer: so.input_transformer,
             autocompleter: so
                 .autocompleter
                 .unwrap_or_else(|| Box::<NoAutoCompletion>::default()),
@@ -148,9 +165,18 @@ impl<'a> TextPrompt<'a> {
         self.input.content()
     }
 
-    fn validate_current_answer(&self) -> InquireResult<Validation> {
+    fn get_mapped_answer(&self) -> Cow<'_, str> {
+        let answer = self.get_current_answer();
+
+        match self.answer_mapper {
+            Some(mapper) => Cow::Owned(mapper(answer)),
+            None => Cow::Borrowed(answer),
+        }
+    }
+
+    fn validate_answer(&self, answer: &str) -> InquireResult<Validation> {
         for validator in &self.validators {
-            match validator.validate(self.get_current_answer()) {
+            match validator.validate(answer) {
                 Ok(Validation::Valid) => {}
                 Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
                 Err(err) => return Err(InquireError::Custom(err)),
@@ -178,16 +204,56 @@ where
     }
 
     fn format_answer(&self, answer: &String) -> String {
-        (self.formatter)(answer)
+        format_edited_answer(
+            self.current_value.map(|current| (self.formatter)(current)),
+            (self.formatter)(answer),
+        )
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    fn show_remaining_time(&self) -> bool {
+        self.show_remaining_time
+    }
+
+    fn show_elapsed_time(&self) -> bool {
+        self.show_elapsed_time
+    }
+
+    fn max_attempts(&self) -> Option<usize> {
+        self.max_attempts
+    }
+
+    fn fallback_answer(&self) -> Option<String> {
+        self.default.map(String::from)
     }
 
     fn setup(&mut self) -> InquireResult<()> {
         self.update_suggestions()
     }
 
+    fn selected_skip_option(&self) -> Option<&str> {
+        match (self.skip_option, self.default) {
+            (Some(skip_option), None)
+                if self.input.is_empty() && self.suggestion_cursor_index.is_none() =>
+            {
+                Some(skip_option)
+            }
+            _ => None,
+        }
+    }
+
+    fn can_submit(&self) -> InquireResult<bool> {
+        Ok(self.validate_answer(&self.get_mapped_answer())? == Validation::Valid)
+    }
+
     fn submit(&mut self) -> InquireResult<Option<String>> {
-        let result = match self.validate_current_answer()? {
-            Validation::Valid => Some(self.get_current_answer().to_owned()),
+        let answer = self.get_mapped_answer();
+
+        let result = match self.validate_answer(&answer)? {
+            Validation::Valid => Some(answer.into_owned()),
             Validation::Invalid(msg) => {
                 self.error = Some(msg);
                 None
@@ -200,7 +266,15 @@ where
     fn handle(&mut self, action: TextPromptAction) -> InquireResult<ActionResult> {
         let result = match action {
             TextPromptAction::ValueInput(input_action) => {
-                let result = self.input.handle(input_action);
+                let result = match (input_action, self.input_transformer) {
+                    (InputAction::Write(c), Some(transformer)) => {
+                        match transformer(c, self.input.content()) {
+                            Some(text) => self.input.insert_str(&text),
+                            None => InputActionResult::Clean,
+                        }
+                    }
+                    (input_action, _) => self.input.handle(input_action),
+                };
 
                 if let InputActionResult::ContentChanged = result {
                     self.update_suggestions()?;
@@ -227,7 +301,8 @@ where
             backend.render_error_message(err)?;
         }
 
-        backend.render_prompt(prompt, self.default, &self.input)?;
+        let current_value = self.current_value.map(|current| (self.formatter)(current));
+        backend.render_prompt(prompt, current_value.as_deref(), self.default, &self.input)?;
 
         let choices = self
             .suggested_options
@@ -111,3 +111,640 @@ text_test!(
         _ => Ok(Validation::Invalid(ErrorMessage::Default)),
     })
 );
+
+text_test!(
+    answer_before_timeout,
+    {
+        let mut events = text_to_events!("on time");
+        events.push(Key::Enter);
+        events
+    },
+    "on time",
+    Text::new("")
+        .with_timeout(std::time::Duration::from_secs(60))
+        .with_show_remaining_time(true)
+);
+
+#[test]
+fn elapsed_timeout_fails_with_timeout_error() {
+    let mut backend = crate::prompts::test::fake_backend(vec![Key::Enter]);
+
+    let ans = Text::new("")
+        .with_timeout(std::time::Duration::ZERO)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(crate::InquireError::Timeout)));
+}
+
+#[test]
+fn elapsed_time_is_rendered_or_replaced_by_remaining_time() {
+    let mut backend = crate::prompts::test::fake_backend(vec![Key::Enter]);
+    Text::new("")
+        .with_show_elapsed_time(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("0:00 elapsed"));
+
+    let mut backend = crate::prompts::test::fake_backend(vec![Key::Enter]);
+    Text::new("")
+        .with_timeout(std::time::Duration::from_secs(90))
+        .with_show_elapsed_time(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("1:30 left"));
+    assert!(!output.contains("elapsed"));
+}
+
+fn notification_backend(
+    input: Vec<Key>,
+    render_config: &crate::ui::RenderConfig<'static>,
+) -> crate::ui::Backend<
+    'static,
+    impl crate::ui::InputReader,
+    crate::terminal::crossterm::CrosstermTerminal,
+> {
+    let terminal = crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output();
+    crate::ui::Backend::new(input.into_iter(), terminal, *render_config).unwrap()
+}
+
+#[test]
+fn prompt_start_notification_is_emitted_once() {
+    let render_config = crate::ui::RenderConfig::empty()
+        .with_prompt_start_notification(crate::ui::Notification::Desktop);
+    let mut events = text_to_events!("ab");
+    events.push(Key::Enter);
+    let mut backend = notification_backend(events, &render_config);
+
+    Text::new("Deploy now?")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert_eq!(1, output.matches("\x1b]9;Deploy now?\x07").count());
+}
+
+#[test]
+fn error_notification_is_emitted_when_error_appears() {
+    let render_config =
+        crate::ui::RenderConfig::empty().with_error_notification(crate::ui::Notification::Bell);
+    let mut events = vec![Key::Enter, Key::Enter];
+    events.append(&mut text_to_events!("a"));
+    events.push(Key::Enter);
+    let mut backend = notification_backend(events, &render_config);
+
+    Text::new("Name:")
+        .with_validator(|ans: &str| match ans.is_empty() {
+            true => Ok(Validation::Invalid(ErrorMessage::Custom("Required".into()))),
+            false => Ok(Validation::Valid),
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert_eq!(1, output.matches('\x07').count());
+}
+
+#[test]
+fn interrupted_prompt_is_rendered_with_canceled_prefix() {
+    let render_config =
+        crate::ui::RenderConfig::empty().with_canceled_prompt_prefix(crate::ui::Styled::new("✘"));
+    let mut events = text_to_events!("ab");
+    events.push(Key::Char('c', KeyModifiers::CONTROL));
+    let mut backend = notification_backend(events, &render_config);
+
+    let ans = Text::new("Name:").prompt_with_backend(&mut backend);
+
+    assert!(matches!(
+        ans,
+        Err(crate::error::InquireError::OperationInterrupted)
+    ));
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("✘ Name: <canceled>"));
+}
+
+#[test]
+fn errored_prompt_is_rendered_with_errored_prefix() {
+    let render_config =
+        crate::ui::RenderConfig::empty().with_errored_prompt_prefix(crate::ui::Styled::new("!"));
+    let events = vec![Key::Enter, Key::Char('a', KeyModifiers::NONE), Key::Enter];
+    let mut backend = notification_backend(events, &render_config);
+
+    Text::new("Name:")
+        .with_validator(|ans: &str| match ans.is_empty() {
+            true => Ok(Validation::Invalid(ErrorMessage::Custom("Required".into()))),
+            false => Ok(Validation::Valid),
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("! Name:"));
+    assert!(output.contains("? Name:"));
+}
+
+#[test]
+fn canceled_prompt_is_rendered_with_custom_indicator() {
+    let render_config = crate::ui::RenderConfig::empty()
+        .with_canceled_prompt_indicator(crate::ui::Styled::new("<skipped>"));
+    let mut events = text_to_events!("ab");
+    events.push(Key::Escape);
+    let mut backend = notification_backend(events, &render_config);
+
+    let ans = Text::new("Name:").prompt_with_backend(&mut backend);
+
+    assert!(matches!(
+        ans,
+        Err(crate::error::InquireError::OperationCanceled)
+    ));
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("? Name: <skipped>"));
+    assert!(!output.contains("<canceled>"));
+}
+
+#[test]
+fn answer_mapper_runs_before_validators_and_formatter() {
+    let mut events = text_to_events!("  USA ");
+    events.push(Key::Enter);
+    let mut backend = crate::prompts::test::fake_backend(events);
+
+    let ans = Text::new("Country:")
+        .with_answer_mapper(&|ans| match ans.trim().to_lowercase().as_str() {
+            "us" | "usa" => "United States".to_string(),
+            other => other.to_string(),
+        })
+        .with_validator(|ans: &str| match ans == ans.trim() {
+            true => Ok(Validation::Valid),
+            false => Ok(Validation::Invalid("Untrimmed answer".into())),
+        })
+        .with_formatter(&|ans| {
+            assert_eq!("United States", ans);
+            ans.to_uppercase()
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("United States", ans);
+}
+
+#[test]
+fn input_transformer_is_applied_as_the_user_types() {
+    let mut events = text_to_events!("My Cool App!");
+    events.push(Key::Enter);
+    let mut backend = crate::prompts::test::fake_backend(events);
+
+    let ans = Text::new("Slug:")
+        .with_input_transformer(&|c, input| match c {
+            ' ' if input.ends_with('-') => None,
+            ' ' => Some("-".to_string()),
+            c if c.is_alphanumeric() => Some(c.to_lowercase().collect()),
+            _ => None,
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("my-cool-app", ans);
+}
+
+#[test]
+fn can_submit_reflects_the_validation_of_the_answer() {
+    use super::prompt::TextPrompt;
+    use crate::{
+        prompts::prompt::Prompt, terminal::crossterm::CrosstermTerminal, ui::Backend,
+        validator::ValueRequiredValidator,
+    };
+
+    type TestBackend = Backend<'static, std::vec::IntoIter<Key>, CrosstermTerminal>;
+
+    let empty = TextPrompt::from(default().with_validator(ValueRequiredValidator::default()));
+    assert!(!Prompt::<TestBackend>::can_submit(&empty).unwrap());
+
+    let filled = TextPrompt::from(
+        default()
+            .with_initial_value("Alice")
+            .with_validator(ValueRequiredValidator::default()),
+    );
+    assert!(Prompt::<TestBackend>::can_submit(&filled).unwrap());
+}
+
+#[test]
+fn unhandled_keys_are_passed_to_the_handler() {
+    use crate::ui::KeyBindings;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static F13_PRESSES: AtomicUsize = AtomicUsize::new(0);
+
+    let bindings = KeyBindings::default().with_unhandled_key_handler(&|key| {
+        if key == Key::F(13, KeyModifiers::NONE) {
+            F13_PRESSES.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    let mut events = vec![Key::F(13, KeyModifiers::NONE)];
+    events.append(&mut text_to_events!("ok"));
+    events.push(Key::Char('x', KeyModifiers::ALT));
+    events.push(Key::Enter);
+    let mut backend = crate::prompts::test::fake_backend(events).with_key_bindings(bindings);
+
+    let ans = default().prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!("ok", ans);
+    assert_eq!(1, F13_PRESSES.load(Ordering::SeqCst));
+}
+
+#[test]
+fn text_input_cursor_shape_is_applied() {
+    let render_config =
+        crate::ui::RenderConfig::empty().with_text_input_cursor(crate::ui::CursorStyle::SteadyBar);
+    let mut backend = crate::ui::Backend::new(
+        text_to_events!("ok\n").into_iter(),
+        crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output(),
+        render_config,
+    )
+    .unwrap();
+
+    let ans = default().prompt_with_backend(&mut backend).unwrap();
+    assert_eq!("ok", ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert_eq!(1, output.matches("\x1b[6 q").count());
+}
+
+#[test]
+fn parsed_answer_is_asked_again_until_valid() {
+    let mut events = text_to_events!("x\n");
+    events.push(Key::Backspace);
+    events.append(&mut text_to_events!("8080\n"));
+    let mut backend = crate::prompts::test::fake_backend(events);
+
+    let ans = Text::new("Port:")
+        .parse::<u16>()
+        .prompt_with_backend(&mut backend);
+
+    assert_eq!(8080, ans.unwrap());
+}
+
+#[test]
+fn parsed_prompt_keeps_the_parsable_default() {
+    let mut backend = crate::prompts::test::fake_backend(vec![Key::Enter]);
+
+    let ans = Text::new("Port:")
+        .with_default("8080")
+        .parse::<u16>()
+        .prompt_with_backend(&mut backend);
+
+    assert_eq!(8080, ans.unwrap());
+    assert_eq!(
+        None,
+        Text::new("Port:")
+            .with_default("http")
+            .parse::<u16>()
+            .default
+    );
+}
+
+#[test]
+fn prompt_gives_up_after_max_attempts() {
+    let digits_only = |ans: &str| match ans.chars().all(|c| c.is_ascii_digit()) {
+        true => Ok(Validation::Valid),
+        false => Ok(Validation::Invalid(ErrorMessage::Default)),
+    };
+
+    let mut backend = crate::prompts::test::fake_backend(text_to_events!("a\n\n"));
+    let ans = Text::new("PIN:")
+        .with_validator(digits_only)
+        .with_max_attempts(2)
+        .prompt_with_backend(&mut backend);
+    assert!(matches!(ans, Err(crate::InquireError::TooManyAttempts)));
+
+    let mut backend = crate::prompts::test::fake_backend(text_to_events!("a\n\n"));
+    let ans = Text::new("PIN:")
+        .with_validator(digits_only)
+        .with_default("0000")
+        .with_initial_value("x")
+        .with_max_attempts(2)
+        .prompt_with_backend(&mut backend);
+    assert_eq!("0000", ans.unwrap());
+}
+
+text_test!(
+    empty_submission_returns_the_default,
+    vec![Key::Enter],
+    "8080",
+    Text::new("Port:").with_default("8080")
+);
+
+text_test!(
+    initial_value_is_edited_instead_of_the_default,
+    vec![Key::Backspace, Key::Enter],
+    "808",
+    Text::new("Port:")
+        .with_default("3000")
+        .with_initial_value("8080")
+);
+
+#[test]
+fn default_is_rendered_next_to_the_prompt() {
+    let render_config = crate::ui::RenderConfig::default_colored();
+    let mut backend = crate::ui::Backend::new(
+        vec![Key::Enter].into_iter(),
+        crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output(),
+        render_config,
+    )
+    .unwrap();
+
+    let ans = Text::new("Port:")
+        .with_default("8080")
+        .prompt_with_backend(&mut backend);
+    assert_eq!("8080", ans.unwrap());
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("Port: \x1b[38;5;8m(8080)"));
+}
+
+#[test]
+fn current_value_is_rendered_and_kept_in_the_answer() {
+    let mut backend = crate::ui::Backend::new(
+        text_to_events!("45s\n").into_iter(),
+        crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output(),
+        crate::ui::RenderConfig::empty(),
+    )
+    .unwrap();
+
+    let ans = Text::new("Timeout:")
+        .with_current_value("30s")
+        .prompt_with_backend(&mut backend);
+    assert_eq!("45s", ans.unwrap());
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("Timeout [current: 30s]:"));
+    assert!(output.contains("30s → 45s"));
+}
+
+#[test]
+fn unchanged_current_value_is_rendered_once_in_the_answer() {
+    let mut backend = crate::ui::Backend::new(
+        text_to_events!("30s\n").into_iter(),
+        crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output(),
+        crate::ui::RenderConfig::empty(),
+    )
+    .unwrap();
+
+    let ans = Text::new("Timeout:")
+        .with_current_value("30s")
+        .prompt_with_backend(&mut backend);
+    assert_eq!("30s", ans.unwrap());
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(!output.contains('→'));
+}
+
+#[test]
+fn empty_submission_with_skip_option_skips_the_prompt() {
+    let mut backend = crate::ui::Backend::new(
+        vec![Key::Enter].into_iter(),
+        crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output(),
+        crate::ui::RenderConfig::empty(),
+    )
+    .unwrap();
+
+    let ans = Text::new("Middle name:")
+        .with_skip_option("(none)")
+        .with_validator(crate::required!())
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(
+        ans,
+        Err(crate::error::InquireError::OperationSkipped)
+    ));
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("Middle name: (none)"));
+}
+
+#[test]
+fn highlighted_suggestion_is_returned_with_skip_option() {
+    let mut backend = crate::ui::Backend::new(
+        vec![Key::Down(KeyModifiers::NONE), Key::Enter].into_iter(),
+        crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output(),
+        crate::ui::RenderConfig::empty(),
+    )
+    .unwrap();
+
+    let suggester = |_: &str| Ok(vec![String::from("Lee"), String::from("Ray")]);
+    let ans = Text::new("Middle name:")
+        .with_skip_option("(none)")
+        .with_autocomplete(suggester)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("Lee", ans);
+}
+
+text_test!(
+    typed_answer_is_returned_with_skip_option,
+    text_to_events!("Lee\n"),
+    "Lee",
+    Text::new("Middle name:").with_skip_option("(none)")
+);
+
+#[test]
+#[cfg(feature = "log")]
+fn prompt_lifecycle_is_logged() {
+    use std::sync::Mutex;
+
+    static RECORDS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    struct RecordingLogger;
+
+    impl log::Log for RecordingLogger {
+        fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+            metadata.target() == "inquire"
+        }
+
+        fn log(&self, record: &log::Record<'_>) {
+            if self.enabled(record.metadata()) {
+                let entry = format!("{} {}", record.level(), record.args());
+                RECORDS.lock().unwrap().push(entry);
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    log::set_logger(&RecordingLogger).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let mut events = vec![Key::Enter];
+    events.append(&mut text_to_events!("a"));
+    events.push(Key::Enter);
+    let mut backend = crate::test::fake_backend(events);
+
+    Text::new("Logged name:")
+        .with_validator(|ans: &str| match ans.is_empty() {
+            true => Ok(Validation::Invalid(ErrorMessage::Custom("Required".into()))),
+            false => Ok(Validation::Valid),
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let records: Vec<String> = RECORDS
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|entry| entry.contains("\"Logged name:\""))
+        .cloned()
+        .collect();
+
+    assert_eq!(r#"DEBUG prompt "Logged name:" started"#, records[0]);
+    assert_eq!(
+        r#"TRACE prompt "Logged name:" received key Enter"#,
+        records[1]
+    );
+    assert_eq!(
+        r#"DEBUG prompt "Logged name:" rejected submission attempt 1"#,
+        records[2]
+    );
+    assert!(records[5].starts_with(r#"DEBUG prompt "Logged name:" answered after "#));
+    assert_eq!(6, records.len());
+
+    // key capture prompts run their own loop, logged just the same
+    let mut backend = crate::test::fake_backend(vec![Key::Enter]);
+    crate::KeyCapture::new("Logged key:")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let records: Vec<String> = RECORDS
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|entry| entry.contains("\"Logged key:\""))
+        .cloned()
+        .collect();
+
+    assert_eq!(r#"DEBUG prompt "Logged key:" started"#, records[0]);
+    assert_eq!(
+        r#"TRACE prompt "Logged key:" received key Enter"#,
+        records[1]
+    );
+    assert!(records[2].starts_with(r#"DEBUG prompt "Logged key:" answered after "#));
+    assert_eq!(3, records.len());
+}
+
+#[test]
+fn resolved_prompts_are_reported_to_the_telemetry_hook() {
+    use std::sync::Mutex;
+
+    use crate::PromptTelemetry;
+
+    static REPORTS: Mutex<Vec<PromptTelemetry>> = Mutex::new(Vec::new());
+
+    crate::set_global_telemetry_hook(|telemetry: &PromptTelemetry| {
+        if telemetry.prompt_id.starts_with("Telemetry") {
+            REPORTS.lock().unwrap().push(telemetry.clone());
+        }
+    });
+
+    let mut events = vec![Key::Enter];
+    events.append(&mut text_to_events!("a"));
+    events.push(Key::Enter);
+    let mut backend = crate::test::fake_backend(events);
+
+    Text::new("Telemetry name:")
+        .with_validator(|ans: &str| match ans.is_empty() {
+            true => Ok(Validation::Invalid(ErrorMessage::Custom("Required".into()))),
+            false => Ok(Validation::Valid),
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let mut backend = crate::test::fake_backend(vec![Key::Escape]);
+    let canceled = Text::new("Telemetry email:").prompt_with_backend(&mut backend);
+    assert!(canceled.is_err());
+
+    let mut backend = crate::test::fake_backend(vec![Key::Enter]);
+    let skipped = Text::new("Telemetry nickname:")
+        .with_skip_option("(none)")
+        .prompt_with_backend(&mut backend);
+    assert!(skipped.is_err());
+
+    let mut backend = crate::test::fake_backend(vec![Key::Char('3', KeyModifiers::NONE)]);
+    crate::Menu::new("Telemetry plan:", vec![('1', "Free"), ('3', "Pro")])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let mut backend = crate::test::fake_backend(vec![Key::Enter]);
+    crate::KeyCapture::new("Telemetry key:")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    crate::clear_global_telemetry_hook();
+
+    let reports = REPORTS.lock().unwrap();
+    let summary: Vec<(&str, usize, bool, bool)> = reports
+        .iter()
+        .map(|report| {
+            (
+                report.prompt_id.as_str(),
+                report.attempts,
+                report.canceled,
+                report.skipped,
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        vec![
+            ("Telemetry name:", 2, false, false),
+            ("Telemetry email:", 0, true, false),
+            ("Telemetry nickname:", 1, false, true),
+            ("Telemetry plan:", 1, false, false),
+            ("Telemetry key:", 1, false, false),
+        ],
+        summary
+    );
+}
+
+#[test]
+fn rendered_frames_are_reported_to_the_frame_hook() {
+    use std::sync::Mutex;
+
+    use crate::RenderedFrame;
+
+    static FRAMES: Mutex<Vec<RenderedFrame>> = Mutex::new(Vec::new());
+
+    crate::set_global_frame_hook(|frame: &RenderedFrame| {
+        if frame.text().contains("Recorded name:") {
+            FRAMES.lock().unwrap().push(frame.clone());
+        }
+    });
+
+    let mut events = text_to_events!("ab");
+    events.push(Key::Enter);
+    let mut backend = crate::test::fake_backend(events);
+
+    Text::new("Recorded name:")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    crate::clear_global_frame_hook();
+
+    let frames = FRAMES.lock().unwrap();
+    let texts: Vec<String> = frames.iter().map(RenderedFrame::text).collect();
+
+    assert_eq!(
+        vec![
+            "? Recorded name:  ",
+            "? Recorded name: a ",
+            "? Recorded name: ab ",
+            "> Recorded name: ab",
+        ],
+        texts
+    );
+    assert!(frames
+        .windows(2)
+        .all(|pair| pair[0].elapsed <= pair[1].elapsed));
+}
@@ -0,0 +1,56 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    HourFormat, InnerAction,
+};
+
+use super::config::TimeSelectConfig;
+
+/// Set of actions for a TimeSelectPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimeSelectPromptAction {
+    /// Moves the cursor to the previous segment.
+    PreviousSegment,
+    /// Moves the cursor to the next segment.
+    NextSegment,
+    /// Increases the value of the current segment, wrapping around.
+    Increment,
+    /// Decreases the value of the current segment, wrapping around.
+    Decrement,
+    /// Types a digit into the current segment.
+    TypeDigit(u32),
+    /// Sets the time before noon, in the 12-hour clock.
+    SetAm,
+    /// Sets the time after noon, in the 12-hour clock.
+    SetPm,
+}
+
+impl InnerAction for TimeSelectPromptAction {
+    type Config = TimeSelectConfig;
+
+    fn from_key(key: Key, config: &TimeSelectConfig) -> Option<Self> {
+        if config.hour_format == HourFormat::TwelveHour {
+            match key {
+                Key::Char('a' | 'A', KeyModifiers::NONE) => return Some(Self::SetAm),
+                Key::Char('p' | 'P', KeyModifiers::NONE) => return Some(Self::SetPm),
+                _ => {}
+            }
+        }
+
+        let action = match key {
+            Key::Left(KeyModifiers::NONE) | Key::Char('b', KeyModifiers::CONTROL) => {
+                Self::PreviousSegment
+            }
+            Key::Right(KeyModifiers::NONE) | Key::Tab | Key::Char('f', KeyModifiers::CONTROL) => {
+                Self::NextSegment
+            }
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::Increment,
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => {
+                Self::Decrement
+            }
+            Key::Char(c, KeyModifiers::NONE) => Self::TypeDigit(c.to_digit(10)?),
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
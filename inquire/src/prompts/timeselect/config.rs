@@ -0,0 +1,20 @@
+use crate::{HourFormat, TimeSelect};
+
+/// Configuration settings used in the execution of a TimeSelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct TimeSelectConfig {
+    /// Whether hours are displayed in the 24-hour or 12-hour clock.
+    pub hour_format: HourFormat,
+
+    /// Whether the seconds are displayed and can be edited.
+    pub with_seconds: bool,
+}
+
+impl From<&TimeSelect<'_>> for TimeSelectConfig {
+    fn from(value: &TimeSelect<'_>) -> Self {
+        Self {
+            hour_format: value.hour_format,
+            with_seconds: value.with_seconds,
+        }
+    }
+}
@@ -0,0 +1,217 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use chrono::NaiveTime;
+
+use crate::{
+    config::get_configuration,
+    date_utils::get_current_time,
+    error::{InquireError, InquireResult},
+    formatter::TimeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{date::TimeSelectBackend, Backend, RenderConfig},
+};
+
+use self::prompt::TimeSelectPrompt;
+
+/// Clock used to display and edit the hours of a [`TimeSelect`] prompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HourFormat {
+    /// Hours from 00 to 23.
+    TwentyFourHour,
+
+    /// Hours from 1 to 12, followed by an AM/PM segment.
+    TwelveHour,
+}
+
+/// Segments of the time edited in a [`TimeSelect`] prompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimeSegment {
+    /// Hours, in the configured [`HourFormat`].
+    Hour,
+
+    /// Minutes.
+    Minute,
+
+    /// Seconds, only displayed when enabled.
+    Second,
+
+    /// AM or PM, only displayed in the 12-hour clock.
+    Period,
+}
+
+/// Prompt for a time of the day, e.g. the time of a meeting or of a scheduled job. Available via the `date` feature.
+///
+/// The time is displayed in segments, hours and minutes and optionally seconds and AM/PM. The left and right arrows switch between the segments, while the up and down arrows spin the value of the current one. Digits can also be typed straight into the current segment, moving on to the next one once it is complete.
+///
+/// `TimeSelect` prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Starting time**: Time displayed when the prompt is first rendered. Current time by default.
+/// - **Hour format**: Whether to use the 24-hour clock, the default, or the 12-hour one with an AM/PM segment.
+/// - **Seconds**: Whether the seconds are displayed and can be edited, disabled by default. When disabled, the returned time has no seconds.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - By default, the answer is formatted as displayed while editing it, e.g. `14:30` or `02:30 PM`.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{HourFormat, TimeSelect};
+///
+/// let time = TimeSelect::new("When should the backup run?")
+///     .with_hour_format(HourFormat::TwelveHour)
+///     .prompt();
+///
+/// match time {
+///     Ok(time) => println!("Backups will run at {}", time),
+///     Err(_) => println!("There was an error in the system."),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TimeSelect<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Time displayed when the prompt is first rendered.
+    pub starting_time: NaiveTime,
+
+    /// Whether hours are displayed in the 24-hour or 12-hour clock.
+    pub hour_format: HourFormat,
+
+    /// Whether the seconds are displayed and can be edited.
+    pub with_seconds: bool,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    ///
+    /// When none is set, the answer is formatted as displayed while editing it.
+    pub formatter: Option<TimeFormatter<'a>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> TimeSelect<'a> {
+    /// Default hour format.
+    pub const DEFAULT_HOUR_FORMAT: HourFormat = HourFormat::TwentyFourHour;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("←→ to switch segment, ↑↓ or type to change it, enter to submit");
+
+    /// Creates a [TimeSelect] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_time: get_current_time(),
+            hour_format: Self::DEFAULT_HOUR_FORMAT,
+            with_seconds: false,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: None,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the default time of the prompt. Equivalent to [TimeSelect::with_starting_time](TimeSelect::with_starting_time).
+    pub fn with_default(self, default: NaiveTime) -> Self {
+        self.with_starting_time(default)
+    }
+
+    /// Sets the starting time. Equivalent to [TimeSelect::with_default](TimeSelect::with_default).
+    pub fn with_starting_time(mut self, starting_time: NaiveTime) -> Self {
+        self.starting_time = starting_time;
+        self
+    }
+
+    /// Sets whether hours are displayed in the 24-hour or 12-hour clock.
+    pub fn with_hour_format(mut self, hour_format: HourFormat) -> Self {
+        self.hour_format = hour_format;
+        self
+    }
+
+    /// Sets whether the seconds are displayed and can be edited.
+    pub fn with_seconds(mut self, with_seconds: bool) -> Self {
+        self.with_seconds = with_seconds;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: TimeFormatter<'a>) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<NaiveTime>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<NaiveTime> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: TimeSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<NaiveTime> {
+        TimeSelectPrompt::new(self).prompt(backend)
+    }
+}
@@ -0,0 +1,268 @@
+use chrono::{NaiveTime, Timelike};
+
+use crate::{
+    error::InquireResult,
+    formatter::TimeFormatter,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::date::TimeSelectBackend,
+    HourFormat, TimeSegment, TimeSelect,
+};
+
+use super::{action::TimeSelectPromptAction, config::TimeSelectConfig};
+
+pub struct TimeSelectPrompt<'a> {
+    message: &'a str,
+    config: TimeSelectConfig,
+    current_time: NaiveTime,
+    current_segment: TimeSegment,
+    typed_value: Option<u32>,
+    help_message: Option<&'a str>,
+    formatter: Option<TimeFormatter<'a>>,
+}
+
+impl<'a> TimeSelectPrompt<'a> {
+    pub fn new(tso: TimeSelect<'a>) -> Self {
+        let starting_time = tso.starting_time.with_nanosecond(0);
+        let starting_time = match tso.with_seconds {
+            true => starting_time,
+            false => starting_time.and_then(|time| time.with_second(0)),
+        };
+
+        Self {
+            message: tso.message,
+            config: (&tso).into(),
+            current_time: starting_time.unwrap_or(tso.starting_time),
+            current_segment: TimeSegment::Hour,
+            typed_value: None,
+            help_message: tso.help_message,
+            formatter: tso.formatter,
+        }
+    }
+
+    fn segments(&self) -> Vec<TimeSegment> {
+        let mut segments = vec![TimeSegment::Hour, TimeSegment::Minute];
+
+        if self.config.with_seconds {
+            segments.push(TimeSegment::Second);
+        }
+        if self.config.hour_format == HourFormat::TwelveHour {
+            segments.push(TimeSegment::Period);
+        }
+
+        segments
+    }
+
+    fn formatted_segments(&self, time: NaiveTime) -> Vec<(TimeSegment, String)> {
+        self.segments()
+            .into_iter()
+            .map(|segment| {
+                let value = match (segment, self.config.hour_format) {
+                    (TimeSegment::Hour, HourFormat::TwentyFourHour) => {
+                        format!("{:02}", time.hour())
+                    }
+                    (TimeSegment::Hour, HourFormat::TwelveHour) => {
+                        format!("{:02}", time.hour12().1)
+                    }
+                    (TimeSegment::Minute, _) => format!("{:02}", time.minute()),
+                    (TimeSegment::Second, _) => format!("{:02}", time.second()),
+                    (TimeSegment::Period, _) => match time.hour12().0 {
+                        true => String::from("PM"),
+                        false => String::from("AM"),
+                    },
+                };
+
+                (segment, value)
+            })
+            .collect()
+    }
+
+    /// Inclusive range of the values of a numeric segment.
+    fn value_range(&self, segment: TimeSegment) -> Option<(u32, u32)> {
+        match (segment, self.config.hour_format) {
+            (TimeSegment::Hour, HourFormat::TwentyFourHour) => Some((0, 23)),
+            (TimeSegment::Hour, HourFormat::TwelveHour) => Some((1, 12)),
+            (TimeSegment::Minute | TimeSegment::Second, _) => Some((0, 59)),
+            (TimeSegment::Period, _) => None,
+        }
+    }
+
+    fn value(&self, segment: TimeSegment) -> u32 {
+        match (segment, self.config.hour_format) {
+            (TimeSegment::Hour, HourFormat::TwelveHour) => self.current_time.hour12().1,
+            (TimeSegment::Hour, HourFormat::TwentyFourHour) => self.current_time.hour(),
+            (TimeSegment::Minute, _) => self.current_time.minute(),
+            (TimeSegment::Second, _) => self.current_time.second(),
+            (TimeSegment::Period, _) => self.current_time.hour12().0 as u32,
+        }
+    }
+
+    fn set_time(&mut self, time: Option<NaiveTime>) -> ActionResult {
+        match time {
+            Some(time) if time != self.current_time => {
+                self.current_time = time;
+                ActionResult::NeedsRedraw
+            }
+            _ => ActionResult::Clean,
+        }
+    }
+
+    fn set_value(&mut self, segment: TimeSegment, value: u32) -> ActionResult {
+        let time = self.current_time;
+
+        let time = match (segment, self.config.hour_format) {
+            (TimeSegment::Hour, HourFormat::TwelveHour) => {
+                let pm = time.hour12().0;
+                time.with_hour(value % 12 + if pm { 12 } else { 0 })
+            }
+            (TimeSegment::Hour, HourFormat::TwentyFourHour) => time.with_hour(value),
+            (TimeSegment::Minute, _) => time.with_minute(value),
+            (TimeSegment::Second, _) => time.with_second(value),
+            (TimeSegment::Period, _) => self.set_period(value == 1),
+        };
+
+        self.set_time(time)
+    }
+
+    fn set_period(&self, pm: bool) -> Option<NaiveTime> {
+        let hour = self.current_time.hour() % 12 + if pm { 12 } else { 0 };
+        self.current_time.with_hour(hour)
+    }
+
+    fn spin(&mut self, forward: bool) -> ActionResult {
+        self.typed_value = None;
+
+        let Some((min, max)) = self.value_range(self.current_segment) else {
+            let time = self.set_period(!self.current_time.hour12().0);
+            return self.set_time(time);
+        };
+
+        let value = self.value(self.current_segment);
+        let value = match forward {
+            true if value >= max => min,
+            true => value + 1,
+            false if value <= min => max,
+            false => value - 1,
+        };
+
+        self.set_value(self.current_segment, value)
+    }
+
+    fn type_digit(&mut self, digit: u32) -> ActionResult {
+        let Some((min, max)) = self.value_range(self.current_segment) else {
+            return ActionResult::Clean;
+        };
+
+        let (value, complete) = match self.typed_value {
+            Some(typed) if typed * 10 + digit <= max => (typed * 10 + digit, true),
+            _ => (digit, digit * 10 > max),
+        };
+
+        if value >= min {
+            self.set_value(self.current_segment, value);
+        }
+
+        if complete {
+            self.move_segment(true);
+        } else {
+            self.typed_value = Some(value);
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn move_segment(&mut self, forward: bool) -> ActionResult {
+        self.typed_value = None;
+
+        let segments = self.segments();
+        let index = segments
+            .iter()
+            .position(|segment| *segment == self.current_segment)
+            .unwrap_or(0);
+
+        let index = match forward {
+            true => (index + 1).min(segments.len() - 1),
+            false => index.saturating_sub(1),
+        };
+
+        if segments[index] == self.current_segment {
+            return ActionResult::Clean;
+        }
+
+        self.current_segment = segments[index];
+
+        ActionResult::NeedsRedraw
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for TimeSelectPrompt<'a>
+where
+    Backend: TimeSelectBackend,
+{
+    type Config = TimeSelectConfig;
+    type InnerAction = TimeSelectPromptAction;
+    type Output = NaiveTime;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &TimeSelectConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &NaiveTime) -> String {
+        if let Some(formatter) = self.formatter {
+            return formatter(*answer);
+        }
+
+        let mut formatted = String::new();
+        for (segment, value) in self.formatted_segments(*answer) {
+            match segment {
+                TimeSegment::Hour => {}
+                TimeSegment::Minute | TimeSegment::Second => formatted.push(':'),
+                TimeSegment::Period => formatted.push(' '),
+            }
+            formatted.push_str(&value);
+        }
+
+        formatted
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<NaiveTime>> {
+        Ok(Some(self.current_time))
+    }
+
+    fn handle(&mut self, action: TimeSelectPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            TimeSelectPromptAction::PreviousSegment => self.move_segment(false),
+            TimeSelectPromptAction::NextSegment => self.move_segment(true),
+            TimeSelectPromptAction::Increment => self.spin(true),
+            TimeSelectPromptAction::Decrement => self.spin(false),
+            TimeSelectPromptAction::TypeDigit(digit) => self.type_digit(digit),
+            TimeSelectPromptAction::SetAm => {
+                let time = self.set_period(false);
+                self.set_time(time)
+            }
+            TimeSelectPromptAction::SetPm => {
+                let time = self.set_period(true);
+                self.set_time(time)
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        backend.render_time_prompt(
+            self.message,
+            &self.formatted_segments(self.current_time),
+            self.current_segment,
+        )?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use chrono::NaiveTime;
+
+use crate::{
+    terminal::crossterm::CrosstermTerminal,
+    test::fake_backend,
+    ui::{Backend, Key, KeyModifiers, RenderConfig},
+    HourFormat, TimeSelect,
+};
+
+fn time(hour: u32, minute: u32, second: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, second).unwrap()
+}
+
+fn char(c: char) -> Key {
+    Key::Char(c, KeyModifiers::NONE)
+}
+
+const UP: Key = Key::Up(KeyModifiers::NONE);
+const DOWN: Key = Key::Down(KeyModifiers::NONE);
+const LEFT: Key = Key::Left(KeyModifiers::NONE);
+const RIGHT: Key = Key::Right(KeyModifiers::NONE);
+
+#[test]
+fn seconds_are_dropped_when_disabled() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = TimeSelect::new("Question")
+        .with_starting_time(time(9, 15, 42))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(time(9, 15, 0), ans);
+}
+
+#[test]
+fn arrows_switch_segment_and_spin_it_around() {
+    let mut backend = fake_backend(vec![DOWN, RIGHT, UP, UP, RIGHT, UP, LEFT, DOWN, Key::Enter]);
+
+    let ans = TimeSelect::new("Question")
+        .with_starting_time(time(0, 58, 0))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(time(22, 1, 0), ans);
+}
+
+#[test]
+fn typed_digits_fill_segments_in_turn() {
+    let mut backend = fake_backend(vec![
+        char('1'),
+        char('7'),
+        char('4'),
+        char('5'),
+        char('0'),
+        char('9'),
+        Key::Enter,
+    ]);
+
+    let ans = TimeSelect::new("Question")
+        .with_seconds(true)
+        .with_starting_time(time(8, 0, 0))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(time(17, 45, 9), ans);
+}
+
+#[test]
+fn digits_that_can_not_start_a_two_digit_value_complete_the_segment() {
+    let mut backend = fake_backend(vec![char('7'), char('8'), Key::Enter]);
+
+    let ans = TimeSelect::new("Question")
+        .with_starting_time(time(12, 30, 0))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(time(7, 8, 0), ans);
+}
+
+#[test]
+fn twelve_hour_clock_edits_hours_and_period() {
+    let mut backend = fake_backend(vec![char('1'), char('2'), RIGHT, char('p'), Key::Enter]);
+
+    let ans = TimeSelect::new("Question")
+        .with_hour_format(HourFormat::TwelveHour)
+        .with_starting_time(time(9, 30, 0))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(time(12, 30, 0), ans);
+}
+
+#[test]
+fn answer_is_formatted_as_displayed() {
+    let mut backend = Backend::new(
+        vec![RIGHT, RIGHT, UP, Key::Enter].into_iter(),
+        CrosstermTerminal::new_in_memory_output(),
+        RenderConfig::empty(),
+    )
+    .unwrap();
+
+    let ans = TimeSelect::new("Start:")
+        .with_hour_format(HourFormat::TwelveHour)
+        .with_starting_time(time(2, 5, 0))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(time(14, 5, 0), ans);
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("? Start: 02:05 AM"));
+    assert!(output.contains("? Start: 02:05 PM\x1b[K\r\n"));
+}
@@ -0,0 +1,109 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::TransferListConfig;
+
+/// One of the two panes rendered by a [TransferList](crate::TransferList) prompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TransferListPane {
+    /// Left pane, listing the options that were not chosen.
+    Available,
+    /// Right pane, listing the chosen options in the order they will be returned.
+    Chosen,
+}
+
+impl TransferListPane {
+    /// Returns the pane on the opposite side.
+    pub fn other(self) -> Self {
+        match self {
+            Self::Available => Self::Chosen,
+            Self::Chosen => Self::Available,
+        }
+    }
+}
+
+/// Set of actions for a TransferListPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TransferListPromptAction {
+    /// Moves the cursor to the option above in the focused pane.
+    MoveUp,
+    /// Moves the cursor to the option below in the focused pane.
+    MoveDown,
+    /// Moves the cursor to the page above in the focused pane.
+    PageUp,
+    /// Moves the cursor to the page below in the focused pane.
+    PageDown,
+    /// Moves the cursor to the start of the focused pane.
+    MoveToStart,
+    /// Moves the cursor to the end of the focused pane.
+    MoveToEnd,
+    /// Moves the focus to the other pane.
+    SwitchPane,
+    /// Moves the focus to the given pane.
+    FocusPane(TransferListPane),
+    /// Moves the highlighted option to the other pane.
+    TransferCurrentOption,
+    /// Moves every available option to the chosen pane.
+    TransferAll,
+    /// Moves every chosen option back to the available pane.
+    ClearChosen,
+    /// Swaps the highlighted chosen option with the one above it.
+    MoveChosenUp,
+    /// Swaps the highlighted chosen option with the one below it.
+    MoveChosenDown,
+}
+
+impl InnerAction for TransferListPromptAction {
+    type Config = TransferListConfig;
+
+    fn from_key(key: Key, config: &TransferListConfig) -> Option<Self> {
+        if config.vim_mode {
+            let action = match key {
+                Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
+                Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
+                Key::Char('h', KeyModifiers::NONE) => {
+                    Some(Self::FocusPane(TransferListPane::Available))
+                }
+                Key::Char('l', KeyModifiers::NONE) => {
+                    Some(Self::FocusPane(TransferListPane::Chosen))
+                }
+                Key::Char('K', KeyModifiers::SHIFT | KeyModifiers::NONE) => {
+                    Some(Self::MoveChosenUp)
+                }
+                Key::Char('J', KeyModifiers::SHIFT | KeyModifiers::NONE) => {
+                    Some(Self::MoveChosenDown)
+                }
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
+        let action = match key {
+            Key::Up(KeyModifiers::SHIFT) => Self::MoveChosenUp,
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::PageUp(_) => Self::PageUp,
+            Key::Home => Self::MoveToStart,
+
+            Key::Down(KeyModifiers::SHIFT) => Self::MoveChosenDown,
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::PageDown(_) => Self::PageDown,
+            Key::End => Self::MoveToEnd,
+
+            Key::Tab => Self::SwitchPane,
+            Key::Left(KeyModifiers::NONE) => Self::FocusPane(TransferListPane::Available),
+            Key::Right(KeyModifiers::NONE) => Self::FocusPane(TransferListPane::Chosen),
+
+            Key::Char(' ', KeyModifiers::NONE) => Self::TransferCurrentOption,
+            Key::Right(KeyModifiers::SHIFT) => Self::TransferAll,
+            Key::Left(KeyModifiers::SHIFT) => Self::ClearChosen,
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
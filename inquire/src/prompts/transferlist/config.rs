@@ -0,0 +1,19 @@
+use crate::TransferList;
+
+/// Configuration settings used in the execution of a TransferListPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct TransferListConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// Page size of each pane.
+    pub page_size: usize,
+}
+
+impl<T> From<&TransferList<'_, T>> for TransferListConfig {
+    fn from(value: &TransferList<'_, T>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            page_size: value.page_size,
+        }
+    }
+}
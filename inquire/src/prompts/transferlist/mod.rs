@@ -0,0 +1,285 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use std::fmt::Display;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::MultiOptionFormatter,
+    list_option::ListOption,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, RenderConfig, TransferListBackend},
+    validator::MultiOptionValidator,
+};
+
+use self::prompt::TransferListPrompt;
+
+/// Prompt suitable for when you need the user to pick an ordered subset of a list of options.
+///
+/// Options are split in two panes rendered side by side: the left one lists the options still available and the right one lists the options chosen so far. The user moves the highlighted option to the other pane by pressing space, switches the focused pane with tab (or the left and right arrows) and reorders the chosen options with shift+up and shift+down. Options are appended to the chosen pane in the order they are moved, and that is also the order of the returned answer.
+///
+/// Unlike space, enter does not move options across: it submits the answer as in every other prompt, being one of the shared submit keys of the [`KeyBindings`](crate::ui::KeyBindings), which take precedence over the keys of the prompt.
+///
+/// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait. It is required that the `Vec` is moved to the prompt, as the prompt will return the ownership of the `Vec` after the user submits, with only the chosen options inside it.
+/// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Options list**: Options displayed to the user. Must be **non-empty**.
+/// - **Default chosen options**: Options that start in the chosen pane, in the given order. If any of the indices is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the chosen options string value, joined using a comma as the separator, by default.
+/// - **Validator**: Custom validator to make sure a given submitted input pass the specified requirements, e.g. not allowing 0 chosen options.
+///   - No validators are on by default.
+/// - **Page size**: Number of options displayed at once in each pane, 7 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::TransferList;
+///
+/// let columns = vec!["Name", "Email", "Created at", "Last login", "Role"];
+///
+/// let ans = TransferList::new("Which columns should be exported, and in which order?", columns)
+///     .with_default([0, 1])
+///     .prompt();
+///
+/// match ans {
+///     Ok(columns) => println!("Exporting {}", columns.join(", ")),
+///     Err(_) => println!("The export was aborted"),
+/// }
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct TransferList<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Options displayed to the user.
+    pub options: Vec<T>,
+
+    /// Indexes of options that start in the chosen pane, in order.
+    pub default: Option<Vec<usize>>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of each pane displayed to the user.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the options using hjkl.
+    pub vim_mode: bool,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: MultiOptionFormatter<'a, T>,
+
+    /// Validator to apply to the user input.
+    ///
+    /// In case of error, the message is displayed one line above the prompt.
+    pub validator: Option<Box<dyn MultiOptionValidator<T>>>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> TransferList<'a, T>
+where
+    T: Display,
+{
+    /// String formatter used by default in [TransferList](crate::TransferList) prompts.
+    /// Prints the string value of all chosen options, in order, separated by commas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::ListOption;
+    /// use inquire::TransferList;
+    ///
+    /// let formatter = TransferList::<&str>::DEFAULT_FORMATTER;
+    ///
+    /// let ans = vec![ListOption::new(3, &"Seattle"), ListOption::new(0, &"New York")];
+    /// assert_eq!(String::from("Seattle, New York"), formatter(&ans));
+    /// ```
+    pub const DEFAULT_FORMATTER: MultiOptionFormatter<'a, T> = &|ans| {
+        ans.iter()
+            .map(|opt| opt.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+
+    /// Default page size, equal to the global default page size [config::DEFAULT_PAGE_SIZE]
+    pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of vim mode, equal to the global default value [config::DEFAULT_VIM_MODE]
+    pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, tab to switch panes, space to transfer, shift+↑↓ to reorder");
+
+    /// Creates a [TransferList] with the provided message and options, along with default configuration values.
+    pub fn new(message: &'a str, options: Vec<T>) -> Self {
+        Self {
+            message,
+            options,
+            default: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            formatter: Self::DEFAULT_FORMATTER,
+            validator: None,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: MultiOptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the validator to apply to the user input. You might want to use this feature
+    /// in case you need to limit the user to specific choices, such as limiting the number
+    /// of chosen options.
+    ///
+    /// In case of error, the message is displayed one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: MultiOptionValidator<T> + 'static,
+    {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Sets the indexes of the options that start in the chosen pane.
+    ///
+    /// The chosen pane lists them in the order given here. Duplicates are ignored.
+    pub fn with_default(mut self, default: impl IntoIterator<Item = usize>) -> Self {
+        self.default = Some(default.into_iter().collect());
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned objects chosen by the user, in the order of the chosen pane.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Vec<T>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned objects chosen by the user, in the order of the chosen pane.
+    pub fn prompt(self) -> InquireResult<Vec<T>> {
+        self.raw_prompt()
+            .map(|op| op.into_iter().map(|o| o.value).collect())
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a vector of [`ListOption`](crate::list_option::ListOption)s containing
+    /// the original index and the owned object of each option chosen by the user,
+    /// in the order of the chosen pane.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn raw_prompt_skippable(self) -> InquireResult<Option<Vec<ListOption<T>>>> {
+        match self.raw_prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a vector of [`ListOption`](crate::list_option::ListOption)s containing
+    /// the original index and the owned object of each option chosen by the user,
+    /// in the order of the chosen pane.
+    pub fn raw_prompt(self) -> InquireResult<Vec<ListOption<T>>> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: TransferListBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<ListOption<T>>> {
+        TransferListPrompt::new(self)?.prompt(backend)
+    }
+}
@@ -0,0 +1,379 @@
+use std::fmt::Display;
+
+use crate::{
+    error::InquireResult,
+    formatter::MultiOptionFormatter,
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::{width::str_width, TransferListBackend},
+    utils::paginate,
+    validator::{ErrorMessage, MultiOptionContext, MultiOptionValidator, Validation},
+    InquireError, TransferList,
+};
+
+use super::{
+    action::{TransferListPane, TransferListPromptAction},
+    config::TransferListConfig,
+};
+
+pub struct TransferListPrompt<'a, T> {
+    message: &'a str,
+    config: TransferListConfig,
+    options: Vec<T>,
+    help_message: Option<&'a str>,
+    available: Vec<usize>,
+    chosen: Vec<usize>,
+    focus: TransferListPane,
+    available_cursor: usize,
+    chosen_cursor: usize,
+    column_width: usize,
+    formatter: MultiOptionFormatter<'a, T>,
+    validator: Option<Box<dyn MultiOptionValidator<T>>>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a, T> TransferListPrompt<'a, T>
+where
+    T: Display,
+{
+    pub fn new(tl: TransferList<'a, T>) -> InquireResult<Self> {
+        if tl.options.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        let mut chosen: Vec<usize> = vec![];
+        if let Some(default) = &tl.default {
+            for i in default {
+                if i >= &tl.options.len() {
+                    return Err(InquireError::InvalidConfiguration(format!(
+                        "Index {} is out-of-bounds for length {} of options",
+                        i,
+                        &tl.options.len()
+                    )));
+                }
+                if !chosen.contains(i) {
+                    chosen.push(*i);
+                }
+            }
+        }
+
+        let column_width = tl
+            .options
+            .iter()
+            .map(|o| str_width(&o.to_string(), tl.render_config.ambiguous_width))
+            .max()
+            .unwrap_or_default();
+
+        let available = (0..tl.options.len())
+            .filter(|i| !chosen.contains(i))
+            .collect();
+
+        Ok(Self {
+            message: tl.message,
+            config: (&tl).into(),
+            options: tl.options,
+            help_message: tl.help_message,
+            available,
+            chosen,
+            focus: TransferListPane::Available,
+            available_cursor: 0,
+            chosen_cursor: 0,
+            column_width,
+            formatter: tl.formatter,
+            validator: tl.validator,
+            error: None,
+        })
+    }
+
+    fn focused_pane_len(&self) -> usize {
+        match self.focus {
+            TransferListPane::Available => self.available.len(),
+            TransferListPane::Chosen => self.chosen.len(),
+        }
+    }
+
+    fn focused_cursor(&self) -> usize {
+        match self.focus {
+            TransferListPane::Available => self.available_cursor,
+            TransferListPane::Chosen => self.chosen_cursor,
+        }
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let cursor = self.focused_cursor();
+        let new_position = if wrap {
+            let after_wrap = qty.saturating_sub(cursor);
+            cursor
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.focused_pane_len().saturating_sub(after_wrap))
+        } else {
+            cursor.saturating_sub(qty)
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let len = self.focused_pane_len();
+        let mut new_position = self.focused_cursor().saturating_add(qty);
+
+        if new_position >= len {
+            new_position = if len == 0 {
+                0
+            } else if wrap {
+                new_position % len
+            } else {
+                len.saturating_sub(1)
+            }
+        }
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        let cursor = match self.focus {
+            TransferListPane::Available => &mut self.available_cursor,
+            TransferListPane::Chosen => &mut self.chosen_cursor,
+        };
+
+        if new_position != *cursor {
+            *cursor = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn clamp_cursors(&mut self) {
+        self.available_cursor = self
+            .available_cursor
+            .min(self.available.len().saturating_sub(1));
+        self.chosen_cursor = self.chosen_cursor.min(self.chosen.len().saturating_sub(1));
+    }
+
+    fn focus_pane(&mut self, pane: TransferListPane) -> ActionResult {
+        if self.focus == pane {
+            return ActionResult::Clean;
+        }
+
+        self.focus = pane;
+        ActionResult::NeedsRedraw
+    }
+
+    fn transfer_current_option(&mut self) -> ActionResult {
+        match self.focus {
+            TransferListPane::Available => {
+                if self.available_cursor >= self.available.len() {
+                    return ActionResult::Clean;
+                }
+
+                let idx = self.available.remove(self.available_cursor);
+                self.chosen.push(idx);
+            }
+            TransferListPane::Chosen => {
+                if self.chosen_cursor >= self.chosen.len() {
+                    return ActionResult::Clean;
+                }
+
+                let idx = self.chosen.remove(self.chosen_cursor);
+                self.return_to_available(idx);
+            }
+        }
+
+        self.clamp_cursors();
+        ActionResult::NeedsRedraw
+    }
+
+    fn transfer_all(&mut self) -> ActionResult {
+        if self.available.is_empty() {
+            return ActionResult::Clean;
+        }
+
+        self.chosen.append(&mut self.available);
+        self.clamp_cursors();
+        ActionResult::NeedsRedraw
+    }
+
+    fn clear_chosen(&mut self) -> ActionResult {
+        if self.chosen.is_empty() {
+            return ActionResult::Clean;
+        }
+
+        for idx in std::mem::take(&mut self.chosen) {
+            self.return_to_available(idx);
+        }
+        self.clamp_cursors();
+        ActionResult::NeedsRedraw
+    }
+
+    /// Available options are always kept in their original order.
+    fn return_to_available(&mut self, idx: usize) {
+        let position = self.available.partition_point(|i| *i < idx);
+        self.available.insert(position, idx);
+    }
+
+    fn move_chosen_option(&mut self, up: bool) -> ActionResult {
+        if self.focus != TransferListPane::Chosen {
+            return ActionResult::Clean;
+        }
+
+        let cursor = self.chosen_cursor;
+        let target = match up {
+            true => cursor.checked_sub(1),
+            false => Some(cursor.saturating_add(1)).filter(|t| *t < self.chosen.len()),
+        };
+
+        match target {
+            Some(target) if cursor < self.chosen.len() => {
+                self.chosen.swap(cursor, target);
+                self.chosen_cursor = target;
+                ActionResult::NeedsRedraw
+            }
+            _ => ActionResult::Clean,
+        }
+    }
+
+    fn validate_current_answer(&self) -> InquireResult<Validation> {
+        if let Some(validator) = &self.validator {
+            let selected_options = self
+                .chosen
+                .iter()
+                .map(|idx| ListOption::new(*idx, &self.options[*idx]))
+                .collect::<Vec<_>>();
+
+            let all_options = self
+                .options
+                .iter()
+                .enumerate()
+                .map(|(idx, opt)| ListOption::new(idx, opt))
+                .collect::<Vec<_>>();
+            let context = MultiOptionContext::new(self.message, &all_options);
+
+            let res = validator.validate_with_context(&selected_options, &context)?;
+            Ok(res)
+        } else {
+            Ok(Validation::Valid)
+        }
+    }
+
+    fn get_final_answer(&mut self) -> Vec<ListOption<T>> {
+        let mut options = self.options.drain(..).map(Some).collect::<Vec<_>>();
+
+        self.chosen
+            .iter()
+            .filter_map(|idx| {
+                options[*idx]
+                    .take()
+                    .map(|value| ListOption::new(*idx, value))
+            })
+            .collect()
+    }
+
+    fn page_choices(&self, indexes: &[usize]) -> Vec<ListOption<&T>> {
+        indexes
+            .iter()
+            .map(|i| ListOption::new(*i, &self.options[*i]))
+            .collect()
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for TransferListPrompt<'a, T>
+where
+    Backend: TransferListBackend,
+    T: Display,
+{
+    type Config = TransferListConfig;
+    type InnerAction = TransferListPromptAction;
+    type Output = Vec<ListOption<T>>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &TransferListConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &Vec<ListOption<T>>) -> String {
+        let refs: Vec<ListOption<&T>> = answer.iter().map(ListOption::as_ref).collect();
+        (self.formatter)(&refs)
+    }
+
+    fn can_submit(&self) -> InquireResult<bool> {
+        Ok(self.validate_current_answer()? == Validation::Valid)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Vec<ListOption<T>>>> {
+        let answer = match self.validate_current_answer()? {
+            Validation::Valid => Some(self.get_final_answer()),
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: TransferListPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            TransferListPromptAction::MoveUp => self.move_cursor_up(1, true),
+            TransferListPromptAction::MoveDown => self.move_cursor_down(1, true),
+            TransferListPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
+            TransferListPromptAction::PageDown => {
+                self.move_cursor_down(self.config.page_size, false)
+            }
+            TransferListPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
+            TransferListPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            TransferListPromptAction::SwitchPane => self.focus_pane(self.focus.other()),
+            TransferListPromptAction::FocusPane(pane) => self.focus_pane(pane),
+            TransferListPromptAction::TransferCurrentOption => self.transfer_current_option(),
+            TransferListPromptAction::TransferAll => self.transfer_all(),
+            TransferListPromptAction::ClearChosen => self.clear_chosen(),
+            TransferListPromptAction::MoveChosenUp => self.move_chosen_option(true),
+            TransferListPromptAction::MoveChosenDown => self.move_chosen_option(false),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        backend.render_transfer_list_prompt(prompt)?;
+
+        let available = self.page_choices(&self.available);
+        let chosen = self.page_choices(&self.chosen);
+
+        let mut available_page = paginate(
+            self.config.page_size,
+            &available,
+            Some(self.available_cursor),
+        );
+        let mut chosen_page = paginate(self.config.page_size, &chosen, Some(self.chosen_cursor));
+
+        match self.focus {
+            TransferListPane::Available => chosen_page.cursor = None,
+            TransferListPane::Chosen => available_page.cursor = None,
+        }
+
+        backend.render_transfer_panes(
+            available_page,
+            chosen_page,
+            self.focus,
+            self.column_width,
+        )?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
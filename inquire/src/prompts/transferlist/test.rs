@@ -0,0 +1,76 @@
+use crate::{
+    list_option::ListOption,
+    test::fake_backend,
+    ui::{Key, KeyModifiers},
+    TransferList,
+};
+
+#[test]
+fn chosen_order_is_the_returned_order() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE), // choose 3
+        Key::Up(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE), // choose 1
+        Key::Enter,
+    ]);
+
+    let options = vec![1, 2, 3, 4];
+
+    let ans = TransferList::new("Question", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(2, 3), ListOption::new(0, 1)], ans);
+}
+
+#[test]
+fn tab_switches_panes_and_space_moves_option_back() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE), // choose 1
+        Key::Char(' ', KeyModifiers::NONE), // choose 2
+        Key::Tab,
+        Key::Char(' ', KeyModifiers::NONE), // return 1
+        Key::Enter,
+    ]);
+
+    let options = vec![1, 2, 3];
+
+    let ans = TransferList::new("Question", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, 2)], ans);
+}
+
+#[test]
+fn shift_arrows_reorder_chosen_options() {
+    let mut backend = fake_backend(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::SHIFT), // move 3 above 1
+        Key::Enter,
+    ]);
+
+    let options = vec![1, 2, 3];
+
+    let ans = TransferList::new("Question", options)
+        .with_default([0, 2])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(2, 3), ListOption::new(0, 1)], ans);
+}
+
+#[test]
+fn out_of_bounds_default_is_rejected() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = TransferList::new("Question", vec![1, 2, 3])
+        .with_default([3])
+        .prompt_with_backend(&mut backend);
+
+    assert!(ans.is_err());
+}
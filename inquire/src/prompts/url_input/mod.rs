@@ -0,0 +1,248 @@
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    prompts::prompt::Prompt,
+    terminal::get_default_terminal,
+    ui::{Backend, CustomTypeBackend, RenderConfig},
+    validator::CustomTypeValidator,
+};
+
+use self::prompt::UrlInputPrompt;
+
+/// Text prompt for URLs, parsed into any type implementing [`FromStr`], e.g. `url::Url` from the `url` crate, or kept as a [`String`].
+///
+/// Before being parsed, the input is normalized: surrounding whitespace is trimmed, the scheme and host are lowercased, and the default scheme is inserted when the input has none, so that `Example.com/docs` is submitted as `https://example.com/docs`. The scheme and host are then checked against the allowed ones, if any.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Starting input**: Initial value of the prompt's text input.
+/// - **Default value**: Default value returned when the user submits an empty response.
+/// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Default scheme**: Scheme inserted when the input has none, `https` by default.
+/// - **Allowed schemes**: Schemes the URL may use, any by default.
+/// - **Allowed hosts**: Hosts the URL may point to, along with their subdomains, any by default.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+/// - **Validators**: Custom validators to make sure a given submitted input pass the specified requirements.
+/// - **Error message**: Message displayed when the normalized input can not be parsed, "Invalid URL" by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::UrlInput;
+///
+/// let webhook: String = UrlInput::new("Webhook URL:")
+///     .with_allowed_schemes(&["https"])
+///     .with_allowed_hosts(&["hooks.slack.com"])
+///     .prompt()
+///     .unwrap();
+/// ```
+pub struct UrlInput<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Initial value of the prompt's text input.
+    pub starting_input: Option<&'a str>,
+
+    /// Default value, returned when the user input is empty.
+    pub default: Option<T>,
+
+    /// Short hint that describes the expected value of the input.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Scheme inserted when the input has none.
+    pub default_scheme: Option<&'a str>,
+
+    /// Schemes the URL may use. Any scheme is allowed when empty.
+    pub allowed_schemes: Vec<&'a str>,
+
+    /// Hosts the URL may point to, along with their subdomains. Any host is allowed when empty.
+    pub allowed_hosts: Vec<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: CustomTypeFormatter<'a, T>,
+
+    /// Collection of validators to apply to the user input.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+
+    /// Error message displayed when the normalized input can not be parsed.
+    pub error_message: String,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> UrlInput<'a, T>
+where
+    T: Clone + FromStr + Display,
+{
+    /// Default validators added to the [UrlInput] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn CustomTypeValidator<T>>> = vec![];
+
+    /// Default scheme inserted when the input has none.
+    pub const DEFAULT_SCHEME: Option<&'a str> = Some("https");
+
+    /// Creates an [UrlInput] with the provided message and default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_input: None,
+            default: None,
+            placeholder: None,
+            help_message: None,
+            default_scheme: Self::DEFAULT_SCHEME,
+            allowed_schemes: vec![],
+            allowed_hosts: vec![],
+            formatter: &|val| val.to_string(),
+            validators: Self::DEFAULT_VALIDATORS,
+            error_message: "Invalid URL".into(),
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the initial value of the prompt's text input.
+    pub fn with_starting_input(mut self, starting_input: &'a str) -> Self {
+        self.starting_input = Some(starting_input);
+        self
+    }
+
+    /// Sets the default value, returned when the user input is empty.
+    pub fn with_default(mut self, default: T) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets the scheme inserted when the input has none.
+    pub fn with_default_scheme(mut self, default_scheme: &'a str) -> Self {
+        self.default_scheme = Some(default_scheme);
+        self
+    }
+
+    /// Requires the input to have a scheme, instead of inserting the default one.
+    pub fn without_default_scheme(mut self) -> Self {
+        self.default_scheme = None;
+        self
+    }
+
+    /// Sets the schemes the URL may use, compared case-insensitively.
+    pub fn with_allowed_schemes(mut self, allowed_schemes: &[&'a str]) -> Self {
+        self.allowed_schemes = allowed_schemes.to_vec();
+        self
+    }
+
+    /// Sets the hosts the URL may point to, compared case-insensitively.
+    /// Their subdomains are allowed as well, e.g. allowing `example.com`
+    /// also allows `api.example.com`.
+    pub fn with_allowed_hosts(mut self, allowed_hosts: &[&'a str]) -> Self {
+        self.allowed_hosts = allowed_hosts.to_vec();
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the parsed user's answer.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: CustomTypeValidator<T> + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Sets the error message displayed when the normalized input can not be parsed.
+    pub fn with_error_message(mut self, error_message: &'a str) -> Self {
+        self.error_message = String::from(error_message);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<T> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<T> {
+        UrlInputPrompt::from(self).prompt(backend)
+    }
+}
@@ -0,0 +1,249 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    error::InquireResult,
+    formatter::CustomTypeFormatter,
+    input::Input,
+    prompts::{
+        custom_type::config::CustomTypeConfig,
+        prompt::{ActionResult, Prompt},
+    },
+    ui::CustomTypeBackend,
+    validator::{CustomTypeValidator, ErrorMessage, Validation},
+    CustomTypePromptAction, InquireError, UrlInput,
+};
+
+pub struct UrlInputPrompt<'a, T> {
+    message: &'a str,
+    config: CustomTypeConfig,
+    error: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    default: Option<T>,
+    input: Input,
+    default_scheme: Option<&'a str>,
+    allowed_schemes: Vec<&'a str>,
+    allowed_hosts: Vec<&'a str>,
+    formatter: CustomTypeFormatter<'a, T>,
+    validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+    error_message: String,
+}
+
+impl<'a, T> From<UrlInput<'a, T>> for UrlInputPrompt<'a, T> {
+    fn from(uo: UrlInput<'a, T>) -> Self {
+        let input = Input::new_with(uo.starting_input.unwrap_or_default());
+        let input = if let Some(placeholder) = uo.placeholder {
+            input.with_placeholder(placeholder)
+        } else {
+            input
+        };
+
+        Self {
+            message: uo.message,
+            config: CustomTypeConfig {},
+            error: None,
+            help_message: uo.help_message,
+            default: uo.default,
+            input,
+            default_scheme: uo.default_scheme,
+            allowed_schemes: uo.allowed_schemes,
+            allowed_hosts: uo.allowed_hosts,
+            formatter: uo.formatter,
+            validators: uo.validators,
+            error_message: uo.error_message,
+        }
+    }
+}
+
+impl<'a, T> UrlInputPrompt<'a, T>
+where
+    T: Clone + FromStr,
+{
+    fn validate_current_answer(&self, value: &T) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator.validate(value) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    /// Normalizes the input, checks its scheme and host and parses it.
+    fn get_final_answer(&self) -> Result<T, String> {
+        match &self.default {
+            Some(val) if self.input.content().trim().is_empty() => return Ok(val.clone()),
+            _ => {}
+        }
+
+        let url = normalize(self.input.content(), self.default_scheme)
+            .ok_or_else(|| self.error_message.clone())?;
+
+        if !self.allowed_schemes.is_empty()
+            && !self
+                .allowed_schemes
+                .iter()
+                .any(|scheme| scheme.eq_ignore_ascii_case(&url.scheme))
+        {
+            return Err(format!(
+                "The {} scheme is not allowed, use {}",
+                url.scheme,
+                self.allowed_schemes.join(" or ")
+            ));
+        }
+
+        if !self.allowed_hosts.is_empty()
+            && !self.allowed_hosts.iter().any(|host| {
+                let host = host.to_ascii_lowercase();
+                url.host == host || url.host.ends_with(&format!(".{}", host))
+            })
+        {
+            return Err(format!("The host {} is not allowed", url.host));
+        }
+
+        match url.normalized.parse::<T>() {
+            Ok(val) => Ok(val),
+            Err(_) => Err(self.error_message.clone()),
+        }
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for UrlInputPrompt<'a, T>
+where
+    Backend: CustomTypeBackend,
+    T: Clone + FromStr + Display,
+{
+    type Config = CustomTypeConfig;
+    type InnerAction = CustomTypePromptAction;
+    type Output = T;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &CustomTypeConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &T) -> String {
+        (self.formatter)(answer.clone())
+    }
+
+    fn fallback_answer(&self) -> Option<T> {
+        self.default.clone()
+    }
+
+    fn can_submit(&self) -> InquireResult<bool> {
+        match self.get_final_answer() {
+            Ok(answer) => Ok(self.validate_current_answer(&answer)? == Validation::Valid),
+            Err(_) => Ok(false),
+        }
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<T>> {
+        let answer = match self.get_final_answer() {
+            Ok(answer) => match self.validate_current_answer(&answer)? {
+                Validation::Valid => Some(answer),
+                Validation::Invalid(msg) => {
+                    self.error = Some(msg);
+                    None
+                }
+            },
+            Err(message) => {
+                self.error = Some(message.into());
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: CustomTypePromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            CustomTypePromptAction::ValueInput(input_action) => {
+                self.input.handle(input_action).into()
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        let default_message = self.default.as_ref().map(|val| val.to_string());
+
+        backend.render_prompt(self.message, None, default_message.as_deref(), &self.input)?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// URL typed by the user, with its scheme and host lowercased.
+struct NormalizedUrl {
+    scheme: String,
+    host: String,
+    normalized: String,
+}
+
+/// Trims the input, inserts the default scheme when it has none and
+/// lowercases its scheme and host. Returns `None` when the input has no
+/// valid scheme or no host.
+fn normalize(input: &str, default_scheme: Option<&str>) -> Option<NormalizedUrl> {
+    let input = input.trim();
+
+    let (scheme, rest) = match (input.split_once("://"), default_scheme) {
+        (Some((scheme, rest)), _) => (scheme, rest),
+        (None, Some(default_scheme)) => (default_scheme, input),
+        (None, None) => return None,
+    };
+
+    let mut scheme_chars = scheme.chars();
+    let valid_scheme = scheme_chars.next()?.is_ascii_alphabetic()
+        && scheme_chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    if !valid_scheme {
+        return None;
+    }
+
+    let authority_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let (authority, path) = rest.split_at(authority_end);
+    let (user_info, host_and_port) = match authority.rsplit_once('@') {
+        Some((user_info, host_and_port)) => (Some(user_info), host_and_port),
+        None => (None, authority),
+    };
+
+    // IPv6 hosts are enclosed in brackets, as they contain colons
+    let host_end = match host_and_port.starts_with('[') {
+        true => host_and_port.find(']')? + 1,
+        false => host_and_port.find(':').unwrap_or(host_and_port.len()),
+    };
+    let (host, port) = host_and_port.split_at(host_end);
+    if host.is_empty() {
+        return None;
+    }
+
+    let scheme = scheme.to_ascii_lowercase();
+    let host = host.to_ascii_lowercase();
+
+    let mut normalized = format!("{}://", scheme);
+    if let Some(user_info) = user_info {
+        normalized.push_str(user_info);
+        normalized.push('@');
+    }
+    normalized.push_str(&host);
+    normalized.push_str(port);
+    normalized.push_str(path);
+
+    Some(NormalizedUrl {
+        scheme,
+        host,
+        normalized,
+    })
+}
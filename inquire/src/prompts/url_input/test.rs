@@ -0,0 +1,96 @@
+use crate::{
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::ErrorMessage,
+    UrlInput,
+};
+
+fn keys(inputs: &[&str]) -> Vec<Key> {
+    let mut keys = vec![];
+    for (i, input) in inputs.iter().enumerate() {
+        if i > 0 {
+            keys.extend(std::iter::repeat(Key::Backspace).take(inputs[i - 1].len()));
+        }
+        keys.extend(input.chars().map(|c| Key::Char(c, KeyModifiers::NONE)));
+        keys.push(Key::Enter);
+    }
+    keys
+}
+
+fn error(message: &str) -> Token {
+    Token::ErrorMessage(ErrorMessage::Custom(message.into()))
+}
+
+#[test]
+fn default_scheme_is_inserted_and_scheme_and_host_are_lowercased() {
+    let mut backend = FakeBackend::new(keys(&["  Example.COM/Docs?q=A  "]));
+
+    let ans: String = UrlInput::new("URL")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("https://example.com/Docs?q=A", ans);
+
+    let mut backend = FakeBackend::new(keys(&["HTTP://User@[::1]:8080"]));
+
+    let ans: String = UrlInput::new("URL")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("http://User@[::1]:8080", ans);
+}
+
+#[test]
+fn scheme_is_required_without_a_default_one() {
+    let mut backend = FakeBackend::new(keys(&["example.com", "ftp://example.com"]));
+
+    let ans: String = UrlInput::new("URL")
+        .without_default_scheme()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("ftp://example.com", ans);
+    assert!(backend
+        .frames()
+        .iter()
+        .any(|f| f.has_token(&error("Invalid URL"))));
+}
+
+#[test]
+fn schemes_and_hosts_must_be_allowed() {
+    let mut backend = FakeBackend::new(keys(&[
+        "http://hooks.slack.com",
+        "https://slack.com.evil.io",
+        "https://HOOKS.slack.com/services/1",
+    ]));
+
+    let ans: String = UrlInput::new("URL")
+        .with_allowed_schemes(&["https"])
+        .with_allowed_hosts(&["slack.com"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("https://hooks.slack.com/services/1", ans);
+
+    let frames = backend.frames();
+    assert!(frames
+        .iter()
+        .any(|f| f.has_token(&error("The http scheme is not allowed, use https"))));
+    assert!(frames
+        .iter()
+        .any(|f| f.has_token(&error("The host slack.com.evil.io is not allowed"))));
+}
+
+#[test]
+fn empty_input_returns_the_default_value() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans: String = UrlInput::new("URL")
+        .with_default("https://example.com".to_string())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("https://example.com", ans);
+}
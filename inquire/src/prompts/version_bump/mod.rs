@@ -0,0 +1,284 @@
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+use std::fmt;
+
+use semver::{BuildMetadata, Prerelease, Version};
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    list_option::ListOption,
+    terminal::get_default_terminal,
+    ui::{Backend, CustomTypeBackend, RenderConfig, SelectBackend},
+    utils::format_edited_answer,
+    CustomType, Select,
+};
+
+/// Levels of a semantic version bump.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BumpLevel {
+    /// Bug fixes, e.g. from `1.2.3` to `1.2.4`.
+    Patch,
+
+    /// Backwards compatible features, e.g. from `1.2.3` to `1.3.0`.
+    Minor,
+
+    /// Breaking changes, e.g. from `1.2.3` to `2.0.0`.
+    Major,
+}
+
+impl BumpLevel {
+    /// Returns the version following `current` at this level, without build metadata.
+    ///
+    /// Pre-releases are promoted to their release when it is at least the
+    /// requested level, e.g. a patch bump of `1.2.3-rc.1` is `1.2.3`, while a
+    /// minor bump of it is `1.3.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::BumpLevel;
+    /// use semver::Version;
+    ///
+    /// let current = Version::parse("1.2.3").unwrap();
+    /// assert_eq!(Version::new(1, 3, 0), BumpLevel::Minor.apply(&current));
+    ///
+    /// let current = Version::parse("2.0.0-beta.2").unwrap();
+    /// assert_eq!(Version::new(2, 0, 0), BumpLevel::Major.apply(&current));
+    /// ```
+    pub fn apply(self, current: &Version) -> Version {
+        let pre_release = !current.pre.is_empty();
+        let mut next = Version {
+            pre: Prerelease::EMPTY,
+            build: BuildMetadata::EMPTY,
+            ..current.clone()
+        };
+
+        match self {
+            Self::Patch if pre_release => {}
+            Self::Patch => next.patch += 1,
+            Self::Minor if pre_release && current.patch == 0 => {}
+            Self::Minor => {
+                next.minor += 1;
+                next.patch = 0;
+            }
+            Self::Major if pre_release && current.minor == 0 && current.patch == 0 => {}
+            Self::Major => {
+                next.major += 1;
+                next.minor = 0;
+                next.patch = 0;
+            }
+        }
+
+        next
+    }
+}
+
+impl fmt::Display for BumpLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Patch => f.pad("patch"),
+            Self::Minor => f.pad("minor"),
+            Self::Major => f.pad("major"),
+        }
+    }
+}
+
+/// Option of the list of versions to choose from.
+#[derive(Clone)]
+enum VersionChoice {
+    Bump(BumpLevel, Version),
+    Custom,
+}
+
+impl fmt::Display for VersionChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bump(level, version) => write!(f, "{:<6} {}", level, version),
+            Self::Custom => f.write_str("custom"),
+        }
+    }
+}
+
+/// Prompt for the next semantic version of a project, the core interaction of release tooling. Available via the `semver` feature.
+///
+/// The user picks the next version from a list of quick choices, the patch, minor and major bumps of the current version, or chooses to type a custom one. The custom version is then typed in a text input, which only accepts valid semantic versions. Either way, the answer is returned as a parsed [`semver::Version`].
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Current version**: Version being bumped, required when creating the prompt.
+/// - **Starting bump**: Bump level under the cursor when the prompt is first rendered, patch by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - By default, prints the current and the next versions, e.g. `1.2.3 → 1.3.0`.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::VersionBump;
+/// use semver::Version;
+///
+/// let current = Version::parse(env!("CARGO_PKG_VERSION")).unwrap();
+///
+/// match VersionBump::new("Next version:", current).prompt() {
+///     Ok(version) => println!("Releasing {}", version),
+///     Err(_) => println!("Release aborted"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct VersionBump<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Version being bumped.
+    pub current: Version,
+
+    /// Bump level under the cursor when the prompt is first rendered.
+    pub starting_bump: BumpLevel,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: CustomTypeFormatter<'a, Version>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> VersionBump<'a> {
+    /// Default starting bump level.
+    pub const DEFAULT_STARTING_BUMP: BumpLevel = BumpLevel::Patch;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, enter to select, choose custom to type a version");
+
+    /// Creates a [VersionBump] with the provided message and current version, along with default configuration values.
+    pub fn new(message: &'a str, current: Version) -> Self {
+        Self {
+            message,
+            current,
+            starting_bump: Self::DEFAULT_STARTING_BUMP,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: &|version| version.to_string(),
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the bump level under the cursor when the prompt is first rendered.
+    pub fn with_starting_bump(mut self, starting_bump: BumpLevel) -> Self {
+        self.starting_bump = starting_bump;
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the formatter of the next version.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, Version>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Version>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<Version> {
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: SelectBackend + CustomTypeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Version> {
+        let levels = [BumpLevel::Patch, BumpLevel::Minor, BumpLevel::Major];
+
+        let starting_cursor = levels
+            .iter()
+            .position(|level| *level == self.starting_bump)
+            .unwrap_or(0);
+
+        let choices = levels
+            .iter()
+            .map(|level| VersionChoice::Bump(*level, level.apply(&self.current)))
+            .chain(std::iter::once(VersionChoice::Custom))
+            .collect();
+
+        let formatter = self.formatter;
+        let current = formatter(self.current.clone());
+        let format_choice = |choice: ListOption<&VersionChoice>| match choice.value {
+            VersionChoice::Bump(_, version) => {
+                format_edited_answer(Some(current.clone()), formatter(version.clone()))
+            }
+            VersionChoice::Custom => String::from("custom"),
+        };
+
+        let mut select = Select::new(self.message, choices)
+            .with_starting_cursor(starting_cursor)
+            .with_formatter(&format_choice)
+            .with_render_config(self.render_config);
+        select.help_message = self.help_message;
+
+        match select.prompt_with_backend(backend)?.value {
+            VersionChoice::Bump(_, version) => Ok(version),
+            VersionChoice::Custom => CustomType::<Version>::new(self.message)
+                .with_current_value(self.current)
+                .with_formatter(formatter)
+                .with_error_message("Please type a valid semantic version, e.g. 1.4.0-rc.1")
+                .with_render_config(self.render_config)
+                .prompt_with_backend(backend),
+        }
+    }
+}
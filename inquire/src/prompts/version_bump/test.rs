@@ -0,0 +1,106 @@
+use semver::Version;
+
+use crate::{
+    terminal::crossterm::CrosstermTerminal,
+    test::fake_backend,
+    ui::{Backend, Key, KeyModifiers, RenderConfig},
+    BumpLevel, VersionBump,
+};
+
+fn version(version: &str) -> Version {
+    Version::parse(version).unwrap()
+}
+
+const DOWN: Key = Key::Down(KeyModifiers::NONE);
+
+#[test]
+fn bumps_reset_lower_components_and_drop_metadata() {
+    let current = version("1.2.3+build.5");
+
+    assert_eq!(version("1.2.4"), BumpLevel::Patch.apply(&current));
+    assert_eq!(version("1.3.0"), BumpLevel::Minor.apply(&current));
+    assert_eq!(version("2.0.0"), BumpLevel::Major.apply(&current));
+}
+
+#[test]
+fn bumps_promote_pre_releases_of_the_same_level() {
+    assert_eq!(
+        version("1.2.3"),
+        BumpLevel::Patch.apply(&version("1.2.3-rc.1"))
+    );
+    assert_eq!(
+        version("1.3.0"),
+        BumpLevel::Minor.apply(&version("1.2.3-rc.1"))
+    );
+    assert_eq!(
+        version("1.3.0"),
+        BumpLevel::Minor.apply(&version("1.3.0-rc.1"))
+    );
+    assert_eq!(
+        version("2.0.0"),
+        BumpLevel::Major.apply(&version("1.3.0-rc.1"))
+    );
+    assert_eq!(
+        version("2.0.0"),
+        BumpLevel::Major.apply(&version("2.0.0-beta"))
+    );
+}
+
+#[test]
+fn quick_choices_return_the_bumped_version() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let ans = VersionBump::new("Version", version("0.7.4"))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(version("0.7.5"), ans);
+
+    let mut backend = fake_backend(vec![DOWN, Key::Enter]);
+    let ans = VersionBump::new("Version", version("0.7.4"))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(version("0.8.0"), ans);
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let ans = VersionBump::new("Version", version("0.7.4"))
+        .with_starting_bump(BumpLevel::Major)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(version("1.0.0"), ans);
+}
+
+#[test]
+fn custom_versions_are_typed_and_parsed() {
+    let mut keys = vec![DOWN, DOWN, DOWN, Key::Enter];
+    keys.extend(
+        "1.0.0-rc.1"
+            .chars()
+            .map(|c| Key::Char(c, KeyModifiers::NONE)),
+    );
+    keys.push(Key::Enter);
+
+    let mut backend = fake_backend(keys);
+
+    let ans = VersionBump::new("Version", version("0.7.4"))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(version("1.0.0-rc.1"), ans);
+}
+
+#[test]
+fn answer_shows_the_current_and_next_versions() {
+    let mut backend = Backend::new(
+        vec![DOWN, Key::Enter].into_iter(),
+        CrosstermTerminal::new_in_memory_output(),
+        RenderConfig::empty(),
+    )
+    .unwrap();
+
+    VersionBump::new("Version:", version("0.7.4"))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+    assert!(output.contains("minor  0.8.0"));
+    assert!(output.contains("? Version: 0.7.4 → 0.8.0\x1b[K\r\n"));
+}
@@ -0,0 +1,383 @@
+//! Sources of the answers to prompts.
+//!
+//! Prompts read the keys answering them from a [`ResponseProvider`]. By
+//! default, it is the terminal, even when stdin is piped. Automation can
+//! instead answer prompts without
+//! anyone at the keyboard, by setting a provider for the whole process with
+//! [`set_global_response_provider`]:
+//!
+//! - [`LineResponses`] types one line of a reader per prompt.
+//! - [`FixedResponses`] types the answer registered for the message of each
+//!   prompt.
+//! - [`RecordedSession`] replays a sequence of keys, e.g. recorded while
+//!   answering the prompts by hand.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    io::{self, BufRead},
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+
+use crate::{
+    error::{InquireError, InquireResult},
+    ui::{Key, KeyModifiers},
+};
+
+/// Source of the keys answering prompts, pressed by the user at the terminal
+/// or provided by automation.
+///
+/// Prompts are still rendered to the terminal while they are answered, so
+/// that the provided answers show up in the output like typed ones.
+pub trait ResponseProvider {
+    /// Hook called when a prompt starts, before the keys answering it are read.
+    fn start_prompt(&mut self, message: &str) -> InquireResult<()> {
+        let _ = message;
+        Ok(())
+    }
+
+    /// Reads the next key answering the current prompt, waiting for it if needed.
+    fn read_key(&mut self) -> InquireResult<Key>;
+
+    /// Reads a key, returning `None` if none was pressed before the timeout elapsed.
+    ///
+    /// Providers unable to wait for input with a timeout block until a key is pressed.
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        let _ = timeout;
+        self.read_key().map(Some)
+    }
+
+    /// Whether [`read_key_timeout`](ResponseProvider::read_key_timeout) returns
+    /// when the timeout elapses instead of blocking until a key is pressed.
+    fn supports_read_timeout(&self) -> bool {
+        false
+    }
+}
+
+/// Types the characters of the answer, then submits it with the enter key.
+fn type_answer(answer: &str) -> VecDeque<Key> {
+    answer
+        .chars()
+        .map(|c| Key::Char(c, KeyModifiers::NONE))
+        .chain(std::iter::once(Key::Enter))
+        .collect()
+}
+
+/// Types the newline-delimited answers read from a reader, e.g. from a pipe
+/// in `yes "answer" | mytool`, as if they were typed by the user and
+/// submitted with the enter key.
+///
+/// Once the reader is exhausted, reading fails with [`InquireError::NotTTY`],
+/// as no answer can be typed anymore.
+///
+/// Prompts never read piped stdin on their own, so that, e.g., an installer
+/// piped into `sh` does not consume the rest of its own script. Tools meant
+/// to be answered through a pipe opt in by setting this provider globally.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::io::{stdin, BufReader};
+///
+/// use inquire::response::{set_global_response_provider, LineResponses};
+///
+/// set_global_response_provider(LineResponses::new(BufReader::new(stdin())));
+/// ```
+pub struct LineResponses<R> {
+    input: R,
+    pending: VecDeque<Key>,
+}
+
+impl<R> LineResponses<R>
+where
+    R: BufRead,
+{
+    /// Creates a provider typing the lines of the given reader.
+    pub fn new(input: R) -> Self {
+        Self {
+            input,
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+impl<R> ResponseProvider for LineResponses<R>
+where
+    R: BufRead,
+{
+    fn read_key(&mut self) -> InquireResult<Key> {
+        if let Some(key) = self.pending.pop_front() {
+            return Ok(key);
+        }
+
+        let mut line = String::new();
+        if self.input.read_line(&mut line)? == 0 {
+            return Err(InquireError::NotTTY);
+        }
+
+        self.pending = type_answer(line.trim_end_matches(&['\r', '\n'][..]));
+
+        Ok(self.pending.pop_front().unwrap_or(Key::Enter))
+    }
+}
+
+/// Types the answer registered for the message of each prompt, regardless
+/// of the order in which the prompts are displayed.
+///
+/// Starting a prompt whose message has no answer fails with
+/// [`InquireError::InvalidConfiguration`].
+///
+/// # Example
+///
+/// ```
+/// use inquire::response::FixedResponses;
+///
+/// let responses = FixedResponses::new()
+///     .with("What's your name?", "Ada")
+///     .with("Continue?", "y");
+///
+/// inquire::response::set_global_response_provider(responses);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FixedResponses {
+    answers: HashMap<String, String>,
+    pending: VecDeque<Key>,
+}
+
+impl FixedResponses {
+    /// Creates a provider without any answers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the answer typed in the prompts with the given message.
+    pub fn with(mut self, message: &str, answer: &str) -> Self {
+        self.answers.insert(message.to_owned(), answer.to_owned());
+        self
+    }
+}
+
+impl ResponseProvider for FixedResponses {
+    fn start_prompt(&mut self, message: &str) -> InquireResult<()> {
+        let Some(answer) = self.answers.get(message) else {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "no response for the prompt \"{}\"",
+                message
+            )));
+        };
+
+        self.pending = type_answer(answer);
+
+        Ok(())
+    }
+
+    fn read_key(&mut self) -> InquireResult<Key> {
+        self.pending
+            .pop_front()
+            .ok_or_else(|| InquireError::InvalidConfiguration("the response was rejected".into()))
+    }
+}
+
+/// Replays a sequence of keys across the following prompts, as if they were
+/// pressed by the user.
+///
+/// Once all keys are replayed, reading fails with an [`InquireError::IO`]
+/// error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof).
+#[derive(Clone, Debug, Default)]
+pub struct RecordedSession {
+    keys: VecDeque<Key>,
+}
+
+impl RecordedSession {
+    /// Creates a session replaying the given keys.
+    pub fn new<K>(keys: K) -> Self
+    where
+        K: IntoIterator<Item = Key>,
+    {
+        Self {
+            keys: keys.into_iter().collect(),
+        }
+    }
+}
+
+impl ResponseProvider for RecordedSession {
+    fn read_key(&mut self) -> InquireResult<Key> {
+        self.keys.pop_front().ok_or_else(|| {
+            InquireError::IO(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "the recorded session has no more keys",
+            ))
+        })
+    }
+
+    // keys are always available right away
+    fn supports_read_timeout(&self) -> bool {
+        true
+    }
+}
+
+static GLOBAL_RESPONSE_PROVIDER: Mutex<Option<Box<dyn ResponseProvider + Send>>> = Mutex::new(None);
+
+fn lock_global_provider() -> MutexGuard<'static, Option<Box<dyn ResponseProvider + Send>>> {
+    GLOBAL_RESPONSE_PROVIDER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Answers all following prompts of the process with the given provider,
+/// instead of the keys pressed at the terminal.
+///
+/// Prompts are then displayed even when the process is not
+/// [interactive](crate::is_interactive).
+pub fn set_global_response_provider<P>(provider: P)
+where
+    P: ResponseProvider + Send + 'static,
+{
+    *lock_global_provider() = Some(Box::new(provider));
+}
+
+/// Removes the global response provider, answering the following prompts
+/// from the terminal again.
+pub fn clear_global_response_provider() {
+    *lock_global_provider() = None;
+}
+
+pub(crate) fn has_global_response_provider() -> bool {
+    lock_global_provider().is_some()
+}
+
+/// Provider forwarding to the global response provider.
+pub(crate) struct GlobalResponses;
+
+impl GlobalResponses {
+    fn with_provider<F, R>(f: F) -> InquireResult<R>
+    where
+        F: FnOnce(&mut dyn ResponseProvider) -> InquireResult<R>,
+    {
+        match lock_global_provider().as_mut() {
+            Some(provider) => f(provider.as_mut()),
+            None => Err(InquireError::InvalidConfiguration(
+                "the global response provider was cleared".into(),
+            )),
+        }
+    }
+}
+
+impl ResponseProvider for GlobalResponses {
+    fn start_prompt(&mut self, message: &str) -> InquireResult<()> {
+        Self::with_provider(|provider| provider.start_prompt(message))
+    }
+
+    fn read_key(&mut self) -> InquireResult<Key> {
+        Self::with_provider(|provider| provider.read_key())
+    }
+
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        Self::with_provider(|provider| provider.read_key_timeout(timeout))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use crate::{
+        error::InquireError,
+        terminal::crossterm::CrosstermTerminal,
+        ui::{Backend, Key, KeyModifiers, RenderConfig},
+        Confirm, CustomType, Select, Text,
+    };
+
+    use super::{FixedResponses, LineResponses, RecordedSession, ResponseProvider};
+
+    #[test]
+    fn lines_are_typed_and_submitted() {
+        let mut responses = LineResponses::new(Cursor::new("ab\r\n\n"));
+
+        assert_eq!(
+            Key::Char('a', KeyModifiers::NONE),
+            responses.read_key().unwrap()
+        );
+        assert_eq!(
+            Key::Char('b', KeyModifiers::NONE),
+            responses.read_key().unwrap()
+        );
+        assert_eq!(Key::Enter, responses.read_key().unwrap());
+        assert_eq!(Key::Enter, responses.read_key().unwrap());
+        assert!(matches!(responses.read_key(), Err(InquireError::NotTTY)));
+    }
+
+    #[test]
+    fn line_responses_are_rendered_on_the_terminal() {
+        let mut backend = Backend::new(
+            LineResponses::new(Cursor::new("Ada\ny\n")),
+            CrosstermTerminal::new_in_memory_output(),
+            RenderConfig::empty(),
+        )
+        .unwrap();
+
+        let name = Text::new("Name:").prompt_with_backend(&mut backend);
+        let confirmed = Confirm::new("Continue?").prompt_with_backend(&mut backend);
+
+        assert_eq!("Ada", name.unwrap());
+        assert!(confirmed.unwrap());
+
+        let output = String::from_utf8(backend.terminal_mut().get_buffer_content()).unwrap();
+        assert!(output.contains("? Name: Ada\x1b[K\r\n"));
+        assert!(output.contains("? Continue? Yes\x1b[K\r\n"));
+    }
+
+    #[test]
+    fn fixed_responses_answer_prompts_by_message() {
+        let responses = FixedResponses::new()
+            .with("Plan:", "pro")
+            .with("Seats:", "3")
+            .with("Name:", "Ada");
+
+        let mut backend = Backend::new(
+            responses,
+            CrosstermTerminal::new_in_memory_output(),
+            RenderConfig::empty(),
+        )
+        .unwrap();
+
+        let seats = CustomType::<u32>::new("Seats:").prompt_with_backend(&mut backend);
+        let name = Text::new("Name:").prompt_with_backend(&mut backend);
+        let plan = Select::new("Plan:", vec!["Free", "Pro"]).prompt_with_backend(&mut backend);
+
+        assert_eq!(3, seats.unwrap());
+        assert_eq!("Ada", name.unwrap());
+        assert_eq!("Pro", plan.unwrap().value);
+
+        let missing = Text::new("Email:").prompt_with_backend(&mut backend);
+        assert!(matches!(
+            missing,
+            Err(InquireError::InvalidConfiguration(_))
+        ));
+    }
+
+    #[test]
+    fn recorded_session_is_replayed_across_prompts() {
+        let mut backend = Backend::new(
+            RecordedSession::new(vec![
+                Key::Char('h', KeyModifiers::NONE),
+                Key::Char('i', KeyModifiers::NONE),
+                Key::Enter,
+                Key::Char('n', KeyModifiers::NONE),
+                Key::Enter,
+            ]),
+            CrosstermTerminal::new_in_memory_output(),
+            RenderConfig::empty(),
+        )
+        .unwrap();
+
+        let greeting = Text::new("Greeting:").prompt_with_backend(&mut backend);
+        let confirmed = Confirm::new("Continue?").prompt_with_backend(&mut backend);
+
+        assert_eq!("hi", greeting.unwrap());
+        assert!(!confirmed.unwrap());
+
+        let exhausted = Text::new("Name:").prompt_with_backend(&mut backend);
+        assert!(matches!(exhausted, Err(InquireError::IO(_))));
+    }
+}
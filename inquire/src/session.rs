@@ -0,0 +1,126 @@
+use std::fmt::Display;
+
+use crate::{
+    error::InquireResult, terminal::SessionGuard, Confirm, MultiSelect, Password, Select, Text,
+};
+
+/// Terminal session shared by the prompts run within [`session`].
+///
+/// The shortcuts below prompt with the default settings. Any other prompt,
+/// e.g. a [`Select`] built with custom settings, also shares the session
+/// when prompted inside the closure.
+pub struct Session {
+    _guard: SessionGuard,
+}
+
+impl Session {
+    /// Prompts the user for a text input, as [`Text::new`] would.
+    pub fn text(&mut self, message: &str) -> InquireResult<String> {
+        Text::new(message).prompt()
+    }
+
+    /// Prompts the user for a secret input, as [`Password::new`] would.
+    pub fn password(&mut self, message: &str) -> InquireResult<String> {
+        Password::new(message).prompt()
+    }
+
+    /// Prompts the user for the confirmation of an action, as [`Confirm::new`]
+    /// would.
+    pub fn confirm(&mut self, message: &str) -> InquireResult<bool> {
+        Confirm::new(message).prompt()
+    }
+
+    /// Prompts the user to select one option among many, as [`Select::new`]
+    /// would.
+    pub fn select<T>(&mut self, message: &str, options: Vec<T>) -> InquireResult<T>
+    where
+        T: Display,
+    {
+        Select::new(message, options).prompt()
+    }
+
+    /// Prompts the user to select any number of options, as
+    /// [`MultiSelect::new`] would.
+    pub fn multi_select<T>(&mut self, message: &str, options: Vec<T>) -> InquireResult<Vec<T>>
+    where
+        T: Display,
+    {
+        MultiSelect::new(message, options).prompt()
+    }
+}
+
+/// Runs many prompts in a single terminal session, entering raw mode once
+/// before the first prompt and restoring the terminal once after the last one.
+///
+/// Prompts otherwise toggle raw mode on their own, which adds latency and
+/// makes the cursor flash between consecutive prompts. Every prompt run inside
+/// the closure shares the session, whether through the [`Session`] shortcuts
+/// or through the regular prompt builders.
+///
+/// The terminal is restored even when the closure fails or panics. Sessions
+/// can be nested, in which case the outermost one restores the terminal.
+///
+/// The session holds the terminal for its whole lifetime: only the prompts of
+/// the thread running it share it, while prompts started on other threads,
+/// e.g. through a [`PromptHandle`](crate::prompt_thread::PromptHandle), wait
+/// for the session to end or fail with
+/// [`InquireError::TerminalBusy`](crate::InquireError::TerminalBusy),
+/// depending on the global [`BusyTerminalBehavior`](crate::BusyTerminalBehavior).
+///
+/// Raw mode is only shared with the `crossterm` back-end. With the other
+/// back-ends, prompts run inside a session behave as they do outside of it.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Select;
+///
+/// let (name, plan, confirmed) = inquire::session(|s| {
+///     let name = s.text("Name:")?;
+///     let plan = Select::new("Plan:", vec!["Free", "Pro"])
+///         .with_help_message("Can be changed later")
+///         .prompt()?;
+///     let confirmed = s.confirm("Create the account?")?;
+///
+///     Ok((name, plan, confirmed))
+/// })?;
+/// # inquire::error::InquireResult::Ok(())
+/// ```
+pub fn session<F, R>(f: F) -> InquireResult<R>
+where
+    F: FnOnce(&mut Session) -> InquireResult<R>,
+{
+    let mut session = Session {
+        _guard: SessionGuard::enter()?,
+    };
+
+    f(&mut session)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        terminal::{in_session, lock::test::serial},
+        InquireError,
+    };
+
+    use super::session;
+
+    #[test]
+    fn session_lasts_for_the_closure_even_when_it_fails() {
+        let _serial = serial();
+        let answer = session(|_| {
+            let nested = session(|_| Ok(in_session()));
+            assert!(matches!(nested, Ok(true)));
+            assert!(in_session());
+
+            Ok(42)
+        });
+        assert_eq!(42, answer.unwrap());
+        assert!(!in_session());
+
+        let failed = session(|_| Err::<(), _>(InquireError::OperationCanceled));
+        assert!(matches!(failed, Err(InquireError::OperationCanceled)));
+        assert!(!in_session());
+    }
+}
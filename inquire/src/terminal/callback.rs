@@ -0,0 +1,560 @@
+//! Terminal back-end exchanging raw bytes with user-provided callbacks.
+//!
+//! It lets prompts run on terminals the other back-ends can not reach, such
+//! as web-based terminals like xterm.js driven from WebAssembly, where the
+//! output bytes are written with `terminal.write` and the input bytes come
+//! from `terminal.onData` events.
+//!
+//! The back-end is used by the prompts when the `callback` feature is the
+//! only terminal back-end enabled, e.g. with `default-features = false`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::{
+//!     io::{self, Write},
+//!     sync::mpsc::Receiver,
+//! };
+//!
+//! use inquire::{callback::TerminalIo, Text};
+//!
+//! /// Bridge to a terminal whose input is forwarded to a channel, e.g. from
+//! /// the `onData` events of xterm.js.
+//! struct Bridge {
+//!     input: Receiver<Vec<u8>>,
+//! }
+//!
+//! impl TerminalIo for Bridge {
+//!     fn write(&mut self, bytes: &[u8]) -> io::Result<()> {
+//!         // e.g. forward the bytes to `terminal.write` through a JS shim
+//!         io::stdout().write_all(bytes)
+//!     }
+//!
+//!     fn read(&mut self) -> io::Result<Vec<u8>> {
+//!         self.input
+//!             .recv()
+//!             .map_err(|err| io::Error::new(io::ErrorKind::BrokenPipe, err))
+//!     }
+//! }
+//!
+//! # let (_, input) = std::sync::mpsc::channel();
+//! inquire::callback::set_terminal_io(Bridge { input });
+//!
+//! let name = Text::new("What is your name?").prompt()?;
+//! # inquire::error::InquireResult::Ok(())
+//! ```
+
+use std::{
+    fmt::Display,
+    io::{self, Read, Result, Write},
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+#[cfg(windows)]
+use std::{fs::File, os::windows::io::OwnedHandle};
+
+use crate::{
+    error::{InquireError, InquireResult},
+    response::ResponseProvider,
+    ui::{Attributes, Color, Key, KeyModifiers, Styled},
+};
+
+use super::{Terminal, TerminalSize};
+
+/// Byte sink and source of the terminal the prompts are rendered on.
+pub trait TerminalIo: Send {
+    /// Writes the bytes of a frame, text along with ANSI escape sequences,
+    /// to the terminal.
+    fn write(&mut self, bytes: &[u8]) -> Result<()>;
+
+    /// Returns the next chunk of input bytes sent by the terminal, blocking
+    /// until one is available.
+    ///
+    /// Escape sequences, e.g. `\x1b[A` for the up arrow, are expected to be
+    /// sent whole in a single chunk, as done by xterm.js.
+    fn read(&mut self) -> Result<Vec<u8>>;
+
+    /// Size of the terminal, in columns and rows. Defaults to 80x24.
+    fn size(&self) -> Result<(u16, u16)> {
+        Ok((80, 24))
+    }
+}
+
+/// [`TerminalIo`] over a pair of byte streams, such as the pipes of a
+/// pseudo console (ConPTY) on Windows or the master side of a pty on Unix,
+/// for hosts where the right terminal is not the ambient console of the
+/// process.
+///
+/// # Example
+///
+/// ```no_run
+/// # #[cfg(windows)]
+/// # fn run(input: std::os::windows::io::OwnedHandle, output: std::os::windows::io::OwnedHandle) {
+/// use inquire::callback::{set_terminal_io, StreamIo};
+///
+/// // e.g. the pipes given to CreatePseudoConsole
+/// set_terminal_io(StreamIo::from_handles(input, output).with_size(120, 30));
+/// # }
+/// ```
+pub struct StreamIo<R, W> {
+    input: R,
+    output: W,
+    size: (u16, u16),
+}
+
+impl<R, W> StreamIo<R, W>
+where
+    R: Read + Send,
+    W: Write + Send,
+{
+    /// Creates a terminal reading the input bytes from `input` and writing
+    /// the output bytes to `output`, assumed to be 80x24.
+    pub fn new(input: R, output: W) -> Self {
+        Self {
+            input,
+            output,
+            size: (80, 24),
+        }
+    }
+
+    /// Sets the size of the terminal, in columns and rows.
+    pub fn with_size(mut self, columns: u16, rows: u16) -> Self {
+        self.size = (columns, rows);
+        self
+    }
+}
+
+#[cfg(windows)]
+#[cfg_attr(docsrs, doc(cfg(windows)))]
+impl StreamIo<File, File> {
+    /// Creates a terminal from explicit Windows handles instead of the
+    /// console of the process, e.g. the pipes of a ConPTY or the console
+    /// handles opened from `CONIN$` and `CONOUT$`.
+    ///
+    /// The console behind the handles must be in virtual terminal mode, as
+    /// the output is made of ANSI escape sequences.
+    pub fn from_handles(input: OwnedHandle, output: OwnedHandle) -> Self {
+        Self::new(File::from(input), File::from(output))
+    }
+}
+
+impl<R, W> TerminalIo for StreamIo<R, W>
+where
+    R: Read + Send,
+    W: Write + Send,
+{
+    fn write(&mut self, bytes: &[u8]) -> Result<()> {
+        self.output.write_all(bytes)?;
+        self.output.flush()
+    }
+
+    fn read(&mut self) -> Result<Vec<u8>> {
+        let mut buffer = [0; 1024];
+        let read = self.input.read(&mut buffer)?;
+
+        if read == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        Ok(buffer[..read].to_vec())
+    }
+
+    fn size(&self) -> Result<(u16, u16)> {
+        Ok(self.size)
+    }
+}
+
+static TERMINAL_IO: Mutex<Option<Box<dyn TerminalIo>>> = Mutex::new(None);
+
+fn lock_io() -> MutexGuard<'static, Option<Box<dyn TerminalIo>>> {
+    TERMINAL_IO.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Sets the terminal the prompts are rendered on, replacing the previous one.
+pub fn set_terminal_io<T>(io: T)
+where
+    T: TerminalIo + 'static,
+{
+    *lock_io() = Some(Box::new(io));
+}
+
+/// Whether a terminal was set with [`set_terminal_io`].
+pub(crate) fn is_set() -> bool {
+    lock_io().is_some()
+}
+
+fn with_io<R>(f: impl FnOnce(&mut dyn TerminalIo) -> Result<R>) -> Result<R> {
+    match lock_io().as_mut() {
+        Some(io) => f(io.as_mut()),
+        None => Err(io::Error::new(
+            io::ErrorKind::NotConnected,
+            "no terminal was set with inquire::callback::set_terminal_io",
+        )),
+    }
+}
+
+pub(crate) struct CallbackTerminal {
+    buffer: Vec<u8>,
+}
+
+impl CallbackTerminal {
+    #[allow(unused)]
+    pub fn new() -> InquireResult<Self> {
+        if !is_set() {
+            return Err(InquireError::NotTTY);
+        }
+
+        Ok(Self { buffer: vec![] })
+    }
+
+    fn write_csi(&mut self, sequence: impl Display) -> Result<()> {
+        self.write(format!("\x1b[{}", sequence))
+    }
+}
+
+impl Terminal for CallbackTerminal {
+    fn get_size(&self) -> Result<TerminalSize> {
+        with_io(|io| io.size()).map(|(width, height)| TerminalSize::new(width, height))
+    }
+
+    fn write<T: Display>(&mut self, val: T) -> Result<()> {
+        self.buffer.extend_from_slice(val.to_string().as_bytes());
+        Ok(())
+    }
+
+    fn write_styled<T: Display>(&mut self, val: &Styled<T>) -> Result<()> {
+        let mut codes = vec![];
+
+        if let Some(color) = val.style.fg {
+            codes.push(format!("38;{}", color_code(color)));
+        }
+        if let Some(color) = val.style.bg {
+            codes.push(format!("48;{}", color_code(color)));
+        }
+        if val.style.att.contains(Attributes::BOLD) {
+            codes.push("1".into());
+        }
+        if val.style.att.contains(Attributes::ITALIC) {
+            codes.push("3".into());
+        }
+
+        if codes.is_empty() {
+            return self.write(&val.content);
+        }
+
+        self.write_csi(format!("{}m", codes.join(";")))?;
+        self.write(&val.content)?;
+        self.write_csi("0m")
+    }
+
+    fn clear_line(&mut self) -> Result<()> {
+        self.write_csi("2K")
+    }
+
+    fn clear_until_new_line(&mut self) -> Result<()> {
+        self.write_csi("K")
+    }
+
+    fn cursor_hide(&mut self) -> Result<()> {
+        self.write_csi("?25l")
+    }
+
+    fn cursor_show(&mut self) -> Result<()> {
+        self.write_csi("?25h")
+    }
+
+    fn cursor_up(&mut self, cnt: u16) -> Result<()> {
+        match cnt {
+            0 => Ok(()),
+            cnt => self.write_csi(format!("{}A", cnt)),
+        }
+    }
+
+    fn cursor_down(&mut self, cnt: u16) -> Result<()> {
+        match cnt {
+            0 => Ok(()),
+            cnt => self.write_csi(format!("{}B", cnt)),
+        }
+    }
+
+    fn cursor_left(&mut self, cnt: u16) -> Result<()> {
+        match cnt {
+            0 => Ok(()),
+            cnt => self.write_csi(format!("{}D", cnt)),
+        }
+    }
+
+    fn cursor_right(&mut self, cnt: u16) -> Result<()> {
+        match cnt {
+            0 => Ok(()),
+            cnt => self.write_csi(format!("{}C", cnt)),
+        }
+    }
+
+    fn cursor_move_to_column(&mut self, idx: u16) -> Result<()> {
+        self.write_csi(format!("{}G", idx.saturating_add(1)))
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+
+        let bytes = std::mem::take(&mut self.buffer);
+        with_io(|io| io.write(&bytes))
+    }
+}
+
+impl Drop for CallbackTerminal {
+    fn drop(&mut self) {
+        let _unused = self.flush();
+    }
+}
+
+/// Parameters of an SGR color, the palette index or RGB value of the color.
+fn color_code(color: Color) -> String {
+    let index = match color {
+        Color::Black => 0,
+        Color::DarkRed => 1,
+        Color::DarkGreen => 2,
+        Color::DarkYellow => 3,
+        Color::DarkBlue => 4,
+        Color::DarkMagenta => 5,
+        Color::DarkCyan => 6,
+        Color::Grey => 7,
+        Color::DarkGrey => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        Color::White => 15,
+        Color::AnsiValue(index) => index,
+        Color::Rgb { r, g, b } => return format!("2;{};{};{}", r, g, b),
+    };
+
+    format!("5;{}", index)
+}
+
+#[derive(Default)]
+pub(crate) struct CallbackKeyReader {
+    pending: Vec<Key>,
+}
+
+impl CallbackKeyReader {
+    #[allow(unused)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ResponseProvider for CallbackKeyReader {
+    fn read_key(&mut self) -> InquireResult<Key> {
+        while self.pending.is_empty() {
+            let bytes = with_io(|io| io.read())?;
+
+            self.pending = decode_keys(&String::from_utf8_lossy(&bytes));
+            self.pending.reverse();
+        }
+
+        Ok(self.pending.pop().unwrap())
+    }
+}
+
+/// Decodes the keys sent by a terminal, as characters and escape sequences.
+fn decode_keys(input: &str) -> Vec<Key> {
+    let mut keys = vec![];
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let key = match c {
+            '\r' | '\n' => Key::Enter,
+            '\t' => Key::Tab,
+            '\x7f' | '\x08' => Key::Backspace,
+            '\x1b' => match chars.next() {
+                None => Key::Escape,
+                Some('[') => {
+                    let mut parameters = String::new();
+                    let mut terminator = None;
+
+                    for c in chars.by_ref() {
+                        if ('@'..='~').contains(&c) {
+                            terminator = Some(c);
+                            break;
+                        }
+                        parameters.push(c);
+                    }
+
+                    match terminator.and_then(|t| decode_csi(&parameters, t)) {
+                        Some(key) => key,
+                        None => continue,
+                    }
+                }
+                Some('O') => match chars.next().and_then(|t| decode_csi("", t)) {
+                    Some(key) => key,
+                    None => continue,
+                },
+                Some(c) => Key::Char(c, KeyModifiers::ALT),
+            },
+            c @ '\x01'..='\x1a' => {
+                Key::Char(char::from(b'a' + (c as u8) - 1), KeyModifiers::CONTROL)
+            }
+            c => Key::Char(c, KeyModifiers::NONE),
+        };
+
+        keys.push(key);
+    }
+
+    keys
+}
+
+/// Decodes a CSI or SS3 escape sequence, e.g. `1;5A` for Ctrl+Up.
+fn decode_csi(parameters: &str, terminator: char) -> Option<Key> {
+    let mut parameters = parameters.split(';').map(|p| p.parse::<u8>().ok());
+    let code = parameters.next().flatten();
+    let modifiers = match parameters.next().flatten() {
+        Some(modifiers) => decode_modifiers(modifiers),
+        None => KeyModifiers::NONE,
+    };
+
+    let key = match (terminator, code) {
+        ('A', _) => Key::Up(modifiers),
+        ('B', _) => Key::Down(modifiers),
+        ('C', _) => Key::Right(modifiers),
+        ('D', _) => Key::Left(modifiers),
+        ('H', _) | ('~', Some(1 | 7)) => Key::Home,
+        ('F', _) | ('~', Some(4 | 8)) => Key::End,
+        ('P'..='S', _) => Key::F(terminator as u8 - b'P' + 1, modifiers),
+        ('~', Some(3)) => Key::Delete(modifiers),
+        ('~', Some(5)) => Key::PageUp(modifiers),
+        ('~', Some(6)) => Key::PageDown(modifiers),
+        ('~', Some(code @ 11..=15)) => Key::F(code - 10, modifiers),
+        ('~', Some(code @ 17..=21)) => Key::F(code - 11, modifiers),
+        ('~', Some(code @ 23..=24)) => Key::F(code - 12, modifiers),
+        _ => return None,
+    };
+
+    Some(key)
+}
+
+/// Decodes the modifiers parameter of an escape sequence, one more than
+/// the bits of the pressed modifiers.
+fn decode_modifiers(parameter: u8) -> KeyModifiers {
+    let bits = parameter.saturating_sub(1);
+    let mut modifiers = KeyModifiers::NONE;
+
+    if bits & 1 != 0 {
+        modifiers |= KeyModifiers::SHIFT;
+    }
+    if bits & 2 != 0 {
+        modifiers |= KeyModifiers::ALT;
+    }
+    if bits & 4 != 0 {
+        modifiers |= KeyModifiers::CONTROL;
+    }
+
+    modifiers
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        io::{Cursor, ErrorKind, Result},
+        sync::{Arc, Mutex},
+    };
+
+    use crate::{
+        ui::{Backend, Key, KeyModifiers, RenderConfig},
+        Text,
+    };
+
+    use super::{
+        decode_keys, set_terminal_io, CallbackKeyReader, CallbackTerminal, StreamIo, TerminalIo,
+    };
+
+    #[test]
+    fn characters_and_escape_sequences_are_decoded() {
+        assert_eq!(
+            vec![
+                Key::Char('h', KeyModifiers::NONE),
+                Key::Char('é', KeyModifiers::NONE),
+                Key::Backspace,
+                Key::Enter,
+            ],
+            decode_keys("hé\x7f\r")
+        );
+        assert_eq!(
+            vec![
+                Key::Up(KeyModifiers::NONE),
+                Key::Right(KeyModifiers::CONTROL),
+                Key::Home,
+                Key::Delete(KeyModifiers::NONE),
+                Key::PageDown(KeyModifiers::NONE),
+                Key::F(1, KeyModifiers::NONE),
+                Key::F(5, KeyModifiers::NONE),
+            ],
+            decode_keys("\x1b[A\x1b[1;5C\x1b[H\x1b[3~\x1b[6~\x1bOP\x1b[15~")
+        );
+        assert_eq!(
+            vec![
+                Key::Char('c', KeyModifiers::CONTROL),
+                Key::Char('b', KeyModifiers::ALT),
+                Key::Escape,
+            ],
+            decode_keys("\x03\x1bb\x1b")
+        );
+    }
+
+    struct Scripted {
+        input: Vec<Vec<u8>>,
+        output: Arc<Mutex<Vec<u8>>>,
+    }
+
+    impl TerminalIo for Scripted {
+        fn write(&mut self, bytes: &[u8]) -> Result<()> {
+            self.output.lock().unwrap().extend_from_slice(bytes);
+            Ok(())
+        }
+
+        fn read(&mut self) -> Result<Vec<u8>> {
+            Ok(self.input.remove(0))
+        }
+    }
+
+    #[test]
+    fn stream_io_exchanges_bytes_with_its_streams() {
+        let mut io = StreamIo::new(Cursor::new(b"\x1b[A".to_vec()), vec![]).with_size(120, 30);
+
+        assert_eq!(b"\x1b[A".to_vec(), io.read().unwrap());
+        assert_eq!(ErrorKind::UnexpectedEof, io.read().unwrap_err().kind());
+
+        io.write(b"Name: ").unwrap();
+        assert_eq!(b"Name: ".to_vec(), io.output);
+        assert_eq!((120, 30), io.size().unwrap());
+    }
+
+    #[test]
+    fn prompt_runs_on_the_terminal_io() {
+        let output = Arc::new(Mutex::new(vec![]));
+        set_terminal_io(Scripted {
+            input: vec![b"Ada".to_vec(), b"\x1b[D\x7f\r".to_vec()],
+            output: output.clone(),
+        });
+
+        let mut backend = Backend::new(
+            CallbackKeyReader::new(),
+            CallbackTerminal::new().unwrap(),
+            RenderConfig::default_colored(),
+        )
+        .unwrap();
+
+        let ans = Text::new("Name:").prompt_with_backend(&mut backend);
+        drop(backend);
+
+        assert_eq!("Aa", ans.unwrap());
+
+        let output = String::from_utf8(output.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("\x1b[38;5;10m?\x1b[0m Name:"));
+        assert!(output.contains("\x1b[38;5;14mAa\x1b[0m"));
+    }
+}
@@ -4,7 +4,8 @@ use console::{Attribute, Color, Key, Style, Term};
 
 use crate::{
     error::InquireResult,
-    ui::{Attributes, InputReader, StyleSheet, Styled},
+    response::ResponseProvider,
+    ui::{Attributes, StyleSheet, Styled},
 };
 
 use super::Terminal;
@@ -23,7 +24,7 @@ impl ConsoleTerminal {
     }
 }
 
-impl InputReader for ConsoleTerminal {
+impl ResponseProvider for ConsoleTerminal {
     fn read_key(&mut self) -> InquireResult<crate::ui::Key> {
         let key = self.term.read_key()?;
         Ok(key.into())
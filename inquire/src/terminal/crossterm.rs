@@ -1,4 +1,10 @@
-use std::io::{stderr, Result, Stderr, Write};
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    io::{stderr, Result, Stderr, Write},
+    rc::Rc,
+    time::{Duration, Instant},
+};
 
 use crossterm::{
     cursor,
@@ -11,52 +17,139 @@ use crossterm::{
 
 use crate::{
     error::InquireResult,
-    ui::{Attributes, InputReader, Key, Styled},
+    response::ResponseProvider,
+    testing::MockState,
+    ui::{Attributes, Key, Styled},
 };
 
 use super::Terminal;
 
 enum IO {
     Std(Stderr),
+    /// Stderr without raw mode, rendering prompts answered by a response
+    /// provider.
+    Piped(Stderr),
     #[allow(unused)]
     Test(Vec<u8>),
+    /// Output of the prompts run with a [`MockBackend`](crate::testing::MockBackend),
+    /// recording their frames.
+    Mocked(Vec<u8>, Rc<RefCell<MockState>>),
 }
 
 pub struct CrosstermTerminal {
     io: IO,
 }
 
-pub struct CrosstermKeyReader;
+pub struct CrosstermKeyReader {
+    /// Keys read from the terminal before the prompt started, returned first.
+    pending: VecDeque<Key>,
+}
 
 impl CrosstermKeyReader {
     pub fn new() -> Self {
-        Self
+        Self {
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Returns the given keys before the ones read from the terminal, e.g. the
+    /// keys typed while the terminal was queried.
+    #[cfg_attr(not(all(unix, not(test))), allow(dead_code))]
+    pub(crate) fn with_pending_keys(mut self, keys: Vec<Key>) -> Self {
+        self.pending.extend(keys);
+        self
     }
 }
 
-impl InputReader for CrosstermKeyReader {
+impl ResponseProvider for CrosstermKeyReader {
     fn read_key(&mut self) -> InquireResult<Key> {
+        if let Some(key) = self.pending.pop_front() {
+            return Ok(key);
+        }
+
         loop {
             if let event::Event::Key(key_event) = event::read()? {
                 return Ok(key_event.into());
             }
         }
     }
+
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        if let Some(key) = self.pending.pop_front() {
+            return Ok(Some(key));
+        }
+
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+
+            if !event::poll(remaining)? {
+                return Ok(None);
+            }
+
+            if let event::Event::Key(key_event) = event::read()? {
+                return Ok(Some(key_event.into()));
+            }
+        }
+    }
+
+    fn supports_read_timeout(&self) -> bool {
+        true
+    }
 }
 
 impl CrosstermTerminal {
+    /// Rows of a frame with the ANSI escape codes of their styles, one line
+    /// per row.
+    pub(crate) fn styled_frame<S: AsRef<[Styled<String>]>>(rows: &[S]) -> String {
+        let mut terminal = Self {
+            io: IO::Test(Vec::new()),
+        };
+        for (idx, row) in rows.iter().enumerate() {
+            if idx > 0 {
+                let _unused = terminal.write("\n");
+            }
+            for piece in row.as_ref() {
+                let _unused = terminal.write_styled(piece);
+            }
+        }
+
+        match terminal.io {
+            IO::Test(ref output) => String::from_utf8_lossy(output).into_owned(),
+            _ => unreachable!(),
+        }
+    }
+
     pub fn new() -> InquireResult<Self> {
-        crossterm::terminal::enable_raw_mode()?;
+        if !super::in_session() {
+            terminal::enable_raw_mode()?;
+        }
 
         Ok(Self {
             io: IO::Std(stderr()),
         })
     }
 
+    /// Renders prompts to stderr without enabling raw mode, leaving the
+    /// terminal untouched while the answers are read from elsewhere, e.g.
+    /// from a response provider.
+    pub fn new_output_only() -> Self {
+        Self {
+            io: IO::Piped(stderr()),
+        }
+    }
+
+    pub(crate) fn new_mocked(state: Rc<RefCell<MockState>>) -> Self {
+        Self {
+            io: IO::Mocked(Vec::new(), state),
+        }
+    }
+
     fn get_writer(&mut self) -> &mut dyn Write {
         match &mut self.io {
-            IO::Std(w) => w,
-            IO::Test(w) => w,
+            IO::Std(w) | IO::Piped(w) => w,
+            IO::Test(w) | IO::Mocked(w, _) => w,
         }
     }
 
@@ -130,10 +223,39 @@ impl Terminal for CrosstermTerminal {
     }
 
     fn flush(&mut self) -> Result<()> {
+        // mocked output is only inspected through the captured frames
+        if let IO::Mocked(w, _) = &mut self.io {
+            w.clear();
+            return Ok(());
+        }
+
         self.get_writer().flush()
     }
 
+    fn captures_frames(&self) -> bool {
+        matches!(self.io, IO::Mocked(..))
+    }
+
+    fn capture_frame(&mut self, rows: &[&[Styled<String>]]) {
+        let IO::Mocked(_, state) = &self.io else {
+            return;
+        };
+
+        let text: Vec<String> = rows
+            .iter()
+            .map(|row| row.iter().map(|styled| styled.content.as_str()).collect())
+            .collect();
+
+        state
+            .borrow_mut()
+            .capture_frame(&text, Self::styled_frame(rows));
+    }
+
     fn get_size(&self) -> Result<super::TerminalSize> {
+        if let IO::Mocked(_, state) = &self.io {
+            return Ok(state.borrow().size());
+        }
+
         terminal::size().map(|(width, height)| super::TerminalSize::new(width, height))
     }
 
@@ -188,12 +310,78 @@ impl Drop for CrosstermTerminal {
     fn drop(&mut self) {
         let _unused = self.flush();
         let _unused = match self.io {
-            IO::Std(_) => terminal::disable_raw_mode(),
-            IO::Test(_) => Ok(()),
+            IO::Std(_) if !super::in_session() => terminal::disable_raw_mode(),
+            IO::Std(_) | IO::Piped(_) | IO::Test(_) | IO::Mocked(..) => Ok(()),
         };
     }
 }
 
+/// Queries the background color of the terminal with an OSC 11 escape
+/// sequence, returning the answer if the terminal sent one, along with the
+/// keys typed in the meantime. Raw mode must be enabled.
+///
+/// The query is followed by a cursor position request, whose answer comes
+/// after the OSC 11 one, if any, so that a late answer is drained here instead
+/// of being read as keys by the prompt. Crossterm writes that request to
+/// stdout, so nothing is queried unless both stdout and stderr are terminals.
+///
+/// Crossterm does not parse OSC answers, which are instead decoded into key
+/// events, e.g. `ESC ]` into Alt+], so the answer is rebuilt from them.
+#[cfg(all(unix, not(test)))]
+pub(crate) fn query_background_color() -> (Option<String>, Vec<Key>) {
+    use crossterm::tty::IsTty;
+    use std::io::stdout;
+
+    if !stdout().is_tty() || !stderr().is_tty() {
+        return (None, vec![]);
+    }
+
+    let mut stderr = stderr();
+    let sent = stderr
+        .write_all(b"\x1b]11;?\x07")
+        .and_then(|()| stderr.flush());
+    if sent.is_err() || cursor::position().is_err() {
+        return (None, vec![]);
+    }
+
+    let mut answer = None;
+    let mut partial_answer: Option<String> = None;
+    let mut keys = vec![];
+
+    while event::poll(Duration::ZERO).unwrap_or(false) {
+        let key_event = match event::read() {
+            Ok(event::Event::Key(key_event)) => key_event,
+            _ => continue,
+        };
+        let KeyEvent {
+            code, modifiers, ..
+        } = key_event;
+
+        match (partial_answer.as_mut(), code) {
+            (None, KeyCode::Char(']')) if modifiers.contains(KeyModifiers::ALT) => {
+                partial_answer = Some(String::from("\x1b]"));
+            }
+            (None, _) => keys.push(key_event.into()),
+            // BEL and ESC \ terminators, decoded as Ctrl+G and Alt+\
+            (Some(_), KeyCode::Char('g')) if modifiers == KeyModifiers::CONTROL => {
+                answer = partial_answer.take();
+            }
+            (Some(_), KeyCode::Char('\\')) if modifiers.contains(KeyModifiers::ALT) => {
+                answer = partial_answer.take();
+            }
+            (Some(partial), KeyCode::Char(c)) => {
+                if modifiers.contains(KeyModifiers::ALT) {
+                    partial.push('\x1b');
+                }
+                partial.push(c);
+            }
+            (Some(_), _) => partial_answer = None,
+        }
+    }
+
+    (answer, keys)
+}
+
 impl From<crate::ui::Color> for Color {
     fn from(c: crate::ui::Color) -> Self {
         use crate::ui::Color as C;
@@ -310,6 +498,11 @@ impl From<KeyEvent> for Key {
                 modifiers: m,
                 ..
             } => Self::Right(m.into()),
+            KeyEvent {
+                code: KeyCode::F(n),
+                modifiers: m,
+                ..
+            } => Self::F(n, m.into()),
             KeyEvent {
                 code: KeyCode::Char(c),
                 modifiers: m,
@@ -339,7 +532,9 @@ mod test {
 
         pub fn get_buffer_content(&mut self) -> Vec<u8> {
             match &mut self.io {
-                IO::Std(_) => panic!("Cannot get write buffer from standard output"),
+                IO::Std(_) | IO::Piped(_) | IO::Mocked(..) => {
+                    panic!("Cannot get write buffer from standard output")
+                }
                 IO::Test(w) => {
                     let mut buffer = Vec::new();
                     std::mem::swap(&mut buffer, w);
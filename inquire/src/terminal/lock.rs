@@ -0,0 +1,286 @@
+//! Exclusive access to the terminal, shared by all prompts of the process.
+
+use std::{
+    sync::{Condvar, Mutex, MutexGuard, PoisonError},
+    thread::{self, ThreadId},
+    time::Duration,
+};
+
+use crate::{
+    config::{get_busy_terminal_behavior, BusyTerminalBehavior},
+    error::{InquireError, InquireResult},
+    response::ResponseProvider,
+    ui::Key,
+};
+
+/// Thread using the terminal, along with the number of
+/// [sessions](crate::session) it is running and whether a prompt is running.
+struct Owner {
+    thread: ThreadId,
+    sessions: usize,
+    prompting: bool,
+}
+
+/// Current user of the terminal, if any.
+static TERMINAL_OWNER: Mutex<Option<Owner>> = Mutex::new(None);
+static TERMINAL_RELEASED: Condvar = Condvar::new();
+
+fn lock_owner() -> MutexGuard<'static, Option<Owner>> {
+    TERMINAL_OWNER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Waits for the terminal to be released by another thread, or fails with
+/// [`InquireError::TerminalBusy`], depending on the global
+/// [`BusyTerminalBehavior`].
+fn wait_for_release(
+    owner: MutexGuard<'static, Option<Owner>>,
+) -> InquireResult<MutexGuard<'static, Option<Owner>>> {
+    match get_busy_terminal_behavior() {
+        BusyTerminalBehavior::Fail => Err(InquireError::TerminalBusy),
+        BusyTerminalBehavior::Wait => Ok(TERMINAL_RELEASED
+            .wait(owner)
+            .unwrap_or_else(PoisonError::into_inner)),
+    }
+}
+
+/// Releases the terminal once its owner neither prompts nor runs a session.
+fn release_if_unused(owner: &mut Option<Owner>) {
+    if let Some(Owner {
+        sessions: 0,
+        prompting: false,
+        ..
+    }) = owner
+    {
+        *owner = None;
+        TERMINAL_RELEASED.notify_one();
+    }
+}
+
+/// Whether the current thread is running a [session](crate::session).
+#[cfg(any(feature = "crossterm", test))]
+pub(crate) fn owns_session() -> bool {
+    let current = thread::current().id();
+
+    matches!(&*lock_owner(), Some(owner) if owner.thread == current && owner.sessions > 0)
+}
+
+/// Guard of the exclusive access to the terminal, released when dropped.
+pub(crate) struct TerminalLock;
+
+impl TerminalLock {
+    /// Acquires the terminal, waiting for the prompt or session of another
+    /// thread to finish or failing with [`InquireError::TerminalBusy`],
+    /// depending on the global [`BusyTerminalBehavior`].
+    ///
+    /// Prompts run within a session of the current thread share its lock. A
+    /// prompt started while another one is running on the same thread, e.g.
+    /// from a callback, always fails, as waiting for it would never end.
+    pub fn acquire() -> InquireResult<Self> {
+        let current = thread::current().id();
+        let mut owner = lock_owner();
+
+        loop {
+            match owner.as_mut() {
+                None => {
+                    *owner = Some(Owner {
+                        thread: current,
+                        sessions: 0,
+                        prompting: true,
+                    });
+                    return Ok(Self);
+                }
+                Some(owned) if owned.thread == current => {
+                    if owned.prompting {
+                        return Err(InquireError::TerminalBusy);
+                    }
+                    owned.prompting = true;
+                    return Ok(Self);
+                }
+                Some(_) => owner = wait_for_release(owner)?,
+            }
+        }
+    }
+}
+
+impl Drop for TerminalLock {
+    fn drop(&mut self) {
+        let mut owner = lock_owner();
+        if let Some(owned) = owner.as_mut() {
+            owned.prompting = false;
+        }
+        release_if_unused(&mut owner);
+    }
+}
+
+/// Guard of the exclusive access to the terminal held by a
+/// [session](crate::session) for its whole lifetime, re-entrant for the
+/// thread running it.
+pub(crate) struct SessionLock;
+
+impl SessionLock {
+    /// Acquires the terminal for a session, waiting for the prompt or session
+    /// of another thread to finish or failing with
+    /// [`InquireError::TerminalBusy`], depending on the global
+    /// [`BusyTerminalBehavior`]. Sessions of the current thread can be nested.
+    pub fn acquire() -> InquireResult<Self> {
+        let current = thread::current().id();
+        let mut owner = lock_owner();
+
+        loop {
+            match owner.as_mut() {
+                None => {
+                    *owner = Some(Owner {
+                        thread: current,
+                        sessions: 1,
+                        prompting: false,
+                    });
+                    return Ok(Self);
+                }
+                Some(owned) if owned.thread == current => {
+                    owned.sessions += 1;
+                    return Ok(Self);
+                }
+                Some(_) => owner = wait_for_release(owner)?,
+            }
+        }
+    }
+}
+
+impl Drop for SessionLock {
+    fn drop(&mut self) {
+        let mut owner = lock_owner();
+        if let Some(owned) = owner.as_mut() {
+            owned.sessions -= 1;
+        }
+        release_if_unused(&mut owner);
+    }
+}
+
+/// Input reader holding the terminal lock, released once the reader is
+/// dropped along with the backend, after the terminal is restored.
+pub(crate) struct LockedInputReader {
+    inner: Box<dyn ResponseProvider>,
+    _lock: Option<TerminalLock>,
+}
+
+impl LockedInputReader {
+    pub fn new<P>(inner: P, lock: TerminalLock) -> Self
+    where
+        P: ResponseProvider + 'static,
+    {
+        Self {
+            inner: Box::new(inner),
+            _lock: Some(lock),
+        }
+    }
+
+    /// Input reader not holding the terminal lock, for prompts that do not
+    /// use the terminal, such as the ones run with a mock.
+    #[cfg(feature = "crossterm")]
+    pub fn unlocked<P>(inner: P) -> Self
+    where
+        P: ResponseProvider + 'static,
+    {
+        Self {
+            inner: Box::new(inner),
+            _lock: None,
+        }
+    }
+}
+
+impl ResponseProvider for LockedInputReader {
+    fn start_prompt(&mut self, message: &str) -> InquireResult<()> {
+        self.inner.start_prompt(message)
+    }
+
+    fn read_key(&mut self) -> InquireResult<Key> {
+        self.inner.read_key()
+    }
+
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        self.inner.read_key_timeout(timeout)
+    }
+
+    fn supports_read_timeout(&self) -> bool {
+        self.inner.supports_read_timeout()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use std::{
+        sync::{Mutex, MutexGuard, PoisonError},
+        thread,
+    };
+
+    use crate::error::InquireError;
+
+    use super::{owns_session, SessionLock, TerminalLock};
+
+    static SERIAL: Mutex<()> = Mutex::new(());
+
+    /// Runs the tests acquiring the terminal one at a time, as it is shared by
+    /// the whole process.
+    pub(crate) fn serial() -> MutexGuard<'static, ()> {
+        SERIAL.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    #[test]
+    fn terminal_can_only_be_acquired_once_at_a_time() {
+        let _serial = serial();
+        let lock = TerminalLock::acquire().unwrap();
+
+        assert!(matches!(
+            TerminalLock::acquire(),
+            Err(InquireError::TerminalBusy)
+        ));
+        assert!(matches!(
+            thread::spawn(TerminalLock::acquire).join().unwrap(),
+            Err(InquireError::TerminalBusy)
+        ));
+
+        drop(lock);
+
+        assert!(thread::spawn(|| TerminalLock::acquire().map(drop))
+            .join()
+            .unwrap()
+            .is_ok());
+    }
+
+    #[test]
+    fn sessions_hold_the_terminal_for_their_own_thread_only() {
+        let _serial = serial();
+        let session = SessionLock::acquire().unwrap();
+        assert!(owns_session());
+
+        let prompt = TerminalLock::acquire().unwrap();
+        assert!(matches!(
+            TerminalLock::acquire(),
+            Err(InquireError::TerminalBusy)
+        ));
+        drop(prompt);
+
+        assert!(thread::spawn(|| !owns_session()).join().unwrap());
+        assert!(matches!(
+            thread::spawn(TerminalLock::acquire).join().unwrap(),
+            Err(InquireError::TerminalBusy)
+        ));
+        assert!(matches!(
+            thread::spawn(SessionLock::acquire).join().unwrap(),
+            Err(InquireError::TerminalBusy)
+        ));
+
+        let nested = SessionLock::acquire().unwrap();
+        drop(nested);
+        assert!(owns_session());
+
+        drop(session);
+        assert!(!owns_session());
+        assert!(thread::spawn(|| TerminalLock::acquire().map(drop))
+            .join()
+            .unwrap()
+            .is_ok());
+    }
+}
@@ -1,7 +1,8 @@
 use std::{fmt::Display, io::Result};
 
 use crate::{
-    error::InquireResult,
+    error::{InquireError, InquireResult},
+    response::{has_global_response_provider, GlobalResponses},
     ui::{dimension::Dimension, InputReader, Styled},
 };
 
@@ -17,8 +18,68 @@ pub mod termion;
 #[cfg_attr(docsrs, doc(cfg(feature = "console")))]
 pub mod console;
 
+#[cfg(feature = "callback")]
+#[cfg_attr(docsrs, doc(cfg(feature = "callback")))]
+pub mod callback;
+
+pub(crate) mod lock;
+
+use self::lock::{LockedInputReader, SessionLock, TerminalLock};
+
 pub type TerminalSize = Dimension;
 
+/// Whether the current thread is running a [session](crate::session), in
+/// which case terminals leave raw mode to the session instead of toggling it
+/// for each prompt.
+#[cfg(any(feature = "crossterm", test))]
+pub(crate) fn in_session() -> bool {
+    lock::owns_session()
+}
+
+/// Holds the terminal until dropped, keeping it in raw mode for the prompts
+/// run in the meantime on the same thread to share it. Prompts of other
+/// threads wait for the session to end or fail, depending on the global
+/// [`BusyTerminalBehavior`](crate::BusyTerminalBehavior).
+///
+/// Raw mode is only toggled by the outermost session, and only when keys are
+/// read from the terminal, i.e. without a global response provider and when
+/// the process is [interactive](is_interactive).
+pub(crate) struct SessionGuard {
+    #[cfg(feature = "crossterm")]
+    raw_mode: bool,
+    _lock: SessionLock,
+}
+
+impl SessionGuard {
+    pub fn enter() -> InquireResult<Self> {
+        #[cfg(feature = "crossterm")]
+        let outermost = !in_session();
+        let lock = SessionLock::acquire()?;
+
+        let guard = Self {
+            #[cfg(feature = "crossterm")]
+            raw_mode: outermost && !has_global_response_provider() && is_interactive(),
+            _lock: lock,
+        };
+
+        #[cfg(feature = "crossterm")]
+        if guard.raw_mode {
+            ::crossterm::terminal::enable_raw_mode()?;
+        }
+
+        Ok(guard)
+    }
+}
+
+impl Drop for SessionGuard {
+    fn drop(&mut self) {
+        #[cfg(feature = "crossterm")]
+        if self.raw_mode {
+            let _unused = ::crossterm::terminal::disable_raw_mode();
+        }
+    }
+}
+
 pub trait Terminal: Sized {
     fn get_size(&self) -> Result<TerminalSize>;
 
@@ -34,21 +95,168 @@ pub trait Terminal: Sized {
     fn cursor_down(&mut self, cnt: u16) -> Result<()>;
     fn cursor_left(&mut self, cnt: u16) -> Result<()>;
     fn cursor_right(&mut self, cnt: u16) -> Result<()>;
+    #[allow(unused)]
     fn cursor_move_to_column(&mut self, idx: u16) -> Result<()>;
 
+    /// Sets the shape of the cursor with the DECSCUSR escape sequence,
+    /// where 0 restores the default shape of the terminal.
+    fn cursor_set_shape(&mut self, code: u8) -> Result<()> {
+        self.write(format!("\x1b[{} q", code))
+    }
+
     fn flush(&mut self) -> Result<()>;
+
+    /// Whether the rendered frames are recorded with
+    /// [`capture_frame`](Terminal::capture_frame), e.g. for tests.
+    fn captures_frames(&self) -> bool {
+        false
+    }
+
+    /// Records the styled content of each row of a rendered frame.
+    fn capture_frame(&mut self, rows: &[&[Styled<String>]]) {
+        let _ = rows;
+    }
+}
+
+/// Returns whether prompts can be displayed, i.e. whether the terminal back-end
+/// can both read keys from and render prompts to a terminal.
+///
+/// With the default `crossterm` back-end, stderr, where prompts are rendered,
+/// must be a terminal, and the process must have a controlling terminal to
+/// read keys from. When it does not, e.g. in CI, prompts fail with
+/// [`InquireError::NotTTY`], so CLIs can check beforehand and fall back to
+/// flags or environment variables instead. A piped stdin is fine: keys are
+/// still read from the controlling terminal, e.g. in `curl ... | sh`.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Text;
+///
+/// let name = match std::env::var("APP_NAME") {
+///     Ok(name) => name,
+///     Err(_) if inquire::is_interactive() => Text::new("Name:").prompt()?,
+///     Err(_) => {
+///         eprintln!("Set APP_NAME or run the command in a terminal");
+///         std::process::exit(2);
+///     }
+/// };
+/// # inquire::error::InquireResult::Ok(())
+/// ```
+///
+/// [`InquireError::NotTTY`]: crate::InquireError::NotTTY
+pub fn is_interactive() -> bool {
+    #[cfg(feature = "crossterm")]
+    {
+        use ::crossterm::tty::IsTty;
+
+        std::io::stderr().is_tty() && has_controlling_terminal()
+    }
+
+    #[cfg(all(feature = "termion", not(feature = "crossterm")))]
+    {
+        ::termion::get_tty().is_ok()
+    }
+
+    #[cfg(all(
+        feature = "console",
+        not(feature = "termion"),
+        not(feature = "crossterm")
+    ))]
+    {
+        ::console::Term::stderr().is_term()
+    }
+
+    #[cfg(all(
+        feature = "callback",
+        not(feature = "console"),
+        not(feature = "termion"),
+        not(feature = "crossterm")
+    ))]
+    {
+        callback::is_set()
+    }
+
+    #[cfg(all(
+        not(feature = "crossterm"),
+        not(feature = "termion"),
+        not(feature = "console"),
+        not(feature = "callback")
+    ))]
+    false
+}
+
+/// Whether the controlling terminal of the process can be opened, which is
+/// where crossterm reads keys from, even when stdin is piped.
+#[cfg(feature = "crossterm")]
+fn has_controlling_terminal() -> bool {
+    #[cfg(unix)]
+    let path = "/dev/tty";
+    #[cfg(windows)]
+    let path = "CONIN$";
+    #[cfg(not(any(unix, windows)))]
+    return false;
+
+    #[cfg(any(unix, windows))]
+    std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(path)
+        .is_ok()
 }
 
+/// Returns the terminal of the process, locked for the lifetime of the
+/// returned input reader, so that prompts running at the same time on
+/// different threads do not interleave their output.
+///
+/// Fails with [`InquireError::NotTTY`](crate::InquireError::NotTTY) right
+/// away when the process is not [interactive](is_interactive), unless a
+/// [global response provider](crate::response::set_global_response_provider)
+/// answers the prompts.
 pub fn get_default_terminal() -> InquireResult<(impl InputReader, impl Terminal)> {
     #[cfg(feature = "crossterm")]
-    return Ok((
-        crossterm::CrosstermKeyReader::new(),
-        crossterm::CrosstermTerminal::new()?,
-    ));
+    if let Some((keys, terminal)) = crate::testing::mocked_terminal() {
+        return Ok((LockedInputReader::unlocked(keys), terminal));
+    }
+
+    let provided = has_global_response_provider();
+
+    if !provided && !is_interactive() {
+        return Err(InquireError::NotTTY);
+    }
+
+    let lock = TerminalLock::acquire()?;
+
+    #[cfg(feature = "crossterm")]
+    return if provided {
+        Ok((
+            LockedInputReader::new(GlobalResponses, lock),
+            crossterm::CrosstermTerminal::new_output_only(),
+        ))
+    } else {
+        let terminal = crossterm::CrosstermTerminal::new()?;
+        let key_reader = crossterm::CrosstermKeyReader::new();
+
+        // queried under the lock and in raw mode, once per process
+        #[cfg(all(unix, not(test)))]
+        let key_reader = match crate::ui::TerminalBackground::needs_query() {
+            true => {
+                let (answer, typed_keys) = crossterm::query_background_color();
+                crate::ui::TerminalBackground::record_query_answer(answer.as_deref());
+                key_reader.with_pending_keys(typed_keys)
+            }
+            false => key_reader,
+        };
+
+        Ok((LockedInputReader::new(key_reader, lock), terminal))
+    };
 
     #[cfg(all(feature = "termion", not(feature = "crossterm")))]
     return Ok((
-        termion::TermionKeyReader::new()?,
+        match provided {
+            true => LockedInputReader::new(GlobalResponses, lock),
+            false => LockedInputReader::new(termion::TermionKeyReader::new()?, lock),
+        },
         termion::TermionTerminal::new()?,
     ));
 
@@ -59,22 +267,40 @@ pub fn get_default_terminal() -> InquireResult<(impl InputReader, impl Terminal)
     ))]
     {
         let console_terminal = console::ConsoleTerminal::new();
-        let console_key_reader = console_terminal.clone();
+        let console_key_reader = match provided {
+            true => LockedInputReader::new(GlobalResponses, lock),
+            false => LockedInputReader::new(console_terminal.clone(), lock),
+        };
         return Ok((console_key_reader, console_terminal));
     }
 
+    #[cfg(all(
+        feature = "callback",
+        not(feature = "console"),
+        not(feature = "termion"),
+        not(feature = "crossterm")
+    ))]
+    return Ok((
+        match provided {
+            true => LockedInputReader::new(GlobalResponses, lock),
+            false => LockedInputReader::new(callback::CallbackKeyReader::new(), lock),
+        },
+        callback::CallbackTerminal::new()?,
+    ));
+
     #[cfg(all(
         not(feature = "crossterm"),
         not(feature = "termion"),
-        not(feature = "console")
+        not(feature = "console"),
+        not(feature = "callback")
     ))]
     {
-        compile_error!("At least one of crossterm, termion or console must be enabled");
+        compile_error!("At least one of crossterm, termion, console or callback must be enabled");
 
         // this is here to silence an additional compilation error
         // when no terminals are enabled. it complains about mismatched
         // return types.
-        Err(crate::error::InquireError::InvalidConfiguration(
+        Err(InquireError::InvalidConfiguration(
             "Missing terminal backend".into(),
         ))
     }
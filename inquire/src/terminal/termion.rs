@@ -15,7 +15,8 @@ use termion::{
 
 use crate::{
     error::InquireResult,
-    ui::{Attributes, InputReader, Styled},
+    response::ResponseProvider,
+    ui::{Attributes, Styled},
 };
 
 use super::Terminal;
@@ -40,7 +41,7 @@ impl TermionKeyReader {
     }
 }
 
-impl InputReader for TermionKeyReader {
+impl ResponseProvider for TermionKeyReader {
     fn read_key(&mut self) -> InquireResult<crate::ui::Key> {
         loop {
             if let Some(key) = self.keys.next() {
@@ -196,11 +197,11 @@ impl<'a> Terminal for TermionTerminal<'a> {
     }
 
     fn cursor_hide(&mut self) -> Result<()> {
-        write!(self.get_writer(), "{}", termion::cursor::Hide)
+        write!(self.get_writer(), "{}", cursor::Hide)
     }
 
     fn cursor_show(&mut self) -> Result<()> {
-        write!(self.get_writer(), "{}", termion::cursor::Show)
+        write!(self.get_writer(), "{}", cursor::Show)
     }
 }
 
@@ -264,6 +265,7 @@ impl From<Key> for crate::ui::Key {
             Key::Down => Self::Down(KeyModifiers::empty()),
             Key::Left => Self::Left(KeyModifiers::empty()),
             Key::Right => Self::Right(KeyModifiers::empty()),
+            Key::F(n) => Self::F(n, KeyModifiers::empty()),
             Key::Char(c) => Self::Char(c, KeyModifiers::empty()),
             Key::Ctrl(c) => Self::Char(c, KeyModifiers::CONTROL),
             Key::Alt(c) => Self::Char(c, KeyModifiers::ALT),
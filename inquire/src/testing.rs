@@ -0,0 +1,286 @@
+//! Utilities to test code running prompts, without a terminal.
+//!
+//! A [`MockBackend`] scripts the keys pressed by the user and records the
+//! frames rendered by the prompts run within [`MockBackend::run`], on the
+//! current thread, instead of reading from and writing to the terminal.
+//!
+//! # Example
+//!
+//! ```
+//! use inquire::{testing::MockBackend, ui::{Key, KeyModifiers}, Select, Text};
+//!
+//! fn sign_up() -> inquire::error::InquireResult<(String, &'static str)> {
+//!     let name = Text::new("Name:").prompt()?;
+//!     let plan = Select::new("Plan:", vec!["Free", "Pro"]).prompt()?;
+//!
+//!     Ok((name, plan))
+//! }
+//!
+//! let mut mock = MockBackend::new()
+//!     .with_text("Ada")
+//!     .with_keys([Key::Enter, Key::Down(KeyModifiers::NONE), Key::Enter]);
+//!
+//! let (name, plan) = mock.run(sign_up).unwrap();
+//!
+//! assert_eq!("Ada", name);
+//! assert_eq!("Pro", plan);
+//! assert_eq!("> Plan: Pro", mock.last_frame().unwrap());
+//! assert!(mock.frames().iter().any(|frame| frame.contains("> Free")));
+//! ```
+
+use std::{cell::RefCell, collections::VecDeque, io, rc::Rc, time::Duration};
+
+use crate::{
+    error::{InquireError, InquireResult},
+    response::ResponseProvider,
+    terminal::{crossterm::CrosstermTerminal, TerminalSize},
+    ui::{Key, KeyModifiers},
+};
+
+thread_local! {
+    static MOCK: RefCell<Option<Rc<RefCell<MockState>>>> = const { RefCell::new(None) };
+}
+
+/// State shared between a [`MockBackend`] and the terminals of the prompts run
+/// with it.
+pub(crate) struct MockState {
+    keys: VecDeque<Key>,
+    frames: Vec<String>,
+    styled_frames: Vec<String>,
+    size: TerminalSize,
+}
+
+impl MockState {
+    pub fn size(&self) -> TerminalSize {
+        self.size
+    }
+
+    pub fn capture_frame(&mut self, rows: &[String], styled: String) {
+        let rows: Vec<&str> = rows.iter().map(|row| row.trim_end()).collect();
+        self.frames.push(rows.join("\n"));
+        self.styled_frames.push(styled);
+    }
+}
+
+/// Scripted replacement of the terminal for the prompts run within
+/// [`run`](MockBackend::run), on the current thread.
+///
+/// Prompts read the enqueued keys in order, failing with an
+/// [`InquireError::IO`] error of kind
+/// [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) once none are left. Every
+/// frame they render is recorded both as plain text, one line per row,
+/// without trailing whitespace, and with the ANSI escape codes of its styles.
+/// Timed features such as refreshes tick as if every key were pressed right
+/// away.
+pub struct MockBackend {
+    state: Rc<RefCell<MockState>>,
+}
+
+impl MockBackend {
+    /// Creates a mock without any keys, with a terminal of 80 columns by 24 rows.
+    pub fn new() -> Self {
+        Self {
+            state: Rc::new(RefCell::new(MockState {
+                keys: VecDeque::new(),
+                frames: vec![],
+                styled_frames: vec![],
+                size: TerminalSize::new(80, 24),
+            })),
+        }
+    }
+
+    /// Sets the size of the terminal, in columns and rows.
+    pub fn with_size(self, width: u16, height: u16) -> Self {
+        self.state.borrow_mut().size = TerminalSize::new(width, height);
+        self
+    }
+
+    /// Enqueues keys pressed by the user.
+    pub fn with_keys<K>(self, keys: K) -> Self
+    where
+        K: IntoIterator<Item = Key>,
+    {
+        self.state.borrow_mut().keys.extend(keys);
+        self
+    }
+
+    /// Enqueues the characters of the text, as typed by the user, without
+    /// submitting it.
+    pub fn with_text(self, text: &str) -> Self {
+        self.with_keys(text.chars().map(|c| Key::Char(c, KeyModifiers::NONE)))
+    }
+
+    /// Runs the given function, in which the prompts of the current thread
+    /// read the enqueued keys and record their frames instead of using the
+    /// terminal.
+    pub fn run<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        let previous = MOCK.with(|mock| mock.replace(Some(Rc::clone(&self.state))));
+        let _restore = RestoreMock(previous);
+
+        f()
+    }
+
+    /// Frames rendered so far, oldest first, including the final rendering of
+    /// the answered prompts.
+    pub fn frames(&self) -> Vec<String> {
+        self.state.borrow().frames.clone()
+    }
+
+    /// Frames rendered so far, oldest first, with the ANSI escape codes of
+    /// their styles, one line per row.
+    pub fn styled_frames(&self) -> Vec<String> {
+        self.state.borrow().styled_frames.clone()
+    }
+
+    /// Last frame rendered, e.g. the answered prompt once it is submitted.
+    pub fn last_frame(&self) -> Option<String> {
+        self.state.borrow().frames.last().cloned()
+    }
+
+    /// Number of enqueued keys not read by the prompts yet.
+    pub fn remaining_keys(&self) -> usize {
+        self.state.borrow().keys.len()
+    }
+}
+
+impl Default for MockBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Renders the first frame of a prompt to a string, with the ANSI escape codes
+/// of its styles, without a terminal.
+///
+/// The closure is run as in [`MockBackend::run`], without any keys, so the
+/// prompt fails as soon as it waits for the user, after rendering its initial
+/// frame. This is useful to preview render configs or to generate screenshots
+/// of prompts, e.g. in CI. Returns `None` when no frame is rendered, e.g. when
+/// the closure does not prompt at all.
+///
+/// # Example
+///
+/// ```
+/// use inquire::{testing::render_first_frame, Select};
+///
+/// let frame = render_first_frame(|| {
+///     Select::new("Plan:", vec!["Free", "Pro"])
+///         .without_help_message()
+///         .prompt()
+/// })
+/// .unwrap();
+///
+/// assert!(frame.contains("Plan:"));
+/// assert!(frame.contains("Pro"));
+/// ```
+pub fn render_first_frame<F, R>(prompt: F) -> Option<String>
+where
+    F: FnOnce() -> R,
+{
+    let mut mock = MockBackend::new();
+    mock.run(prompt);
+
+    mock.styled_frames().into_iter().next()
+}
+
+/// Keys enqueued in the mock, read by the prompts run with it.
+pub(crate) struct MockKeys(Rc<RefCell<MockState>>);
+
+impl ResponseProvider for MockKeys {
+    fn read_key(&mut self) -> InquireResult<Key> {
+        self.0.borrow_mut().keys.pop_front().ok_or_else(|| {
+            InquireError::IO(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "the mock backend has no more keys",
+            ))
+        })
+    }
+
+    fn read_key_timeout(&mut self, _timeout: Duration) -> InquireResult<Option<Key>> {
+        self.read_key().map(Some)
+    }
+
+    // keys are always available right away
+    fn supports_read_timeout(&self) -> bool {
+        true
+    }
+}
+
+/// Puts back the mock that was run on the current thread before a
+/// [`MockBackend::run`], even if the function run within it panics.
+struct RestoreMock(Option<Rc<RefCell<MockState>>>);
+
+impl Drop for RestoreMock {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        MOCK.with(|mock| mock.replace(previous));
+    }
+}
+
+/// Keys and terminal of the mock run on the current thread, if any.
+pub(crate) fn mocked_terminal() -> Option<(MockKeys, CrosstermTerminal)> {
+    MOCK.with(|mock| {
+        mock.borrow().as_ref().map(|state| {
+            (
+                MockKeys(Rc::clone(state)),
+                CrosstermTerminal::new_mocked(Rc::clone(state)),
+            )
+        })
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::InquireError,
+        ui::{Key, KeyModifiers},
+        Confirm, Text,
+    };
+
+    use super::{mocked_terminal, render_first_frame, MockBackend};
+
+    #[test]
+    fn prompts_fail_once_the_keys_run_out() {
+        let mut mock = MockBackend::new()
+            .with_text("y")
+            .with_keys([Key::Enter, Key::Char('x', KeyModifiers::NONE)]);
+
+        let confirmed = mock.run(|| Confirm::new("Continue?").prompt());
+        assert!(confirmed.unwrap());
+        assert_eq!(1, mock.remaining_keys());
+        assert_eq!("> Continue? Yes", mock.last_frame().unwrap());
+
+        let name = mock.run(|| Text::new("Name:").prompt());
+        assert!(
+            matches!(name, Err(InquireError::IO(err)) if err.kind() == std::io::ErrorKind::UnexpectedEof)
+        );
+        assert_eq!(0, mock.remaining_keys());
+        assert!(mock.frames().iter().any(|frame| frame == "? Name: x"));
+    }
+
+    #[test]
+    fn mock_is_removed_when_the_run_panics() {
+        let mut mock = MockBackend::new();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            mock.run(|| panic!("prompt failed"));
+        }));
+
+        assert!(result.is_err());
+        assert!(mocked_terminal().is_none());
+    }
+
+    #[test]
+    fn first_frame_is_rendered_with_styles() {
+        let frame = render_first_frame(|| Text::new("Name:").with_default("Ada").prompt()).unwrap();
+
+        assert!(frame.starts_with('\u{1b}'));
+        assert!(frame.contains("Name:"));
+        assert!(frame.contains("Ada"));
+
+        assert!(render_first_frame(|| ()).is_none());
+    }
+}
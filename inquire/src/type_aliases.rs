@@ -1,6 +1,6 @@
 //! General type aliases.
 
-use crate::error::CustomUserError;
+use crate::{error::CustomUserError, list_option::ListOption, ui::Key};
 
 /// Type alias to represent the function used to Score and filter options.
 ///
@@ -73,6 +73,90 @@ use crate::error::CustomUserError;
 /// ```
 pub type Scorer<'a, T> = &'a dyn Fn(&str, &T, &str, usize) -> Option<i64>;
 
+/// Type alias to represent the function called with the checked options of a
+/// [`MultiSelect`](crate::MultiSelect) prompt whenever they change, e.g. to
+/// keep an external preview up to date.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+///
+/// use inquire::type_aliases::SelectionChangeCallback;
+///
+/// let sizes = [120_u64, 45, 300];
+/// let total_size = Cell::new(0);
+///
+/// let on_change: SelectionChangeCallback<&str> = &|checked| {
+///     total_size.set(checked.iter().map(|option| sizes[option.index]).sum());
+/// };
+/// ```
+pub type SelectionChangeCallback<'a, T> = &'a dyn Fn(&[ListOption<&T>]);
+
+/// Type alias to represent the function computing the footer line rendered
+/// below the options of a [`MultiSelect`](crate::MultiSelect) prompt, from
+/// its checked options. Empty footers are not rendered.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::SelectionFooter;
+///
+/// let sizes = [120_u64, 45, 17];
+///
+/// let footer: SelectionFooter<&str> = &|checked| {
+///     let total: u64 = checked.iter().map(|option| sizes[option.index]).sum();
+///     format!("Total download: {} MB", total)
+/// };
+/// ```
+pub type SelectionFooter<'a, T> = &'a dyn Fn(&[ListOption<&T>]) -> String;
+
+/// Type alias to represent the function used to normalize a text answer before
+/// it is validated, formatted and returned, e.g. trimming whitespace or
+/// resolving aliases.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::StringMapper;
+///
+/// let mapper: StringMapper = &|ans| match ans.trim().to_lowercase().as_str() {
+///     "us" | "usa" => "United States".to_string(),
+///     other => other.to_string(),
+/// };
+///
+/// assert_eq!("United States", mapper(" USA "));
+/// assert_eq!("canada", mapper("Canada"));
+/// ```
+pub type StringMapper<'a> = &'a dyn Fn(&str) -> String;
+
+/// Type alias to represent the function used to transform characters as the
+/// user types them.
+///
+/// The function receives the typed character and the current content of the
+/// input, returning the text to write in its place, or `None` to ignore the
+/// character.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::InputTransformer;
+///
+/// // lowercase slugs, with spaces converted to dashes
+/// let slugify: InputTransformer = &|c, input| match c {
+///     ' ' | '-' if input.is_empty() || input.ends_with('-') => None,
+///     ' ' => Some("-".to_string()),
+///     c if c.is_alphanumeric() || c == '-' => Some(c.to_lowercase().collect()),
+///     _ => None,
+/// };
+///
+/// assert_eq!(Some("a".to_string()), slugify('A', "my-"));
+/// assert_eq!(Some("-".to_string()), slugify(' ', "my"));
+/// assert_eq!(None, slugify(' ', "my-"));
+/// assert_eq!(None, slugify('!', "my"));
+/// ```
+pub type InputTransformer<'a> = &'a dyn Fn(char, &str) -> Option<String>;
+
 /// Type alias to represent the function used to retrieve text input suggestions.
 /// The function receives the current input and should return a collection of strings
 /// containing the suggestions to be made to the user.
@@ -82,3 +166,101 @@ pub type Suggester<'a> = &'a dyn Fn(&str) -> Result<Vec<String>, CustomUserError
 /// The function receives the current input and should return the suggestion (if any)
 /// that will replace the current input.
 pub type Completer<'a> = &'a dyn Fn(&str) -> Result<Option<String>, CustomUserError>;
+
+/// Type alias to represent the function computing the details of the
+/// highlighted option of a [`Select`](crate::Select) prompt, rendered in a
+/// preview pane next to the options.
+///
+/// Lines of the returned text are rendered as rows of the pane, truncated to
+/// its width.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::OptionPreview;
+///
+/// struct Commit {
+///     hash: &'static str,
+///     message: &'static str,
+/// }
+///
+/// let preview: OptionPreview<Commit> = &|commit| format!("{}\n\n{}", commit.hash, commit.message);
+///
+/// let commit = Commit { hash: "43685e9", message: "Add a telemetry hook" };
+/// assert_eq!("43685e9\n\nAdd a telemetry hook", preview(&commit));
+/// ```
+pub type OptionPreview<'a, T> = &'a dyn Fn(&T) -> String;
+
+/// Type alias to represent the function computing the stable key identifying
+/// an option of a [`Select`](crate::Select) or [`MultiSelect`](crate::MultiSelect)
+/// prompt, defaulting to the string value of the option.
+///
+/// Keys keep the cursor and the checked options on the same options when the
+/// list is refreshed or reordered, select the default options, and identify
+/// the options in usage and selection stores. Their string value might change,
+/// e.g. when it includes a status.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::OptionKey;
+///
+/// struct Pod {
+///     name: String,
+///     status: String,
+/// }
+///
+/// let key: OptionKey<Pod> = &|pod| pod.name.clone();
+///
+/// let pod = Pod { name: "api-7f9c".into(), status: "Running".into() };
+/// assert_eq!("api-7f9c", key(&pod));
+/// ```
+pub type OptionKey<'a, T> = &'a dyn Fn(&T) -> String;
+
+/// Type alias to represent the function used to periodically refresh the
+/// options of a [`Select`](crate::Select) or [`MultiSelect`](crate::MultiSelect)
+/// prompt while it is displayed, e.g. with the latest status of each option.
+///
+/// Options are identified across refreshes by their [`OptionKey`], which
+/// keeps the cursor and the checked options on them when the list changes.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::OptionProvider;
+///
+/// let provider: OptionProvider<String> = &|| {
+///     let pods = ["api-7f9c", "worker-2b1d"];
+///     Ok(pods.iter().map(|pod| pod.to_string()).collect())
+/// };
+///
+/// assert_eq!(2, provider().unwrap().len());
+/// ```
+pub type OptionProvider<'a, T> = &'a dyn Fn() -> Result<Vec<T>, CustomUserError>;
+
+/// Type alias to represent the function called with the keys that a prompt
+/// does not handle, e.g. function keys or Alt combinations, so that
+/// applications can implement their own shortcuts.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::UnhandledKeyHandler;
+/// use inquire::ui::{Key, KeyModifiers};
+///
+/// let handler: UnhandledKeyHandler = &|key| {
+///     if key == Key::F(1, KeyModifiers::NONE) {
+///         println!("See https://docs.rs/inquire for help");
+///     }
+/// };
+/// ```
+pub type UnhandledKeyHandler<'a> = &'a (dyn Fn(Key) + Sync);
+
+/// Type alias to represent the answer of a [`CheckboxMatrix`](crate::CheckboxMatrix) prompt:
+/// every row, in the original order, paired with the columns selected for it.
+pub type MatrixAnswer<R, C> = Vec<(R, Vec<C>)>;
+
+/// Type alias to represent the answer of a [`Likert`](crate::Likert) prompt:
+/// every statement, in the original order, paired with the point of the scale
+/// chosen for it.
+pub type LikertAnswer<'a, S> = Vec<(S, ListOption<&'a str>)>;
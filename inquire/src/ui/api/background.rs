@@ -0,0 +1,180 @@
+//! Contains the detection of the terminal background.
+
+use std::env;
+
+use once_cell::sync::OnceCell;
+
+/// Background classified from the answer to the OSC 11 query, once sent.
+static QUERIED_BACKGROUND: OnceCell<Option<TerminalBackground>> = OnceCell::new();
+
+/// Brightness of the terminal background, used to pick a default theme
+/// whose colors are readable on it.
+///
+/// The background is read, in order of precedence, from:
+///
+/// - the `INQUIRE_BACKGROUND` environment variable, set to `light` or `dark`.
+///   Any other value disables the detection.
+/// - the `COLORFGBG` environment variable, set by some terminals such as
+///   rxvt and Konsole.
+/// - the answer of the terminal to an OSC 11 query, only sent with the
+///   `crossterm` backend on Unix, when both stdout and stderr are terminals.
+///   The query is sent once per process, when the first prompt acquires the
+///   terminal, so its answer only applies to the prompts created afterwards.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::ui::{RenderConfig, TerminalBackground};
+///
+/// // Skips the detection, e.g. in a tool whose users run a light theme.
+/// inquire::set_global_render_config(RenderConfig::for_background(TerminalBackground::Light));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TerminalBackground {
+    /// Dark background, e.g. black. Assumed when the background is unknown.
+    Dark,
+
+    /// Light background, e.g. white.
+    Light,
+}
+
+impl TerminalBackground {
+    /// Returns the detected background of the current terminal, if any.
+    ///
+    /// This never queries the terminal itself: the answer to the OSC 11 query
+    /// is only known once a prompt has acquired the terminal.
+    pub fn detect() -> Option<Self> {
+        match Self::is_set_by_env() {
+            true => Self::from_env(),
+            false => QUERIED_BACKGROUND.get().copied().flatten(),
+        }
+    }
+
+    /// Whether the terminal has to be queried for its background, which is
+    /// neither set by the environment nor queried already.
+    #[cfg_attr(not(all(feature = "crossterm", unix, not(test))), allow(dead_code))]
+    pub(crate) fn needs_query() -> bool {
+        !Self::is_set_by_env() && QUERIED_BACKGROUND.get().is_none()
+    }
+
+    /// Records the answer to the OSC 11 query, if the terminal sent one.
+    #[cfg_attr(not(all(feature = "crossterm", unix, not(test))), allow(dead_code))]
+    pub(crate) fn record_query_answer(answer: Option<&str>) {
+        let _unused = QUERIED_BACKGROUND.set(answer.and_then(Self::from_osc_response));
+    }
+
+    /// Whether the environment variables settle the background, or disable
+    /// its detection, in which case the terminal is not queried.
+    fn is_set_by_env() -> bool {
+        env::var_os("INQUIRE_BACKGROUND").is_some() || Self::from_colorfgbg_env().is_some()
+    }
+
+    /// Background set by the environment variables, if any.
+    fn from_env() -> Option<Self> {
+        if let Ok(value) = env::var("INQUIRE_BACKGROUND") {
+            return match value.to_ascii_lowercase().as_str() {
+                "light" => Some(Self::Light),
+                "dark" => Some(Self::Dark),
+                _ => None,
+            };
+        }
+
+        Self::from_colorfgbg_env()
+    }
+
+    fn from_colorfgbg_env() -> Option<Self> {
+        Self::from_colorfgbg(&env::var("COLORFGBG").ok()?)
+    }
+
+    /// Parses the value of the `COLORFGBG` environment variable, e.g. `15;0`,
+    /// whose last component is the palette index of the background color.
+    fn from_colorfgbg(value: &str) -> Option<Self> {
+        let background = value.rsplit(';').next()?.parse::<u8>().ok()?;
+
+        match background {
+            0..=6 | 8 => Some(Self::Dark),
+            7 | 9..=15 => Some(Self::Light),
+            _ => None,
+        }
+    }
+
+    /// Parses the answer to an OSC 11 query, e.g. `\x1b]11;rgb:ffff/ffff/ffff`,
+    /// from the relative luminance of the color.
+    fn from_osc_response(response: &str) -> Option<Self> {
+        let (_, color) = response.split_once("rgb:")?;
+        let mut channels = color.split('/').map(parse_color_channel);
+
+        let red = channels.next()??;
+        let green = channels.next()??;
+        let blue = channels.next()??;
+
+        let luminance = 0.2126 * red + 0.7152 * green + 0.0722 * blue;
+
+        if luminance > 0.5 {
+            Some(Self::Light)
+        } else {
+            Some(Self::Dark)
+        }
+    }
+}
+
+/// Parses a channel of an X11 color specification, of 1 to 4 hex digits,
+/// into the `0.0..=1.0` range.
+fn parse_color_channel(channel: &str) -> Option<f64> {
+    let digits: String = channel
+        .chars()
+        .take_while(char::is_ascii_hexdigit)
+        .collect();
+
+    if digits.is_empty() || digits.len() > 4 {
+        return None;
+    }
+
+    let value = u32::from_str_radix(&digits, 16).ok()?;
+    let max = (1u32 << (4 * digits.len())) - 1;
+
+    Some(f64::from(value) / f64::from(max))
+}
+
+#[cfg(test)]
+mod test {
+    use super::TerminalBackground;
+
+    #[test]
+    fn colorfgbg_background_is_the_last_component() {
+        assert_eq!(
+            Some(TerminalBackground::Dark),
+            TerminalBackground::from_colorfgbg("15;0")
+        );
+        assert_eq!(
+            Some(TerminalBackground::Light),
+            TerminalBackground::from_colorfgbg("0;default;15")
+        );
+        assert_eq!(
+            Some(TerminalBackground::Light),
+            TerminalBackground::from_colorfgbg("0;7")
+        );
+        assert_eq!(None, TerminalBackground::from_colorfgbg("15;default"));
+    }
+
+    #[test]
+    fn osc_response_is_classified_by_luminance() {
+        assert_eq!(
+            Some(TerminalBackground::Light),
+            TerminalBackground::from_osc_response("\x1b]11;rgb:ffff/ffff/ffff")
+        );
+        assert_eq!(
+            Some(TerminalBackground::Dark),
+            TerminalBackground::from_osc_response("\x1b]11;rgb:1e1e/1e1e/2e2e")
+        );
+        assert_eq!(
+            Some(TerminalBackground::Light),
+            TerminalBackground::from_osc_response("\x1b]11;rgb:fd/f6/e3")
+        );
+        assert_eq!(None, TerminalBackground::from_osc_response("\x1b]11;?"));
+        assert_eq!(
+            None,
+            TerminalBackground::from_osc_response("\x1b]11;rgb:ffff/ffff")
+        );
+    }
+}
@@ -0,0 +1,126 @@
+//! Contains the definition of terminal hyperlinks.
+
+use std::{env, fmt::Display};
+
+use once_cell::sync::Lazy;
+
+static HYPERLINKS_SUPPORTED: Lazy<bool> = Lazy::new(detect_hyperlink_support);
+
+/// Text rendered as a clickable terminal hyperlink, using the OSC 8 escape
+/// sequence, in terminals that support it.
+///
+/// In other terminals, only the text is rendered. Support is detected from
+/// the environment of the process, and can be forced on or off by setting
+/// the `FORCE_HYPERLINK` environment variable to `1` or `0`.
+///
+/// As hyperlinks implement [`Display`], they can be used as options of list
+/// prompts, in which case filtering only considers their text, or be
+/// formatted into messages and answers.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{ui::Hyperlink, Select};
+///
+/// let issues = vec![
+///     Hyperlink::new("#42 Crash on startup", "https://example.com/issues/42"),
+///     Hyperlink::new("#43 Typo in docs", "https://example.com/issues/43"),
+/// ];
+///
+/// let issue = Select::new("Issue to triage:", issues).prompt();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hyperlink<T> {
+    /// Text rendered to the user.
+    pub text: T,
+
+    /// Target of the hyperlink.
+    pub url: String,
+}
+
+impl<T> Hyperlink<T>
+where
+    T: Display,
+{
+    /// Creates a hyperlink with the given text and target URL.
+    pub fn new<U: Into<String>>(text: T, url: U) -> Self {
+        Self {
+            text,
+            url: url.into(),
+        }
+    }
+
+    /// Returns whether hyperlinks are rendered as such in the current terminal.
+    pub fn supported() -> bool {
+        *HYPERLINKS_SUPPORTED
+    }
+
+    fn render(&self, supported: bool) -> String {
+        if supported && !self.url.is_empty() {
+            format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", self.url, self.text)
+        } else {
+            self.text.to_string()
+        }
+    }
+}
+
+impl<T> Display for Hyperlink<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render(Self::supported()))
+    }
+}
+
+fn detect_hyperlink_support() -> bool {
+    if let Ok(force) = env::var("FORCE_HYPERLINK") {
+        return force != "0";
+    }
+
+    if ["WT_SESSION", "KONSOLE_VERSION", "DOMTERM"]
+        .iter()
+        .any(|var| env::var_os(var).is_some())
+    {
+        return true;
+    }
+
+    // VTE-based terminals support hyperlinks since 0.50
+    if let Ok(version) = env::var("VTE_VERSION") {
+        if version.parse::<u32>().map(|v| v >= 5000).unwrap_or(false) {
+            return true;
+        }
+    }
+
+    matches!(
+        env::var("TERM_PROGRAM").as_deref(),
+        Ok("iTerm.app" | "WezTerm" | "vscode" | "ghostty")
+    ) || matches!(
+        env::var("TERM").as_deref(),
+        Ok("xterm-kitty" | "alacritty" | "foot" | "xterm-ghostty")
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::Hyperlink;
+
+    #[test]
+    fn renders_osc8_sequence_when_supported() {
+        let link = Hyperlink::new("docs", "https://example.com");
+
+        assert_eq!(
+            "\x1b]8;;https://example.com\x1b\\docs\x1b]8;;\x1b\\",
+            link.render(true)
+        );
+    }
+
+    #[test]
+    fn falls_back_to_plain_text() {
+        assert_eq!(
+            "docs",
+            Hyperlink::new("docs", "https://example.com").render(false)
+        );
+        assert_eq!("docs", Hyperlink::new("docs", "").render(true));
+    }
+}
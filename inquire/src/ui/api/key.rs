@@ -1,49 +1,153 @@
+use std::fmt::Display;
+
 use bitflags::bitflags;
 
 // Using the same struct, but without importing, to cut prompts' direct dependencies to crossterm
 // https://github.com/crossterm-rs/crossterm/blob/e1260446e94e9a8f7809fef61dc1369b6f8d6e12/src/event.rs#L376-L385
 bitflags! {
+    /// Modifier keys held while a [Key] was pressed.
     #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
     pub struct KeyModifiers: u8 {
+        /// Shift key.
         const SHIFT = 0b0000_0001;
+        /// Control key.
         const CONTROL = 0b0000_0010;
+        /// Alt key.
         const ALT = 0b0000_0100;
+        /// Super key.
         const SUPER = 0b0000_1000;
+        /// Hyper key.
         const HYPER = 0b0001_0000;
+        /// Meta key.
         const META = 0b0010_0000;
+        /// No modifiers.
         const NONE = 0b0000_0000;
     }
 }
 
+/// Key event read from the terminal, as received by prompts.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Key {
+    /// Escape key.
     Escape,
+    /// Enter key.
     Enter,
+    /// Backspace key.
     Backspace,
+    /// Tab key.
     Tab,
+    /// Delete key.
     Delete(KeyModifiers),
+    /// Home key.
     Home,
+    /// End key.
     End,
+    /// Page up key.
     PageUp(KeyModifiers),
+    /// Page down key.
     PageDown(KeyModifiers),
+    /// Up arrow key.
     Up(KeyModifiers),
+    /// Down arrow key.
     Down(KeyModifiers),
+    /// Left arrow key.
     Left(KeyModifiers),
+    /// Right arrow key.
     Right(KeyModifiers),
+    /// Function key, e.g. `F(1, KeyModifiers::NONE)` for F1.
+    F(u8, KeyModifiers),
+    /// Character key.
     Char(char, KeyModifiers),
+    /// Any other key.
     #[deprecated(note = "If the key you want isn't mapped, please open a PR.")]
     Any,
 }
 
+impl Display for KeyModifiers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names = [
+            (Self::CONTROL, "Ctrl"),
+            (Self::ALT, "Alt"),
+            (Self::SHIFT, "Shift"),
+            (Self::SUPER, "Super"),
+            (Self::HYPER, "Hyper"),
+            (Self::META, "Meta"),
+        ];
+
+        let mut first = true;
+        for (modifier, name) in names {
+            if self.contains(modifier) {
+                if !first {
+                    write!(f, "+")?;
+                }
+                write!(f, "{}", name)?;
+                first = false;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Key {
+    /// Formats the key in a human-readable way, e.g. `Ctrl+Shift+Up` or `Alt+x`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        #[allow(deprecated)]
+        let (name, modifiers) = match self {
+            Self::Escape => ("Esc".into(), KeyModifiers::NONE),
+            Self::Enter => ("Enter".into(), KeyModifiers::NONE),
+            Self::Backspace => ("Backspace".into(), KeyModifiers::NONE),
+            Self::Tab => ("Tab".into(), KeyModifiers::NONE),
+            Self::Delete(m) => ("Delete".into(), *m),
+            Self::Home => ("Home".into(), KeyModifiers::NONE),
+            Self::End => ("End".into(), KeyModifiers::NONE),
+            Self::PageUp(m) => ("PageUp".into(), *m),
+            Self::PageDown(m) => ("PageDown".into(), *m),
+            Self::Up(m) => ("Up".into(), *m),
+            Self::Down(m) => ("Down".into(), *m),
+            Self::Left(m) => ("Left".into(), *m),
+            Self::Right(m) => ("Right".into(), *m),
+            Self::F(n, m) => (format!("F{}", n), *m),
+            Self::Char(' ', m) => ("Space".into(), *m),
+            Self::Char('\n' | '\r', m) => ("Enter".into(), *m),
+            Self::Char('\t', m) => ("Tab".into(), *m),
+            Self::Char(c, m) => (c.to_string(), *m),
+            Self::Any => ("Any".into(), KeyModifiers::NONE),
+        };
+
+        if modifiers.is_empty() {
+            write!(f, "{}", name)
+        } else {
+            write!(f, "{}+{}", modifiers, name)
+        }
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod key_test {
     use super::{Key, KeyModifiers};
 
     impl Key {
+        /// Converts each character of the string into a key press without modifiers.
         pub fn char_keys_from_str(s: &str) -> Vec<Self> {
             s.chars()
                 .map(|c| Key::Char(c, KeyModifiers::NONE))
                 .collect()
         }
     }
+
+    #[test]
+    fn keys_are_displayed_with_their_modifiers() {
+        assert_eq!("Enter", Key::Enter.to_string());
+        assert_eq!("a", Key::Char('a', KeyModifiers::NONE).to_string());
+        assert_eq!("Ctrl+s", Key::Char('s', KeyModifiers::CONTROL).to_string());
+        assert_eq!("F1", Key::F(1, KeyModifiers::NONE).to_string());
+        assert_eq!("Enter", Key::Char('\n', KeyModifiers::NONE).to_string());
+        assert_eq!("Shift+F12", Key::F(12, KeyModifiers::SHIFT).to_string());
+        assert_eq!(
+            "Ctrl+Alt+Space",
+            Key::Char(' ', KeyModifiers::ALT | KeyModifiers::CONTROL).to_string()
+        );
+        assert_eq!("Shift+Up", Key::Up(KeyModifiers::SHIFT).to_string());
+    }
 }
@@ -0,0 +1,195 @@
+use std::fmt::Debug;
+
+use crate::type_aliases::UnhandledKeyHandler;
+
+use super::{Key, KeyModifiers};
+
+/// Key bindings of the actions shared by all prompts: submitting,
+/// canceling and interrupting.
+///
+/// Keys not bound to any of these actions are handled by the prompt
+/// itself, e.g. written to the text input or used to move the cursor.
+/// Keys the prompt does not handle either are passed to the
+/// [unhandled key handler](KeyBindings::with_unhandled_key_handler), if any.
+/// When a key is bound to more than one action, submitting takes
+/// precedence over canceling, which takes precedence over interrupting.
+///
+/// Key bindings can be set for all prompts through
+/// [`set_global_key_bindings`](crate::set_global_key_bindings).
+///
+/// # Example
+///
+/// ```
+/// use inquire::ui::{Key, KeyBindings, KeyModifiers};
+///
+/// // Ctrl+S submits the prompt, Enter is handled by the prompt itself.
+/// let submit_keys = [Key::Char('s', KeyModifiers::CONTROL)];
+///
+/// let bindings = KeyBindings::default()
+///     .with_submit_keys(&submit_keys)
+///     .with_submit_requires_valid_input(true);
+///
+/// // F1 or ? list the key bindings of the current prompt.
+/// let help_keys = [Key::F(1, KeyModifiers::NONE), Key::Char('?', KeyModifiers::NONE)];
+///
+/// let bindings = bindings.with_help_keys(&help_keys);
+/// ```
+#[derive(Copy, Clone)]
+pub struct KeyBindings<'a> {
+    /// Keys that submit the current answer of the prompt.
+    pub submit: &'a [Key],
+
+    /// Keys that cancel the prompt with a graceful shutdown.
+    pub cancel: &'a [Key],
+
+    /// Keys that interrupt the prompt without a graceful shutdown.
+    pub interrupt: &'a [Key],
+
+    /// Keys that display an overlay listing the key bindings of the prompt,
+    /// dismissed by pressing any key. Empty by default.
+    pub help: &'a [Key],
+
+    /// Whether submit keys are ignored while the current answer does not
+    /// pass the validators of the prompt.
+    ///
+    /// When disabled, submitting an invalid answer displays the error
+    /// message returned by the validator instead.
+    pub submit_requires_valid_input: bool,
+
+    /// Function called with the keys that are neither bound to a shared
+    /// action nor handled by the prompt, e.g. function keys.
+    pub unhandled_key_handler: Option<UnhandledKeyHandler<'a>>,
+}
+
+impl<'a> KeyBindings<'a> {
+    /// Default submit keys: Enter and its emacs-style alternative, Ctrl+J.
+    pub const DEFAULT_SUBMIT_KEYS: &'static [Key] = &[
+        Key::Enter,
+        Key::Char('\n', KeyModifiers::NONE),
+        Key::Char('j', KeyModifiers::CONTROL),
+    ];
+
+    /// Default cancel keys: Escape and the emacs-style Ctrl+G and Ctrl+D.
+    pub const DEFAULT_CANCEL_KEYS: &'static [Key] = &[
+        Key::Escape,
+        Key::Char('g', KeyModifiers::CONTROL),
+        Key::Char('d', KeyModifiers::CONTROL),
+    ];
+
+    /// Default interrupt keys: Ctrl+C.
+    pub const DEFAULT_INTERRUPT_KEYS: &'static [Key] = &[Key::Char('c', KeyModifiers::CONTROL)];
+
+    /// Sets the keys that submit the prompt, replacing the current ones.
+    ///
+    /// An empty list disables submission through the keyboard.
+    pub fn with_submit_keys(mut self, keys: &'a [Key]) -> Self {
+        self.submit = keys;
+        self
+    }
+
+    /// Sets the keys that cancel the prompt, replacing the current ones.
+    pub fn with_cancel_keys(mut self, keys: &'a [Key]) -> Self {
+        self.cancel = keys;
+        self
+    }
+
+    /// Sets the keys that interrupt the prompt, replacing the current ones.
+    pub fn with_interrupt_keys(mut self, keys: &'a [Key]) -> Self {
+        self.interrupt = keys;
+        self
+    }
+
+    /// Sets the keys that display the help overlay, replacing the current ones.
+    ///
+    /// Keep in mind that character keys bound here, e.g. `?`, can no longer be
+    /// typed into the text inputs of prompts.
+    pub fn with_help_keys(mut self, keys: &'a [Key]) -> Self {
+        self.help = keys;
+        self
+    }
+
+    /// Sets whether submit keys are ignored while the current answer
+    /// does not pass the validators of the prompt.
+    pub fn with_submit_requires_valid_input(mut self, submit_requires_valid_input: bool) -> Self {
+        self.submit_requires_valid_input = submit_requires_valid_input;
+        self
+    }
+
+    /// Sets the function called with the keys that are neither bound to a
+    /// shared action nor handled by the prompt, allowing applications to
+    /// implement their own shortcuts, e.g. F1 to open an external help page.
+    pub fn with_unhandled_key_handler(mut self, handler: UnhandledKeyHandler<'a>) -> Self {
+        self.unhandled_key_handler = Some(handler);
+        self
+    }
+
+    /// Lists the key bindings of the actions shared by all prompts,
+    /// as displayed in the help overlay.
+    pub fn key_help(&self) -> Vec<KeyHelp> {
+        [
+            (self.submit, "submit"),
+            (self.cancel, "cancel"),
+            (self.interrupt, "interrupt"),
+            (self.help, "show this help"),
+        ]
+        .iter()
+        .filter(|(keys, _)| !keys.is_empty())
+        .map(|(keys, description)| KeyHelp::new(keys.iter().copied(), description))
+        .collect()
+    }
+}
+
+impl<'a> Default for KeyBindings<'a> {
+    fn default() -> Self {
+        Self {
+            submit: Self::DEFAULT_SUBMIT_KEYS,
+            cancel: Self::DEFAULT_CANCEL_KEYS,
+            interrupt: Self::DEFAULT_INTERRUPT_KEYS,
+            help: &[],
+            submit_requires_valid_input: false,
+            unhandled_key_handler: None,
+        }
+    }
+}
+
+impl<'a> Debug for KeyBindings<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyBindings")
+            .field("submit", &self.submit)
+            .field("cancel", &self.cancel)
+            .field("interrupt", &self.interrupt)
+            .field("help", &self.help)
+            .field(
+                "submit_requires_valid_input",
+                &self.submit_requires_valid_input,
+            )
+            .field(
+                "unhandled_key_handler",
+                &self.unhandled_key_handler.map(|_| "<function>"),
+            )
+            .finish()
+    }
+}
+
+/// Description of a key binding, as listed in the help overlay.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyHelp {
+    /// Keys that trigger the action.
+    pub keys: Vec<Key>,
+
+    /// Short description of the action, e.g. "move up".
+    pub description: &'static str,
+}
+
+impl KeyHelp {
+    /// Creates a new description of a key binding.
+    pub fn new<I>(keys: I, description: &'static str) -> Self
+    where
+        I: IntoIterator<Item = Key>,
+    {
+        Self {
+            keys: keys.into_iter().collect(),
+            description,
+        }
+    }
+}
@@ -1,9 +1,15 @@
+mod background;
 mod color;
+mod hyperlink;
 mod key;
+mod key_bindings;
 mod render_config;
 mod style;
 
+pub use background::*;
 pub use color::*;
-pub(crate) use key::*;
+pub use hyperlink::*;
+pub use key::*;
+pub use key_bindings::*;
 pub use render_config::*;
 pub use style::*;
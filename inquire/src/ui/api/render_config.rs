@@ -1,6 +1,6 @@
 use std::env;
 
-use super::{Color, StyleSheet, Styled};
+use super::{Attributes, Color, StyleSheet, Styled, TerminalBackground};
 
 /// Rendering configuration that can be applied to a prompt.
 ///
@@ -33,6 +33,19 @@ pub struct RenderConfig<'a> {
     /// and the prompt message.
     pub answered_prompt_prefix: Styled<&'a str>,
 
+    /// Prefix added before prompts while an error message, e.g. from a
+    /// failed validation, is displayed.
+    ///
+    /// Note: a space character will be added to separate the prefix
+    /// and the prompt message.
+    pub errored_prompt_prefix: Styled<&'a str>,
+
+    /// Prefix added before canceled or interrupted prompts.
+    ///
+    /// Note: a space character will be added to separate the prefix
+    /// and the prompt message.
+    pub canceled_prompt_prefix: Styled<&'a str>,
+
     /// Style of the prompt message, applicable to all prompt types.
     pub prompt: StyleSheet,
 
@@ -43,6 +56,10 @@ pub struct RenderConfig<'a> {
     /// and after the default value, as separators.
     pub default_value: StyleSheet,
 
+    /// Render configuration of the current value of a setting being edited,
+    /// displayed wrapped in brackets, e.g. `[current: 30s]`.
+    pub current_value: StyleSheet,
+
     /// Render configuration of placeholders.
     ///
     /// Note: placeholders are displayed wrapped in parenthesis, e.g. (yes).
@@ -55,6 +72,22 @@ pub struct RenderConfig<'a> {
     /// Note: help messages are displayed wrapped in brackets, e.g. [Be careful!].
     pub help_message: StyleSheet,
 
+    /// Render configuration of the filter header line of list prompts, showing
+    /// the current filter text and the number of matching options.
+    pub filter_header: StyleSheet,
+
+    /// Render configuration of the footer line of list prompts, computed from
+    /// the current selections, e.g. `Total download: 182 MB`.
+    pub footer: StyleSheet,
+
+    /// Render configuration of the titles of section headers, rendered
+    /// between groups of prompts with [`section`](crate::section).
+    pub section_title: StyleSheet,
+
+    /// Token repeated to draw the horizontal rule of section headers and
+    /// [`separator`](crate::separator)s.
+    pub section_rule: Styled<&'a str>,
+
     /// Character used to mask password text inputs when in mode
     /// [`Masked`](crate::prompts::PasswordDisplayMode).
     ///
@@ -75,7 +108,9 @@ pub struct RenderConfig<'a> {
     pub answer: StyleSheet,
 
     /// Render configuration of the message printed in the place of an answer
-    /// when the prompt is canceled by the user - by pressing ESC.
+    /// when the prompt is canceled by the user - by pressing ESC - interrupted
+    /// with Ctrl+C or times out, so that the transcript shows which questions
+    /// were skipped.
     ///
     /// Note: a non-styled space character is added before the indicator as
     /// a separator from the prompt message.
@@ -84,6 +119,18 @@ pub struct RenderConfig<'a> {
     /// Render configuration for error messages.
     pub error_message: ErrorMessageRenderConfig<'a>,
 
+    /// Render configuration of informational lines printed between prompts
+    /// with [`info`](crate::info).
+    pub info_message: MessageRenderConfig<'a>,
+
+    /// Render configuration of warning lines printed between prompts
+    /// with [`warn`](crate::warn).
+    pub warning_message: MessageRenderConfig<'a>,
+
+    /// Render configuration of success lines printed between prompts
+    /// with [`success`](crate::success).
+    pub success_message: MessageRenderConfig<'a>,
+
     /// Prefix for the current highlighted option.
     ///
     /// Note: a space character will be added to separate the prefix
@@ -118,6 +165,12 @@ pub struct RenderConfig<'a> {
     /// option value to the right.
     pub unselected_checkbox: Styled<&'a str>,
 
+    /// Symbol representing a point reached by the current value in rating prompts.
+    pub filled_rating_symbol: Styled<&'a str>,
+
+    /// Symbol representing a point not reached by the current value in rating prompts.
+    pub empty_rating_symbol: Styled<&'a str>,
+
     /// Definition of index prefixes in option lists.
     pub option_index_prefix: IndexPrefix,
 
@@ -134,6 +187,91 @@ pub struct RenderConfig<'a> {
     /// a separator from the prefix.
     pub selected_option: Option<StyleSheet>,
 
+    /// Style sheet applied on top of the option style to the characters
+    /// matching the filter, in prompts highlighting fuzzy matches.
+    pub option_match: StyleSheet,
+
+    /// Render configuration for diffs displayed in diff approval prompts.
+    pub diff: DiffRenderConfig,
+
+    /// Render configuration for the results of quizzes, displayed after a
+    /// [`Select`](crate::Select) or [`MultiSelect`](crate::MultiSelect) quiz
+    /// is answered.
+    pub quiz: QuizRenderConfig<'a>,
+
+    /// Notification emitted when a prompt is first displayed, useful to draw
+    /// the attention of the user after a long non-interactive phase.
+    pub prompt_start_notification: Notification,
+
+    /// Notification emitted when an error message, e.g. from a failed
+    /// validation, is displayed.
+    pub error_notification: Notification,
+
+    /// Width of East Asian ambiguous-width characters, such as `±` or `°`,
+    /// used for cursor positioning, wrapping and alignment.
+    ///
+    /// Should match the setting of the terminal, usually wide in CJK locales.
+    pub ambiguous_width: AmbiguousWidth,
+
+    /// Where to place the ellipsis when options are too wide to fit in the
+    /// terminal, or in their column in table-like prompts.
+    ///
+    /// When `None`, long options wrap to the following lines.
+    pub option_truncation: Option<Truncation>,
+
+    /// Terminal width, in columns, below which prompts are rendered in a
+    /// compact layout, e.g. on phones over SSH: prompt prefixes and help
+    /// messages are omitted, and options are truncated at the end when
+    /// [`option_truncation`](RenderConfig::option_truncation) is `None`.
+    ///
+    /// Defaults to 50 columns. `None` disables the compact layout.
+    pub compact_layout_threshold: Option<u16>,
+
+    /// Whether ANSI escape sequences contained in options, messages and
+    /// answers, e.g. pre-colored `git log` lines, are passed through to the
+    /// terminal. They never count towards the width of the text, nor are they
+    /// considered when filtering options.
+    ///
+    /// When `false`, escape sequences are removed from the rendered text,
+    /// except for hyperlinks.
+    pub ansi_passthrough: bool,
+
+    /// Whether the rendered text is restricted to ASCII characters, for
+    /// environments without UTF-8 support such as minimal containers and
+    /// serial consoles.
+    ///
+    /// When `true`, glyphs such as arrows in help messages, check marks and
+    /// box drawing characters are replaced by look-alike ASCII characters,
+    /// and any other non-ASCII character by `?`.
+    pub ascii_only: bool,
+
+    /// Style of the terminal cursor while it is placed in a text input,
+    /// e.g. the answer of a `Text` prompt or the filter of a list.
+    pub text_input_cursor: CursorStyle,
+
+    /// Style of the terminal cursor while no text input has focus,
+    /// e.g. when navigating a calendar.
+    pub navigation_cursor: CursorStyle,
+
+    /// Maximum number of frames rendered per second. Key presses are still
+    /// handled one by one, but redraws are coalesced, which helps on slow
+    /// connections when keys are held down and auto-repeated.
+    ///
+    /// Only effective when the terminal backend supports reading keys with
+    /// a timeout, as the crossterm one does. Defaults to `None`, uncapped.
+    pub max_frame_rate: Option<u16>,
+
+    /// Whether prompt and help messages are parsed for inline markup:
+    /// `**bold**`, `*italic*`, `` `code` `` and `{red}colored{/}` text,
+    /// with any [`Color`] name such as `red`, `lightblue` or `darkgrey`.
+    ///
+    /// Markers without a matching closing marker are rendered as-is, and
+    /// markers can be escaped with a backslash, e.g. `\*`.
+    pub inline_markup: bool,
+
+    /// Style sheet of code spans in messages when inline markup is enabled.
+    pub code_span: StyleSheet,
+
     /// Render configuration for calendar
 
     #[cfg(feature = "date")]
@@ -149,17 +287,31 @@ pub struct RenderConfig<'a> {
 }
 
 impl<'a> RenderConfig<'a> {
+    /// Default terminal width, in columns, below which prompts are rendered
+    /// in a compact layout.
+    pub const DEFAULT_COMPACT_LAYOUT_THRESHOLD: u16 = 50;
+
     /// RenderConfig in which no colors or attributes are applied.
     pub fn empty() -> Self {
         Self {
             prompt_prefix: Styled::new("?"),
             answered_prompt_prefix: Styled::new("?"),
+            errored_prompt_prefix: Styled::new("?"),
+            canceled_prompt_prefix: Styled::new("?"),
             prompt: StyleSheet::empty(),
             default_value: StyleSheet::empty(),
+            current_value: StyleSheet::empty(),
             placeholder: StyleSheet::empty(),
             help_message: StyleSheet::empty(),
+            filter_header: StyleSheet::empty(),
+            footer: StyleSheet::empty(),
+            section_title: StyleSheet::empty(),
+            section_rule: Styled::new("─"),
             text_input: StyleSheet::empty(),
             error_message: ErrorMessageRenderConfig::empty(),
+            info_message: MessageRenderConfig::new(Styled::new("i")),
+            warning_message: MessageRenderConfig::new(Styled::new("!")),
+            success_message: MessageRenderConfig::new(Styled::new("✓")),
             answer: StyleSheet::empty(),
             canceled_prompt_indicator: Styled::new("<canceled>"),
             password_mask: '*',
@@ -168,9 +320,26 @@ impl<'a> RenderConfig<'a> {
             scroll_down_prefix: Styled::new("v"),
             selected_checkbox: Styled::new("[x]"),
             unselected_checkbox: Styled::new("[ ]"),
+            filled_rating_symbol: Styled::new("★"),
+            empty_rating_symbol: Styled::new("☆"),
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
             selected_option: None,
+            option_match: StyleSheet::empty(),
+            diff: DiffRenderConfig::empty(),
+            quiz: QuizRenderConfig::empty(),
+            prompt_start_notification: Notification::None,
+            error_notification: Notification::None,
+            ambiguous_width: AmbiguousWidth::Narrow,
+            option_truncation: None,
+            compact_layout_threshold: Some(Self::DEFAULT_COMPACT_LAYOUT_THRESHOLD),
+            ansi_passthrough: false,
+            ascii_only: false,
+            text_input_cursor: CursorStyle::Default,
+            navigation_cursor: CursorStyle::Default,
+            max_frame_rate: None,
+            inline_markup: false,
+            code_span: StyleSheet::empty(),
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::empty(),
@@ -185,12 +354,29 @@ impl<'a> RenderConfig<'a> {
         Self {
             prompt_prefix: Styled::new("?").with_fg(Color::LightGreen),
             answered_prompt_prefix: Styled::new(">").with_fg(Color::LightGreen),
+            errored_prompt_prefix: Styled::new("?").with_fg(Color::LightGreen),
+            canceled_prompt_prefix: Styled::new("?").with_fg(Color::LightGreen),
             prompt: StyleSheet::empty(),
-            default_value: StyleSheet::empty(),
+            default_value: StyleSheet::new().with_fg(Color::DarkGrey),
+            current_value: StyleSheet::new()
+                .with_fg(Color::DarkGrey)
+                .with_attr(Attributes::ITALIC),
             placeholder: StyleSheet::new().with_fg(Color::DarkGrey),
             help_message: StyleSheet::empty().with_fg(Color::LightCyan),
+            filter_header: StyleSheet::empty()
+                .with_fg(Color::DarkGrey)
+                .with_attr(Attributes::ITALIC),
+            footer: StyleSheet::empty().with_fg(Color::DarkGrey),
+            section_title: StyleSheet::empty()
+                .with_fg(Color::LightCyan)
+                .with_attr(Attributes::BOLD),
+            section_rule: Styled::new("─").with_fg(Color::DarkGrey),
             text_input: StyleSheet::empty(),
             error_message: ErrorMessageRenderConfig::default_colored(),
+            info_message: MessageRenderConfig::new(Styled::new("i").with_fg(Color::LightCyan)),
+            warning_message: MessageRenderConfig::new(Styled::new("!").with_fg(Color::LightYellow))
+                .with_message(StyleSheet::empty().with_fg(Color::LightYellow)),
+            success_message: MessageRenderConfig::new(Styled::new("✓").with_fg(Color::LightGreen)),
             password_mask: '*',
             answer: StyleSheet::empty().with_fg(Color::LightCyan),
             canceled_prompt_indicator: Styled::new("<canceled>").with_fg(Color::DarkRed),
@@ -199,9 +385,28 @@ impl<'a> RenderConfig<'a> {
             scroll_down_prefix: Styled::new("v"),
             selected_checkbox: Styled::new("[x]").with_fg(Color::LightGreen),
             unselected_checkbox: Styled::new("[ ]"),
+            filled_rating_symbol: Styled::new("★").with_fg(Color::LightYellow),
+            empty_rating_symbol: Styled::new("☆").with_fg(Color::DarkGrey),
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
             selected_option: Some(StyleSheet::new().with_fg(Color::LightCyan)),
+            option_match: StyleSheet::new()
+                .with_fg(Color::LightGreen)
+                .with_attr(Attributes::BOLD),
+            diff: DiffRenderConfig::default_colored(),
+            quiz: QuizRenderConfig::default_colored(),
+            prompt_start_notification: Notification::None,
+            error_notification: Notification::None,
+            ambiguous_width: AmbiguousWidth::Narrow,
+            option_truncation: None,
+            compact_layout_threshold: Some(Self::DEFAULT_COMPACT_LAYOUT_THRESHOLD),
+            ansi_passthrough: false,
+            ascii_only: false,
+            text_input_cursor: CursorStyle::Default,
+            navigation_cursor: CursorStyle::Default,
+            max_frame_rate: None,
+            inline_markup: false,
+            code_span: StyleSheet::new().with_fg(Color::LightYellow),
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::default_colored(),
@@ -211,6 +416,68 @@ impl<'a> RenderConfig<'a> {
         }
     }
 
+    /// Variant of [`RenderConfig::default_colored`] readable on light
+    /// terminal backgrounds, where the light colors are replaced by their
+    /// dark counterparts.
+    pub fn default_colored_light() -> Self {
+        Self {
+            prompt_prefix: Styled::new("?").with_fg(Color::DarkGreen),
+            answered_prompt_prefix: Styled::new(">").with_fg(Color::DarkGreen),
+            errored_prompt_prefix: Styled::new("?").with_fg(Color::DarkGreen),
+            canceled_prompt_prefix: Styled::new("?").with_fg(Color::DarkGreen),
+            help_message: StyleSheet::empty().with_fg(Color::DarkCyan),
+            section_title: StyleSheet::empty()
+                .with_fg(Color::DarkCyan)
+                .with_attr(Attributes::BOLD),
+            error_message: ErrorMessageRenderConfig::default_colored()
+                .with_prefix(Styled::new("#").with_fg(Color::DarkRed))
+                .with_message(StyleSheet::empty().with_fg(Color::DarkRed)),
+            info_message: MessageRenderConfig::new(Styled::new("i").with_fg(Color::DarkCyan)),
+            warning_message: MessageRenderConfig::new(Styled::new("!").with_fg(Color::DarkYellow))
+                .with_message(StyleSheet::empty().with_fg(Color::DarkYellow)),
+            success_message: MessageRenderConfig::new(Styled::new("✓").with_fg(Color::DarkGreen)),
+            answer: StyleSheet::empty().with_fg(Color::DarkCyan),
+            highlighted_option_prefix: Styled::new(">").with_fg(Color::DarkCyan),
+            selected_checkbox: Styled::new("[x]").with_fg(Color::DarkGreen),
+            filled_rating_symbol: Styled::new("★").with_fg(Color::DarkYellow),
+            selected_option: Some(StyleSheet::new().with_fg(Color::DarkCyan)),
+            option_match: StyleSheet::new()
+                .with_fg(Color::DarkGreen)
+                .with_attr(Attributes::BOLD),
+            diff: DiffRenderConfig::default_colored()
+                .with_added(StyleSheet::empty().with_fg(Color::DarkGreen))
+                .with_removed(StyleSheet::empty().with_fg(Color::DarkRed)),
+            quiz: QuizRenderConfig {
+                correct_marker: Styled::new("✓").with_fg(Color::DarkGreen),
+                wrong_marker: Styled::new("✗").with_fg(Color::DarkRed),
+                correct_option: StyleSheet::empty().with_fg(Color::DarkGreen),
+                wrong_option: StyleSheet::empty().with_fg(Color::DarkRed),
+            },
+            code_span: StyleSheet::new().with_fg(Color::DarkYellow),
+
+            #[cfg(feature = "date")]
+            calendar: calendar::CalendarRenderConfig {
+                prefix: Styled::new(">").with_fg(Color::DarkGreen),
+                today_date: StyleSheet::empty().with_fg(Color::DarkGreen),
+                ..calendar::CalendarRenderConfig::default_colored()
+            },
+
+            ..Self::default_colored()
+        }
+    }
+
+    /// Colored render configuration readable on the given terminal background.
+    ///
+    /// [`RenderConfig::default`] uses it with the
+    /// [detected background](TerminalBackground::detect), if any, without
+    /// querying the terminal itself.
+    pub fn for_background(background: TerminalBackground) -> Self {
+        match background {
+            TerminalBackground::Dark => Self::default_colored(),
+            TerminalBackground::Light => Self::default_colored_light(),
+        }
+    }
+
     /// Sets the prompt prefix and its style sheet.
     pub fn with_prompt_prefix(mut self, prompt_prefix: Styled<&'a str>) -> Self {
         self.prompt_prefix = prompt_prefix;
@@ -223,6 +490,18 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the prefix of prompts displaying an error message and its style sheet.
+    pub fn with_errored_prompt_prefix(mut self, errored_prompt_prefix: Styled<&'a str>) -> Self {
+        self.errored_prompt_prefix = errored_prompt_prefix;
+        self
+    }
+
+    /// Sets the prefix of canceled or interrupted prompts and its style sheet.
+    pub fn with_canceled_prompt_prefix(mut self, canceled_prompt_prefix: Styled<&'a str>) -> Self {
+        self.canceled_prompt_prefix = canceled_prompt_prefix;
+        self
+    }
+
     /// Sets style for text inputs.
     pub fn with_text_input(mut self, text_input: StyleSheet) -> Self {
         self.text_input = text_input;
@@ -235,12 +514,43 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the style sheet for the current value of a setting being edited.
+    pub fn with_current_value(mut self, current_value: StyleSheet) -> Self {
+        self.current_value = current_value;
+        self
+    }
+
     /// Sets the style sheet for help messages.
     pub fn with_help_message(mut self, help_message: StyleSheet) -> Self {
         self.help_message = help_message;
         self
     }
 
+    /// Sets the style sheet for the filter header line of list prompts.
+    pub fn with_filter_header(mut self, filter_header: StyleSheet) -> Self {
+        self.filter_header = filter_header;
+        self
+    }
+
+    /// Sets the style sheet for the footer line of list prompts.
+    pub fn with_footer(mut self, footer: StyleSheet) -> Self {
+        self.footer = footer;
+        self
+    }
+
+    /// Sets the style sheet for the titles of section headers.
+    pub fn with_section_title(mut self, section_title: StyleSheet) -> Self {
+        self.section_title = section_title;
+        self
+    }
+
+    /// Sets the token repeated to draw the horizontal rule of section headers
+    /// and separators.
+    pub fn with_section_rule(mut self, section_rule: Styled<&'a str>) -> Self {
+        self.section_rule = section_rule;
+        self
+    }
+
     /// Sets the style sheet for answers.
     pub fn with_answer(mut self, answer: StyleSheet) -> Self {
         self.answer = answer;
@@ -253,6 +563,24 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the render configuration of informational lines.
+    pub fn with_info_message(mut self, info_message: MessageRenderConfig<'a>) -> Self {
+        self.info_message = info_message;
+        self
+    }
+
+    /// Sets the render configuration of warning lines.
+    pub fn with_warning_message(mut self, warning_message: MessageRenderConfig<'a>) -> Self {
+        self.warning_message = warning_message;
+        self
+    }
+
+    /// Sets the render configuration of success lines.
+    pub fn with_success_message(mut self, success_message: MessageRenderConfig<'a>) -> Self {
+        self.success_message = success_message;
+        self
+    }
+
     /// Sets the styled component for prefixes in highlighted options.
     pub fn with_highlighted_option_prefix(
         mut self,
@@ -286,6 +614,18 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the styled component for filled points in rating prompts.
+    pub fn with_filled_rating_symbol(mut self, filled_rating_symbol: Styled<&'a str>) -> Self {
+        self.filled_rating_symbol = filled_rating_symbol;
+        self
+    }
+
+    /// Sets the styled component for empty points in rating prompts.
+    pub fn with_empty_rating_symbol(mut self, empty_rating_symbol: Styled<&'a str>) -> Self {
+        self.empty_rating_symbol = empty_rating_symbol;
+        self
+    }
+
     /// Sets the index prefix for option lists.
     pub fn with_option_index_prefix(mut self, index_prefix: IndexPrefix) -> Self {
         self.option_index_prefix = index_prefix;
@@ -304,7 +644,14 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
-    /// Sets the indicator for canceled prompts.
+    /// Sets the style sheet applied to the characters of options matching the filter.
+    pub fn with_option_match(mut self, option_match: StyleSheet) -> Self {
+        self.option_match = option_match;
+        self
+    }
+
+    /// Sets the indicator, text and style, printed in the place of an answer
+    /// for canceled prompts.
     pub fn with_canceled_prompt_indicator(
         mut self,
         canceled_prompt_indicator: Styled<&'a str>,
@@ -313,6 +660,91 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the render configuration for diffs.
+    pub fn with_diff_config(mut self, diff: DiffRenderConfig) -> Self {
+        self.diff = diff;
+        self
+    }
+
+    /// Sets the render configuration for the results of quizzes.
+    pub fn with_quiz_config(mut self, quiz: QuizRenderConfig<'a>) -> Self {
+        self.quiz = quiz;
+        self
+    }
+
+    /// Sets the notification emitted when a prompt is first displayed.
+    pub fn with_prompt_start_notification(mut self, notification: Notification) -> Self {
+        self.prompt_start_notification = notification;
+        self
+    }
+
+    /// Sets the notification emitted when an error message is displayed.
+    pub fn with_error_notification(mut self, notification: Notification) -> Self {
+        self.error_notification = notification;
+        self
+    }
+
+    /// Sets the width of East Asian ambiguous-width characters.
+    pub fn with_ambiguous_width(mut self, ambiguous_width: AmbiguousWidth) -> Self {
+        self.ambiguous_width = ambiguous_width;
+        self
+    }
+
+    /// Sets where to place the ellipsis when options are too wide to fit.
+    pub fn with_option_truncation(mut self, truncation: Option<Truncation>) -> Self {
+        self.option_truncation = truncation;
+        self
+    }
+
+    /// Sets the terminal width, in columns, below which prompts are rendered
+    /// in a compact layout. `None` disables the compact layout.
+    pub fn with_compact_layout_threshold(mut self, threshold: Option<u16>) -> Self {
+        self.compact_layout_threshold = threshold;
+        self
+    }
+
+    /// Enables or disables the parsing of inline markup in prompt and help messages.
+    pub fn with_inline_markup(mut self, inline_markup: bool) -> Self {
+        self.inline_markup = inline_markup;
+        self
+    }
+
+    /// Sets the style sheet of code spans in messages.
+    pub fn with_code_span(mut self, code_span: StyleSheet) -> Self {
+        self.code_span = code_span;
+        self
+    }
+
+    /// Sets whether ANSI escape sequences in the rendered text are passed through to the terminal.
+    pub fn with_ansi_passthrough(mut self, ansi_passthrough: bool) -> Self {
+        self.ansi_passthrough = ansi_passthrough;
+        self
+    }
+
+    /// Sets whether the rendered text is restricted to ASCII characters.
+    pub fn with_ascii_only(mut self, ascii_only: bool) -> Self {
+        self.ascii_only = ascii_only;
+        self
+    }
+
+    /// Sets the style of the terminal cursor while it is placed in a text input.
+    pub fn with_text_input_cursor(mut self, text_input_cursor: CursorStyle) -> Self {
+        self.text_input_cursor = text_input_cursor;
+        self
+    }
+
+    /// Sets the style of the terminal cursor while no text input has focus.
+    pub fn with_navigation_cursor(mut self, navigation_cursor: CursorStyle) -> Self {
+        self.navigation_cursor = navigation_cursor;
+        self
+    }
+
+    /// Sets the maximum number of frames rendered per second. `None` removes the cap.
+    pub fn with_max_frame_rate(mut self, max_frame_rate: Option<u16>) -> Self {
+        self.max_frame_rate = max_frame_rate;
+        self
+    }
+
     #[cfg(feature = "date")]
     /// Sets the render configuration for calendars.
     pub fn with_calendar_config(mut self, calendar: calendar::CalendarRenderConfig<'a>) -> Self {
@@ -332,7 +764,10 @@ impl<'a> Default for RenderConfig<'a> {
     fn default() -> Self {
         match env::var("NO_COLOR") {
             Ok(_) => Self::empty(),
-            Err(_) => Self::default_colored(),
+            Err(_) => match TerminalBackground::detect() {
+                Some(background) => Self::for_background(background),
+                None => Self::default_colored(),
+            },
         }
     }
 }
@@ -373,6 +808,98 @@ pub enum IndexPrefix {
     ZeroPadded,
 }
 
+/// Width of East Asian ambiguous-width characters when rendered in the terminal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AmbiguousWidth {
+    /// Ambiguous characters take one column, as in most western locales.
+    Narrow,
+
+    /// Ambiguous characters take two columns, as in most CJK locales.
+    Wide,
+}
+
+/// Strategies to shorten text that is too wide to fit, replacing the
+/// removed part with an ellipsis.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Truncation {
+    /// Keeps the start of the text, e.g. `a long opt…`.
+    #[default]
+    End,
+
+    /// Keeps the start and the end of the text, e.g. `src/…/mod.rs`,
+    /// useful for file paths.
+    Middle,
+
+    /// Keeps the end of the text, e.g. `…long option`.
+    Start,
+}
+
+/// Notifications emitted to draw the attention of the user to a prompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Notification {
+    /// No notification is emitted.
+    None,
+
+    /// The terminal bell is rung.
+    Bell,
+
+    /// A desktop notification is requested through the OSC 9 escape
+    /// sequence, with the prompt message or the error message as its body.
+    ///
+    /// Supported by terminals such as iTerm2 and WezTerm. Most other
+    /// terminals silently ignore the sequence.
+    Desktop,
+}
+
+/// Visibility and shape of the terminal cursor.
+///
+/// Shapes are set through the DECSCUSR escape sequence, supported by most
+/// terminals. Once the prompt finishes, the cursor is shown again and its
+/// shape is reset to the default of the terminal.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CursorStyle {
+    /// The cursor is hidden.
+    Hidden,
+
+    /// The cursor is shown with the shape configured in the terminal.
+    #[default]
+    Default,
+
+    /// Blinking block, `█`.
+    BlinkingBlock,
+
+    /// Steady block, `█`.
+    SteadyBlock,
+
+    /// Blinking underline, `_`.
+    BlinkingUnderline,
+
+    /// Steady underline, `_`.
+    SteadyUnderline,
+
+    /// Blinking vertical bar, `|`, as in most text editors.
+    BlinkingBar,
+
+    /// Steady vertical bar, `|`.
+    SteadyBar,
+}
+
+impl CursorStyle {
+    /// Parameter of the DECSCUSR escape sequence setting the shape, if any.
+    pub(crate) fn shape_code(self) -> Option<u8> {
+        match self {
+            Self::Hidden => None,
+            Self::Default => Some(0),
+            Self::BlinkingBlock => Some(1),
+            Self::SteadyBlock => Some(2),
+            Self::BlinkingUnderline => Some(3),
+            Self::SteadyUnderline => Some(4),
+            Self::BlinkingBar => Some(5),
+            Self::SteadyBar => Some(6),
+        }
+    }
+}
+
 /// Render configuration for error messages.
 #[derive(Copy, Clone, Debug)]
 pub struct ErrorMessageRenderConfig<'a> {
@@ -435,11 +962,183 @@ impl<'a> ErrorMessageRenderConfig<'a> {
     }
 }
 
+/// Render configuration for one-off message lines printed between prompts,
+/// e.g. with [`info`](crate::info).
+#[derive(Copy, Clone, Debug)]
+pub struct MessageRenderConfig<'a> {
+    /// Prefix style.
+    ///
+    /// Note: a space character will be added to separate the prefix
+    /// and the message.
+    pub prefix: Styled<&'a str>,
+
+    /// Message style.
+    pub message: StyleSheet,
+}
+
+impl<'a> MessageRenderConfig<'a> {
+    /// Creates a render configuration with the given prefix and an unstyled message.
+    pub fn new(prefix: Styled<&'a str>) -> Self {
+        Self {
+            prefix,
+            message: StyleSheet::empty(),
+        }
+    }
+
+    /// Sets the prefix.
+    pub fn with_prefix(mut self, prefix: Styled<&'a str>) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Sets the message stylesheet.
+    pub fn with_message(mut self, message: StyleSheet) -> Self {
+        self.message = message;
+        self
+    }
+}
+
+/// Render configuration for unified diffs.
+#[derive(Copy, Clone, Debug)]
+pub struct DiffRenderConfig {
+    /// Style sheet for file headers, e.g. `--- a/Cargo.toml` and `+++ b/Cargo.toml`.
+    pub file_header: StyleSheet,
+
+    /// Style sheet for hunk headers, e.g. `@@ -1,4 +1,4 @@`.
+    pub hunk_header: StyleSheet,
+
+    /// Style sheet for added lines.
+    pub added: StyleSheet,
+
+    /// Style sheet for removed lines.
+    pub removed: StyleSheet,
+
+    /// Style sheet for context lines.
+    pub context: StyleSheet,
+}
+
+impl DiffRenderConfig {
+    /// Render configuration in which no colors or attributes are applied.
+    pub fn empty() -> Self {
+        Self {
+            file_header: StyleSheet::empty(),
+            hunk_header: StyleSheet::empty(),
+            added: StyleSheet::empty(),
+            removed: StyleSheet::empty(),
+            context: StyleSheet::empty(),
+        }
+    }
+
+    /// Render configuration where default colors and attributes are applied.
+    pub fn default_colored() -> Self {
+        Self {
+            file_header: StyleSheet::empty().with_attr(Attributes::BOLD),
+            hunk_header: StyleSheet::empty().with_fg(Color::DarkCyan),
+            added: StyleSheet::empty().with_fg(Color::LightGreen),
+            removed: StyleSheet::empty().with_fg(Color::LightRed),
+            context: StyleSheet::empty(),
+        }
+    }
+
+    /// Sets the style sheet for file headers.
+    pub fn with_file_header(mut self, file_header: StyleSheet) -> Self {
+        self.file_header = file_header;
+        self
+    }
+
+    /// Sets the style sheet for hunk headers.
+    pub fn with_hunk_header(mut self, hunk_header: StyleSheet) -> Self {
+        self.hunk_header = hunk_header;
+        self
+    }
+
+    /// Sets the style sheet for added lines.
+    pub fn with_added(mut self, added: StyleSheet) -> Self {
+        self.added = added;
+        self
+    }
+
+    /// Sets the style sheet for removed lines.
+    pub fn with_removed(mut self, removed: StyleSheet) -> Self {
+        self.removed = removed;
+        self
+    }
+
+    /// Sets the style sheet for context lines.
+    pub fn with_context(mut self, context: StyleSheet) -> Self {
+        self.context = context;
+        self
+    }
+}
+
+/// Render configuration for the results of quizzes, listing every option
+/// with the correct ones and the wrongly chosen ones marked and styled.
+#[derive(Copy, Clone, Debug)]
+pub struct QuizRenderConfig<'a> {
+    /// Marker displayed before correct options.
+    pub correct_marker: Styled<&'a str>,
+
+    /// Marker displayed before options chosen by the user that are not correct.
+    pub wrong_marker: Styled<&'a str>,
+
+    /// Style sheet for correct options.
+    pub correct_option: StyleSheet,
+
+    /// Style sheet for options chosen by the user that are not correct.
+    pub wrong_option: StyleSheet,
+}
+
+impl<'a> QuizRenderConfig<'a> {
+    /// Render configuration in which no colors or attributes are applied.
+    pub fn empty() -> Self {
+        Self {
+            correct_marker: Styled::new("✓"),
+            wrong_marker: Styled::new("✗"),
+            correct_option: StyleSheet::empty(),
+            wrong_option: StyleSheet::empty(),
+        }
+    }
+
+    /// Render configuration where default colors and attributes are applied.
+    pub fn default_colored() -> Self {
+        Self {
+            correct_marker: Styled::new("✓").with_fg(Color::LightGreen),
+            wrong_marker: Styled::new("✗").with_fg(Color::LightRed),
+            correct_option: StyleSheet::empty().with_fg(Color::LightGreen),
+            wrong_option: StyleSheet::empty().with_fg(Color::LightRed),
+        }
+    }
+
+    /// Sets the marker displayed before correct options.
+    pub fn with_correct_marker(mut self, correct_marker: Styled<&'a str>) -> Self {
+        self.correct_marker = correct_marker;
+        self
+    }
+
+    /// Sets the marker displayed before wrongly chosen options.
+    pub fn with_wrong_marker(mut self, wrong_marker: Styled<&'a str>) -> Self {
+        self.wrong_marker = wrong_marker;
+        self
+    }
+
+    /// Sets the style sheet for correct options.
+    pub fn with_correct_option(mut self, correct_option: StyleSheet) -> Self {
+        self.correct_option = correct_option;
+        self
+    }
+
+    /// Sets the style sheet for wrongly chosen options.
+    pub fn with_wrong_option(mut self, wrong_option: StyleSheet) -> Self {
+        self.wrong_option = wrong_option;
+        self
+    }
+}
+
 #[cfg(feature = "date")]
 pub mod calendar {
     //! Module containing additional render config for date prompts.
 
-    use super::{Color, StyleSheet, Styled};
+    use super::{Attributes, Color, StyleSheet, Styled};
 
     /// Calendar configuration for error messages.
     #[derive(Copy, Clone, Debug)]
@@ -473,6 +1172,16 @@ pub mod calendar {
         /// Style sheet for dates that can not be selected due to the
         /// min/max settings.
         pub unavailable_date: StyleSheet,
+
+        /// Style sheet for the dates checked in a
+        /// [`MultiDateSelect`](crate::MultiDateSelect) prompt.
+        pub marked_date: StyleSheet,
+
+        /// Marker rendered before the dates checked in a
+        /// [`MultiDateSelect`](crate::MultiDateSelect) prompt, in place of the
+        /// space separating them from the previous date. It should be one
+        /// column wide, e.g. `*`.
+        pub marker: Styled<&'a str>,
     }
 
     impl<'a> CalendarRenderConfig<'a> {
@@ -486,6 +1195,8 @@ pub mod calendar {
                 today_date: StyleSheet::empty(),
                 different_month_date: StyleSheet::empty(),
                 unavailable_date: StyleSheet::empty(),
+                marked_date: StyleSheet::empty(),
+                marker: Styled::new("*"),
             }
         }
 
@@ -503,6 +1214,10 @@ pub mod calendar {
                 today_date: StyleSheet::empty().with_fg(Color::LightGreen),
                 different_month_date: StyleSheet::empty().with_fg(Color::DarkGrey),
                 unavailable_date: StyleSheet::empty().with_fg(Color::DarkGrey),
+                marked_date: StyleSheet::empty()
+                    .with_fg(Color::LightCyan)
+                    .with_attr(Attributes::BOLD),
+                marker: Styled::new("*").with_fg(Color::LightCyan),
             }
         }
 
@@ -511,5 +1226,12 @@ pub mod calendar {
             self.prefix = prefix;
             self
         }
+
+        /// Sets the marker of the dates checked in a
+        /// [`MultiDateSelect`](crate::MultiDateSelect) prompt.
+        pub fn with_marker(mut self, marker: Styled<&'a str>) -> Self {
+            self.marker = marker;
+            self
+        }
     }
 }
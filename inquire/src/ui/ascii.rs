@@ -0,0 +1,81 @@
+//! Fallback of rendered text to pure ASCII, for terminals without UTF-8 support.
+
+use std::borrow::Cow;
+
+use super::{width::WidthCounter, AmbiguousWidth};
+
+/// Replaces the non-ASCII characters of the string, keeping its width.
+///
+/// Glyphs such as arrows, check marks and box drawing characters are replaced
+/// by look-alike ASCII characters, while any other visible character is
+/// replaced by as many `?` as the columns it takes.
+pub fn to_ascii(s: &str, ambiguous_width: AmbiguousWidth) -> Cow<'_, str> {
+    if s.is_ascii() {
+        return Cow::Borrowed(s);
+    }
+
+    let mut counter = WidthCounter::new(ambiguous_width);
+    let mut ascii = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        let width = counter.next(c);
+
+        if c.is_ascii() {
+            ascii.push(c);
+        } else if let Some(glyph) = ascii_glyph(c) {
+            ascii.push(glyph);
+            ascii.extend(std::iter::repeat(' ').take(width.saturating_sub(1)));
+        } else {
+            ascii.extend(std::iter::repeat('?').take(width));
+        }
+    }
+
+    Cow::Owned(ascii)
+}
+
+fn ascii_glyph(c: char) -> Option<char> {
+    let glyph = match c {
+        '↑' | '▲' | '▴' => '^',
+        '↓' | '▼' | '▾' => 'v',
+        '←' | '◀' | '◂' => '<',
+        '→' | '▶' | '▸' => '>',
+        '✗' | '✘' | '×' => 'x',
+        '★' | '•' | '●' => '*',
+        '○' => 'o',
+        '…' => '~',
+        '☆' | '─' | '━' | '═' | '–' | '—' => '-',
+        '│' | '┃' | '║' => '|',
+        // check marks, and the corners and junctions of the box drawing block
+        '✓' | '✔' | '┌'..='╿' => '+',
+        '‘' | '’' => '\'',
+        '“' | '”' => '"',
+        _ => return None,
+    };
+
+    Some(glyph)
+}
+
+#[cfg(test)]
+mod test {
+    use super::to_ascii;
+    use crate::ui::AmbiguousWidth;
+
+    #[test]
+    fn glyphs_are_replaced_by_look_alikes() {
+        assert_eq!(
+            "^v to move, > to all",
+            to_ascii("↑↓ to move, → to all", AmbiguousWidth::Narrow)
+        );
+        assert_eq!("+ Deployed", to_ascii("✓ Deployed", AmbiguousWidth::Narrow));
+        assert_eq!("***--", to_ascii("★★★☆☆", AmbiguousWidth::Narrow));
+        assert_eq!("--- a | b", to_ascii("─── a │ b", AmbiguousWidth::Narrow));
+    }
+
+    #[test]
+    fn other_characters_keep_their_width() {
+        assert_eq!("?and?", to_ascii("ñandú", AmbiguousWidth::Narrow));
+        assert_eq!("????", to_ascii("日本", AmbiguousWidth::Narrow));
+        assert_eq!("??", to_ascii("👍\u{1F3FD}", AmbiguousWidth::Narrow));
+        assert_eq!("- ", to_ascii("─", AmbiguousWidth::Wide));
+    }
+}
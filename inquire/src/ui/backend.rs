@@ -1,32 +1,62 @@
-use std::{collections::BTreeSet, fmt::Display, io::Result};
+use std::{borrow::Cow, collections::BTreeSet, fmt::Display, io::Result, time::Duration};
 
 use crate::{
+    ansi::{AnsiAware, AnsiAwareChar},
     error::InquireResult,
     input::Input,
     list_option::ListOption,
     terminal::Terminal,
-    ui::{IndexPrefix, Key, RenderConfig, Styled},
+    ui::{
+        IndexPrefix, Key, KeyBindings, KeyHelp, Notification, RenderConfig, StyleSheet, Styled,
+        Truncation,
+    },
     utils::{int_log10, Page},
     validator::ErrorMessage,
+    DiffDecision, MultiSelectView, RatingDisplayMode, TransferListPane,
 };
 
-use super::{frame_renderer::FrameRenderer, InputReader};
+use super::{
+    frame_renderer::FrameRenderer,
+    markup::{parse_markup, strip_markup},
+    width::{str_width, truncate},
+    InputReader,
+};
 
 pub trait CommonBackend: InputReader {
     fn frame_setup(&mut self) -> Result<()>;
     fn frame_finish(&mut self, is_last_frame: bool) -> Result<()>;
+    /// Minimum time between two frames, if the frame rate is capped.
+    fn frame_interval(&self) -> Option<Duration>;
+    /// Key bindings used in place of the global ones, if any.
+    fn key_bindings(&self) -> Option<KeyBindings<'static>>;
 
     fn render_canceled_prompt(&mut self, prompt: &str) -> Result<()>;
     fn render_prompt_with_answer(&mut self, prompt: &str, answer: &str) -> Result<()>;
 
     fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()>;
     fn render_help_message(&mut self, help: &str) -> Result<()>;
+    fn render_remaining_time(&mut self, remaining: Duration) -> Result<()>;
+    fn render_elapsed_time(&mut self, elapsed: Duration) -> Result<()>;
+    fn render_filter_header(&mut self, filter: &str, matches: usize) -> Result<()>;
+    #[cfg(feature = "regex")]
+    fn render_regex_filter_header(
+        &mut self,
+        pattern: &str,
+        matches: usize,
+        invalid: bool,
+    ) -> Result<()>;
+    #[cfg(feature = "async")]
+    fn render_loading_indicator(&mut self, tick: usize) -> Result<()>;
+    fn render_search_header(&mut self, search: &Input, editing: bool, matches: usize)
+        -> Result<()>;
+    fn render_key_help(&mut self, entries: &[KeyHelp]) -> Result<()>;
 }
 
 pub trait TextBackend: CommonBackend {
     fn render_prompt(
         &mut self,
         prompt: &str,
+        current: Option<&str>,
         default: Option<&str>,
         cur_input: &Input,
     ) -> Result<()>;
@@ -40,7 +70,23 @@ pub trait EditorBackend: CommonBackend {
 
 pub trait SelectBackend: CommonBackend {
     fn render_select_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()>;
-    fn render_options<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()>;
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        matches: &[Vec<usize>],
+        styles: &[StyleSheet],
+    ) -> Result<()>;
+    /// Renders the options in a column to the left of a preview pane of the
+    /// given width, listing the lines of the preview.
+    fn render_options_with_preview<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        matches: &[Vec<usize>],
+        styles: &[StyleSheet],
+        preview: &[&str],
+        preview_width: usize,
+    ) -> Result<()>;
+    fn render_skip_option(&mut self, label: &str, highlighted: bool) -> Result<()>;
 }
 
 pub trait MultiSelectBackend: CommonBackend {
@@ -49,6 +95,97 @@ pub trait MultiSelectBackend: CommonBackend {
         &mut self,
         page: Page<'_, ListOption<D>>,
         checked: &BTreeSet<usize>,
+        matches: &[Vec<usize>],
+        styles: &[StyleSheet],
+    ) -> Result<()>;
+    fn render_skip_option(&mut self, label: &str, highlighted: bool) -> Result<()>;
+    fn render_footer(&mut self, footer: &str) -> Result<()>;
+    fn render_view_header(&mut self, view: MultiSelectView, options: usize) -> Result<()>;
+}
+
+pub trait TransferListBackend: CommonBackend {
+    fn render_transfer_list_prompt(&mut self, prompt: &str) -> Result<()>;
+    fn render_transfer_panes<D: Display>(
+        &mut self,
+        available: Page<'_, ListOption<D>>,
+        chosen: Page<'_, ListOption<D>>,
+        focus: TransferListPane,
+        column_width: usize,
+    ) -> Result<()>;
+}
+
+pub trait CheckboxMatrixBackend: CommonBackend {
+    fn render_checkbox_matrix_prompt(&mut self, prompt: &str) -> Result<()>;
+    fn render_checkbox_matrix<R: Display, C: Display>(
+        &mut self,
+        rows: Page<'_, ListOption<R>>,
+        columns: &[C],
+        checked: &BTreeSet<(usize, usize)>,
+        column_cursor: usize,
+        row_label_width: usize,
+    ) -> Result<()>;
+}
+
+pub trait QuizBackend: CommonBackend {
+    fn render_quiz_result(
+        &mut self,
+        options: &[String],
+        chosen: &BTreeSet<usize>,
+        correct: &BTreeSet<usize>,
+    ) -> Result<()>;
+}
+
+/// Kinds of one-off message lines printed between prompts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MessageLevel {
+    Info,
+    Warning,
+    Success,
+}
+
+pub trait MessageBackend: CommonBackend {
+    fn render_message(&mut self, level: MessageLevel, message: &str) -> Result<()>;
+}
+
+pub trait SectionBackend: CommonBackend {
+    fn render_section(&mut self, title: Option<&str>) -> Result<()>;
+}
+
+pub trait KeyCaptureBackend: CommonBackend {
+    fn render_key_capture_prompt(&mut self, prompt: &str) -> Result<()>;
+}
+
+pub trait RatingBackend: CommonBackend {
+    fn render_rating_prompt(
+        &mut self,
+        prompt: &str,
+        value: u8,
+        max: u8,
+        display_mode: RatingDisplayMode,
+        labels: Option<(&str, &str)>,
+    ) -> Result<()>;
+}
+
+pub trait DiffBackend: CommonBackend {
+    fn render_diff_approval_prompt(&mut self, prompt: &str) -> Result<()>;
+    fn render_diff(&mut self, lines: &[&str], first_line: usize, total_lines: usize) -> Result<()>;
+    fn render_diff_decisions(
+        &mut self,
+        decisions: &[DiffDecision],
+        highlighted: usize,
+    ) -> Result<()>;
+}
+
+pub trait PagerBackend: CommonBackend {
+    fn render_pager_prompt(&mut self, prompt: &str) -> Result<()>;
+    fn render_pager_lines(&mut self, lines: &[&str], highlighted: Option<usize>) -> Result<()>;
+    fn render_pager_status(
+        &mut self,
+        first_line: usize,
+        last_line: usize,
+        total_lines: usize,
+        search: Option<&Input>,
+        not_found: bool,
     ) -> Result<()>;
 }
 
@@ -56,6 +193,7 @@ pub trait CustomTypeBackend: CommonBackend {
     fn render_prompt(
         &mut self,
         prompt: &str,
+        current: Option<&str>,
         default: Option<&str>,
         cur_input: &Input,
     ) -> Result<()>;
@@ -81,6 +219,10 @@ where
     frame_renderer: FrameRenderer<T>,
     input_reader: I,
     render_config: RenderConfig<'a>,
+    key_bindings: Option<KeyBindings<'static>>,
+    prompt_start_notified: bool,
+    frame_error: Option<ErrorMessage>,
+    last_frame_error: Option<ErrorMessage>,
 }
 
 impl<'a, I, T> Backend<'a, I, T>
@@ -91,14 +233,54 @@ where
     #[allow(clippy::large_types_passed_by_value)]
     pub fn new(input_reader: I, terminal: T, render_config: RenderConfig<'a>) -> Result<Self> {
         let backend = Self {
-            frame_renderer: FrameRenderer::new(terminal)?,
+            frame_renderer: FrameRenderer::new(
+                terminal,
+                render_config.ambiguous_width,
+                render_config.ansi_passthrough,
+                render_config.ascii_only,
+                render_config.text_input_cursor,
+                render_config.navigation_cursor,
+            )?,
             input_reader,
             render_config,
+            key_bindings: None,
+            prompt_start_notified: false,
+            frame_error: None,
+            last_frame_error: None,
         };
 
         Ok(backend)
     }
 
+    /// Sets the key bindings of the prompts run on this backend, in place of
+    /// the global ones.
+    #[cfg(test)]
+    pub(crate) fn with_key_bindings(mut self, key_bindings: KeyBindings<'static>) -> Self {
+        self.key_bindings = Some(key_bindings);
+        self
+    }
+
+    #[cfg(test)]
+    pub(crate) fn terminal_mut(&mut self) -> &mut T {
+        self.frame_renderer.terminal_mut()
+    }
+
+    fn str_width(&self, s: &str) -> usize {
+        str_width(s, self.render_config.ambiguous_width)
+    }
+
+    fn notify(&mut self, notification: Notification, body: &str) -> Result<()> {
+        match notification {
+            Notification::None => Ok(()),
+            Notification::Bell => self.frame_renderer.write_unframed('\x07'),
+            Notification::Desktop => {
+                let body: String = body.chars().filter(|c| !c.is_control()).collect();
+                self.frame_renderer
+                    .write_unframed(format!("\x1b]9;{}\x07", body))
+            }
+        }
+    }
+
     fn print_option_prefix<D: Display>(
         &mut self,
         option_relative_index: usize,
@@ -125,6 +307,46 @@ where
         option: &ListOption<D>,
         page: &Page<'_, ListOption<D>>,
     ) -> Result<()> {
+        self.print_matched_option_value(option_relative_index, option, page, &[], None)
+    }
+
+    /// Prints the option value with the characters at the given indexes
+    /// highlighted, as matched by the filter, and the given style applied on
+    /// top of the option style.
+    fn print_matched_option_value<D: Display>(
+        &mut self,
+        option_relative_index: usize,
+        option: &ListOption<D>,
+        page: &Page<'_, ListOption<D>>,
+        matched: &[usize],
+        style: Option<StyleSheet>,
+    ) -> Result<()> {
+        let max_width = self.frame_renderer.remaining_line_width();
+        self.print_option_value_within(
+            option_relative_index,
+            option,
+            page,
+            max_width,
+            matched,
+            style,
+        )?;
+        Ok(())
+    }
+
+    /// Prints the option value, truncated to the given width when truncation
+    /// is enabled in the render config. Returns the width of the printed value.
+    ///
+    /// The matched characters are only highlighted when the value is printed
+    /// in full, as their indexes refer to the original value.
+    fn print_option_value_within<D: Display>(
+        &mut self,
+        option_relative_index: usize,
+        option: &ListOption<D>,
+        page: &Page<'_, ListOption<D>>,
+        max_width: Option<usize>,
+        matched: &[usize],
+        style: Option<StyleSheet>,
+    ) -> Result<usize> {
         let stylesheet = if let Some(selected_option_style) = self.render_config.selected_option {
             match page.cursor {
                 Some(cursor) if cursor == option_relative_index => selected_option_style,
@@ -133,9 +355,105 @@ where
         } else {
             self.render_config.option
         };
+        let stylesheet = match style {
+            Some(style) => StyleSheet {
+                fg: style.fg.or(stylesheet.fg),
+                bg: style.bg.or(stylesheet.bg),
+                att: stylesheet.att | style.att,
+            },
+            None => stylesheet,
+        };
+
+        let truncation = match self.render_config.option_truncation {
+            Some(truncation) => Some(truncation),
+            None if self.is_compact() => Some(Truncation::End),
+            None => None,
+        };
+
+        let value = option.value.to_string();
+        let value = match (truncation, max_width) {
+            (Some(truncation), Some(max_width)) => truncate(
+                &value,
+                max_width,
+                truncation,
+                self.render_config.ambiguous_width,
+            ),
+            _ => Cow::Borrowed(value.as_str()),
+        };
+        let width = self.str_width(&value);
+
+        if matched.is_empty() || matches!(value, Cow::Owned(_)) {
+            self.frame_renderer
+                .write_styled(Styled::new(value).with_style_sheet(stylesheet))?;
+
+            return Ok(width);
+        }
 
+        let match_stylesheet = StyleSheet {
+            fg: self.render_config.option_match.fg.or(stylesheet.fg),
+            bg: self.render_config.option_match.bg.or(stylesheet.bg),
+            att: stylesheet.att | self.render_config.option_match.att,
+        };
+
+        // Matched indices count visible characters only, so escape sequences
+        // are carried along in the current run without advancing them.
+        let mut run = String::new();
+        let mut run_matched = false;
+        let mut idx = 0;
+        for c in value.ansi_aware_chars() {
+            let c = match c {
+                AnsiAwareChar::Char(c) => c,
+                AnsiAwareChar::AnsiEscapeSequence(seq) => {
+                    run.push_str(seq);
+                    continue;
+                }
+            };
+            let is_matched = matched.binary_search(&idx).is_ok();
+            idx += 1;
+            if is_matched != run_matched && !run.is_empty() {
+                let run_stylesheet = if run_matched {
+                    match_stylesheet
+                } else {
+                    stylesheet
+                };
+                self.frame_renderer.write_styled(
+                    Styled::new(std::mem::take(&mut run)).with_style_sheet(run_stylesheet),
+                )?;
+            }
+            run_matched = is_matched;
+            run.push(c);
+        }
+
+        let run_stylesheet = if run_matched {
+            match_stylesheet
+        } else {
+            stylesheet
+        };
         self.frame_renderer
-            .write_styled(Styled::new(&option.value).with_style_sheet(stylesheet))
+            .write_styled(Styled::new(run).with_style_sheet(run_stylesheet))?;
+
+        Ok(width)
+    }
+
+    fn print_skip_option(&mut self, label: &str, highlighted: bool) -> Result<()> {
+        let (prefix, stylesheet) = match (highlighted, self.render_config.selected_option) {
+            (true, Some(selected_option)) => (
+                self.render_config.highlighted_option_prefix,
+                selected_option,
+            ),
+            (true, None) => (
+                self.render_config.highlighted_option_prefix,
+                self.render_config.option,
+            ),
+            (false, _) => (Styled::new(" "), self.render_config.option),
+        };
+
+        self.frame_renderer.write_styled(prefix)?;
+        self.frame_renderer.write(" ")?;
+        self.frame_renderer
+            .write_styled(Styled::new(label).with_style_sheet(stylesheet))?;
+
+        self.new_line()
     }
 
     fn print_option_index_prefix(&mut self, index: usize, max_index: usize) -> Option<Result<()>> {
@@ -167,25 +485,64 @@ where
         self.frame_renderer.write_styled(token)
     }
 
+    /// Whether the terminal is narrow enough for the compact layout.
+    fn is_compact(&self) -> bool {
+        match (
+            self.render_config.compact_layout_threshold,
+            self.frame_renderer.terminal_width(),
+        ) {
+            (Some(threshold), Some(width)) => width < threshold,
+            _ => false,
+        }
+    }
+
     fn print_prompt_with_prefix(&mut self, prefix: Styled<&str>, prompt: &str) -> Result<()> {
-        self.frame_renderer.write_styled(prefix)?;
+        if !self.is_compact() {
+            self.frame_renderer.write_styled(prefix)?;
+            self.frame_renderer.write(" ")?;
+        }
 
-        self.frame_renderer.write(" ")?;
+        self.print_message(prompt, self.render_config.prompt)
+    }
 
-        self.frame_renderer
-            .write_styled(Styled::new(prompt).with_style_sheet(self.render_config.prompt))?;
+    /// Prints a message, applying its inline markup when enabled.
+    fn print_message(&mut self, message: &str, style: StyleSheet) -> Result<()> {
+        if !self.render_config.inline_markup {
+            return self
+                .frame_renderer
+                .write_styled(Styled::new(message).with_style_sheet(style));
+        }
+
+        for segment in parse_markup(message, style, self.render_config.code_span) {
+            self.frame_renderer.write_styled(segment)?;
+        }
 
         Ok(())
     }
 
     fn print_prompt(&mut self, prompt: &str) -> Result<()> {
-        self.print_prompt_with_prefix(self.render_config.prompt_prefix, prompt)
+        if !self.prompt_start_notified {
+            self.prompt_start_notified = true;
+            let body = match self.render_config.inline_markup {
+                true => strip_markup(prompt),
+                false => prompt.to_string(),
+            };
+            self.notify(self.render_config.prompt_start_notification, &body)?;
+        }
+
+        // error messages are rendered before the prompt line
+        let prefix = match self.frame_error {
+            Some(_) => self.render_config.errored_prompt_prefix,
+            None => self.render_config.prompt_prefix,
+        };
+
+        self.print_prompt_with_prefix(prefix, prompt)
     }
 
     fn print_input(&mut self, input: &Input) -> Result<()> {
         self.frame_renderer.write(" ")?;
 
-        let cursor_offset = input.pre_cursor().chars().count();
+        let cursor_offset = str_width(input.pre_cursor(), self.render_config.ambiguous_width);
         self.frame_renderer
             .mark_cursor_position(cursor_offset as isize);
 
@@ -212,13 +569,41 @@ where
         Ok(())
     }
 
+    /// Prints the prompt followed by the current value of the setting being
+    /// edited, if any, e.g. `Timeout [current: 30s]:`.
+    fn print_prompt_with_current_value(
+        &mut self,
+        prompt: &str,
+        current: Option<&str>,
+    ) -> Result<()> {
+        let Some(current) = current else {
+            return self.print_prompt(prompt);
+        };
+
+        // the value goes before the trailing colon of the prompt, if any
+        let (prompt, suffix) = match prompt.strip_suffix(':') {
+            Some(stripped) => (stripped, ":"),
+            None => (prompt, ""),
+        };
+
+        self.print_prompt(prompt)?;
+        self.frame_renderer.write(" ")?;
+
+        let content = format!("[current: {current}]");
+        let token = Styled::new(content).with_style_sheet(self.render_config.current_value);
+        self.frame_renderer.write_styled(token)?;
+
+        self.print_message(suffix, self.render_config.prompt)
+    }
+
     fn print_prompt_with_input(
         &mut self,
         prompt: &str,
+        current: Option<&str>,
         default: Option<&str>,
         input: &Input,
     ) -> Result<()> {
-        self.print_prompt(prompt)?;
+        self.print_prompt_with_current_value(prompt, current)?;
 
         if let Some(default) = default {
             self.frame_renderer.write(" ")?;
@@ -247,12 +632,44 @@ where
         self.frame_renderer.start_frame()
     }
 
+    fn key_bindings(&self) -> Option<KeyBindings<'static>> {
+        self.key_bindings
+    }
+
+    fn frame_interval(&self) -> Option<Duration> {
+        // pending frames would only be drawn on the next key press otherwise
+        if !self.input_reader.supports_read_timeout() {
+            return None;
+        }
+
+        self.render_config
+            .max_frame_rate
+            .filter(|fps| *fps > 0)
+            .map(|fps| Duration::from_secs(1) / u32::from(fps))
+    }
+
     fn frame_finish(&mut self, is_last_frame: bool) -> Result<()> {
-        self.frame_renderer.finish_current_frame(is_last_frame)
+        self.frame_renderer.finish_current_frame(is_last_frame)?;
+
+        // notify only when an error appears or changes, not on every redraw
+        let frame_error = self.frame_error.take();
+        if let Some(error) = &frame_error {
+            if self.last_frame_error.as_ref() != Some(error) {
+                let message = match error {
+                    ErrorMessage::Default => self.render_config.error_message.default_message,
+                    ErrorMessage::Custom(msg) => msg,
+                }
+                .to_string();
+                self.notify(self.render_config.error_notification, &message)?;
+            }
+        }
+        self.last_frame_error = frame_error;
+
+        Ok(())
     }
 
     fn render_canceled_prompt(&mut self, prompt: &str) -> Result<()> {
-        self.print_prompt(prompt)?;
+        self.print_prompt_with_prefix(self.render_config.canceled_prompt_prefix, prompt)?;
 
         self.frame_renderer.write(" ")?;
 
@@ -277,65 +694,714 @@ where
         Ok(())
     }
 
-    fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()> {
-        self.frame_renderer
-            .write_styled(self.render_config.error_message.prefix)?;
+    fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()> {
+        self.frame_error = Some(error.clone());
+
+        self.frame_renderer
+            .write_styled(self.render_config.error_message.prefix)?;
+
+        self.frame_renderer.write_styled(
+            Styled::new(" ").with_style_sheet(self.render_config.error_message.separator),
+        )?;
+
+        let message = match error {
+            ErrorMessage::Default => self.render_config.error_message.default_message,
+            ErrorMessage::Custom(msg) => msg,
+        };
+
+        self.frame_renderer.write_styled(
+            Styled::new(message).with_style_sheet(self.render_config.error_message.message),
+        )?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+
+    fn render_help_message(&mut self, help: &str) -> Result<()> {
+        if self.is_compact() {
+            return Ok(());
+        }
+
+        self.frame_renderer
+            .write_styled(Styled::new("[").with_style_sheet(self.render_config.help_message))?;
+
+        self.print_message(help, self.render_config.help_message)?;
+
+        self.frame_renderer
+            .write_styled(Styled::new("]").with_style_sheet(self.render_config.help_message))?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+
+    fn render_remaining_time(&mut self, remaining: Duration) -> Result<()> {
+        let secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+        let text = format!("{}:{:02} left", secs / 60, secs % 60);
+
+        self.frame_renderer
+            .write_styled(Styled::new(text).with_style_sheet(self.render_config.help_message))?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+
+    fn render_elapsed_time(&mut self, elapsed: Duration) -> Result<()> {
+        let secs = elapsed.as_secs();
+        let text = format!("{}:{:02} elapsed", secs / 60, secs % 60);
+
+        self.frame_renderer
+            .write_styled(Styled::new(text).with_style_sheet(self.render_config.help_message))?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+
+    fn render_filter_header(&mut self, filter: &str, matches: usize) -> Result<()> {
+        let count = match (filter.is_empty(), matches) {
+            (true, 1) => String::from("1 option"),
+            (true, n) => format!("{} options", n),
+            (false, 1) => String::from("1 match"),
+            (false, n) => format!("{} matches", n),
+        };
+        let text = match filter.is_empty() {
+            true => count,
+            false => format!("Filter: {} ({})", filter, count),
+        };
+
+        self.frame_renderer
+            .write_styled(Styled::new(text).with_style_sheet(self.render_config.filter_header))?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "regex")]
+    fn render_regex_filter_header(
+        &mut self,
+        pattern: &str,
+        matches: usize,
+        invalid: bool,
+    ) -> Result<()> {
+        self.frame_renderer.write_styled(
+            Styled::new("Regex:").with_style_sheet(self.render_config.filter_header),
+        )?;
+        self.frame_renderer.write(" ")?;
+
+        let status = match (invalid, matches) {
+            (true, _) => {
+                self.frame_renderer.write_styled(
+                    Styled::new(pattern).with_style_sheet(self.render_config.error_message.message),
+                )?;
+                Styled::new(String::from("(invalid pattern)"))
+                    .with_style_sheet(self.render_config.error_message.message)
+            }
+            (false, 1) => {
+                self.frame_renderer.write(pattern)?;
+                Styled::new(String::from("(1 match)"))
+                    .with_style_sheet(self.render_config.filter_header)
+            }
+            (false, n) => {
+                self.frame_renderer.write(pattern)?;
+                Styled::new(format!("({} matches)", n))
+                    .with_style_sheet(self.render_config.filter_header)
+            }
+        };
+
+        self.frame_renderer.write(" ")?;
+        self.frame_renderer.write_styled(status)?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    fn render_loading_indicator(&mut self, tick: usize) -> Result<()> {
+        const FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+        const ASCII_FRAMES: [&str; 4] = ["|", "/", "-", "\\"];
+
+        let frame = match self.render_config.ascii_only {
+            true => ASCII_FRAMES[tick % ASCII_FRAMES.len()],
+            false => FRAMES[tick % FRAMES.len()],
+        };
+
+        self.frame_renderer.write_styled(
+            Styled::new(format!("{} Loading options...", frame))
+                .with_style_sheet(self.render_config.filter_header),
+        )?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+
+    fn render_search_header(
+        &mut self,
+        search: &Input,
+        editing: bool,
+        matches: usize,
+    ) -> Result<()> {
+        self.frame_renderer.write_styled(
+            Styled::new("Search:").with_style_sheet(self.render_config.filter_header),
+        )?;
+
+        if editing {
+            self.print_input(search)?;
+        } else {
+            self.frame_renderer.write(" ")?;
+            self.frame_renderer.write_styled(
+                Styled::new(search.content()).with_style_sheet(self.render_config.text_input),
+            )?;
+        }
+
+        if !search.is_empty() {
+            let count = match matches {
+                0 => Styled::new(String::from("(pattern not found)"))
+                    .with_style_sheet(self.render_config.error_message.message),
+                1 => Styled::new(String::from("(1 match)"))
+                    .with_style_sheet(self.render_config.filter_header),
+                n => Styled::new(format!("({} matches)", n))
+                    .with_style_sheet(self.render_config.filter_header),
+            };
+
+            self.frame_renderer.write(" ")?;
+            self.frame_renderer.write_styled(count)?;
+        }
+
+        self.new_line()?;
+
+        Ok(())
+    }
+
+    fn render_key_help(&mut self, entries: &[KeyHelp]) -> Result<()> {
+        let keys = entries
+            .iter()
+            .map(|entry| {
+                let mut names: Vec<String> = vec![];
+                for name in entry.keys.iter().map(Key::to_string) {
+                    if !names.contains(&name) {
+                        names.push(name);
+                    }
+                }
+                names.join(", ")
+            })
+            .collect::<Vec<String>>();
+        let width = keys
+            .iter()
+            .map(|k| self.str_width(k))
+            .max()
+            .unwrap_or_default();
+
+        for (keys, entry) in keys.iter().zip(entries) {
+            let padding = " ".repeat(width - self.str_width(keys));
+            let line = format!("  {}{}  {}", keys, padding, entry.description);
+
+            self.frame_renderer.write_styled(
+                Styled::new(line).with_style_sheet(self.render_config.help_message),
+            )?;
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, I, T> TextBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_prompt(
+        &mut self,
+        prompt: &str,
+        current: Option<&str>,
+        default: Option<&str>,
+        cur_input: &Input,
+    ) -> Result<()> {
+        self.print_prompt_with_input(prompt, current, default, cur_input)
+    }
+
+    fn render_suggestions<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()> {
+        for (idx, option) in page.content.iter().enumerate() {
+            self.print_option_prefix(idx, &page)?;
+
+            self.frame_renderer.write(" ")?;
+            self.print_option_value(idx, option, &page)?;
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "editor")]
+impl<'a, I, T> EditorBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_prompt(&mut self, prompt: &str, editor_command: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        self.frame_renderer.write(" ")?;
+
+        let message = format!("[(e) to open {}, (enter) to submit]", editor_command);
+        let token = Styled::new(message).with_style_sheet(self.render_config.editor_prompt);
+        self.frame_renderer.write_styled(token)?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+}
+
+impl<'a, I, T> SelectBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_select_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()> {
+        if let Some(input) = cur_input {
+            self.print_prompt_with_input(prompt, None, None, input)
+        } else {
+            self.print_prompt(prompt)
+        }
+    }
+
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        matches: &[Vec<usize>],
+        styles: &[StyleSheet],
+    ) -> Result<()> {
+        for (idx, option) in page.content.iter().enumerate() {
+            self.print_option_prefix(idx, &page)?;
+
+            self.frame_renderer.write(" ")?;
+
+            if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
+                res?;
+                self.frame_renderer.write(" ")?;
+            }
+
+            let matched = matches.get(idx).map(Vec::as_slice).unwrap_or_default();
+            let style = styles.get(idx).copied();
+            self.print_matched_option_value(idx, option, &page, matched, style)?;
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+
+    fn render_options_with_preview<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        matches: &[Vec<usize>],
+        styles: &[StyleSheet],
+        preview: &[&str],
+        preview_width: usize,
+    ) -> Result<()> {
+        const SEPARATOR: &str = " │ ";
+
+        let line_width = self
+            .frame_renderer
+            .remaining_line_width()
+            .unwrap_or_default();
+        let available_width = line_width.saturating_sub(SEPARATOR.chars().count());
+        let preview_width = preview_width.min(available_width / 2);
+
+        if preview_width == 0 {
+            return SelectBackend::render_options(self, page, matches, styles);
+        }
+
+        let column_width = available_width - preview_width;
+        let rows = page.content.len().max(preview.len());
+
+        for idx in 0..rows {
+            if let Some(option) = page.content.get(idx) {
+                self.print_option_prefix(idx, &page)?;
+                self.frame_renderer.write(" ")?;
+
+                if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
+                    res?;
+                    self.frame_renderer.write(" ")?;
+                }
+
+                // the value is truncated to the column whatever the render
+                // config, as wrapping it would break the layout of the panes
+                let used_width = line_width.saturating_sub(
+                    self.frame_renderer
+                        .remaining_line_width()
+                        .unwrap_or(line_width),
+                );
+                let value = option.value.to_string();
+                let value = truncate(
+                    &value,
+                    column_width.saturating_sub(used_width),
+                    Truncation::End,
+                    self.render_config.ambiguous_width,
+                );
+                let matched = match value {
+                    Cow::Borrowed(_) => matches.get(idx).map(Vec::as_slice).unwrap_or_default(),
+                    Cow::Owned(_) => &[],
+                };
+                let truncated = [ListOption::new(option.index, value)];
+                self.print_option_value_within(
+                    idx,
+                    &truncated[0],
+                    &page.with_content(&truncated),
+                    None,
+                    matched,
+                    styles.get(idx).copied(),
+                )?;
+            }
+
+            let used_width = line_width.saturating_sub(
+                self.frame_renderer
+                    .remaining_line_width()
+                    .unwrap_or(line_width),
+            );
+            self.frame_renderer
+                .write(" ".repeat(column_width.saturating_sub(used_width)))?;
+            self.frame_renderer.write(SEPARATOR)?;
+
+            if let Some(line) = preview.get(idx) {
+                let line = truncate(
+                    line,
+                    preview_width,
+                    Truncation::End,
+                    self.render_config.ambiguous_width,
+                );
+                self.frame_renderer.write(line)?;
+            }
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+
+    fn render_skip_option(&mut self, label: &str, highlighted: bool) -> Result<()> {
+        self.print_skip_option(label, highlighted)
+    }
+}
+
+impl<'a, I, T> MultiSelectBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_multiselect_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()> {
+        if let Some(input) = cur_input {
+            self.print_prompt_with_input(prompt, None, None, input)
+        } else {
+            self.print_prompt(prompt)
+        }
+    }
+
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        checked: &BTreeSet<usize>,
+        matches: &[Vec<usize>],
+        styles: &[StyleSheet],
+    ) -> Result<()> {
+        for (idx, option) in page.content.iter().enumerate() {
+            self.print_option_prefix(idx, &page)?;
+
+            self.frame_renderer.write(" ")?;
+
+            if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
+                res?;
+                self.frame_renderer.write(" ")?;
+            }
+
+            let mut checkbox = match checked.contains(&option.index) {
+                true => self.render_config.selected_checkbox,
+                false => self.render_config.unselected_checkbox,
+            };
+
+            match (self.render_config.selected_option, page.cursor) {
+                (Some(stylesheet), Some(cursor)) if cursor == idx => checkbox.style = stylesheet,
+                _ => {}
+            }
+
+            self.frame_renderer.write_styled(checkbox)?;
+
+            self.frame_renderer.write(" ")?;
+
+            let matched = matches.get(idx).map(Vec::as_slice).unwrap_or_default();
+            let style = styles.get(idx).copied();
+            self.print_matched_option_value(idx, option, &page, matched, style)?;
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+
+    fn render_skip_option(&mut self, label: &str, highlighted: bool) -> Result<()> {
+        self.print_skip_option(label, highlighted)
+    }
+
+    fn render_footer(&mut self, footer: &str) -> Result<()> {
+        if footer.is_empty() {
+            return Ok(());
+        }
+
+        self.print_message(footer, self.render_config.footer)?;
+        self.new_line()?;
+
+        Ok(())
+    }
+
+    fn render_view_header(&mut self, view: MultiSelectView, options: usize) -> Result<()> {
+        let label = match view {
+            MultiSelectView::All => "All",
+            MultiSelectView::Checked => "Checked",
+            MultiSelectView::Unchecked => "Unchecked",
+        };
+        let text = format!("{} options ({})", label, options);
+
+        self.frame_renderer
+            .write_styled(Styled::new(text).with_style_sheet(self.render_config.filter_header))?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+}
+
+impl<'a, I, T> TransferListBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_transfer_list_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.new_line()
+    }
+
+    fn render_transfer_panes<D: Display>(
+        &mut self,
+        available: Page<'_, ListOption<D>>,
+        chosen: Page<'_, ListOption<D>>,
+        focus: TransferListPane,
+        column_width: usize,
+    ) -> Result<()> {
+        const AVAILABLE_TITLE: &str = "Available";
+        const CHOSEN_TITLE: &str = "Chosen";
+        const SEPARATOR: &str = " │ ";
+
+        let mut column_width = column_width.max(AVAILABLE_TITLE.len());
+
+        if self.render_config.option_truncation.is_some() {
+            if let Some(line_width) = self.frame_renderer.remaining_line_width() {
+                // each pane is preceded by the cursor prefix and a space
+                let max_column_width = line_width.saturating_sub(4 + SEPARATOR.chars().count()) / 2;
+                column_width = column_width.min(max_column_width.max(AVAILABLE_TITLE.len()));
+            }
+        }
+
+        let title_style = |pane: TransferListPane| match focus == pane {
+            true => self.render_config.prompt,
+            false => self.render_config.help_message,
+        };
+        let available_title_style = title_style(TransferListPane::Available);
+        let chosen_title_style = title_style(TransferListPane::Chosen);
+
+        self.frame_renderer.write("  ")?;
+        self.frame_renderer
+            .write_styled(Styled::new(AVAILABLE_TITLE).with_style_sheet(available_title_style))?;
+        self.frame_renderer
+            .write(" ".repeat(column_width - AVAILABLE_TITLE.len()))?;
+        self.frame_renderer.write(SEPARATOR)?;
+        self.frame_renderer.write("  ")?;
+        self.frame_renderer
+            .write_styled(Styled::new(CHOSEN_TITLE).with_style_sheet(chosen_title_style))?;
+        self.new_line()?;
+
+        let rows = available.content.len().max(chosen.content.len());
+
+        for idx in 0..rows {
+            match available.content.get(idx) {
+                Some(option) => {
+                    self.print_option_prefix(idx, &available)?;
+                    self.frame_renderer.write(" ")?;
+                    let width = self.print_option_value_within(
+                        idx,
+                        option,
+                        &available,
+                        Some(column_width),
+                        &[],
+                        None,
+                    )?;
+
+                    self.frame_renderer
+                        .write(" ".repeat(column_width.saturating_sub(width)))?;
+                }
+                None => self.frame_renderer.write(" ".repeat(column_width + 2))?,
+            }
+
+            self.frame_renderer.write(SEPARATOR)?;
+
+            if let Some(option) = chosen.content.get(idx) {
+                self.print_option_prefix(idx, &chosen)?;
+                self.frame_renderer.write(" ")?;
+                self.print_option_value(idx, option, &chosen)?;
+            }
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, I, T> CheckboxMatrixBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_checkbox_matrix_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.new_line()
+    }
+
+    fn render_checkbox_matrix<R: Display, C: Display>(
+        &mut self,
+        rows: Page<'_, ListOption<R>>,
+        columns: &[C],
+        checked: &BTreeSet<(usize, usize)>,
+        column_cursor: usize,
+        row_label_width: usize,
+    ) -> Result<()> {
+        let checkbox_width = self
+            .str_width(self.render_config.selected_checkbox.content)
+            .max(self.str_width(self.render_config.unselected_checkbox.content));
+
+        let column_labels = columns.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+        let column_widths = column_labels
+            .iter()
+            .map(|label| self.str_width(label).max(checkbox_width))
+            .collect::<Vec<_>>();
+
+        // each cell is preceded by a separator and a slot for the cursor prefix.
+        self.frame_renderer.write(" ".repeat(row_label_width + 2))?;
+        for (idx, label) in column_labels.iter().enumerate() {
+            let style = match idx == column_cursor {
+                true => self.render_config.prompt,
+                false => self.render_config.help_message,
+            };
 
-        self.frame_renderer.write_styled(
-            Styled::new(" ").with_style_sheet(self.render_config.error_message.separator),
-        )?;
+            self.frame_renderer.write("  ")?;
+            self.frame_renderer
+                .write_styled(Styled::new(label.as_str()).with_style_sheet(style))?;
+            self.frame_renderer
+                .write(" ".repeat(column_widths[idx] - self.str_width(label)))?;
+        }
+        self.new_line()?;
 
-        let message = match error {
-            ErrorMessage::Default => self.render_config.error_message.default_message,
-            ErrorMessage::Custom(msg) => msg,
-        };
+        for (idx, option) in rows.content.iter().enumerate() {
+            self.print_option_prefix(idx, &rows)?;
+            self.frame_renderer.write(" ")?;
+            self.print_option_value(idx, option, &rows)?;
 
-        self.frame_renderer.write_styled(
-            Styled::new(message).with_style_sheet(self.render_config.error_message.message),
-        )?;
+            let label_width = self.str_width(&option.value.to_string());
+            self.frame_renderer
+                .write(" ".repeat(row_label_width.saturating_sub(label_width)))?;
 
-        self.new_line()?;
+            let is_cursor_row = rows.cursor == Some(idx);
 
-        Ok(())
-    }
+            for (column, width) in column_widths.iter().enumerate() {
+                self.frame_renderer.write(" ")?;
 
-    fn render_help_message(&mut self, help: &str) -> Result<()> {
-        self.frame_renderer
-            .write_styled(Styled::new("[").with_style_sheet(self.render_config.help_message))?;
+                let is_cursor_cell = is_cursor_row && column == column_cursor;
+                match is_cursor_cell {
+                    true => self
+                        .frame_renderer
+                        .write_styled(self.render_config.highlighted_option_prefix)?,
+                    false => self.frame_renderer.write(" ")?,
+                }
 
-        self.frame_renderer
-            .write_styled(Styled::new(help).with_style_sheet(self.render_config.help_message))?;
+                let mut checkbox = match checked.contains(&(option.index, column)) {
+                    true => self.render_config.selected_checkbox,
+                    false => self.render_config.unselected_checkbox,
+                };
 
-        self.frame_renderer
-            .write_styled(Styled::new("]").with_style_sheet(self.render_config.help_message))?;
+                match self.render_config.selected_option {
+                    Some(stylesheet) if is_cursor_cell => checkbox.style = stylesheet,
+                    _ => {}
+                }
 
-        self.new_line()?;
+                let checkbox_len = self.str_width(checkbox.content);
+                self.frame_renderer.write_styled(checkbox)?;
+                self.frame_renderer
+                    .write(" ".repeat(width.saturating_sub(checkbox_len)))?;
+            }
+
+            self.new_line()?;
+        }
 
         Ok(())
     }
 }
 
-impl<'a, I, T> TextBackend for Backend<'a, I, T>
+impl<'a, I, T> QuizBackend for Backend<'a, I, T>
 where
     I: InputReader,
     T: Terminal,
 {
-    fn render_prompt(
+    fn render_quiz_result(
         &mut self,
-        prompt: &str,
-        default: Option<&str>,
-        cur_input: &Input,
+        options: &[String],
+        chosen: &BTreeSet<usize>,
+        correct: &BTreeSet<usize>,
     ) -> Result<()> {
-        self.print_prompt_with_input(prompt, default, cur_input)
-    }
+        let quiz = self.render_config.quiz;
+        let prefix_width = self.str_width(self.render_config.highlighted_option_prefix.content);
+        let marker_width = self
+            .str_width(quiz.correct_marker.content)
+            .max(self.str_width(quiz.wrong_marker.content));
+
+        for (idx, option) in options.iter().enumerate() {
+            // chosen options are pointed at like the option under the cursor
+            match chosen.contains(&idx) {
+                true => self
+                    .frame_renderer
+                    .write_styled(self.render_config.highlighted_option_prefix)?,
+                false => self.frame_renderer.write(" ".repeat(prefix_width))?,
+            }
+            self.frame_renderer.write(" ")?;
 
-    fn render_suggestions<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()> {
-        for (idx, option) in page.content.iter().enumerate() {
-            self.print_option_prefix(idx, &page)?;
+            let (marker, style) = if correct.contains(&idx) {
+                (Some(quiz.correct_marker), quiz.correct_option)
+            } else if chosen.contains(&idx) {
+                (Some(quiz.wrong_marker), quiz.wrong_option)
+            } else {
+                (None, self.render_config.option)
+            };
 
-            self.frame_renderer.write(" ")?;
-            self.print_option_value(idx, option, &page)?;
+            let written = match marker {
+                Some(marker) => {
+                    self.frame_renderer.write_styled(marker)?;
+                    self.str_width(marker.content)
+                }
+                None => 0,
+            };
+            self.frame_renderer
+                .write(" ".repeat(marker_width.saturating_sub(written) + 1))?;
 
+            self.frame_renderer
+                .write_styled(Styled::new(option.as_str()).with_style_sheet(style))?;
             self.new_line()?;
         }
 
@@ -343,114 +1409,294 @@ where
     }
 }
 
-#[cfg(feature = "editor")]
-impl<'a, I, T> EditorBackend for Backend<'a, I, T>
+impl<'a, I, T> MessageBackend for Backend<'a, I, T>
 where
     I: InputReader,
     T: Terminal,
 {
-    fn render_prompt(&mut self, prompt: &str, editor_command: &str) -> Result<()> {
-        self.print_prompt(prompt)?;
+    fn render_message(&mut self, level: MessageLevel, message: &str) -> Result<()> {
+        let config = match level {
+            MessageLevel::Info => self.render_config.info_message,
+            MessageLevel::Warning => self.render_config.warning_message,
+            MessageLevel::Success => self.render_config.success_message,
+        };
 
+        self.frame_renderer.write_styled(config.prefix)?;
         self.frame_renderer.write(" ")?;
+        self.print_message(message, config.message)?;
 
-        let message = format!("[(e) to open {}, (enter) to submit]", editor_command);
-        let token = Styled::new(message).with_style_sheet(self.render_config.editor_prompt);
-        self.frame_renderer.write_styled(token)?;
+        self.new_line()
+    }
+}
 
-        self.new_line()?;
+impl<'a, I, T> SectionBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_section(&mut self, title: Option<&str>) -> Result<()> {
+        // rules spanning very wide terminals are more distracting than helpful
+        const MAX_RULE_WIDTH: usize = 80;
+
+        let rule = self.render_config.section_rule;
+        let rule_width = self.str_width(rule.content).max(1);
+        let line_width = self
+            .frame_renderer
+            .remaining_line_width()
+            .unwrap_or(MAX_RULE_WIDTH)
+            .min(MAX_RULE_WIDTH);
+
+        let mut used_width = 0;
+        if let Some(title) = title {
+            self.frame_renderer.write_styled(rule)?;
+            self.frame_renderer.write_styled(rule)?;
+            self.frame_renderer.write(" ")?;
+            self.frame_renderer.write_styled(
+                Styled::new(title).with_style_sheet(self.render_config.section_title),
+            )?;
+            self.frame_renderer.write(" ")?;
 
-        Ok(())
+            used_width = 2 * rule_width + self.str_width(title) + 2;
+        }
+
+        let fill = line_width.saturating_sub(used_width) / rule_width;
+        let fill = Styled::new(rule.content.repeat(fill)).with_style_sheet(rule.style);
+        self.frame_renderer.write_styled(fill)?;
+
+        self.new_line()
     }
 }
 
-impl<'a, I, T> SelectBackend for Backend<'a, I, T>
+impl<'a, I, T> KeyCaptureBackend for Backend<'a, I, T>
 where
     I: InputReader,
     T: Terminal,
 {
-    fn render_select_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()> {
-        if let Some(input) = cur_input {
-            self.print_prompt_with_input(prompt, None, input)
-        } else {
-            self.print_prompt(prompt)
-        }
+    fn render_key_capture_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        // keeps the cursor after the prompt, where the captured key will be shown.
+        self.frame_renderer.write(" ")?;
+        self.frame_renderer.mark_cursor_position(0);
+        self.frame_renderer.write(" ")?;
+
+        self.new_line()
     }
+}
 
-    fn render_options<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()> {
-        for (idx, option) in page.content.iter().enumerate() {
-            self.print_option_prefix(idx, &page)?;
+impl<'a, I, T> RatingBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_rating_prompt(
+        &mut self,
+        prompt: &str,
+        value: u8,
+        max: u8,
+        display_mode: RatingDisplayMode,
+        labels: Option<(&str, &str)>,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.frame_renderer.write(" ")?;
 
+        if let Some((low, _)) = labels {
+            self.frame_renderer
+                .write_styled(Styled::new(low).with_style_sheet(self.render_config.help_message))?;
             self.frame_renderer.write(" ")?;
+        }
 
-            if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
-                res?;
-                self.frame_renderer.write(" ")?;
+        match display_mode {
+            RatingDisplayMode::Stars => {
+                for point in 1..=max {
+                    let symbol = match point <= value {
+                        true => self.render_config.filled_rating_symbol,
+                        false => self.render_config.empty_rating_symbol,
+                    };
+                    self.frame_renderer.write_styled(symbol)?;
+                }
             }
+            RatingDisplayMode::Numeric => {
+                for point in 1..=max {
+                    if point == value {
+                        let style = self
+                            .render_config
+                            .selected_option
+                            .unwrap_or(self.render_config.option);
+                        self.frame_renderer.write_styled(
+                            Styled::new(format!("[{}]", point)).with_style_sheet(style),
+                        )?;
+                    } else {
+                        self.frame_renderer.write_styled(
+                            Styled::new(format!(" {} ", point))
+                                .with_style_sheet(self.render_config.option),
+                        )?;
+                    }
+                }
+            }
+        }
 
-            self.print_option_value(idx, option, &page)?;
-
-            self.new_line()?;
+        if let Some((_, high)) = labels {
+            self.frame_renderer.write(" ")?;
+            self.frame_renderer.write_styled(
+                Styled::new(high).with_style_sheet(self.render_config.help_message),
+            )?;
         }
 
-        Ok(())
+        self.new_line()
     }
 }
 
-impl<'a, I, T> MultiSelectBackend for Backend<'a, I, T>
+impl<'a, I, T> DiffBackend for Backend<'a, I, T>
 where
     I: InputReader,
     T: Terminal,
 {
-    fn render_multiselect_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()> {
-        if let Some(input) = cur_input {
-            self.print_prompt_with_input(prompt, None, input)
-        } else {
-            self.print_prompt(prompt)
+    fn render_diff_approval_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.new_line()
+    }
+
+    fn render_diff(&mut self, lines: &[&str], first_line: usize, total_lines: usize) -> Result<()> {
+        let diff_config = self.render_config.diff;
+
+        for line in lines {
+            let style = if line.starts_with("+++")
+                || line.starts_with("---")
+                || line.starts_with("diff ")
+                || line.starts_with("index ")
+            {
+                diff_config.file_header
+            } else if line.starts_with("@@") {
+                diff_config.hunk_header
+            } else if line.starts_with('+') {
+                diff_config.added
+            } else if line.starts_with('-') {
+                diff_config.removed
+            } else {
+                diff_config.context
+            };
+
+            self.frame_renderer
+                .write_styled(Styled::new(*line).with_style_sheet(style))?;
+            self.new_line()?;
+        }
+
+        if lines.len() < total_lines {
+            let position = format!(
+                "lines {}-{} of {}",
+                first_line + 1,
+                first_line + lines.len(),
+                total_lines
+            );
+            self.frame_renderer.write_styled(
+                Styled::new(position).with_style_sheet(self.render_config.help_message),
+            )?;
+            self.new_line()?;
         }
+
+        Ok(())
     }
 
-    fn render_options<D: Display>(
+    fn render_diff_decisions(
         &mut self,
-        page: Page<'_, ListOption<D>>,
-        checked: &BTreeSet<usize>,
+        decisions: &[DiffDecision],
+        highlighted: usize,
     ) -> Result<()> {
-        for (idx, option) in page.content.iter().enumerate() {
-            self.print_option_prefix(idx, &page)?;
-
-            self.frame_renderer.write(" ")?;
+        for (idx, decision) in decisions.iter().enumerate() {
+            let label = match decision {
+                DiffDecision::Approve => "[a]pprove",
+                DiffDecision::Reject => "[r]eject",
+                DiffDecision::Edit => "[e]dit",
+            };
 
-            if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
-                res?;
+            if idx == highlighted {
+                let style = self
+                    .render_config
+                    .selected_option
+                    .unwrap_or(self.render_config.option);
+                self.frame_renderer
+                    .write_styled(self.render_config.highlighted_option_prefix)?;
                 self.frame_renderer.write(" ")?;
+                self.frame_renderer
+                    .write_styled(Styled::new(label).with_style_sheet(style))?;
+            } else {
+                self.frame_renderer.write("  ")?;
+                self.frame_renderer
+                    .write_styled(Styled::new(label).with_style_sheet(self.render_config.option))?;
             }
 
-            let mut checkbox = match checked.contains(&option.index) {
-                true => self.render_config.selected_checkbox,
-                false => self.render_config.unselected_checkbox,
-            };
-
-            match (self.render_config.selected_option, page.cursor) {
-                (Some(stylesheet), Some(cursor)) if cursor == idx => checkbox.style = stylesheet,
-                _ => {}
-            }
+            self.frame_renderer.write("  ")?;
+        }
 
-            self.frame_renderer.write_styled(checkbox)?;
+        self.new_line()
+    }
+}
 
-            self.frame_renderer.write(" ")?;
+impl<'a, I, T> PagerBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_pager_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.new_line()
+    }
 
-            self.print_option_value(idx, option, &page)?;
+    fn render_pager_lines(&mut self, lines: &[&str], highlighted: Option<usize>) -> Result<()> {
+        for (idx, line) in lines.iter().enumerate() {
+            let style = match highlighted {
+                Some(highlighted) if highlighted == idx => self
+                    .render_config
+                    .selected_option
+                    .unwrap_or(self.render_config.option),
+                _ => self.render_config.option,
+            };
 
+            self.frame_renderer
+                .write_styled(Styled::new(*line).with_style_sheet(style))?;
             self.new_line()?;
         }
 
         Ok(())
     }
+
+    fn render_pager_status(
+        &mut self,
+        first_line: usize,
+        last_line: usize,
+        total_lines: usize,
+        search: Option<&Input>,
+        not_found: bool,
+    ) -> Result<()> {
+        let position = match total_lines {
+            0 => String::from("(empty)"),
+            _ => format!("lines {}-{} of {}", first_line + 1, last_line, total_lines),
+        };
+        self.frame_renderer.write_styled(
+            Styled::new(position).with_style_sheet(self.render_config.help_message),
+        )?;
+
+        if not_found {
+            self.frame_renderer.write(" ")?;
+            self.frame_renderer.write_styled(
+                Styled::new("(pattern not found)")
+                    .with_style_sheet(self.render_config.error_message.message),
+            )?;
+        }
+
+        if let Some(search) = search {
+            self.frame_renderer.write(" /")?;
+            self.print_input(search)?;
+        }
+
+        self.new_line()
+    }
 }
 
 #[cfg(feature = "date")]
 pub mod date {
-    use std::{io::Result, ops::Sub};
+    use std::{collections::BTreeSet, io::Result, ops::Sub};
 
     use chrono::{Datelike, Duration};
 
@@ -458,6 +1704,7 @@ pub mod date {
         date_utils::get_start_date,
         terminal::Terminal,
         ui::{InputReader, Styled},
+        TimeSegment,
     };
 
     use super::{Backend, CommonBackend};
@@ -473,11 +1720,21 @@ pub mod date {
             week_start: chrono::Weekday,
             today: chrono::NaiveDate,
             selected_date: chrono::NaiveDate,
+            marked_dates: &BTreeSet<chrono::NaiveDate>,
             min_date: Option<chrono::NaiveDate>,
             max_date: Option<chrono::NaiveDate>,
         ) -> Result<()>;
     }
 
+    pub trait TimeSelectBackend: CommonBackend {
+        fn render_time_prompt(
+            &mut self,
+            prompt: &str,
+            segments: &[(TimeSegment, String)],
+            current: TimeSegment,
+        ) -> Result<()>;
+    }
+
     impl<'a, I, T> DateSelectBackend for Backend<'a, I, T>
     where
         I: InputReader,
@@ -496,6 +1753,7 @@ pub mod date {
             week_start: chrono::Weekday,
             today: chrono::NaiveDate,
             selected_date: chrono::NaiveDate,
+            marked_dates: &BTreeSet<chrono::NaiveDate>,
             min_date: Option<chrono::NaiveDate>,
             max_date: Option<chrono::NaiveDate>,
         ) -> Result<()> {
@@ -555,10 +1813,15 @@ pub mod date {
             }
 
             for _ in 0..6 {
-                write_prefix!()?;
-
-                for i in 0..7 {
-                    if i > 0 {
+                self.frame_renderer
+                    .write_styled(self.render_config.calendar.prefix)?;
+
+                for _ in 0..7 {
+                    // marked dates replace the space before them with the marker
+                    if marked_dates.contains(&date_it) {
+                        self.frame_renderer
+                            .write_styled(self.render_config.calendar.marker)?;
+                    } else {
                         self.frame_renderer.write(" ")?;
                     }
 
@@ -574,6 +1837,8 @@ pub mod date {
                         {
                             style_sheet = custom_style_sheet;
                         }
+                    } else if marked_dates.contains(&date_it) {
+                        style_sheet = self.render_config.calendar.marked_date;
                     } else if date_it == today {
                         style_sheet = self.render_config.calendar.today_date;
                     } else if date_it.month() != month.number_from_month() {
@@ -604,6 +1869,44 @@ pub mod date {
             Ok(())
         }
     }
+
+    impl<'a, I, T> TimeSelectBackend for Backend<'a, I, T>
+    where
+        I: InputReader,
+        T: Terminal,
+    {
+        fn render_time_prompt(
+            &mut self,
+            prompt: &str,
+            segments: &[(TimeSegment, String)],
+            current: TimeSegment,
+        ) -> Result<()> {
+            self.print_prompt(prompt)?;
+            self.frame_renderer.write(" ")?;
+
+            for (segment, value) in segments {
+                match segment {
+                    TimeSegment::Hour => {}
+                    TimeSegment::Minute | TimeSegment::Second => self.frame_renderer.write(":")?,
+                    TimeSegment::Period => self.frame_renderer.write(" ")?,
+                }
+
+                let style = if *segment == current {
+                    self.frame_renderer.mark_cursor_position(0);
+                    self.render_config
+                        .selected_option
+                        .unwrap_or(self.render_config.option)
+                } else {
+                    self.render_config.option
+                };
+
+                self.frame_renderer
+                    .write_styled(Styled::new(value.as_str()).with_style_sheet(style))?;
+            }
+
+            self.new_line()
+        }
+    }
 }
 
 impl<'a, I, T> CustomTypeBackend for Backend<'a, I, T>
@@ -614,10 +1917,11 @@ where
     fn render_prompt(
         &mut self,
         prompt: &str,
+        current: Option<&str>,
         default: Option<&str>,
         cur_input: &Input,
     ) -> Result<()> {
-        self.print_prompt_with_input(prompt, default, cur_input)
+        self.print_prompt_with_input(prompt, current, default, cur_input)
     }
 }
 
@@ -639,11 +1943,11 @@ where
 
         let masked_input = Input::new_with(masked_string).with_cursor(cur_input.cursor());
 
-        self.print_prompt_with_input(prompt, None, &masked_input)
+        self.print_prompt_with_input(prompt, None, None, &masked_input)
     }
 
     fn render_prompt_with_full_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
-        self.print_prompt_with_input(prompt, None, cur_input)
+        self.print_prompt_with_input(prompt, None, None, cur_input)
     }
 }
 
@@ -652,15 +1956,30 @@ where
     I: InputReader,
     T: Terminal,
 {
+    fn start_prompt(&mut self, message: &str) -> InquireResult<()> {
+        self.input_reader.start_prompt(message)
+    }
+
     fn read_key(&mut self) -> InquireResult<Key> {
         self.input_reader.read_key()
     }
+
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        self.input_reader.read_key_timeout(timeout)
+    }
+
+    fn supports_read_timeout(&self) -> bool {
+        self.input_reader.supports_read_timeout()
+    }
 }
 
 #[cfg(test)]
 pub(crate) mod test {
+    #[cfg(feature = "date")]
+    use std::collections::BTreeSet;
     use std::collections::VecDeque;
 
+    #[cfg(feature = "date")]
     use chrono::{Month, NaiveDate, Weekday};
 
     use crate::{
@@ -674,18 +1993,28 @@ pub(crate) mod test {
     #[derive(Debug, Clone, PartialEq)]
     pub enum Token {
         Prompt(String),
+        CurrentValue(String),
         DefaultValue(String),
         Input(Input),
         CanceledPrompt(String),
         AnsweredPrompt(String, String),
         ErrorMessage(ErrorMessage),
         HelpMessage(String),
+        RemainingTime(std::time::Duration),
+        ElapsedTime(std::time::Duration),
+        FilterHeader(String, usize),
+        SearchHeader(String, usize),
+        KeyHelp(Vec<crate::ui::KeyHelp>),
+        #[cfg(feature = "async")]
+        LoadingIndicator,
+        #[cfg(feature = "date")]
         Calendar {
             month: Month,
             year: i32,
             week_start: Weekday,
             today: NaiveDate,
             selected_date: NaiveDate,
+            marked_dates: BTreeSet<NaiveDate>,
             min_date: Option<NaiveDate>,
             max_date: Option<NaiveDate>,
         },
@@ -752,6 +2081,14 @@ pub(crate) mod test {
             Ok(())
         }
 
+        fn frame_interval(&self) -> Option<std::time::Duration> {
+            None
+        }
+
+        fn key_bindings(&self) -> Option<crate::ui::KeyBindings<'static>> {
+            None
+        }
+
         fn frame_finish(&mut self, is_last_frame: bool) -> std::io::Result<()> {
             if is_last_frame {
                 self.push_token(Token::PromptEnd);
@@ -787,6 +2124,53 @@ pub(crate) mod test {
             self.push_token(Token::HelpMessage(help.to_string()));
             Ok(())
         }
+
+        fn render_remaining_time(&mut self, remaining: std::time::Duration) -> std::io::Result<()> {
+            self.push_token(Token::RemainingTime(remaining));
+            Ok(())
+        }
+
+        fn render_elapsed_time(&mut self, elapsed: std::time::Duration) -> std::io::Result<()> {
+            self.push_token(Token::ElapsedTime(elapsed));
+            Ok(())
+        }
+
+        fn render_key_help(&mut self, entries: &[crate::ui::KeyHelp]) -> std::io::Result<()> {
+            self.push_token(Token::KeyHelp(entries.to_vec()));
+            Ok(())
+        }
+
+        fn render_filter_header(&mut self, filter: &str, matches: usize) -> std::io::Result<()> {
+            self.push_token(Token::FilterHeader(filter.to_string(), matches));
+            Ok(())
+        }
+
+        #[cfg(feature = "regex")]
+        fn render_regex_filter_header(
+            &mut self,
+            pattern: &str,
+            matches: usize,
+            _invalid: bool,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::FilterHeader(pattern.to_string(), matches));
+            Ok(())
+        }
+
+        #[cfg(feature = "async")]
+        fn render_loading_indicator(&mut self, _tick: usize) -> std::io::Result<()> {
+            self.push_token(Token::LoadingIndicator);
+            Ok(())
+        }
+
+        fn render_search_header(
+            &mut self,
+            search: &Input,
+            _editing: bool,
+            matches: usize,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::SearchHeader(search.content().to_string(), matches));
+            Ok(())
+        }
     }
 
     #[cfg(feature = "date")]
@@ -803,6 +2187,7 @@ pub(crate) mod test {
             week_start: Weekday,
             today: NaiveDate,
             selected_date: NaiveDate,
+            marked_dates: &BTreeSet<NaiveDate>,
             min_date: Option<NaiveDate>,
             max_date: Option<NaiveDate>,
         ) -> std::io::Result<()> {
@@ -812,6 +2197,7 @@ pub(crate) mod test {
                 week_start,
                 today,
                 selected_date,
+                marked_dates: marked_dates.clone(),
                 min_date,
                 max_date,
             });
@@ -823,10 +2209,14 @@ pub(crate) mod test {
         fn render_prompt(
             &mut self,
             prompt: &str,
+            current: Option<&str>,
             default: Option<&str>,
             cur_input: &Input,
         ) -> std::io::Result<()> {
             self.push_token(Token::Prompt(prompt.to_string()));
+            if let Some(current) = current {
+                self.push_token(Token::CurrentValue(current.to_string()));
+            }
             if let Some(default) = default {
                 self.push_token(Token::DefaultValue(default.to_string()));
             }
@@ -4,11 +4,13 @@ use std::hash::{Hash, Hasher};
 use std::io;
 
 use fxhash::FxHasher;
-use unicode_width::UnicodeWidthChar;
 
+use super::ascii::to_ascii;
 use super::dimension::Dimension;
-use super::{Position, Styled};
+use super::width::WidthCounter;
+use super::{AmbiguousWidth, CursorStyle, Position, Styled};
 use crate::ansi::{AnsiAware, AnsiAwareChar};
+use crate::config::report_frame;
 use crate::terminal::{Terminal, TerminalSize};
 
 #[derive(Debug, Default)]
@@ -46,10 +48,16 @@ struct FrameState {
     pub current_line: Vec<Styled<String>>,
     pub current_line_width: u16,
     pub current_line_hasher: FxHasher,
+    pub width_counter: WidthCounter,
+    pub ansi_passthrough: bool,
 }
 
 impl FrameState {
-    pub fn new(terminal_size: TerminalSize) -> Self {
+    pub fn new(
+        terminal_size: TerminalSize,
+        ambiguous_width: AmbiguousWidth,
+        ansi_passthrough: bool,
+    ) -> Self {
         Self {
             terminal_size,
             frame_size: Dimension::new(0, 0),
@@ -59,11 +67,14 @@ impl FrameState {
             current_line_hasher: FxHasher::default(),
             current_line_width: 0,
             expected_cursor_position: None,
+            width_counter: WidthCounter::new(ambiguous_width),
+            ansi_passthrough,
         }
     }
 
     pub fn write(&mut self, value: &Styled<impl AsRef<str> + Display>) {
         self.current_styled.style = value.style;
+        let mut passed_through_style = false;
 
         for piece in value.content.ansi_aware_chars() {
             piece.hash(&mut self.current_line_hasher);
@@ -71,20 +82,26 @@ impl FrameState {
 
             let current_char = match piece {
                 AnsiAwareChar::Char(c) => c,
-                AnsiAwareChar::AnsiEscapeSequence(_) => {
+                AnsiAwareChar::AnsiEscapeSequence(seq) => {
                     // we don't care for escape sequences when calculating cursor position
-                    // and box size
+                    // and box size, but hyperlinks (OSC 8) are kept as they don't affect
+                    // the layout
+                    if self.ansi_passthrough || seq.starts_with("\x1b]8;") {
+                        self.current_styled.content.push_str(seq);
+                        passed_through_style |= seq.starts_with("\x1b[") && seq.ends_with('m');
+                    }
                     continue;
                 }
             };
 
             if current_char == '\n' {
                 self.finish_line();
+                self.width_counter.reset();
                 continue;
             }
 
             let remaining_width_space = self.terminal_size.width() - self.current_line_width;
-            let character_length = UnicodeWidthChar::width(current_char).unwrap_or(0) as u16;
+            let character_length = self.width_counter.next(current_char) as u16;
 
             if character_length > remaining_width_space {
                 // the character will (probably) not fit into the current line
@@ -95,6 +112,11 @@ impl FrameState {
             self.current_styled.content.push(current_char);
         }
 
+        // keeps styles passed through from bleeding into the following content
+        if passed_through_style {
+            self.current_styled.content.push_str("\x1b[0m");
+        }
+
         if !self.current_styled.content.is_empty() {
             self.current_line
                 .push(std::mem::take(&mut self.current_styled));
@@ -124,7 +146,11 @@ impl FrameState {
             return;
         }
 
-        let mut new_state = Self::new(new_size);
+        let mut new_state = Self::new(
+            new_size,
+            self.width_counter.ambiguous_width(),
+            self.ansi_passthrough,
+        );
         for row in &self.finished_rows {
             for styled in row.get_content() {
                 new_state.write(styled);
@@ -190,17 +216,36 @@ where
     terminal: T,
     cursor_position: Position,
     state: RenderState,
+    ambiguous_width: AmbiguousWidth,
+    ansi_passthrough: bool,
+    ascii_only: bool,
+    text_input_cursor: CursorStyle,
+    navigation_cursor: CursorStyle,
+    cursor_shape: u8,
 }
 
 impl<T> FrameRenderer<T>
 where
     T: Terminal,
 {
-    pub fn new(terminal: T) -> io::Result<Self> {
+    pub fn new(
+        terminal: T,
+        ambiguous_width: AmbiguousWidth,
+        ansi_passthrough: bool,
+        ascii_only: bool,
+        text_input_cursor: CursorStyle,
+        navigation_cursor: CursorStyle,
+    ) -> io::Result<Self> {
         Ok(Self {
             terminal,
             cursor_position: Position::default(),
             state: RenderState::Initial,
+            ambiguous_width,
+            ansi_passthrough,
+            ascii_only,
+            text_input_cursor,
+            navigation_cursor,
+            cursor_shape: 0,
         })
     }
 
@@ -217,7 +262,10 @@ where
                 //
                 // we pay a little bit in memory/cpu usage for this so we can
                 // calculate incremental rendering and cursor position on-the-fly.
-                let formatted = format!("{}", value.content);
+                let mut formatted = format!("{}", value.content);
+                if self.ascii_only {
+                    formatted = to_ascii(&formatted, self.ambiguous_width).into_owned();
+                }
                 let value = value.with_content(formatted);
 
                 current_frame.write(&value);
@@ -227,6 +275,42 @@ where
         Ok(())
     }
 
+    /// Writes the value straight to the terminal, outside of the frame being rendered.
+    ///
+    /// Meant for control sequences that don't move the cursor, such as the bell.
+    pub fn write_unframed(&mut self, value: impl Display) -> io::Result<()> {
+        self.terminal.write(value)?;
+        self.terminal.flush()
+    }
+
+    /// Width left in the line being rendered, if a frame is being rendered.
+    pub fn remaining_line_width(&self) -> Option<usize> {
+        match &self.state {
+            RenderState::Rendered(_) | RenderState::Initial => None,
+            RenderState::ActiveRender { current_frame, .. } => Some(
+                current_frame
+                    .terminal_size
+                    .width()
+                    .saturating_sub(current_frame.current_line_width) as usize,
+            ),
+        }
+    }
+
+    /// Width of the terminal, if a frame is being rendered.
+    pub fn terminal_width(&self) -> Option<u16> {
+        match &self.state {
+            RenderState::Rendered(_) | RenderState::Initial => None,
+            RenderState::ActiveRender { current_frame, .. } => {
+                Some(current_frame.terminal_size.width())
+            }
+        }
+    }
+
+    #[cfg(test)]
+    pub fn terminal_mut(&mut self) -> &mut T {
+        &mut self.terminal
+    }
+
     pub fn mark_cursor_position(&mut self, offset: isize) {
         match &mut self.state {
             RenderState::Rendered(_) | RenderState::Initial => {}
@@ -241,13 +325,25 @@ where
 
         self.state = match std::mem::replace(&mut self.state, RenderState::Initial) {
             RenderState::Initial => RenderState::ActiveRender {
-                last_rendered_frame: FrameState::new(terminal_size),
-                current_frame: FrameState::new(terminal_size),
+                last_rendered_frame: FrameState::new(
+                    terminal_size,
+                    self.ambiguous_width,
+                    self.ansi_passthrough,
+                ),
+                current_frame: FrameState::new(
+                    terminal_size,
+                    self.ambiguous_width,
+                    self.ansi_passthrough,
+                ),
             },
 
             RenderState::Rendered(last_rendered_frame) => RenderState::ActiveRender {
                 last_rendered_frame,
-                current_frame: FrameState::new(terminal_size),
+                current_frame: FrameState::new(
+                    terminal_size,
+                    self.ambiguous_width,
+                    self.ansi_passthrough,
+                ),
             },
 
             RenderState::ActiveRender {
@@ -275,6 +371,17 @@ where
 
         current_frame.finish();
 
+        let rows: Vec<&[Styled<String>]> = current_frame
+            .finished_rows
+            .iter()
+            .map(FrameRow::get_content)
+            .collect();
+
+        if self.terminal.captures_frames() {
+            self.terminal.capture_frame(&rows);
+        }
+        report_frame(&rows);
+
         let rows_to_iterate = std::cmp::max(
             last_rendered_frame.frame_size.height(),
             current_frame.frame_size.height(),
@@ -326,11 +433,15 @@ where
             self.cursor_position.row += 1;
         }
 
-        if let Some(expected_cursor_position) = current_frame.expected_cursor_position {
-            self.move_cursor_to(expected_cursor_position)?;
-        }
+        let cursor_style = match current_frame.expected_cursor_position {
+            Some(expected_cursor_position) => {
+                self.move_cursor_to(expected_cursor_position)?;
+                self.text_input_cursor
+            }
+            None => self.navigation_cursor,
+        };
 
-        self.terminal.cursor_show()?;
+        self.show_cursor(cursor_style)?;
         self.terminal.flush()?;
 
         self.state = RenderState::Rendered(current_frame);
@@ -338,6 +449,19 @@ where
         Ok(())
     }
 
+    fn show_cursor(&mut self, style: CursorStyle) -> io::Result<()> {
+        let Some(shape) = style.shape_code() else {
+            return Ok(());
+        };
+
+        if shape != self.cursor_shape {
+            self.terminal.cursor_set_shape(shape)?;
+            self.cursor_shape = shape;
+        }
+
+        self.terminal.cursor_show()
+    }
+
     fn move_cursor_to_end_position(&mut self) -> io::Result<()> {
         self.refresh_terminal_size();
 
@@ -434,7 +558,7 @@ where
 {
     fn drop(&mut self) {
         let _unused = self.move_cursor_to_end_position();
-        let _unused = self.terminal.cursor_show();
+        let _unused = self.show_cursor(CursorStyle::Default);
         let _unused = self.terminal.flush();
     }
 }
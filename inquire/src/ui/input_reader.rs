@@ -1,7 +1,50 @@
-use crate::error::InquireResult;
+use std::time::Duration;
+
+use crate::{error::InquireResult, response::ResponseProvider};
 
 use super::Key;
 
 pub trait InputReader: Sized {
+    /// Hook called when a prompt starts, before the keys answering it are read.
+    fn start_prompt(&mut self, message: &str) -> InquireResult<()> {
+        let _ = message;
+        Ok(())
+    }
+
     fn read_key(&mut self) -> InquireResult<Key>;
+
+    /// Reads a key, returning `None` if none was pressed before the timeout elapsed.
+    ///
+    /// Readers unable to wait for input with a timeout block until a key is pressed.
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        let _ = timeout;
+        self.read_key().map(Some)
+    }
+
+    /// Whether [`read_key_timeout`](InputReader::read_key_timeout) returns
+    /// when the timeout elapses instead of blocking until a key is pressed.
+    fn supports_read_timeout(&self) -> bool {
+        false
+    }
+}
+
+impl<P> InputReader for P
+where
+    P: ResponseProvider,
+{
+    fn start_prompt(&mut self, message: &str) -> InquireResult<()> {
+        ResponseProvider::start_prompt(self, message)
+    }
+
+    fn read_key(&mut self) -> InquireResult<Key> {
+        ResponseProvider::read_key(self)
+    }
+
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        ResponseProvider::read_key_timeout(self, timeout)
+    }
+
+    fn supports_read_timeout(&self) -> bool {
+        ResponseProvider::supports_read_timeout(self)
+    }
 }
@@ -0,0 +1,212 @@
+//! Minimal inline markup for messages: `**bold**`, `*italic*`, `` `code` ``
+//! and `{color}colored{/}` spans.
+
+use super::{Attributes, Color, StyleSheet, Styled};
+
+/// Splits the text into styled segments according to its inline markup.
+///
+/// Markers without a matching closing marker, as well as unknown color names,
+/// are rendered as-is. Markers can be escaped with a backslash.
+pub fn parse_markup(text: &str, base: StyleSheet, code: StyleSheet) -> Vec<Styled<String>> {
+    let mut parser = MarkupParser {
+        base,
+        code,
+        segments: vec![],
+        current: String::new(),
+        bold: false,
+        italic: false,
+        in_code: false,
+        colors: vec![],
+    };
+
+    let mut i = 0;
+    while i < text.len() {
+        i += parser.step(&text[i..]);
+    }
+    parser.flush();
+
+    parser.segments
+}
+
+/// Text without its inline markup.
+pub fn strip_markup(text: &str) -> String {
+    parse_markup(text, StyleSheet::empty(), StyleSheet::empty())
+        .into_iter()
+        .map(|segment| segment.content)
+        .collect()
+}
+
+struct MarkupParser {
+    base: StyleSheet,
+    code: StyleSheet,
+    segments: Vec<Styled<String>>,
+    current: String,
+    bold: bool,
+    italic: bool,
+    in_code: bool,
+    colors: Vec<Color>,
+}
+
+impl MarkupParser {
+    /// Consumes the start of the remaining text, returning the number of bytes consumed.
+    fn step(&mut self, rest: &str) -> usize {
+        if self.in_code {
+            if rest.starts_with('`') {
+                self.flush();
+                self.in_code = false;
+                return 1;
+            }
+        } else if let Some(escaped) = rest
+            .strip_prefix('\\')
+            .and_then(|r| r.chars().next())
+            .filter(|c| matches!(c, '*' | '`' | '{' | '\\'))
+        {
+            self.current.push(escaped);
+            return 2;
+        } else if rest.starts_with("**") && (self.bold || rest[2..].contains("**")) {
+            self.flush();
+            self.bold = !self.bold;
+            return 2;
+        } else if rest.starts_with('*') && (self.italic || rest[1..].contains('*')) {
+            self.flush();
+            self.italic = !self.italic;
+            return 1;
+        } else if rest.starts_with('`') && rest[1..].contains('`') {
+            self.flush();
+            self.in_code = true;
+            return 1;
+        } else if rest.starts_with("{/}") && !self.colors.is_empty() {
+            self.flush();
+            self.colors.pop();
+            return 3;
+        } else if let Some((name, len)) = rest
+            .strip_prefix('{')
+            .and_then(|r| r.find('}').map(|end| (&r[..end], end + 2)))
+        {
+            if let Some(color) = color_by_name(name) {
+                self.flush();
+                self.colors.push(color);
+                return len;
+            }
+        }
+
+        let c = rest.chars().next().unwrap_or_default();
+        self.current.push(c);
+        c.len_utf8()
+    }
+
+    fn flush(&mut self) {
+        if self.current.is_empty() {
+            return;
+        }
+
+        let mut style = self.base;
+
+        if self.bold {
+            style.att |= Attributes::BOLD;
+        }
+        if self.italic {
+            style.att |= Attributes::ITALIC;
+        }
+        if let Some(color) = self.colors.last() {
+            style.fg = Some(*color);
+        }
+        if self.in_code {
+            style.fg = self.code.fg.or(style.fg);
+            style.bg = self.code.bg.or(style.bg);
+            style.att |= self.code.att;
+        }
+
+        let content = std::mem::take(&mut self.current);
+        self.segments
+            .push(Styled::new(content).with_style_sheet(style));
+    }
+}
+
+fn color_by_name(name: &str) -> Option<Color> {
+    let color = match name
+        .to_ascii_lowercase()
+        .replace(['-', '_', ' '], "")
+        .as_str()
+    {
+        "black" => Color::Black,
+        "red" | "darkred" => Color::DarkRed,
+        "lightred" => Color::LightRed,
+        "green" | "darkgreen" => Color::DarkGreen,
+        "lightgreen" => Color::LightGreen,
+        "yellow" | "darkyellow" => Color::DarkYellow,
+        "lightyellow" => Color::LightYellow,
+        "blue" | "darkblue" => Color::DarkBlue,
+        "lightblue" => Color::LightBlue,
+        "magenta" | "darkmagenta" => Color::DarkMagenta,
+        "lightmagenta" => Color::LightMagenta,
+        "cyan" | "darkcyan" => Color::DarkCyan,
+        "lightcyan" => Color::LightCyan,
+        "white" => Color::White,
+        "grey" | "gray" => Color::Grey,
+        "darkgrey" | "darkgray" => Color::DarkGrey,
+        _ => return None,
+    };
+
+    Some(color)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_markup, strip_markup};
+    use crate::ui::{Attributes, Color, StyleSheet};
+
+    fn segments(text: &str) -> Vec<(String, StyleSheet)> {
+        let code = StyleSheet::new().with_fg(Color::LightYellow);
+
+        parse_markup(text, StyleSheet::empty(), code)
+            .into_iter()
+            .map(|s| (s.content, s.style))
+            .collect()
+    }
+
+    #[test]
+    fn emphasis_maps_to_attributes() {
+        assert_eq!(
+            vec![
+                ("Deploy ".to_string(), StyleSheet::empty()),
+                (
+                    "now".to_string(),
+                    StyleSheet::empty().with_attr(Attributes::BOLD)
+                ),
+                (" or ".to_string(), StyleSheet::empty()),
+                (
+                    "later".to_string(),
+                    StyleSheet::empty().with_attr(Attributes::ITALIC)
+                ),
+            ],
+            segments("Deploy **now** or *later*")
+        );
+    }
+
+    #[test]
+    fn code_spans_and_colors_map_to_styles() {
+        assert_eq!(
+            vec![
+                ("Run ".to_string(), StyleSheet::empty()),
+                (
+                    "cargo *test*".to_string(),
+                    StyleSheet::new().with_fg(Color::LightYellow)
+                ),
+                (" on ".to_string(), StyleSheet::empty()),
+                (
+                    "prod".to_string(),
+                    StyleSheet::new().with_fg(Color::DarkRed)
+                ),
+            ],
+            segments("Run `cargo *test*` on {red}prod{/}")
+        );
+    }
+
+    #[test]
+    fn unmatched_markers_are_rendered_as_is() {
+        assert_eq!("2 * 3 = {six} {/}", strip_markup("2 * 3 = {six} {/}"));
+        assert_eq!("a*b*", strip_markup("a\\*b\\*"));
+        assert_eq!("a `b", strip_markup("a `b"));
+    }
+}
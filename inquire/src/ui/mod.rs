@@ -1,10 +1,13 @@
 //! UI-related definitions for rendered content.
 
 mod api;
+mod ascii;
 mod backend;
 pub(crate) mod dimension;
 mod frame_renderer;
 mod input_reader;
+pub(crate) mod markup;
+pub(crate) mod width;
 
 pub(crate) use backend::*;
 pub(crate) use input_reader::*;
@@ -0,0 +1,181 @@
+//! Measurement of the width of rendered text in terminal columns.
+
+use std::borrow::Cow;
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::ansi::AnsiStrippable;
+
+use super::{AmbiguousWidth, Truncation};
+
+const ELLIPSIS: char = '…';
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+const EMOJI_PRESENTATION_SELECTOR: char = '\u{FE0F}';
+const SKIN_TONE_MODIFIERS: std::ops::RangeInclusive<char> = '\u{1F3FB}'..='\u{1F3FF}';
+
+/// Measures the width of characters written one after the other, taking into
+/// account emoji sequences that terminals render as a single wide glyph.
+#[derive(Copy, Clone, Debug)]
+pub struct WidthCounter {
+    ambiguous_width: AmbiguousWidth,
+    previous: Option<(char, usize)>,
+}
+
+impl WidthCounter {
+    pub fn new(ambiguous_width: AmbiguousWidth) -> Self {
+        Self {
+            ambiguous_width,
+            previous: None,
+        }
+    }
+
+    pub fn ambiguous_width(&self) -> AmbiguousWidth {
+        self.ambiguous_width
+    }
+
+    /// Forgets the previous character, e.g. after a line break.
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+
+    /// Returns the number of columns the character adds to the text written so far.
+    pub fn next(&mut self, c: char) -> usize {
+        let width = match self.previous {
+            // the emoji selector turns the previous narrow character into an emoji
+            Some((_, 1)) if c == EMOJI_PRESENTATION_SELECTOR => 1,
+            // characters joined to the previous emoji share its glyph
+            Some((ZERO_WIDTH_JOINER, _)) => 0,
+            Some((_, 2)) if SKIN_TONE_MODIFIERS.contains(&c) => 0,
+            _ => char_width(c, self.ambiguous_width),
+        };
+
+        self.previous = Some((c, width));
+        width
+    }
+}
+
+/// Width of a single character, where ambiguous-width characters are
+/// measured according to the given policy.
+pub fn char_width(c: char, ambiguous_width: AmbiguousWidth) -> usize {
+    let width = match ambiguous_width {
+        AmbiguousWidth::Narrow => c.width(),
+        AmbiguousWidth::Wide => c.width_cjk(),
+    };
+
+    width.unwrap_or(0)
+}
+
+/// Width of a string, ignoring ANSI escape sequences.
+pub fn str_width(s: &str, ambiguous_width: AmbiguousWidth) -> usize {
+    let mut counter = WidthCounter::new(ambiguous_width);
+
+    s.ansi_stripped_chars().map(|c| counter.next(c)).sum()
+}
+
+/// Shortens the string to fit in the given width, replacing the removed part
+/// with an ellipsis according to the truncation strategy.
+///
+/// Strings that already fit are returned untouched. Otherwise, ANSI escape
+/// sequences are stripped from the result.
+pub fn truncate(
+    s: &str,
+    max_width: usize,
+    truncation: Truncation,
+    ambiguous_width: AmbiguousWidth,
+) -> Cow<'_, str> {
+    if str_width(s, ambiguous_width) <= max_width {
+        return Cow::Borrowed(s);
+    }
+
+    let ellipsis_width = char_width(ELLIPSIS, ambiguous_width);
+    if max_width < ellipsis_width {
+        return Cow::Owned(String::new());
+    }
+    let budget = max_width - ellipsis_width;
+
+    let mut counter = WidthCounter::new(ambiguous_width);
+    let chars: Vec<(char, usize)> = s
+        .ansi_stripped_chars()
+        .map(|c| (c, counter.next(c)))
+        .collect();
+
+    let (head_budget, tail_budget) = match truncation {
+        Truncation::End => (budget, 0),
+        Truncation::Middle => (budget - budget / 2, budget / 2),
+        Truncation::Start => (0, budget),
+    };
+
+    let head = take_width(chars.iter(), head_budget);
+    let tail = take_width(chars.iter().rev(), tail_budget);
+
+    let mut truncated: String = chars[..head].iter().map(|(c, _)| c).collect();
+    truncated.push(ELLIPSIS);
+    truncated.extend(chars[chars.len() - tail..].iter().map(|(c, _)| c));
+
+    Cow::Owned(truncated)
+}
+
+/// Number of characters that fit in the given width.
+fn take_width<'a>(chars: impl Iterator<Item = &'a (char, usize)>, max_width: usize) -> usize {
+    let mut width = 0;
+
+    chars
+        .take_while(|(_, w)| {
+            width += w;
+            width <= max_width
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{str_width, truncate};
+    use crate::ui::{AmbiguousWidth, Truncation};
+
+    #[test]
+    fn ambiguous_characters_follow_policy() {
+        assert_eq!(3, str_width("±°§", AmbiguousWidth::Narrow));
+        assert_eq!(6, str_width("±°§", AmbiguousWidth::Wide));
+        assert_eq!(3, str_width("abc", AmbiguousWidth::Wide));
+    }
+
+    #[test]
+    fn emoji_sequences_are_measured_as_one_glyph() {
+        assert_eq!(2, str_width("❤\u{FE0F}", AmbiguousWidth::Narrow));
+        assert_eq!(2, str_width("👍\u{1F3FD}", AmbiguousWidth::Narrow));
+        assert_eq!(
+            2,
+            str_width("👨\u{200D}👩\u{200D}👧", AmbiguousWidth::Narrow)
+        );
+        assert_eq!(4, str_width("🚀 a", AmbiguousWidth::Narrow));
+    }
+
+    #[test]
+    fn escape_sequences_have_no_width() {
+        assert_eq!(5, str_width("\x1b[1mhello\x1b[0m", AmbiguousWidth::Narrow));
+    }
+
+    #[test]
+    fn truncation_places_ellipsis_according_to_strategy() {
+        let path = "src/prompts/select/mod.rs";
+        let narrow = AmbiguousWidth::Narrow;
+
+        assert_eq!("src/prom…", truncate(path, 9, Truncation::End, narrow));
+        assert_eq!("src/…d.rs", truncate(path, 9, Truncation::Middle, narrow));
+        assert_eq!("…t/mod.rs", truncate(path, 9, Truncation::Start, narrow));
+        assert_eq!(path, truncate(path, 40, Truncation::Middle, narrow));
+    }
+
+    #[test]
+    fn truncation_does_not_split_wide_characters() {
+        assert_eq!(
+            "日本…",
+            truncate("日本語です", 6, Truncation::End, AmbiguousWidth::Narrow)
+        );
+        assert_eq!(
+            "日…",
+            truncate("日本語です", 4, Truncation::End, AmbiguousWidth::Wide)
+        );
+    }
+}
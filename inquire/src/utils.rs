@@ -1,6 +1,13 @@
 // sorry for this file
 
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
+
+use crate::{
+    formatter::{LiveOptionFormatter, OptionState},
+    list_option::ListOption,
+    type_aliases::OptionKey,
+    ui::StyleSheet,
+};
 
 pub struct Page<'a, T> {
     /// Whether this is the first page.
@@ -19,6 +26,44 @@ pub struct Page<'a, T> {
     pub total: usize,
 }
 
+impl<'a, T> Page<'a, T> {
+    /// Same page with its content replaced, e.g. by a formatted version of it.
+    pub fn with_content<U>(&self, content: &'a [U]) -> Page<'a, U> {
+        Page {
+            first: self.first,
+            last: self.last,
+            content,
+            cursor: self.cursor,
+            total: self.total,
+        }
+    }
+}
+
+/// Options of the page formatted by the given formatter, along with the style
+/// of each one.
+pub fn format_page<T>(
+    page: &Page<'_, ListOption<&T>>,
+    formatter: LiveOptionFormatter<'_, T>,
+    is_checked: impl Fn(usize) -> bool,
+) -> (Vec<ListOption<String>>, Vec<StyleSheet>) {
+    page.content
+        .iter()
+        .enumerate()
+        .map(|(idx, option)| {
+            let state = OptionState {
+                highlighted: page.cursor == Some(idx),
+                checked: is_checked(option.index),
+            };
+            let formatted = formatter(option.value, state);
+
+            (
+                ListOption::new(option.index, formatted.content),
+                formatted.style,
+            )
+        })
+        .unzip()
+}
+
 pub fn paginate<T>(page_size: usize, choices: &[T], sel: Option<usize>) -> Page<'_, T> {
     // if there is no selection, we default to the first page.
     // in practice, the same as selecting the 0 index.
@@ -68,6 +113,119 @@ pub fn paginate<T>(page_size: usize, choices: &[T], sel: Option<usize>) -> Page<
     }
 }
 
+/// Index of the first visible choice of a scrolling window that keeps at least
+/// `scrolloff` choices visible above and below the cursor, moving the previous
+/// window as little as possible.
+pub fn scroll_window_start(
+    page_size: usize,
+    total: usize,
+    sel: Option<usize>,
+    scrolloff: usize,
+    previous_start: usize,
+) -> usize {
+    if total <= page_size {
+        return 0;
+    }
+
+    let max_start = total - page_size;
+    let scrolloff = scrolloff.min(page_size.saturating_sub(1) / 2);
+    let mut start = previous_start.min(max_start);
+
+    if let Some(index) = sel {
+        if index < start + scrolloff {
+            start = index.saturating_sub(scrolloff);
+        } else if index + scrolloff >= start + page_size {
+            start = index + scrolloff + 1 - page_size;
+        }
+    }
+
+    start.min(max_start)
+}
+
+/// Same as [paginate], with the window starting at the given index instead of
+/// being centered on the cursor.
+pub fn paginate_from<T>(
+    page_size: usize,
+    choices: &[T],
+    sel: Option<usize>,
+    start: usize,
+) -> Page<'_, T> {
+    let start = start.min(choices.len().saturating_sub(page_size));
+    let end = (start + page_size).min(choices.len());
+
+    Page {
+        first: start == 0,
+        last: end == choices.len(),
+        content: &choices[start..end],
+        cursor: sel.and_then(|index| index.checked_sub(start)),
+        total: choices.len(),
+    }
+}
+
+/// Longest prefix shared by all the given strings, ending at a char boundary.
+pub fn longest_common_prefix<'a>(values: &[&'a str]) -> &'a str {
+    let Some((first, rest)) = values.split_first() else {
+        return "";
+    };
+
+    let mut len = first.len();
+    for value in rest {
+        len = first
+            .char_indices()
+            .zip(value.chars())
+            .find(|((_, a), b)| a != b)
+            .map(|((idx, _), _)| idx)
+            .unwrap_or_else(|| first.len().min(value.len()))
+            .min(len);
+    }
+
+    &first[..len]
+}
+
+/// Score of a value against a filter made of space-separated terms, fzf-style:
+/// every term is required to match according to `score_term`, and the scores
+/// are summed, while terms prefixed by `!` exclude the values containing them,
+/// case-insensitively. A filter with no required terms matches every value not
+/// excluded.
+pub fn extended_filter_score<F>(filter: &str, value: &str, score_term: F) -> Option<i64>
+where
+    F: Fn(&str) -> Option<i64>,
+{
+    let lowercase_value = value.to_lowercase();
+    let mut score = 0;
+
+    for term in filter.split_whitespace() {
+        match term.strip_prefix('!') {
+            Some(excluded) if !excluded.is_empty() => {
+                if lowercase_value.contains(&excluded.to_lowercase()) {
+                    return None;
+                }
+            }
+            _ => score += score_term(term)?,
+        }
+    }
+
+    Some(score)
+}
+
+/// Stable key of each option, computed by the given function or defaulting
+/// to the string value of the option.
+pub fn option_keys<T: Display>(options: &[T], option_key: Option<OptionKey<'_, T>>) -> Vec<String> {
+    match option_key {
+        Some(option_key) => options.iter().map(option_key).collect(),
+        None => options.iter().map(T::to_string).collect(),
+    }
+}
+
+/// Formatted answer of a prompt editing a setting, showing the transition
+/// from the current value when the answer changes it, e.g. `30s → 45s`.
+pub fn format_edited_answer(current: Option<String>, answer: String) -> String {
+    match current {
+        Some(current) if current != answer => format!("{current} → {answer}"),
+        _ => answer,
+    }
+}
+
 pub fn int_log10<T>(mut i: T) -> usize
 where
     T: std::ops::DivAssign + PartialOrd + From<u8> + Copy,
@@ -102,7 +260,10 @@ mod test {
 
     use crate::{
         list_option::ListOption,
-        utils::{int_log10, paginate},
+        utils::{
+            extended_filter_score, int_log10, longest_common_prefix, paginate, paginate_from,
+            scroll_window_start,
+        },
     };
 
     impl<T> ListOption<T> {
@@ -260,4 +421,71 @@ mod test {
         assert_eq!(false, page.last);
         assert_eq!(6, page.total);
     }
+
+    #[test]
+    fn scroll_window_only_moves_within_scrolloff_of_the_edges() {
+        // window of 5 over 20 choices, keeping 1 choice of context
+        assert_eq!(0, scroll_window_start(5, 20, Some(3), 1, 0));
+        assert_eq!(1, scroll_window_start(5, 20, Some(4), 1, 0));
+        assert_eq!(1, scroll_window_start(5, 20, Some(2), 1, 1));
+        assert_eq!(0, scroll_window_start(5, 20, Some(1), 1, 1));
+        assert_eq!(15, scroll_window_start(5, 20, Some(19), 1, 0));
+        assert_eq!(0, scroll_window_start(5, 3, Some(2), 1, 0));
+    }
+
+    #[test]
+    fn scroll_window_scrolloff_is_capped_to_half_the_window() {
+        // a huge scrolloff keeps the cursor centered
+        assert_eq!(8, scroll_window_start(5, 20, Some(10), 100, 0));
+        assert_eq!(8, scroll_window_start(5, 20, Some(10), 100, 12));
+    }
+
+    #[test]
+    fn paginate_from_uses_given_start() {
+        let choices = ListOption::from_list(vec!["1", "2", "3", "4", "5", "6"]);
+
+        let page = paginate_from(3, &choices, Some(3), 2);
+        assert_eq!(&choices[2..5], page.content);
+        assert_eq!(Some(1), page.cursor);
+        assert_eq!(false, page.first);
+        assert_eq!(false, page.last);
+
+        let page = paginate_from(3, &choices, Some(5), 10);
+        assert_eq!(&choices[3..6], page.content);
+        assert_eq!(Some(2), page.cursor);
+        assert_eq!(true, page.last);
+    }
+
+    #[test]
+    fn longest_common_prefix_works() {
+        assert_eq!(
+            "deployment/api-",
+            longest_common_prefix(&["deployment/api-gateway", "deployment/api-server"])
+        );
+        assert_eq!("ñan", longest_common_prefix(&["ñandu", "ñandú", "ñan"]));
+        assert_eq!("", longest_common_prefix(&["pod", "service"]));
+        assert_eq!("pod", longest_common_prefix(&["pod"]));
+        assert_eq!("", longest_common_prefix(&[]));
+    }
+
+    #[test]
+    fn extended_filter_score_requires_every_term_and_excludes_negated_ones() {
+        let score_term = |value: &str, term: &str| {
+            value
+                .to_lowercase()
+                .contains(term)
+                .then_some(term.len() as i64)
+        };
+        let score = |filter: &str, value: &str| {
+            extended_filter_score(filter, value, |term| score_term(value, term))
+        };
+
+        assert_eq!(Some(8), score("prod west", "api-prod-us-west"));
+        assert_eq!(None, score("prod east", "api-prod-us-west"));
+        assert_eq!(Some(4), score("prod !east", "api-prod-us-west"));
+        assert_eq!(None, score("prod !EAST", "api-prod-us-east"));
+        assert_eq!(Some(0), score("!east", "api-prod-us-west"));
+        assert_eq!(Some(0), score("", "api-prod-us-west"));
+        assert_eq!(None, score("!", "api-prod-us-west"));
+    }
 }
@@ -189,6 +189,114 @@ where
 pub trait MultiOptionValidator<T: ?Sized>: DynClone {
     /// Confirm the given input list is a valid value.
     fn validate(&self, input: &[ListOption<&T>]) -> Result<Validation, CustomUserError>;
+
+    /// Confirm the given input list is a valid value, with access to the
+    /// context of the prompt, such as all of its options.
+    ///
+    /// Prompts call this method, which by default ignores the context and
+    /// delegates to [`validate`](MultiOptionValidator::validate).
+    fn validate_with_context(
+        &self,
+        input: &[ListOption<&T>],
+        context: &MultiOptionContext<'_, T>,
+    ) -> Result<Validation, CustomUserError> {
+        let _ = context;
+        self.validate(input)
+    }
+}
+
+/// Context of a prompt with multiple options at the time of validation.
+#[derive(Debug)]
+pub struct MultiOptionContext<'a, T: ?Sized> {
+    /// Message of the prompt.
+    pub message: &'a str,
+
+    /// All options of the prompt, selected or not, along with their indexes.
+    pub options: &'a [ListOption<&'a T>],
+
+    /// Filter text typed by the user, if the prompt supports filtering.
+    pub filter: Option<&'a str>,
+}
+
+impl<'a, T: ?Sized> MultiOptionContext<'a, T> {
+    /// Creates a context with the given prompt message and options, and no filter text.
+    pub fn new(message: &'a str, options: &'a [ListOption<&'a T>]) -> Self {
+        Self {
+            message,
+            options,
+            filter: None,
+        }
+    }
+
+    /// Sets the filter text typed by the user.
+    pub fn with_filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+}
+
+/// [`MultiOptionValidator`] built from a closure that also receives the
+/// [`MultiOptionContext`] of the prompt.
+///
+/// When called through [`validate`](MultiOptionValidator::validate), outside of
+/// a prompt, the closure receives an empty context.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::list_option::ListOption;
+/// use inquire::validator::{MultiOptionContext, MultiOptionContextValidator, MultiOptionValidator, Validation};
+///
+/// let validator = MultiOptionContextValidator::new(
+///     |input: &[ListOption<&&str>], context: &MultiOptionContext<'_, &str>| {
+///         let required = context.options.len().min(3);
+///         match input.len() >= required {
+///             true => Ok(Validation::Valid),
+///             false => Ok(Validation::Invalid(
+///                 format!("You selected {} of the {} required items", input.len(), required).into(),
+///             )),
+///         }
+///     },
+/// );
+///
+/// let options = vec![ListOption::new(0, &"a"), ListOption::new(1, &"b")];
+/// let context = MultiOptionContext::new("Pick:", &options);
+///
+/// assert_eq!(
+///     Validation::Invalid("You selected 1 of the 2 required items".into()),
+///     validator.validate_with_context(&options[..1], &context)?
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct MultiOptionContextValidator<F> {
+    validator: F,
+}
+
+impl<F> MultiOptionContextValidator<F> {
+    /// Creates a validator from the given closure.
+    pub fn new(validator: F) -> Self {
+        Self { validator }
+    }
+}
+
+impl<F, T> MultiOptionValidator<T> for MultiOptionContextValidator<F>
+where
+    F: Fn(&[ListOption<&T>], &MultiOptionContext<'_, T>) -> Result<Validation, CustomUserError>
+        + Clone,
+    T: ?Sized,
+{
+    fn validate(&self, input: &[ListOption<&T>]) -> Result<Validation, CustomUserError> {
+        (self.validator)(input, &MultiOptionContext::new("", &[]))
+    }
+
+    fn validate_with_context(
+        &self,
+        input: &[ListOption<&T>],
+        context: &MultiOptionContext<'_, T>,
+    ) -> Result<Validation, CustomUserError> {
+        (self.validator)(input, context)
+    }
 }
 
 impl<T> Clone for Box<dyn MultiOptionValidator<T>> {